@@ -51,6 +51,47 @@ expanded_categories = {
     'Cc': ['C'], 'Cf': ['C'], 'Cs': ['C'], 'Co': ['C'], 'Cn': ['C'],
 }
 
+# files which live outside of the top level of the UCD, and where to find them
+remote_dirs = {
+    "DerivedBidiClass.txt": "UNIDATA/extracted/",
+    "DerivedNumericValues.txt": "UNIDATA/extracted/",
+    # emoji data is versioned separately from the UCD, by UTS #51. 2.0 is the
+    # earliest release in this format and is based on Unicode 8.0; against an
+    # older UCD, check_assigned refuses it
+    "emoji-data.txt": "emoji/2.0/",
+}
+
+# The Bidi_Class values, with their long names from
+# http://www.unicode.org/reports/tr44/#Bidi_Class_Values
+bidi_class_names = [
+    ('L', 'Left_To_Right'), ('R', 'Right_To_Left'), ('AL', 'Arabic_Letter'),
+    ('EN', 'European_Number'), ('ES', 'European_Separator'),
+    ('ET', 'European_Terminator'), ('AN', 'Arabic_Number'), ('CS', 'Common_Separator'),
+    ('NSM', 'Nonspacing_Mark'), ('BN', 'Boundary_Neutral'), ('B', 'Paragraph_Separator'),
+    ('S', 'Segment_Separator'), ('WS', 'White_Space'), ('ON', 'Other_Neutral'),
+    ('LRE', 'Left_To_Right_Embedding'), ('LRO', 'Left_To_Right_Override'),
+    ('RLE', 'Right_To_Left_Embedding'), ('RLO', 'Right_To_Left_Override'),
+    ('PDF', 'Pop_Directional_Format'), ('LRI', 'Left_To_Right_Isolate'),
+    ('RLI', 'Right_To_Left_Isolate'), ('FSI', 'First_Strong_Isolate'),
+    ('PDI', 'Pop_Directional_Isolate')]
+
+# The leaf categories, with their long names from
+# http://www.unicode.org/reports/tr44/#General_Category_Values
+general_category_names = [
+    ('Lu', 'Uppercase_Letter'), ('Ll', 'Lowercase_Letter'), ('Lt', 'Titlecase_Letter'),
+    ('Lm', 'Modifier_Letter'), ('Lo', 'Other_Letter'),
+    ('Mn', 'Nonspacing_Mark'), ('Mc', 'Spacing_Mark'), ('Me', 'Enclosing_Mark'),
+    ('Nd', 'Decimal_Number'), ('Nl', 'Letter_Number'), ('No', 'Other_Number'),
+    ('Pc', 'Connector_Punctuation'), ('Pd', 'Dash_Punctuation'),
+    ('Ps', 'Open_Punctuation'), ('Pe', 'Close_Punctuation'),
+    ('Pi', 'Initial_Punctuation'), ('Pf', 'Final_Punctuation'), ('Po', 'Other_Punctuation'),
+    ('Sm', 'Math_Symbol'), ('Sc', 'Currency_Symbol'), ('Sk', 'Modifier_Symbol'),
+    ('So', 'Other_Symbol'),
+    ('Zs', 'Space_Separator'), ('Zl', 'Line_Separator'), ('Zp', 'Paragraph_Separator'),
+    ('Cc', 'Control'), ('Cf', 'Format'), ('Cs', 'Surrogate'), ('Co', 'Private_Use'),
+    ('Cn', 'Unassigned'),
+]
+
 
 # Grapheme cluster data
 # taken from UAX29, http://www.unicode.org/reports/tr29/
@@ -77,8 +118,11 @@ grapheme_spacingmark_extra = set([0xe33, 0xeb3])
 
 def fetch(f):
     if not os.path.exists(f):
-        os.system("curl -O http://www.unicode.org/Public/UNIDATA/%s"
-                  % f)
+        subdir = "UNIDATA/"
+        if f in remote_dirs:
+            subdir = remote_dirs[f]
+        os.system("curl -O http://www.unicode.org/Public/%s%s"
+                  % (subdir, f))
 
     if not os.path.exists(f):
         sys.stderr.write("cannot load %s" % f)
@@ -92,9 +136,11 @@ def load_unicode_data(f):
     gencats = {}
     upperlower = {}
     lowerupper = {}
+    titleupper = {}
     combines = {}
     canon_decomp = {}
     compat_decomp = {}
+    range_start = None
 
     for line in fileinput.input(f):
         fields = line.split(";")
@@ -110,6 +156,15 @@ def load_unicode_data(f):
         if not is_valid_unicode(code):
             continue
 
+        # large blocks such as the CJK ideographs are only listed by their
+        # first and last code points, which share all their properties
+        if name.endswith(", First>"):
+            range_start = code
+            continue
+        codes = [code]
+        if name.endswith(", Last>"):
+            codes = range(range_start, code + 1)
+
         # generate char to char direct common and simple conversions
         # uppercase to lowercase
         if gencat == "Lu" and lowcase != "" and code_org != lowcase:
@@ -119,6 +174,12 @@ def load_unicode_data(f):
         if gencat == "Ll" and upcase != "" and code_org != upcase:
             lowerupper[code] = int(upcase, 16)
 
+        # titlecase, only where it differs from the uppercase conversion
+        # above (e.g. for the Latin digraphs)
+        upper = upcase if gencat == "Ll" and upcase != "" else code_org
+        if titlecase.strip() != "" and titlecase.strip() != upper:
+            titleupper[code] = int(titlecase, 16)
+
         # store decomposition, if given
         if decomp != "":
             if decomp.startswith('<'):
@@ -136,7 +197,7 @@ def load_unicode_data(f):
         for cat in [gencat, "Assigned"] + expanded_categories.get(gencat, []):
             if cat not in gencats:
                 gencats[cat] = []
-            gencats[cat].append(code)
+            gencats[cat].extend(codes)
 
         # record combining class, if any
         if combine != "0":
@@ -153,7 +214,8 @@ def load_unicode_data(f):
     gencats = group_cats(gencats)
     combines = to_combines(group_cats(combines))
 
-    return (canon_decomp, compat_decomp, gencats, combines, lowerupper, upperlower)
+    return (canon_decomp, compat_decomp, gencats, combines, lowerupper, upperlower,
+            titleupper)
 
 def group_cats(cats):
     cats_out = {}
@@ -190,6 +252,17 @@ def gen_unassigned(assigned):
     return ([i for i in range(0, 0xd800) if i not in assigned] +
             [i for i in range(0xe000, 0x110000) if i not in assigned])
 
+def check_assigned(f, props, unassigned):
+    # data versioned separately from the UCD must not list code points which
+    # the UCD leaves unassigned, or the two are for different Unicode versions
+    unassigned = set(ungroup_cat(unassigned))
+    for prop in props:
+        for code in ungroup_cat(props[prop]):
+            if code in unassigned:
+                sys.stderr.write("%s: %s has %s but is unassigned in UnicodeData.txt\n"
+                                 % (f, hex(code), prop))
+                exit(1)
+
 def to_combines(combs):
     combs_out = []
     for comb in combs:
@@ -213,6 +286,82 @@ def format_table_content(f, content, indent):
             line = " "*indent + chunk
     f.write(line)
 
+def load_special_casing(f):
+    fetch(f)
+    lowerfull = {}
+    upperfull = {}
+
+    for line in fileinput.input(f):
+        # unconditional entries have four fields and a trailing semicolon;
+        # conditional ones carry a fifth, and depend on context or language
+        fields = line.split("#")[0].split(";")
+        if len(fields) != 5:
+            continue
+        [code, lower, title, upper, _] = map(lambda s: s.strip(), fields)
+
+        # one-to-one mappings are already covered by UnicodeData.txt
+        code = int(code, 16)
+        lower = map(lambda c: int(c, 16), lower.split())
+        upper = map(lambda c: int(c, 16), upper.split())
+        if len(lower) > 1:
+            lowerfull[code] = lower
+        if len(upper) > 1:
+            upperfull[code] = upper
+
+    return (lowerfull, upperfull)
+
+def load_case_folding(f):
+    fetch(f)
+    folding = {}
+
+    for line in fileinput.input(f):
+        # only the common and simple foldings map to a single character;
+        # the full (F) and Turkic (T) ones are left out
+        fields = line.split("#")[0].split(";")
+        if len(fields) != 4:
+            continue
+        [code, status, mapping, _] = map(lambda s: s.strip(), fields)
+        if status in ["C", "S"]:
+            folding[int(code, 16)] = int(mapping, 16)
+
+    return folding
+
+def load_numeric_values(f):
+    fetch(f)
+    values = []
+
+    for line in fileinput.input(f):
+        # the last field holds the value as a fraction: "00BD ; 0.5 ; ; 1/2"
+        fields = line.split("#")[0].split(";")
+        if len(fields) != 4:
+            continue
+        codes = fields[0].strip().split("..")
+        lo = int(codes[0], 16)
+        hi = int(codes[-1], 16)
+        value = fields[3].strip().split("/")
+        num = int(value[0])
+        den = 1
+        if len(value) > 1:
+            den = int(value[1])
+        values.append((lo, hi, num, den))
+
+    values.sort(key=lambda v: v[0])
+    return values
+
+def load_bidi_mirroring(f):
+    fetch(f)
+    mirrors = {}
+
+    for line in fileinput.input(f):
+        # each line maps a code point to its mirrored glyph: "0028; 0029 # ..."
+        fields = line.split("#")[0].split(";")
+        if len(fields) != 2:
+            continue
+        [code, glyph] = map(lambda s: int(s.strip(), 16), fields)
+        mirrors[code] = glyph
+
+    return mirrors
+
 def load_properties(f, interestingprops):
     fetch(f)
     props = {}
@@ -326,6 +475,58 @@ def emit_property_module(f, mod, tbl, emit_fn):
             f.write("    }\n\n")
     f.write("}\n\n")
 
+def emit_category_module(f, gencats):
+    f.write("""pub mod category {
+    use core::kinds::Copy;
+    use core::slice::SlicePrelude;
+    use core::slice;
+    pub use self::GeneralCategory::*;
+
+    /// The Unicode General_Category of a character
+    #[deriving(Clone, PartialEq, Eq, Show)]
+    pub enum GeneralCategory {
+""")
+    for (cat, name) in general_category_names:
+        f.write("        /// %s\n" % name)
+        f.write("        %s,\n" % cat)
+    f.write("""    }
+
+    impl Copy for GeneralCategory {}
+
+    fn bsearch_range_value_table(c: char, r: &'static [(char, char, GeneralCategory)])
+                                 -> GeneralCategory {
+        use core::cmp::Ordering::{Equal, Less, Greater};
+        match r.binary_search(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(idx) => {
+                let (_, _, cat) = r[idx];
+                cat
+            }
+            slice::BinarySearchResult::NotFound(_) => Cn
+        }
+    }
+
+    pub fn general_category(c: char) -> GeneralCategory {
+        bsearch_range_value_table(c, general_category_table)
+    }
+
+""")
+
+    # anything not in the table is unassigned, and surrogates are not chars
+    category_table = []
+    for (cat, _) in general_category_names:
+        if cat != "Cn" and cat in gencats:
+            category_table.extend([(x, y, cat) for (x, y) in gencats[cat]])
+    category_table.sort(key=lambda w: w[0])
+    emit_table(f, "general_category_table", category_table,
+        "&'static [(char, char, GeneralCategory)]",
+        pfun=lambda x: "(%s,%s,%s)" % (escape_char(x[0]), escape_char(x[1]), x[2]),
+        is_pub=False)
+    f.write("}\n\n")
+
 def emit_regex_module(f, cats, w_data):
     f.write("pub mod regex {\n")
     regex_class = "&'static [(char, char)]"
@@ -343,7 +544,8 @@ def emit_regex_module(f, cats, w_data):
 
     f.write("}\n\n")
 
-def emit_conversions_module(f, lowerupper, upperlower):
+def emit_conversions_module(f, lowerupper, upperlower, titleupper, lowerfull, upperfull,
+                            folding):
     f.write("pub mod conversions {")
     f.write("""
     use core::cmp::Ordering::{Equal, Less, Greater};
@@ -367,6 +569,28 @@ def emit_conversions_module(f, lowerupper, upperlower):
         }
     }
 
+    pub fn to_title(c: char) -> char {
+        match bsearch_case_table(c, title_table) {
+            None        => to_upper(c),
+            Some(index) => title_table[index].val1()
+        }
+    }
+
+    pub fn fold_case(c: char) -> char {
+        match bsearch_case_table(c, fold_table) {
+            None        => c,
+            Some(index) => fold_table[index].val1()
+        }
+    }
+
+    pub fn to_lower_full(c: char) -> Option<&'static [char]> {
+        bsearch_full_case_table(c, lower_full_table)
+    }
+
+    pub fn to_upper_full(c: char) -> Option<&'static [char]> {
+        bsearch_full_case_table(c, upper_full_table)
+    }
+
     fn bsearch_case_table(c: char, table: &'static [(char, char)]) -> Option<uint> {
         match table.binary_search(|&(key, _)| {
             if c == key { Equal }
@@ -378,11 +602,134 @@ def emit_conversions_module(f, lowerupper, upperlower):
         }
     }
 
+    fn bsearch_full_case_table(c: char, table: &'static [(char, &'static [char])])
+                               -> Option<&'static [char]> {
+        match table.binary_search(|&(key, _)| {
+            if c == key { Equal }
+            else if key < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(i) => Some(table[i].val1()),
+            slice::BinarySearchResult::NotFound(_) => None,
+        }
+    }
+
 """)
     emit_table(f, "LuLl_table",
         sorted(upperlower.iteritems(), key=operator.itemgetter(0)), is_pub=False)
     emit_table(f, "LlLu_table",
         sorted(lowerupper.iteritems(), key=operator.itemgetter(0)), is_pub=False)
+    emit_table(f, "title_table",
+        sorted(titleupper.iteritems(), key=operator.itemgetter(0)), is_pub=False)
+    emit_table(f, "fold_table",
+        sorted(folding.iteritems(), key=operator.itemgetter(0)), is_pub=False)
+
+    def mkdata_fun(table):
+        def f(char):
+            return "(%s,&[%s])" % (escape_char(char), ",".join(map(escape_char, table[char])))
+        return f
+
+    lower_keys = lowerfull.keys()
+    lower_keys.sort()
+    emit_table(f, "lower_full_table", lower_keys, "&'static [(char, &'static [char])]",
+        is_pub=False, pfun=mkdata_fun(lowerfull))
+    upper_keys = upperfull.keys()
+    upper_keys.sort()
+    emit_table(f, "upper_full_table", upper_keys, "&'static [(char, &'static [char])]",
+        is_pub=False, pfun=mkdata_fun(upperfull))
+    f.write("}\n\n")
+
+def emit_numeric_module(f, values):
+    f.write("pub mod numeric {")
+    f.write("""
+    use core::cmp::Ordering::{Equal, Less, Greater};
+    use core::slice::SlicePrelude;
+    use core::option::Option;
+    use core::option::Option::{Some, None};
+    use core::slice;
+
+    pub fn numeric_value(c: char) -> Option<(i64, u32)> {
+        match numeric_value_table.binary_search(|&(lo, hi, _, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(idx) => {
+                let (_, _, num, den) = numeric_value_table[idx];
+                Some((num, den))
+            }
+            slice::BinarySearchResult::NotFound(_) => None
+        }
+    }
+
+""")
+    emit_table(f, "numeric_value_table", values, "&'static [(char, char, i64, u32)]",
+        pfun=lambda x: "(%s,%s,%d,%d)" % (escape_char(x[0]), escape_char(x[1]), x[2], x[3]),
+        is_pub=False)
+    f.write("}\n\n")
+
+def emit_bidi_module(f, bidi_classes, mirrors):
+    f.write("pub mod bidi {")
+    f.write("""
+    use core::cmp::Ordering::{Equal, Less, Greater};
+    use core::kinds::Copy;
+    use core::slice::SlicePrelude;
+    use core::tuple::Tuple2;
+    use core::option::Option;
+    use core::option::Option::{Some, None};
+    use core::slice;
+    pub use self::BidiClass::*;
+
+    /// The Unicode Bidi_Class of a character
+    #[deriving(Clone, PartialEq, Eq, Show)]
+    pub enum BidiClass {
+""")
+    for (cls, name) in bidi_class_names:
+        f.write("        /// %s\n" % name)
+        f.write("        %s,\n" % cls)
+    f.write("""    }
+
+    impl Copy for BidiClass {}
+
+    pub fn bidi_class(c: char) -> BidiClass {
+        match bidi_class_table.binary_search(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(idx) => {
+                let (_, _, cls) = bidi_class_table[idx];
+                cls
+            }
+            slice::BinarySearchResult::NotFound(_) => L
+        }
+    }
+
+    pub fn mirror(c: char) -> Option<char> {
+        match mirror_table.binary_search(|&(key, _)| {
+            if c == key { Equal }
+            else if key < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(i) => Some(mirror_table[i].val1()),
+            slice::BinarySearchResult::NotFound(_) => None,
+        }
+    }
+
+""")
+    # anything not in the table is L, which is also the default for unassigned
+    # code points outside of the right-to-left blocks
+    class_table = []
+    for (cls, _) in bidi_class_names:
+        if cls != "L" and cls in bidi_classes:
+            class_table.extend([(x, y, cls) for (x, y) in bidi_classes[cls]])
+    class_table.sort(key=lambda w: w[0])
+    emit_table(f, "bidi_class_table", class_table, "&'static [(char, char, BidiClass)]",
+        pfun=lambda x: "(%s,%s,%s)" % (escape_char(x[0]), escape_char(x[1]), x[2]),
+        is_pub=False)
+
+    emit_table(f, "mirror_table",
+        sorted(mirrors.iteritems(), key=operator.itemgetter(0)), is_pub=False)
     f.write("}\n\n")
 
 def emit_grapheme_module(f, grapheme_table, grapheme_cats):
@@ -616,7 +963,7 @@ if __name__ == "__main__":
 pub const UNICODE_VERSION: (uint, uint, uint) = (%s, %s, %s);
 """ % unicode_version)
         (canon_decomp, compat_decomp, gencats, combines,
-                lowerupper, upperlower) = load_unicode_data("UnicodeData.txt")
+                lowerupper, upperlower, titleupper) = load_unicode_data("UnicodeData.txt")
         want_derived = ["XID_Start", "XID_Continue", "Alphabetic", "Lowercase", "Uppercase"]
         other_derived = ["Default_Ignorable_Code_Point", "Grapheme_Extend"]
         derived = load_properties("DerivedCoreProperties.txt", want_derived + other_derived)
@@ -625,6 +972,14 @@ pub const UNICODE_VERSION: (uint, uint, uint) = (%s, %s, %s);
                 ["White_Space", "Join_Control", "Noncharacter_Code_Point"])
         norm_props = load_properties("DerivedNormalizationProps.txt",
                      ["Full_Composition_Exclusion"])
+        (lowerfull, upperfull) = load_special_casing("SpecialCasing.txt")
+        want_emoji = ["Emoji", "Emoji_Presentation"]
+        emoji = load_properties("emoji-data.txt", want_emoji)
+        check_assigned("emoji-data.txt", emoji, gencats["Cn"])
+        bidi_classes = load_properties("DerivedBidiClass.txt", [])
+        mirrors = load_bidi_mirroring("BidiMirroring.txt")
+        numeric_values = load_numeric_values("DerivedNumericValues.txt")
+        folding = load_case_folding("CaseFolding.txt")
 
         # grapheme cluster category from DerivedCoreProperties
         # the rest are defined below
@@ -637,7 +992,7 @@ pub const UNICODE_VERSION: (uint, uint, uint) = (%s, %s, %s);
 
         # all of these categories will also be available as \p{} in libregex
         allcats = []
-        for (name, cat, pfuns) in ("general_category", gencats, ["N", "Cc"]), \
+        for (name, cat, pfuns) in ("general_category", gencats, ["N", "M", "Cc"]), \
                                   ("derived_property", derived, want_derived), \
                                   ("script", scripts, []), \
                                   ("property", props, ["White_Space"]):
@@ -645,6 +1000,11 @@ pub const UNICODE_VERSION: (uint, uint, uint) = (%s, %s, %s);
             allcats.extend(map(lambda x: (x, name), cat))
         allcats.sort(key=lambda c: c[0])
 
+        # not exposed as \p{} classes, since they are not part of the UCD proper
+        emit_property_module(rf, "emoji", emoji, want_emoji)
+
+        emit_category_module(rf, gencats)
+
         # the \w regex corresponds to Alphabetic + Mark + Decimal_Number +
         # Connector_Punctuation + Join-Control according to UTS#18
         # http://www.unicode.org/reports/tr18/#Compatibility_Properties
@@ -659,7 +1019,10 @@ pub const UNICODE_VERSION: (uint, uint, uint) = (%s, %s, %s);
 
         # normalizations and conversions module
         emit_norm_module(rf, canon_decomp, compat_decomp, combines, norm_props)
-        emit_conversions_module(rf, lowerupper, upperlower)
+        emit_conversions_module(rf, lowerupper, upperlower, titleupper,
+                                lowerfull, upperfull, folding)
+        emit_bidi_module(rf, bidi_classes, mirrors)
+        emit_numeric_module(rf, numeric_values)
 
         ### character width module
         width_table = []
@@ -17,6 +17,7 @@ use core::prelude::*;
 use alloc::boxed::Box;
 use alloc::heap::{EMPTY, allocate, reallocate, deallocate};
 use core::borrow::{Cow, IntoCow};
+use core::char::CharWriter;
 use core::cmp::max;
 use core::default::Default;
 use core::fmt;
@@ -1753,6 +1754,13 @@ impl<'a> fmt::FormatWriter for Vec<u8> {
     }
 }
 
+impl CharWriter for Vec<u8> {
+    #[inline]
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        self.push_all(bytes);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate test;
@@ -15,17 +15,24 @@
 #![allow(non_snake_case)]
 #![doc(primitive = "char")]
 
+use kinds::Copy;
 use mem::transmute;
+use num::Int;
 use option::Option;
 use option::Option::{None, Some};
-use iter::{range_step, Iterator, RangeStep};
+use result::Result;
+use result::Result::{Ok, Err};
+use iter::{range, Iterator, IteratorExt, DoubleEndedIterator, ExactSizeIterator};
+use iter::Peekable;
 use slice::SlicePrelude;
+use str::StrPrelude;
 
 // UTF-8 ranges and tags for encoding characters
 static TAG_CONT: u8    = 0b1000_0000u8;
 static TAG_TWO_B: u8   = 0b1100_0000u8;
 static TAG_THREE_B: u8 = 0b1110_0000u8;
 static TAG_FOUR_B: u8  = 0b1111_0000u8;
+static CONT_MASK: u8   = 0b0011_1111u8;
 static MAX_ONE_B: u32   =     0x80u32;
 static MAX_TWO_B: u32   =    0x800u32;
 static MAX_THREE_B: u32 =  0x10000u32;
@@ -63,19 +70,160 @@ static MAX_THREE_B: u32 =  0x10000u32;
     Cn  Unassigned              a reserved unassigned code point or a noncharacter
 */
 
+/// The lowest valid code point
+#[stable]
+pub const MIN: char = '\0';
+
 /// The highest valid code point
 #[stable]
 pub const MAX: char = '\u{10ffff}';
 
+/// U+FFFD REPLACEMENT CHARACTER, which is used in place of input that could
+/// not be decoded
+#[unstable = "pending decisions about the decoding API"]
+pub const REPLACEMENT_CHARACTER: char = '\u{fffd}';
+
+/// The maximum number of bytes a `char` needs when encoded in UTF-8
+#[unstable = "pending trait organization"]
+pub const MAX_UTF8_LEN: uint = 4;
+
+/// The maximum number of `u16`s a `char` needs when encoded in UTF-16
+#[unstable = "pending trait organization"]
+pub const MAX_UTF16_LEN: uint = 2;
+
 /// Converts from `u32` to a `char`
 #[inline]
 #[unstable = "pending decisions about costructors for primitives"]
 pub fn from_u32(i: u32) -> Option<char> {
+    from_u32_detailed(i).ok()
+}
+
+/// Converts from `u32` to a `char`, reporting why the conversion failed
+///
+/// # Return value
+///
+/// Returns `Err` holding the rejected value if it is a surrogate or is
+/// above `MAX`.
+#[inline]
+#[unstable = "pending error conventions"]
+pub fn from_u32_detailed(i: u32) -> Result<char, CharTryFromError> {
     // catch out-of-bounds and surrogates
-    if (i > MAX as u32) || (i >= 0xD800 && i <= 0xDFFF) {
-        None
+    if i > MAX as u32 {
+        Err(CharTryFromError::OutOfRange(i))
+    } else if is_surrogate(i) {
+        Err(CharTryFromError::Surrogate(i))
+    } else {
+        Ok(unsafe { from_u32_unchecked(i) })
+    }
+}
+
+/// The error returned by `from_u32_detailed` for a value that is not a
+/// Unicode scalar value.
+#[deriving(Clone, PartialEq, Eq, Show)]
+#[unstable = "pending error conventions"]
+pub enum CharTryFromError {
+    /// The value is a surrogate code point, in the range [0xD800,0xDFFF].
+    Surrogate(u32),
+    /// The value is greater than `MAX`.
+    OutOfRange(u32),
+}
+
+impl Copy for CharTryFromError {}
+
+impl CharTryFromError {
+    /// Returns the value that could not be converted.
+    #[inline]
+    #[unstable = "pending error conventions"]
+    pub fn value(&self) -> u32 {
+        match *self {
+            CharTryFromError::Surrogate(i) | CharTryFromError::OutOfRange(i) => i
+        }
+    }
+}
+
+/// Converts from `u32` to a `char`, without checking that the value is a
+/// valid Unicode scalar value.
+///
+/// This is intended for decoders that have already validated their input,
+/// and want to avoid the redundant check performed by `from_u32`.
+///
+/// # Safety
+///
+/// Passing a surrogate code point (`0xD800` to `0xDFFF`) or a value greater
+/// than `MAX` results in undefined behavior.
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub unsafe fn from_u32_unchecked(i: u32) -> char {
+    transmute(i)
+}
+
+/// Converts from `u32` to a `char`, substituting `REPLACEMENT_CHARACTER` for
+/// values that are not valid Unicode scalar values.
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn from_u32_or_replacement(i: u32) -> char {
+    from_u32(i).unwrap_or(REPLACEMENT_CHARACTER)
+}
+
+/// Converts an ASCII byte to a `char`
+///
+/// # Return value
+///
+/// Returns `None` if the byte is not ASCII, i.e. is 0x80 or above.
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn from_ascii(b: u8) -> Option<char> {
+    if b < 0x80 { Some(b as char) } else { None }
+}
+
+/// Converts an ASCII byte to a `char`, without checking that it is ASCII
+///
+/// The caller must guarantee that `b` is below 0x80.
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub unsafe fn from_ascii_unchecked(b: u8) -> char {
+    b as char
+}
+
+/// Returns whether a code point is a surrogate, in the range [0xD800,0xDFFF]
+///
+/// Surrogates are reserved for the UTF-16 encoding, and are not valid
+/// `char`s.
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn is_surrogate(i: u32) -> bool {
+    i >= 0xD800 && i <= 0xDFFF
+}
+
+/// Returns whether a code point is a high (or leading) surrogate, in the
+/// range [0xD800,0xDBFF]
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn is_high_surrogate(i: u32) -> bool {
+    i >= 0xD800 && i <= 0xDBFF
+}
+
+/// Returns whether a code point is a low (or trailing) surrogate, in the
+/// range [0xDC00,0xDFFF]
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn is_low_surrogate(i: u32) -> bool {
+    i >= 0xDC00 && i <= 0xDFFF
+}
+
+/// Converts from `u32` to a `CodePoint`, which unlike a `char` may also be
+/// a surrogate
+///
+/// # Return value
+///
+/// Returns `None` if the value is above `MAX`.
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn from_u32_surrogate(i: u32) -> Option<CodePoint> {
+    if i <= MAX as u32 {
+        Some(CodePoint { value: i })
     } else {
-        Some(unsafe { transmute(i) })
+        None
     }
 }
 
@@ -139,22 +287,142 @@ pub fn to_digit(c: char, radix: uint) -> Option<uint> {
 #[inline]
 #[unstable = "pending decisions about costructors for primitives"]
 pub fn from_digit(num: uint, radix: uint) -> Option<char> {
+    match try_from_digit(num, radix) {
+        Ok(c) => c,
+        Err(_) => panic!("from_digit: radix is to high (maximum 36)"),
+    }
+}
+
+///
+/// Converts a number to the character representing it, using uppercase
+/// letters
+///
+/// # Return value
+///
+/// Returns `Some(char)` if `num` represents one digit under `radix`,
+/// using one character of `0-9` or `A-Z`, or `None` if it doesn't.
+///
+/// # Panics
+///
+/// Panics if given an `radix` > 36.
+///
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn from_digit_upper(num: uint, radix: uint) -> Option<char> {
+    from_digit(num, radix).map(|c| c.to_ascii_uppercase())
+}
+
+///
+/// Converts a number to the character representing it, using uppercase or
+/// lowercase letters as chosen by `uppercase`
+///
+/// This is `from_digit_upper()` if `uppercase` is `true`, and `from_digit()`
+/// otherwise.
+///
+/// # Panics
+///
+/// Panics if given an `radix` > 36.
+///
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn digit_char(num: uint, radix: uint, uppercase: bool) -> Option<char> {
+    if uppercase {
+        from_digit_upper(num, radix)
+    } else {
+        from_digit(num, radix)
+    }
+}
+
+///
+/// Converts a number to the character representing it, without panicking
+/// on an invalid radix
+///
+/// # Return value
+///
+/// Returns `Ok(Some(char))` if `num` represents one digit under `radix`,
+/// using one character of `0-9` or `a-z`, or `Ok(None)` if it doesn't.
+/// Returns `Err` if `radix` > 36. Radices 0 and 1 have no digits at all.
+///
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn try_from_digit(num: uint, radix: uint) -> Result<Option<char>, RadixError> {
     if radix > 36 {
-        panic!("from_digit: radix is to high (maximum 36)");
+        return Err(RadixError { radix: radix });
     }
-    if num < radix {
+    if radix >= 2 && num < radix {
         unsafe {
             if num < 10 {
-                Some(transmute(('0' as uint + num) as u32))
+                Ok(Some(transmute(('0' as uint + num) as u32)))
             } else {
-                Some(transmute(('a' as uint + num - 10u) as u32))
+                Ok(Some(transmute(('a' as uint + num - 10u) as u32)))
             }
         }
     } else {
-        None
+        Ok(None)
     }
 }
 
+/// The error returned when a radix is larger than 36, the maximum supported
+/// for converting between characters and digits.
+#[deriving(Clone, PartialEq, Eq, Show)]
+#[unstable = "pending error conventions"]
+pub struct RadixError {
+    /// The radix that was rejected.
+    pub radix: uint,
+}
+
+impl Copy for RadixError {}
+
+/// The case in which a digit was written, as returned by
+/// `Char::to_digit_cased`.
+#[deriving(Clone, PartialEq, Eq, Show)]
+#[unstable = "pending trait organization"]
+pub enum Case {
+    /// An uppercase letter, `A-Z`.
+    Upper,
+    /// A lowercase letter, `a-z`.
+    Lower,
+    /// A decimal digit, `0-9`, which has no case.
+    NotApplicable,
+}
+
+impl Copy for Case {}
+
+///
+/// Converts a decimal digit to the ASCII character representing it
+///
+/// # Return value
+///
+/// Returns `Some(char)` in the range `0-9` if `d` is less than 10, or `None`
+/// if it isn't. This is the inverse of `Char::to_ascii_digit()`.
+///
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn from_ascii_digit(d: u8) -> Option<char> {
+    if d < 10 { Some((b'0' + d) as char) } else { None }
+}
+
+///
+/// Converts a number to the character representing it in base 62
+///
+/// # Return value
+///
+/// Returns `Some(char)` if `num` is less than 62, using `0-9` for 0 to 9,
+/// `A-Z` for 10 to 35 and `a-z` for 36 to 61, or `None` if it isn't. This
+/// is the inverse of `Char::to_digit_base62()`.
+///
+#[inline]
+#[unstable = "pending decisions about costructors for primitives"]
+pub fn from_digit_base62(num: uint) -> Option<char> {
+    let c = match num {
+        0 ... 9 => '0' as uint + num,
+        10 ... 35 => 'A' as uint + num - 10,
+        36 ... 61 => 'a' as uint + num - 36,
+        _ => return None
+    };
+    Some(unsafe { from_u32_unchecked(c as u32) })
+}
+
 ///
 /// Returns the hexadecimal Unicode escape of a `char`
 ///
@@ -197,6 +465,344 @@ pub fn len_utf8_bytes(c: char) -> uint {
     c.len_utf8()
 }
 
+/// Creates an iterator over the `char`s from `start` up to, but not
+/// including, `end`
+///
+/// The surrogate range [0xD800,0xDFFF] is skipped, so every value yielded is
+/// a valid `char`.
+#[inline]
+#[unstable = "pending decisions about ranges of chars"]
+pub fn range_chars(start: char, end: char) -> CharRange {
+    CharRange { start: start, end: end }
+}
+
+/// Parses a hexadecimal Unicode escape back into the `char` it represents
+///
+/// This is the inverse of `escape_unicode` and `escape_unicode_rust`. The
+/// accepted forms are `\\xNN`, `\\uNNNN` and `\\UNNNNNNNN`, with exactly 2, 4
+/// and 8 hex digits respectively, and `\\u{N}` with 1 to 6 hex digits. The
+/// hex digits may be upper or lowercase.
+///
+/// # Return value
+///
+/// Returns `None` if `s` is not exactly one such escape, or if it encodes
+/// a surrogate or a value greater than `MAX`. As in Rust `char` literals,
+/// `\\xNN` is limited to ASCII, so values above 0x7f are rejected too.
+#[unstable = "pending decisions about the decoding API"]
+pub fn parse_unicode_escape(s: &str) -> Option<char> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 3 || bytes[0] != b'\\' {
+        return None;
+    }
+    let digits = match (bytes[1], bytes.len() - 2) {
+        (b'u', n) if bytes[2] == b'{' => {
+            if n < 3 || n > 8 || bytes[n + 1] != b'}' { return None }
+            bytes[3..n + 1]
+        }
+        (b'x', 2) | (b'u', 4) | (b'U', 8) => bytes[2..],
+        _ => return None
+    };
+
+    let mut code = 0u32;
+    for &b in digits.iter() {
+        match (b as char).to_digit(16) {
+            Some(d) => code = code << 4 | d as u32,
+            None => return None
+        }
+    }
+    // the escapers only use `\x` for ASCII, as the literal syntax does
+    if bytes[1] == b'x' && code > 0x7f {
+        return None;
+    }
+    from_u32(code)
+}
+
+/// Encodes a sequence of characters as UTF-8 into the provided byte buffer,
+/// one after another
+///
+/// # Return value
+///
+/// Returns `Some(n)` with the total number of bytes written, or `None` if the
+/// buffer is not large enough. In that case, the characters before the first
+/// one which did not fit have been written to the start of the buffer, and
+/// nothing after them.
+#[inline]
+#[unstable = "pending error conventions"]
+pub fn encode_utf8_into<I: Iterator<char>>(iter: I, dst: &mut [u8]) -> Option<uint> {
+    let mut n = 0;
+    for c in iter {
+        if dst.len() - n >= MAX_UTF8_LEN {
+            // any char fits, so there is no need to check its length
+            n += unsafe { c.encode_utf8_unchecked(dst[mut n..]) };
+        } else {
+            match c.encode_utf8_len(dst[mut n..]) {
+                Ok(len) => n += len,
+                Err(_) => return None
+            }
+        }
+    }
+    Some(n)
+}
+
+/// Returns the length of the UTF-8 sequence started by the given leading
+/// byte, judging by its high bits alone
+///
+/// # Return value
+///
+/// Returns `Some(n)` with `n` from 1 to 4, or `None` if the byte is a
+/// continuation byte, or a byte in the range [0xF8,0xFF] which never
+/// appears in UTF-8. A length being returned does not mean that any valid
+/// sequence starts with the byte; 0xC0, for example, only begins overlong
+/// encodings.
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn utf8_char_width(first_byte: u8) -> Option<uint> {
+    if first_byte < TAG_CONT {
+        Some(1)
+    } else if first_byte & !0x1F == TAG_TWO_B {
+        Some(2)
+    } else if first_byte & !0x0F == TAG_THREE_B {
+        Some(3)
+    } else if first_byte & !0x07 == TAG_FOUR_B {
+        Some(4)
+    } else {
+        None
+    }
+}
+
+/// Returns whether a byte is a UTF-8 continuation byte, in the range
+/// [0x80,0xBF], and so does not start a character
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn is_utf8_continuation(b: u8) -> bool {
+    b & !CONT_MASK == TAG_CONT
+}
+
+/// Decodes a single UTF-8 encoded `char` from the front of a byte slice
+///
+/// # Return value
+///
+/// Returns `Some((c, n))`, where `n` is the number of bytes that the
+/// character `c` occupied at the start of `src`, or `None` if `src` does
+/// not start with a complete and well-formed UTF-8 sequence. Overlong
+/// encodings and encoded surrogates are not well-formed.
+#[unstable = "pending decisions about the decoding API"]
+pub fn decode_utf8(src: &[u8]) -> Option<(char, uint)> {
+    match decode_utf8_iter(src.iter().map(|&b| b)).next() {
+        // a well-formed sequence is never longer than needed
+        Some(Ok(c)) => Some((c, c.len_utf8())),
+        _ => None
+    }
+}
+
+/// Decodes a single UTF-8 encoded `char` from the front of a stream of bytes
+///
+/// Only the bytes of the sequence are consumed: a byte which interrupts a
+/// multi-byte sequence is left in `bytes`, so that the next call decodes it.
+/// Calling this in a loop yields the same items as `decode_utf8_iter`.
+///
+/// This is why `bytes` must be `Peekable`: finding out that a byte does not
+/// continue the sequence means looking at it, and a plain iterator could
+/// only hand it back by consuming it, losing the character it starts. To
+/// decode from a `&mut I` instead, use `decode_utf8_iter(bytes.by_ref())`,
+/// which holds on to the interrupting byte between items.
+///
+/// # Return value
+///
+/// Returns `None` at the end of the stream, `Some(Ok(c))` if the stream
+/// starts with a well-formed sequence, or `Some(Err(e))` if it doesn't. The
+/// error is `TruncatedSequence` if the stream ended in the middle of a
+/// multi-byte sequence.
+#[unstable = "pending decisions about the decoding API"]
+pub fn next_code_point<I: Iterator<u8>>(bytes: &mut Peekable<u8, I>)
+                                        -> Option<Result<char, Utf8Error>> {
+    decode_utf8_sequence(bytes).map(|(result, _)| result)
+}
+
+/// Creates an iterator over the UTF-8 encoded characters in a stream of
+/// bytes, yielding an error for each malformed sequence
+///
+/// After an error, decoding resumes at the first byte that was not part of
+/// the malformed sequence. Each malformed sequence is the longest prefix of
+/// a well-formed one, or else a single byte, as recommended by the Unicode
+/// Standard and required by the WHATWG Encoding Standard.
+#[unstable = "pending decisions about the decoding API"]
+pub fn decode_utf8_iter<I: Iterator<u8>>(iter: I) -> DecodeUtf8<I> {
+    DecodeUtf8 { iter: iter, buf: None }
+}
+
+/// Creates an iterator over the UTF-8 encoded characters in a stream of
+/// bytes, replacing each malformed sequence with `REPLACEMENT_CHARACTER`
+///
+/// Malformed sequences are delimited as for `decode_utf8_iter`, so this
+/// yields the same chars as the WHATWG UTF-8 decoder.
+#[unstable = "pending decisions about the decoding API"]
+pub fn decode_utf8_lossy<I: Iterator<u8>>(iter: I) -> DecodeUtf8Lossy<I> {
+    DecodeUtf8Lossy { inner: decode_utf8_iter(iter) }
+}
+
+/// Creates an iterator over the UTF-8 encoded characters in a stream of
+/// bytes, yielding each one along with the offset of its first byte
+///
+/// Malformed sequences are yielded as errors, as for `decode_utf8_iter`,
+/// with the offset at which they start.
+#[unstable = "pending decisions about the decoding API"]
+pub fn char_indices_utf8<I: Iterator<u8>>(iter: I) -> CharIndicesUtf8<I> {
+    CharIndicesUtf8 { inner: decode_utf8_iter(iter), offset: 0 }
+}
+
+/// Validates a UTF-8 encoded byte slice, and counts the characters in it
+///
+/// # Return value
+///
+/// Returns `Ok(n)` if the whole of `bytes` is well-formed and holds `n`
+/// characters, or else `Err((offset, e))` for the first malformed sequence,
+/// which starts at byte `offset`. Sequences are decoded and delimited as
+/// for `decode_utf8_iter`.
+#[unstable = "pending decisions about the decoding API"]
+pub fn utf8_char_count(bytes: &[u8]) -> Result<uint, (uint, Utf8Error)> {
+    let mut count = 0;
+    for (offset, result) in char_indices_utf8(bytes.iter().map(|&b| b)) {
+        match result {
+            Ok(_) => count += 1,
+            Err(e) => return Err((offset, e))
+        }
+    }
+    Ok(count)
+}
+
+/// Returns whether a UTF-16 code unit is a high (or leading) surrogate, in
+/// the range [0xD800,0xDBFF]
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn is_utf16_high_surrogate(u: u16) -> bool {
+    is_high_surrogate(u as u32)
+}
+
+/// Returns whether a UTF-16 code unit is a low (or trailing) surrogate, in
+/// the range [0xDC00,0xDFFF]
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn is_utf16_low_surrogate(u: u16) -> bool {
+    is_low_surrogate(u as u32)
+}
+
+/// Decodes a UTF-16 surrogate pair into the `char` it represents
+///
+/// # Return value
+///
+/// Returns `None` if `high` is not a high surrogate or `low` is not a low
+/// surrogate.
+#[inline]
+#[unstable = "pending decisions about the decoding API"]
+pub fn decode_utf16_pair(high: u16, low: u16) -> Option<char> {
+    if is_utf16_high_surrogate(high) && is_utf16_low_surrogate(low) {
+        let c = (((high - 0xD800) as u32) << 10 | (low - 0xDC00) as u32) + 0x1_0000;
+        Some(unsafe { from_u32_unchecked(c) })
+    } else {
+        None
+    }
+}
+
+/// Creates an iterator over the UTF-16 encoded characters in a stream of
+/// `u16`s, yielding an error for each unpaired surrogate
+///
+/// A high surrogate that is not followed by a low surrogate is an error,
+/// after which decoding resumes at the code unit that followed it.
+#[unstable = "pending decisions about the decoding API"]
+pub fn decode_utf16<I: Iterator<u16>>(iter: I) -> DecodeUtf16<I> {
+    DecodeUtf16 { iter: iter, buf: None }
+}
+
+/// Creates an iterator over the UTF-16 encoded characters in a stream of
+/// `u16`s, replacing each unpaired surrogate with `REPLACEMENT_CHARACTER`
+#[unstable = "pending decisions about the decoding API"]
+pub fn decode_utf16_lossy<I: Iterator<u16>>(iter: I) -> DecodeUtf16Lossy<I> {
+    DecodeUtf16Lossy { inner: decode_utf16(iter) }
+}
+
+// The number of bytes needed to encode a code point, surrogate or not, in
+// the UTF-8 scheme.
+#[inline]
+fn utf8_len(code: u32) -> uint {
+    match () {
+        _ if code < MAX_ONE_B   => 1u,
+        _ if code < MAX_TWO_B   => 2u,
+        _ if code < MAX_THREE_B => 3u,
+        _  => 4u,
+    }
+}
+
+// Encodes a code point, surrogate or not, in the UTF-8 scheme. The caller
+// must guarantee that `dst.len() >= utf8_len(code)`.
+#[inline]
+unsafe fn encode_utf8_raw_unchecked(code: u32, dst: &mut [u8]) -> uint {
+    if code < MAX_ONE_B {
+        *dst.unsafe_mut(0) = code as u8;
+        1
+    } else if code < MAX_TWO_B {
+        *dst.unsafe_mut(0) = (code >> 6u & 0x1F_u32) as u8 | TAG_TWO_B;
+        *dst.unsafe_mut(1) = (code & 0x3F_u32) as u8 | TAG_CONT;
+        2
+    } else if code < MAX_THREE_B {
+        *dst.unsafe_mut(0) = (code >> 12u & 0x0F_u32) as u8 | TAG_THREE_B;
+        *dst.unsafe_mut(1) = (code >>  6u & 0x3F_u32) as u8 | TAG_CONT;
+        *dst.unsafe_mut(2) = (code & 0x3F_u32) as u8 | TAG_CONT;
+        3
+    } else {
+        *dst.unsafe_mut(0) = (code >> 18u & 0x07_u32) as u8 | TAG_FOUR_B;
+        *dst.unsafe_mut(1) = (code >> 12u & 0x3F_u32) as u8 | TAG_CONT;
+        *dst.unsafe_mut(2) = (code >>  6u & 0x3F_u32) as u8 | TAG_CONT;
+        *dst.unsafe_mut(3) = (code & 0x3F_u32) as u8 | TAG_CONT;
+        4
+    }
+}
+
+// Encodes a char's code point in the UTF-16 scheme. The caller must
+// guarantee that `dst.len() >= c.len_utf16()`.
+#[inline]
+unsafe fn encode_utf16_raw_unchecked(code: u32, dst: &mut [u16]) -> uint {
+    if code < 0x1_0000_u32 {
+        // The BMP falls through (assuming non-surrogate, as it should)
+        *dst.unsafe_mut(0) = code as u16;
+        1
+    } else {
+        // Supplementary planes break into surrogates.
+        let code = code - 0x1_0000_u32;
+        *dst.unsafe_mut(0) = 0xD800_u16 | ((code >> 10) as u16);
+        *dst.unsafe_mut(1) = 0xDC00_u16 | ((code as u16) & 0x3FF_u16);
+        2
+    }
+}
+
+/// Encodes a code point held as a `u32` in the UTF-8 scheme into the provided
+/// byte buffer, and then returns the number of bytes written
+///
+/// This is the encoding used by `Char::encode_utf8`, without the guarantee
+/// that the value is a `char`. It is up to the caller to make sure that the
+/// value is a Unicode scalar value: surrogates are encoded as in WTF-8, and
+/// the bytes written for values above `MAX` are meaningless.
+///
+/// If the buffer is not large enough, nothing will be written into it and a
+/// `None` will be returned.
+#[inline]
+#[unstable = "pending error conventions"]
+pub fn encode_utf8_raw(code: u32, dst: &mut [u8]) -> Option<uint> {
+    if dst.len() >= utf8_len(code) {
+        Some(unsafe { encode_utf8_raw_unchecked(code, dst) })
+    } else {
+        None
+    }
+}
+
+/// A sink for the bytes of encoded characters, as used by
+/// `Char::encode_utf8_to`.
+#[unstable = "pending trait organization"]
+pub trait CharWriter {
+    /// Appends the given bytes to the sink.
+    fn push_bytes(&mut self, bytes: &[u8]);
+}
+
 /// Basic `char` manipulations.
 #[experimental = "trait organization may change"]
 pub trait Char {
@@ -232,6 +838,34 @@ pub trait Char {
     #[unstable = "pending error conventions"]
     fn is_digit(self, radix: uint) -> bool;
 
+    /// Checks if a `char` parses as a numeric digit in the given radix,
+    /// without panicking on an invalid radix.
+    ///
+    /// # Return value
+    ///
+    /// Returns `Ok` with the same value that `is_digit()` would return, or
+    /// `Err` if given a radix > 36.
+    #[unstable = "pending error conventions"]
+    fn is_digit_checked(self, radix: uint) -> Result<bool, RadixError>;
+
+    /// Checks if a `char` is a binary digit, `0` or `1`.
+    ///
+    /// This is equivalent to `is_digit(2)`, but has no radix to check.
+    #[unstable = "pending trait organization"]
+    fn is_binary_digit(self) -> bool;
+
+    /// Checks if a `char` is an octal digit, `0-7`.
+    ///
+    /// This is equivalent to `is_digit(8)`, but has no radix to check.
+    #[unstable = "pending trait organization"]
+    fn is_octal_digit(self) -> bool;
+
+    /// Checks if a `char` is a hexadecimal digit, `0-9`, `a-f` or `A-F`.
+    ///
+    /// This is equivalent to `is_digit(16)`, but has no radix to check.
+    #[unstable = "pending trait organization"]
+    fn is_hex_digit(self) -> bool;
+
     /// Converts a character to the corresponding digit.
     ///
     /// # Return value
@@ -246,13 +880,62 @@ pub trait Char {
     #[unstable = "pending error conventions, trait organization"]
     fn to_digit(self, radix: uint) -> Option<uint>;
 
-    /// Converts a number to the character representing it.
+    /// Converts a character to the corresponding digit, without panicking
+    /// on an invalid radix.
     ///
     /// # Return value
     ///
-    /// Returns `Some(char)` if `num` represents one digit under `radix`,
-    /// using one character of `0-9` or `a-z`, or `None` if it doesn't.
-    ///
+    /// Returns `Ok` with the same value that `to_digit()` would return, or
+    /// `Err` if given a radix > 36. Radices 0 and 1 have no digits at all.
+    #[unstable = "pending error conventions, trait organization"]
+    fn to_digit_checked(self, radix: uint) -> Result<Option<uint>, RadixError>;
+
+    /// Converts a character to the corresponding digit, accepting the
+    /// fullwidth digits `０-９` (U+FF10 to U+FF19) as well.
+    ///
+    /// Other characters, including other fullwidth forms, are converted as
+    /// by `to_digit()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given a radix > 36.
+    #[unstable = "pending error conventions, trait organization"]
+    fn to_digit_wide(self, radix: uint) -> Option<uint>;
+
+    /// Converts a character to the corresponding digit, along with the case
+    /// of the character, so that it can be written back the same way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given a radix > 36.
+    #[unstable = "pending error conventions, trait organization"]
+    fn to_digit_cased(self, radix: uint) -> Option<(uint, Case)>;
+
+    /// Converts a character to the corresponding base 62 digit.
+    ///
+    /// Unlike `to_digit()`, this is case-sensitive: `0-9` map to 0 to 9,
+    /// `A-Z` to 10 to 35 and `a-z` to 36 to 61.
+    ///
+    /// # Return value
+    ///
+    /// Returns `Some(digit)`, or `None` if the character is not ASCII
+    /// alphanumeric.
+    #[unstable = "pending trait organization"]
+    fn to_digit_base62(self) -> Option<uint>;
+
+    /// Converts an ASCII decimal digit, `0-9`, to its value.
+    ///
+    /// This is equivalent to `to_digit(10)`, but has no radix to check.
+    #[unstable = "pending trait organization"]
+    fn to_ascii_digit(self) -> Option<u8>;
+
+    /// Converts a number to the character representing it.
+    ///
+    /// # Return value
+    ///
+    /// Returns `Some(char)` if `num` represents one digit under `radix`,
+    /// using one character of `0-9` or `a-z`, or `None` if it doesn't.
+    ///
     /// # Panics
     ///
     /// Panics if given a radix > 36.
@@ -274,6 +957,23 @@ pub trait Char {
     #[unstable = "pending error conventions, trait organization"]
     fn escape_unicode(self) -> UnicodeEscapedChars;
 
+    /// Returns an iterator that yields the hexadecimal Unicode escape
+    /// of a character, as `char`s, using uppercase hex digits.
+    ///
+    /// This follows the same rules as `escape_unicode()`, except that the
+    /// digits 10 to 15 are written as `A` to `F` rather than `a` to `f`.
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_unicode_upper(self) -> UnicodeEscapedChars;
+
+    /// Returns an iterator that yields the hexadecimal Unicode escape
+    /// of a character in the braced form accepted by Rust source, as `char`s.
+    ///
+    /// Every character is escaped as `\\u{N}`, with the minimal number of
+    /// lowercase hex digits and no leading zeros, e.g. `\\u{61}` for `a` and
+    /// `\\u{1f4a9}` for U+1F4A9.
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_unicode_rust(self) -> RustUnicodeEscape;
+
     /// Returns an iterator that yields the 'default' ASCII and
     /// C++11-like literal escape of a character, as `char`s.
     ///
@@ -289,6 +989,54 @@ pub trait Char {
     #[unstable = "pending error conventions, trait organization"]
     fn escape_default(self) -> DefaultEscapedChars;
 
+    /// Returns an iterator that yields the escape of a character for use in
+    /// debugging output, as `char`s.
+    ///
+    /// Unlike `escape_default`, this leaves printable non-ASCII characters
+    /// alone. The exact rules are:
+    ///
+    /// * Tab, CR and LF are escaped as '\t', '\r' and '\n' respectively.
+    /// * Single-quote, double-quote and backslash chars are backslash-
+    ///   escaped.
+    /// * Control chars, in the ranges [0,0x1f] and [0x7f,0x9f], are given
+    ///   hex Unicode escapes; see `escape_unicode`.
+    /// * Any other chars are not escaped.
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_debug(self) -> DebugEscapedChars;
+
+    /// Returns an iterator that yields the escape of a character for use
+    /// inside a single-quoted character literal, as `char`s.
+    ///
+    /// This follows the same rules as `escape_default()`, except that
+    /// double-quote chars, which need no escaping in a character literal,
+    /// are not escaped.
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_char_literal(self) -> CharLiteralEscapedChars;
+
+    /// Returns an iterator that yields the escape of a character for use in
+    /// a JSON string, as `char`s.
+    ///
+    /// The exact rules are:
+    ///
+    /// * Backspace, form feed, LF, CR and tab are escaped as '\b', '\f',
+    ///   '\n', '\r' and '\t' respectively.
+    /// * Double-quote and backslash chars are backslash-escaped.
+    /// * Any other control chars in the range [0,0x1f] are escaped as
+    ///   `\\uNNNN`, with four hex digits.
+    /// * Any other chars, including single-quote and non-ASCII chars, are
+    ///   not escaped.
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_json(self) -> JsonEscape;
+
+    /// Returns an iterator that yields the escape of a character for use in
+    /// XML or HTML text and attribute values, as `char`s.
+    ///
+    /// The characters `&`, `<`, `>`, `"` and `'` are replaced by the
+    /// predefined entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;`
+    /// respectively. Any other chars are not escaped.
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_xml(self) -> XmlEscape;
+
     /// Returns the amount of bytes this character would need if encoded in
     /// UTF-8.
     #[deprecated = "use len_utf8"]
@@ -312,6 +1060,47 @@ pub trait Char {
     #[unstable = "pending trait organization"]
     fn encode_utf8(&self, dst: &mut [u8]) -> Option<uint>;
 
+    /// Encodes this character as UTF-8 into the provided byte buffer,
+    /// and then returns the number of bytes written.
+    ///
+    /// # Return value
+    ///
+    /// Returns `Ok(n)` with the number of bytes written, or, if the buffer
+    /// is not large enough, `Err(n)` with the number of bytes that would
+    /// have been needed. Nothing is written into the buffer in that case.
+    #[unstable = "pending error conventions, trait organization"]
+    fn encode_utf8_len(&self, dst: &mut [u8]) -> Result<uint, uint>;
+
+    /// Encodes this character as UTF-8 into the provided byte buffer,
+    /// and then returns the number of bytes written as a `u8`.
+    ///
+    /// This is `encode_utf8` with a narrower return type, since at most
+    /// `MAX_UTF8_LEN` bytes are ever written.
+    #[unstable = "pending trait organization"]
+    fn encode_utf8_small(&self, dst: &mut [u8]) -> Option<u8>;
+
+    /// Encodes this character as UTF-8 into the provided byte buffer,
+    /// and then returns the number of bytes written, without checking that
+    /// the buffer is large enough.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `dst.len() >= self.len_utf8()`.
+    #[unstable = "pending error conventions, trait organization"]
+    unsafe fn encode_utf8_unchecked(&self, dst: &mut [u8]) -> uint;
+
+    /// Encodes this character as UTF-8, handing all of its bytes to the
+    /// given writer in a single call to `push_bytes`.
+    #[unstable = "pending trait organization"]
+    fn encode_utf8_to<W: CharWriter>(&self, w: &mut W);
+
+    /// Encodes this character as UTF-8 into a fixed-size array, and returns
+    /// it along with the number of bytes used.
+    ///
+    /// The bytes of the array past those used are zero.
+    #[unstable = "pending trait organization"]
+    fn to_utf8_array(self) -> ([u8, ..MAX_UTF8_LEN], uint);
+
     /// Encodes this character as UTF-16 into the provided `u16` buffer,
     /// and then returns the number of `u16`s written.
     ///
@@ -319,6 +1108,144 @@ pub trait Char {
     /// and a `None` will be returned.
     #[unstable = "pending trait organization"]
     fn encode_utf16(&self, dst: &mut [u16]) -> Option<uint>;
+
+    /// Encodes this character as UTF-16 into the provided `u16` buffer,
+    /// and then returns the number of `u16`s written.
+    ///
+    /// # Return value
+    ///
+    /// Returns `Ok(n)` with the number of `u16`s written, or, if the buffer
+    /// is not large enough, `Err(n)` with the number of `u16`s that would
+    /// have been needed. Nothing is written into the buffer in that case.
+    #[unstable = "pending error conventions, trait organization"]
+    fn encode_utf16_len(&self, dst: &mut [u16]) -> Result<uint, uint>;
+
+    /// Encodes this character as UTF-16 into a fixed-size array, and returns
+    /// it along with the number of `u16`s used.
+    ///
+    /// The `u16`s of the array past those used are zero.
+    #[unstable = "pending trait organization"]
+    fn to_utf16_array(self) -> ([u16, ..MAX_UTF16_LEN], uint);
+
+    /// Encodes this character as UTF-32 into the provided `u32` buffer,
+    /// and then returns the number of `u32`s written.
+    ///
+    /// If the buffer is empty, nothing will be written into it and a `None`
+    /// will be returned.
+    #[unstable = "pending trait organization"]
+    fn encode_utf32(&self, dst: &mut [u32]) -> Option<uint>;
+
+    /// Converts a character to its ASCII uppercase equivalent.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but all other
+    /// characters, including non-ASCII letters, are returned unchanged.
+    #[unstable = "pending trait organization"]
+    fn to_ascii_uppercase(self) -> char;
+
+    /// Converts a character to its ASCII lowercase equivalent.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but all other
+    /// characters, including non-ASCII letters, are returned unchanged.
+    #[unstable = "pending trait organization"]
+    fn to_ascii_lowercase(self) -> char;
+
+    /// Checks that two characters are equal, ignoring the case of ASCII
+    /// letters.
+    ///
+    /// This is equivalent to comparing `to_ascii_lowercase()` of both
+    /// characters, so non-ASCII characters only ever compare equal to
+    /// themselves.
+    #[unstable = "pending trait organization"]
+    fn eq_ignore_ascii_case(&self, other: &char) -> bool;
+
+    /// Checks if the character is an ASCII decimal digit, `0-9`.
+    #[unstable = "pending trait organization"]
+    fn is_ascii_digit(self) -> bool;
+
+    /// Checks if the character is an ASCII hexadecimal digit, `0-9`, `a-f`
+    /// or `A-F`.
+    #[unstable = "pending trait organization"]
+    fn is_ascii_hexdigit(self) -> bool;
+
+    /// Checks if the character is an ASCII letter, `a-z` or `A-Z`.
+    #[unstable = "pending trait organization"]
+    fn is_ascii_alphabetic(self) -> bool;
+
+    /// Checks if the character is an ASCII letter or decimal digit.
+    #[unstable = "pending trait organization"]
+    fn is_ascii_alphanumeric(self) -> bool;
+
+    /// Checks if the character is ASCII punctuation, i.e. a printable,
+    /// non-space ASCII character that is not alphanumeric.
+    #[unstable = "pending trait organization"]
+    fn is_ascii_punctuation(self) -> bool;
+
+    /// Checks if the character is ASCII whitespace: space, tab, LF, form
+    /// feed or CR.
+    ///
+    /// Vertical tab is not included, following the definition used by
+    /// HTML and other web standards.
+    #[unstable = "pending trait organization"]
+    fn is_ascii_whitespace(self) -> bool;
+
+    /// Checks if the character is an ASCII control character, in the range
+    /// [0,0x1f] or 0x7f.
+    #[unstable = "pending trait organization"]
+    fn is_ascii_control(self) -> bool;
+
+    /// Checks if the character is a Unicode noncharacter: one of the 32
+    /// code points in the range [0xFDD0,0xFDEF], or one of the last two
+    /// code points of any plane, such as 0xFFFE and 0x10FFFF.
+    ///
+    /// Noncharacters are valid `char`s, but are permanently reserved for
+    /// internal use, and should not be interchanged.
+    #[unstable = "pending trait organization"]
+    fn is_noncharacter(self) -> bool;
+
+    /// Estimates this character's displayed width in columns, without
+    /// consulting the Unicode tables.
+    ///
+    /// This is a rough `wcwidth` for use where `UnicodeChar::width` is not
+    /// available. Only the following ranges are recognized:
+    ///
+    /// * `'\x00'` is 0 columns wide, and other C0 and C1 controls have no
+    ///   width, so `None` is returned for them.
+    /// * The combining marks in [0x300,0x36F], [0x1AB0,0x1AFF],
+    ///   [0x1DC0,0x1DFF], [0x20D0,0x20FF] and [0xFE20,0xFE2F], the Hangul
+    ///   medial vowels and final consonants in [0x1160,0x11FF], the
+    ///   zero-width space and joiners and the directional marks in
+    ///   [0x200B,0x200F], and U+FEFF are 0 columns wide.
+    /// * The Hangul initial consonants in [0x1100,0x115F], CJK symbols,
+    ///   kana and ideographs in [0x2E80,0x303E], [0x3041,0x33FF],
+    ///   [0x3400,0x4DBF], [0x4E00,0x9FFF] and [0xF900,0xFAFF], Yi in
+    ///   [0xA000,0xA4CF], Hangul syllables in [0xAC00,0xD7A3], the CJK
+    ///   compatibility forms in [0xFE30,0xFE4F], the fullwidth forms in
+    ///   [0xFF00,0xFF60] and [0xFFE0,0xFFE6], and the supplementary
+    ///   ideographic planes in [0x20000,0x3FFFD] are 2 columns wide.
+    /// * Everything else is 1 column wide.
+    #[unstable = "pending trait organization"]
+    fn approx_width(self) -> Option<uint>;
+
+    /// Returns the next valid `char` after this one, skipping over the
+    /// surrogate range, or `None` if this is `MAX`.
+    #[unstable = "pending trait organization"]
+    fn successor(self) -> Option<char>;
+
+    /// Returns the previous valid `char` before this one, skipping over the
+    /// surrogate range, or `None` if this is `MIN`.
+    #[unstable = "pending trait organization"]
+    fn predecessor(self) -> Option<char>;
+
+    /// Adds `n` to this character's code point, returning `None` if the
+    /// result overflows or is not a valid `char`, i.e. is a surrogate or is
+    /// greater than `MAX`.
+    #[unstable = "pending trait organization"]
+    fn checked_add(self, n: u32) -> Option<char>;
+
+    /// Subtracts `n` from this character's code point, returning `None` if
+    /// the result underflows or is a surrogate.
+    #[unstable = "pending trait organization"]
+    fn checked_sub(self, n: u32) -> Option<char>;
 }
 
 #[experimental = "trait is experimental"]
@@ -334,19 +1261,98 @@ impl Char for char {
         }
     }
 
+    #[unstable = "pending error conventions"]
+    fn is_digit_checked(self, radix: uint) -> Result<bool, RadixError> {
+        self.to_digit_checked(radix).map(|val| val.is_some())
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_binary_digit(self) -> bool {
+        match self {
+            '0' | '1' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_octal_digit(self) -> bool {
+        match self {
+            '0' ... '7' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_hex_digit(self) -> bool { self.is_ascii_hexdigit() }
+
     #[unstable = "pending trait organization"]
     fn to_digit(self, radix: uint) -> Option<uint> {
+        match self.to_digit_checked(radix) {
+            Ok(val) => val,
+            Err(_) => panic!("to_digit: radix is too high (maximum 36)"),
+        }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn to_digit_checked(self, radix: uint) -> Result<Option<uint>, RadixError> {
         if radix > 36 {
-            panic!("to_digit: radix is too high (maximum 36)");
+            return Err(RadixError { radix: radix });
         }
         let val = match self {
           '0' ... '9' => self as uint - ('0' as uint),
           'a' ... 'z' => self as uint + 10u - ('a' as uint),
           'A' ... 'Z' => self as uint + 10u - ('A' as uint),
-          _ => return None,
+          _ => return Ok(None),
         };
-        if val < radix { Some(val) }
-        else { None }
+        if radix >= 2 && val < radix { Ok(Some(val)) }
+        else { Ok(None) }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn to_digit_wide(self, radix: uint) -> Option<uint> {
+        match self {
+            '\u{ff10}' ... '\u{ff19}' => {
+                // the fullwidth digit with the same value
+                let c = (self as u32 - 0xff10 + '0' as u32) as u8 as char;
+                c.to_digit(radix)
+            }
+            _ => self.to_digit(radix)
+        }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn to_digit_cased(self, radix: uint) -> Option<(uint, Case)> {
+        self.to_digit(radix).map(|val| {
+            let case = match self {
+                'A' ... 'Z' => Case::Upper,
+                'a' ... 'z' => Case::Lower,
+                _ => Case::NotApplicable
+            };
+            (val, case)
+        })
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn to_digit_base62(self) -> Option<uint> {
+        match self {
+            '0' ... '9' => Some(self as uint - '0' as uint),
+            'A' ... 'Z' => Some(self as uint - 'A' as uint + 10),
+            'a' ... 'z' => Some(self as uint - 'a' as uint + 36),
+            _ => None
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn to_ascii_digit(self) -> Option<u8> {
+        match self {
+            '0' ... '9' => Some(self as u8 - b'0'),
+            _ => None
+        }
     }
 
     #[deprecated = "use the char::from_digit free function"]
@@ -358,7 +1364,20 @@ impl Char for char {
 
     #[unstable = "pending error conventions, trait organization"]
     fn escape_unicode(self) -> UnicodeEscapedChars {
-        UnicodeEscapedChars { c: self, state: UnicodeEscapedCharsState::Backslash }
+        let (_, pad) = unicode_escape_kind(self);
+        UnicodeEscapedChars { c: self, upper: false, front: 0, back: 2 + pad }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_unicode_upper(self) -> UnicodeEscapedChars {
+        let (_, pad) = unicode_escape_kind(self);
+        UnicodeEscapedChars { c: self, upper: true, front: 0, back: 2 + pad }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_unicode_rust(self) -> RustUnicodeEscape {
+        // `\u{`, the digits, and `}`
+        RustUnicodeEscape { c: self, front: 0, back: 4 + rust_escape_digits(self) }
     }
 
     #[unstable = "pending error conventions, trait organization"]
@@ -376,21 +1395,62 @@ impl Char for char {
         DefaultEscapedChars { state: init_state }
     }
 
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_debug(self) -> DebugEscapedChars {
+        let init_state = match self {
+            '\t' => DefaultEscapedCharsState::Backslash('t'),
+            '\r' => DefaultEscapedCharsState::Backslash('r'),
+            '\n' => DefaultEscapedCharsState::Backslash('n'),
+            '\\' => DefaultEscapedCharsState::Backslash('\\'),
+            '\'' => DefaultEscapedCharsState::Backslash('\''),
+            '"'  => DefaultEscapedCharsState::Backslash('"'),
+            '\x00' ... '\x1f' | '\x7f' ... '\u{9f}' => {
+                DefaultEscapedCharsState::Unicode(self.escape_unicode())
+            }
+            _ => DefaultEscapedCharsState::Char(self)
+        };
+        DebugEscapedChars { inner: DefaultEscapedChars { state: init_state } }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_char_literal(self) -> CharLiteralEscapedChars {
+        let inner = match self {
+            '"' => DefaultEscapedChars { state: DefaultEscapedCharsState::Char(self) },
+            _ => self.escape_default()
+        };
+        CharLiteralEscapedChars { inner: inner }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_json(self) -> JsonEscape {
+        let len = match json_escape_kind(self) {
+            None => 1,
+            Some('u') => 6,
+            Some(_) => 2
+        };
+        JsonEscape { c: self, front: 0, back: len }
+    }
+
+    #[unstable = "pending error conventions, trait organization"]
+    fn escape_xml(self) -> XmlEscape {
+        let init_state = match self {
+            '&' => XmlEscapeState::Entity(b"&amp;"),
+            '<' => XmlEscapeState::Entity(b"&lt;"),
+            '>' => XmlEscapeState::Entity(b"&gt;"),
+            '"' => XmlEscapeState::Entity(b"&quot;"),
+            '\'' => XmlEscapeState::Entity(b"&apos;"),
+            _ => XmlEscapeState::Char(self)
+        };
+        XmlEscape { state: init_state }
+    }
+
     #[inline]
     #[deprecated = "use len_utf8"]
     fn len_utf8_bytes(self) -> uint { self.len_utf8() }
 
     #[inline]
     #[unstable = "pending trait organization"]
-    fn len_utf8(self) -> uint {
-        let code = self as u32;
-        match () {
-            _ if code < MAX_ONE_B   => 1u,
-            _ if code < MAX_TWO_B   => 2u,
-            _ if code < MAX_THREE_B => 3u,
-            _  => 4u,
-        }
-    }
+    fn len_utf8(self) -> uint { utf8_len(self as u32) }
 
     #[inline]
     #[unstable = "pending trait organization"]
@@ -402,100 +1462,376 @@ impl Char for char {
     #[inline]
     #[unstable = "pending error conventions, trait organization"]
     fn encode_utf8<'a>(&self, dst: &'a mut [u8]) -> Option<uint> {
-        // Marked #[inline] to allow llvm optimizing it away
-        let code = *self as u32;
-        if code < MAX_ONE_B && dst.len() >= 1 {
-            dst[0] = code as u8;
-            Some(1)
-        } else if code < MAX_TWO_B && dst.len() >= 2 {
-            dst[0] = (code >> 6u & 0x1F_u32) as u8 | TAG_TWO_B;
-            dst[1] = (code & 0x3F_u32) as u8 | TAG_CONT;
-            Some(2)
-        } else if code < MAX_THREE_B && dst.len() >= 3  {
-            dst[0] = (code >> 12u & 0x0F_u32) as u8 | TAG_THREE_B;
-            dst[1] = (code >>  6u & 0x3F_u32) as u8 | TAG_CONT;
-            dst[2] = (code & 0x3F_u32) as u8 | TAG_CONT;
-            Some(3)
-        } else if dst.len() >= 4 {
-            dst[0] = (code >> 18u & 0x07_u32) as u8 | TAG_FOUR_B;
-            dst[1] = (code >> 12u & 0x3F_u32) as u8 | TAG_CONT;
-            dst[2] = (code >>  6u & 0x3F_u32) as u8 | TAG_CONT;
-            dst[3] = (code & 0x3F_u32) as u8 | TAG_CONT;
-            Some(4)
+        self.encode_utf8_len(dst).ok()
+    }
+
+    #[inline]
+    #[unstable = "pending error conventions, trait organization"]
+    fn encode_utf8_len(&self, dst: &mut [u8]) -> Result<uint, uint> {
+        let n = self.len_utf8();
+        if dst.len() >= n {
+            Ok(unsafe { self.encode_utf8_unchecked(dst) })
         } else {
-            None
+            Err(n)
         }
     }
 
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn encode_utf8_small(&self, dst: &mut [u8]) -> Option<u8> {
+        self.encode_utf8(dst).map(|n| n as u8)
+    }
+
+    #[inline]
+    #[unstable = "pending error conventions, trait organization"]
+    unsafe fn encode_utf8_unchecked(&self, dst: &mut [u8]) -> uint {
+        encode_utf8_raw_unchecked(*self as u32, dst)
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn encode_utf8_to<W: CharWriter>(&self, w: &mut W) {
+        let (buf, n) = self.to_utf8_array();
+        w.push_bytes(buf[..n]);
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn to_utf8_array(self) -> ([u8, ..MAX_UTF8_LEN], uint) {
+        let mut buf = [0u8, ..MAX_UTF8_LEN];
+        let n = unsafe { self.encode_utf8_unchecked(buf.as_mut_slice()) };
+        (buf, n)
+    }
+
     #[inline]
     #[unstable = "pending error conventions, trait organization"]
     fn encode_utf16(&self, dst: &mut [u16]) -> Option<uint> {
+        self.encode_utf16_len(dst).ok()
+    }
+
+    #[inline]
+    #[unstable = "pending error conventions, trait organization"]
+    fn encode_utf16_len(&self, dst: &mut [u16]) -> Result<uint, uint> {
         // Marked #[inline] to allow llvm optimizing it away
-        let mut ch = *self as u32;
-        if (ch & 0xFFFF_u32) == ch  && dst.len() >= 1 {
-            // The BMP falls through (assuming non-surrogate, as it should)
-            dst[0] = ch as u16;
-            Some(1)
-        } else if dst.len() >= 2 {
-            // Supplementary planes break into surrogates.
-            ch -= 0x1_0000_u32;
-            dst[0] = 0xD800_u16 | ((ch >> 10) as u16);
-            dst[1] = 0xDC00_u16 | ((ch as u16) & 0x3FF_u16);
-            Some(2)
+        let n = self.len_utf16();
+        if dst.len() >= n {
+            Ok(unsafe { encode_utf16_raw_unchecked(*self as u32, dst) })
         } else {
-            None
+            Err(n)
         }
     }
-}
-
-/// An iterator over the characters that represent a `char`, as escaped by
-/// Rust's unicode escaping rules.
-pub struct UnicodeEscapedChars {
-    c: char,
-    state: UnicodeEscapedCharsState
-}
 
-enum UnicodeEscapedCharsState {
-    Backslash,
-    Type,
-    Value(RangeStep<i32>),
-}
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn to_utf16_array(self) -> ([u16, ..MAX_UTF16_LEN], uint) {
+        let mut buf = [0u16, ..MAX_UTF16_LEN];
+        let n = unsafe { encode_utf16_raw_unchecked(self as u32, buf.as_mut_slice()) };
+        (buf, n)
+    }
 
-impl Iterator<char> for UnicodeEscapedChars {
-    fn next(&mut self) -> Option<char> {
-        match self.state {
-            UnicodeEscapedCharsState::Backslash => {
-                self.state = UnicodeEscapedCharsState::Type;
-                Some('\\')
-            }
-            UnicodeEscapedCharsState::Type => {
-                let (typechar, pad) = if self.c <= '\x7f' { ('x', 2) }
-                                      else if self.c <= '\u{ffff}' { ('u', 4) }
-                                      else { ('U', 8) };
-                self.state = UnicodeEscapedCharsState::Value(range_step(4 * (pad - 1), -1, -4i32));
-                Some(typechar)
-            }
-            UnicodeEscapedCharsState::Value(ref mut range_step) => match range_step.next() {
-                Some(offset) => {
-                    let offset = offset as uint;
-                    let v = match ((self.c as i32) >> offset) & 0xf {
-                        i @ 0 ... 9 => '0' as i32 + i,
-                        i => 'a' as i32 + (i - 10)
-                    };
-                    Some(unsafe { transmute(v) })
-                }
-                None => None
-            }
+    #[inline]
+    #[unstable = "pending error conventions, trait organization"]
+    fn encode_utf32(&self, dst: &mut [u32]) -> Option<uint> {
+        if dst.len() >= 1 {
+            dst[0] = *self as u32;
+            Some(1)
+        } else {
+            None
         }
     }
-}
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn to_ascii_uppercase(self) -> char {
+        match self {
+            'a' ... 'z' => (self as u8 - b'a' + b'A') as char,
+            _ => self
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn to_ascii_lowercase(self) -> char {
+        match self {
+            'A' ... 'Z' => (self as u8 - b'A' + b'a') as char,
+            _ => self
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn eq_ignore_ascii_case(&self, other: &char) -> bool {
+        self.to_ascii_lowercase() == other.to_ascii_lowercase()
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_ascii_digit(self) -> bool {
+        match self {
+            '0' ... '9' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_ascii_hexdigit(self) -> bool {
+        match self {
+            '0' ... '9' | 'a' ... 'f' | 'A' ... 'F' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_ascii_alphabetic(self) -> bool {
+        match self {
+            'a' ... 'z' | 'A' ... 'Z' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_ascii_alphanumeric(self) -> bool {
+        self.is_ascii_alphabetic() || self.is_ascii_digit()
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_ascii_punctuation(self) -> bool {
+        match self {
+            '!' ... '/' | ':' ... '@' | '[' ... '`' | '{' ... '~' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_ascii_whitespace(self) -> bool {
+        match self {
+            ' ' | '\t' | '\n' | '\x0c' | '\r' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_ascii_control(self) -> bool {
+        match self {
+            '\x00' ... '\x1f' | '\x7f' => true,
+            _ => false
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn is_noncharacter(self) -> bool {
+        let code = self as u32;
+        (code >= 0xFDD0 && code <= 0xFDEF) || code & 0xFFFE == 0xFFFE
+    }
+
+    #[unstable = "pending trait organization"]
+    fn approx_width(self) -> Option<uint> {
+        match self {
+            '\x00' => Some(0),
+            '\x01' ... '\x1f' | '\x7f' ... '\u{9f}' => None,
+            '\x20' ... '\x7e' => Some(1),
+            '\u{300}' ... '\u{36f}' | '\u{1160}' ... '\u{11ff}' | '\u{1ab0}' ... '\u{1aff}' |
+            '\u{1dc0}' ... '\u{1dff}' | '\u{200b}' ... '\u{200f}' | '\u{20d0}' ... '\u{20ff}' |
+            '\u{fe20}' ... '\u{fe2f}' | '\u{feff}' => Some(0),
+            '\u{1100}' ... '\u{115f}' | '\u{2e80}' ... '\u{303e}' | '\u{3041}' ... '\u{33ff}' |
+            '\u{3400}' ... '\u{4dbf}' | '\u{4e00}' ... '\u{9fff}' | '\u{a000}' ... '\u{a4cf}' |
+            '\u{ac00}' ... '\u{d7a3}' | '\u{f900}' ... '\u{faff}' | '\u{fe30}' ... '\u{fe4f}' |
+            '\u{ff00}' ... '\u{ff60}' | '\u{ffe0}' ... '\u{ffe6}' |
+            '\u{20000}' ... '\u{3fffd}' => Some(2),
+            _ => Some(1)
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn successor(self) -> Option<char> {
+        match self {
+            MAX => None,
+            '\u{d7ff}' => Some('\u{e000}'),
+            _ => Some(unsafe { from_u32_unchecked(self as u32 + 1) })
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn predecessor(self) -> Option<char> {
+        match self {
+            MIN => None,
+            '\u{e000}' => Some('\u{d7ff}'),
+            _ => Some(unsafe { from_u32_unchecked(self as u32 - 1) })
+        }
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn checked_add(self, n: u32) -> Option<char> {
+        (self as u32).checked_add(n).and_then(|i| from_u32(i))
+    }
+
+    #[inline]
+    #[unstable = "pending trait organization"]
+    fn checked_sub(self, n: u32) -> Option<char> {
+        (self as u32).checked_sub(n).and_then(|i| from_u32(i))
+    }
+}
+
+/// An iterator over the characters that represent a `char`, as escaped by
+/// Rust's unicode escaping rules.
+#[deriving(Clone)]
+pub struct UnicodeEscapedChars {
+    c: char,
+    // whether to use uppercase hex digits
+    upper: bool,
+    // The escape is laid out as a backslash at position 0, the type char at
+    // position 1 and then the hex digits, most significant first. The
+    // positions in `front..back` are those not yet yielded from either end.
+    front: uint,
+    back: uint,
+}
+
+// Returns the type char and the number of hex digits used to escape `c`.
+fn unicode_escape_kind(c: char) -> (char, uint) {
+    if c <= '\x7f' { ('x', 2) }
+    else if c <= '\u{ffff}' { ('u', 4) }
+    else { ('U', 8) }
+}
+
+impl UnicodeEscapedChars {
+    // Returns the character at position `pos` of the escape.
+    fn char_at(&self, pos: uint) -> char {
+        let (typechar, pad) = unicode_escape_kind(self.c);
+        match pos {
+            0 => '\\',
+            1 => typechar,
+            _ => {
+                let offset = 4 * (pad - 1 - (pos - 2));
+                let v = match ((self.c as u32) >> offset) & 0xf {
+                    i @ 0 ... 9 => '0' as u32 + i,
+                    i if self.upper => 'A' as u32 + (i - 10),
+                    i => 'a' as u32 + (i - 10)
+                };
+                unsafe { from_u32_unchecked(v) }
+            }
+        }
+    }
+}
+
+impl Iterator<char> for UnicodeEscapedChars {
+    fn next(&mut self) -> Option<char> {
+        if self.front < self.back {
+            let c = self.char_at(self.front);
+            self.front += 1;
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator<char> for UnicodeEscapedChars {
+    fn next_back(&mut self) -> Option<char> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.char_at(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator<char> for UnicodeEscapedChars {}
+
+/// An iterator over the characters that represent a `char`, as escaped in
+/// the braced `\u{NNNN}` form accepted by Rust source.
+#[deriving(Clone)]
+pub struct RustUnicodeEscape {
+    c: char,
+    // The escape is laid out as `\u{` at positions 0 to 2, the hex digits,
+    // most significant first, and then `}`. As for `UnicodeEscapedChars`,
+    // the positions in `front..back` are those not yet yielded.
+    front: uint,
+    back: uint,
+}
+
+// Returns the number of hex digits needed to write `c`, which is at least one.
+fn rust_escape_digits(c: char) -> uint {
+    let bits = 32 - (c as u32 | 1).leading_zeros();
+    (bits + 3) / 4
+}
+
+impl RustUnicodeEscape {
+    // Returns the character at position `pos` of the escape.
+    fn char_at(&self, pos: uint) -> char {
+        let digits = rust_escape_digits(self.c);
+        match pos {
+            0 => '\\',
+            1 => 'u',
+            2 => '{',
+            _ if pos == 3 + digits => '}',
+            _ => {
+                let offset = 4 * (digits - 1 - (pos - 3));
+                let v = match ((self.c as u32) >> offset) & 0xf {
+                    i @ 0 ... 9 => '0' as u32 + i,
+                    i => 'a' as u32 + (i - 10)
+                };
+                unsafe { from_u32_unchecked(v) }
+            }
+        }
+    }
+}
+
+impl Iterator<char> for RustUnicodeEscape {
+    fn next(&mut self) -> Option<char> {
+        if self.front < self.back {
+            let c = self.char_at(self.front);
+            self.front += 1;
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator<char> for RustUnicodeEscape {
+    fn next_back(&mut self) -> Option<char> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.char_at(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator<char> for RustUnicodeEscape {}
 
 /// An iterator over the characters that represent a `char`, escaped
 /// for maximum portability.
+#[deriving(Clone)]
 pub struct DefaultEscapedChars {
     state: DefaultEscapedCharsState
 }
 
+#[deriving(Clone)]
 enum DefaultEscapedCharsState {
     Backslash(char),
     Char(char),
@@ -518,5 +1854,607 @@ impl Iterator<char> for DefaultEscapedChars {
             DefaultEscapedCharsState::Unicode(ref mut iter) => iter.next()
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        match self.state {
+            DefaultEscapedCharsState::Backslash(_) => (2, Some(2)),
+            DefaultEscapedCharsState::Char(_) => (1, Some(1)),
+            DefaultEscapedCharsState::Done => (0, Some(0)),
+            DefaultEscapedCharsState::Unicode(ref iter) => iter.size_hint()
+        }
+    }
+}
+
+impl DoubleEndedIterator<char> for DefaultEscapedChars {
+    fn next_back(&mut self) -> Option<char> {
+        match self.state {
+            DefaultEscapedCharsState::Backslash(c) => {
+                // only the backslash itself is left
+                self.state = DefaultEscapedCharsState::Char('\\');
+                Some(c)
+            }
+            DefaultEscapedCharsState::Char(c) => {
+                self.state = DefaultEscapedCharsState::Done;
+                Some(c)
+            }
+            DefaultEscapedCharsState::Done => None,
+            DefaultEscapedCharsState::Unicode(ref mut iter) => iter.next_back()
+        }
+    }
+}
+
+impl ExactSizeIterator<char> for DefaultEscapedChars {}
+
+
+/// An iterator that decodes UTF-8 encoded characters from an iterator of
+/// bytes.
+#[deriving(Clone)]
+pub struct DecodeUtf8<I> {
+    iter: I,
+    // A byte that has been looked at but not yet consumed, such as one that
+    // ended the previous, malformed, sequence. It is decoded before reading
+    // anything else from `iter`.
+    buf: Option<u8>,
+}
+
+/// The error yielded by `DecodeUtf8` for a malformed UTF-8 sequence.
+#[deriving(Clone, PartialEq, Eq, Show)]
+pub enum Utf8Error {
+    /// The bytes do not form a valid encoding of a character, for the
+    /// given reason.
+    InvalidSequence(Utf8ErrorKind),
+    /// The input ended in the middle of a multi-byte sequence.
+    TruncatedSequence,
+}
+
+/// The reason that a sequence of bytes is not valid UTF-8, as held by
+/// `Utf8Error::InvalidSequence`.
+#[deriving(Clone, PartialEq, Eq, Show)]
+pub enum Utf8ErrorKind {
+    /// The sequence starts with a byte in the range [0xF8,0xFF], which
+    /// never appears in UTF-8.
+    InvalidLeadByte,
+    /// The sequence starts with a continuation byte, in the range
+    /// [0x80,0xBF], rather than a leading byte.
+    UnexpectedContinuation,
+    /// A multi-byte sequence is interrupted by a byte which is not a
+    /// continuation byte.
+    MissingContinuation,
+    /// The sequence encodes a code point which fits in fewer bytes, such
+    /// as `0xC0 0x80` for U+0000.
+    OverlongEncoding,
+    /// The sequence encodes a surrogate, or a value above `MAX`.
+    InvalidCodePoint,
+}
+
+impl Copy for Utf8Error {}
+
+impl Copy for Utf8ErrorKind {}
+
+// A source of bytes which can be looked at before they are consumed, so
+// that decoding a sequence never takes a byte that doesn't belong to it.
+trait PeekByte {
+    fn peek_byte(&mut self) -> Option<u8>;
+    fn take_byte(&mut self);
+}
+
+impl<I: Iterator<u8>> PeekByte for DecodeUtf8<I> {
+    #[inline]
+    fn peek_byte(&mut self) -> Option<u8> {
+        if self.buf.is_none() {
+            self.buf = self.iter.next();
+        }
+        self.buf
+    }
+
+    #[inline]
+    fn take_byte(&mut self) { self.buf = None; }
+}
+
+impl<I: Iterator<u8>> PeekByte for Peekable<u8, I> {
+    #[inline]
+    fn peek_byte(&mut self) -> Option<u8> { self.peek().map(|&b| b) }
+
+    #[inline]
+    fn take_byte(&mut self) { self.next(); }
+}
+
+// Decodes the next character, along with the number of bytes in its
+// sequence, or in the malformed sequence found in its place.
+fn decode_utf8_sequence<P: PeekByte>(src: &mut P) -> Option<(Result<char, Utf8Error>, uint)> {
+    use self::Utf8Error::{InvalidSequence, TruncatedSequence};
+    use self::Utf8ErrorKind::{InvalidLeadByte, UnexpectedContinuation, MissingContinuation};
+    use self::Utf8ErrorKind::{OverlongEncoding, InvalidCodePoint};
+
+    let first = match src.peek_byte() {
+        Some(b) => b,
+        None => return None
+    };
+    src.take_byte();
+    // The payload bits of the leading byte, the length of the sequence,
+    // and the range of its second byte. Restricting the second byte
+    // rules out overlong encodings, surrogates and values above `MAX` as
+    // soon as possible, so that each malformed sequence is as short as
+    // it can be.
+    let (init, width, lo, hi) = match first {
+        0x00 ... 0x7f => return Some((Ok(first as char), 1)),
+        0xc2 ... 0xdf => (first & 0x1F, 2u, 0x80, 0xbf),
+        0xe0 => (first & 0x0F, 3u, 0xa0, 0xbf),
+        0xed => (first & 0x0F, 3u, 0x80, 0x9f),
+        0xe1 ... 0xef => (first & 0x0F, 3u, 0x80, 0xbf),
+        0xf0 => (first & 0x07, 4u, 0x90, 0xbf),
+        0xf4 => (first & 0x07, 4u, 0x80, 0x8f),
+        0xf1 ... 0xf3 => (first & 0x07, 4u, 0x80, 0xbf),
+        0x80 ... 0xbf => return Some((Err(InvalidSequence(UnexpectedContinuation)), 1)),
+        // these only ever begin overlong encodings
+        0xc0 | 0xc1 => return Some((Err(InvalidSequence(OverlongEncoding)), 1)),
+        // these only ever begin encodings of values above `MAX`
+        0xf5 ... 0xf7 => return Some((Err(InvalidSequence(InvalidCodePoint)), 1)),
+        _ => return Some((Err(InvalidSequence(InvalidLeadByte)), 1))
+    };
+
+    let mut code = init as u32;
+    for i in range(1, width) {
+        let b = match src.peek_byte() {
+            Some(b) => b,
+            None => return Some((Err(TruncatedSequence), i))
+        };
+        if !is_utf8_continuation(b) {
+            // not part of this sequence, so it is left to be decoded next
+            return Some((Err(InvalidSequence(MissingContinuation)), i))
+        }
+        if i == 1 && (b < lo || b > hi) {
+            // the sequence can only be overlong or out of range, so it
+            // ends before this byte, which is left to be decoded next
+            let kind = if first == 0xe0 || first == 0xf0 {
+                OverlongEncoding
+            } else {
+                InvalidCodePoint
+            };
+            return Some((Err(InvalidSequence(kind)), 1))
+        }
+        src.take_byte();
+        code = (code << 6) | (b & CONT_MASK) as u32;
+    }
+    // the checks above leave only valid chars
+    Some((Ok(unsafe { from_u32_unchecked(code) }), width))
+}
+
+impl<I: Iterator<u8>> DecodeUtf8<I> {
+    // Decodes the next character, along with the number of bytes in its
+    // sequence, or in the malformed sequence found in its place.
+    #[inline]
+    fn next_sequence(&mut self) -> Option<(Result<char, Utf8Error>, uint)> {
+        decode_utf8_sequence(self)
+    }
+}
+
+impl<I: Iterator<u8>> Iterator<Result<char, Utf8Error>> for DecodeUtf8<I> {
+    #[inline]
+    fn next(&mut self) -> Option<Result<char, Utf8Error>> {
+        self.next_sequence().map(|(result, _)| result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (low, high) = self.iter.size_hint();
+        let buffered = if self.buf.is_some() { 1 } else { 0 };
+        // every byte yields at most one item, and every item consumes at
+        // most four bytes
+        (low / MAX_UTF8_LEN + buffered, high.and_then(|h| h.checked_add(buffered)))
+    }
+}
+
+/// An iterator that decodes UTF-8 encoded characters from an iterator of
+/// bytes, replacing malformed sequences with `REPLACEMENT_CHARACTER`.
+#[deriving(Clone)]
+pub struct DecodeUtf8Lossy<I> {
+    inner: DecodeUtf8<I>
+}
+
+impl<I: Iterator<u8>> Iterator<char> for DecodeUtf8Lossy<I> {
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next().map(|r| r.unwrap_or(REPLACEMENT_CHARACTER))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.inner.size_hint() }
+}
+
+/// An iterator that decodes UTF-8 encoded characters from an iterator of
+/// bytes, along with the byte offset at which each one starts.
+#[deriving(Clone)]
+pub struct CharIndicesUtf8<I> {
+    inner: DecodeUtf8<I>,
+    // the offset of the next sequence
+    offset: uint,
+}
+
+impl<I: Iterator<u8>> Iterator<(uint, Result<char, Utf8Error>)> for CharIndicesUtf8<I> {
+    #[inline]
+    fn next(&mut self) -> Option<(uint, Result<char, Utf8Error>)> {
+        self.inner.next_sequence().map(|(result, len)| {
+            let offset = self.offset;
+            self.offset += len;
+            (offset, result)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.inner.size_hint() }
+}
+
+/// An iterator that decodes UTF-16 encoded characters from an iterator of
+/// `u16`s.
+#[deriving(Clone)]
+pub struct DecodeUtf16<I> {
+    iter: I,
+    // A code unit that followed an unpaired high surrogate; it is decoded
+    // before reading anything else from `iter`.
+    buf: Option<u16>,
+}
+
+/// The error yielded by `DecodeUtf16` for an unpaired surrogate.
+#[deriving(Clone, PartialEq, Eq, Show)]
+pub struct Utf16Error {
+    /// The surrogate which could not be paired.
+    pub unit: u16,
+}
+
+impl Copy for Utf16Error {}
+
+impl<I: Iterator<u16>> Iterator<Result<char, Utf16Error>> for DecodeUtf16<I> {
+    fn next(&mut self) -> Option<Result<char, Utf16Error>> {
+        let u = match self.buf.take() {
+            Some(u) => u,
+            None => match self.iter.next() {
+                Some(u) => u,
+                None => return None
+            }
+        };
+
+        if is_utf16_low_surrogate(u) {
+            // a trailing surrogate without its leading one
+            Some(Err(Utf16Error { unit: u }))
+        } else if !is_utf16_high_surrogate(u) {
+            // not a surrogate
+            Some(Ok(unsafe { from_u32_unchecked(u as u32) }))
+        } else {
+            let u2 = match self.iter.next() {
+                Some(u2) => u2,
+                // eof
+                None => return Some(Err(Utf16Error { unit: u }))
+            };
+            match decode_utf16_pair(u, u2) {
+                Some(c) => Some(Ok(c)),
+                None => {
+                    // not a trailing surrogate, so decode it next time
+                    self.buf = Some(u2);
+                    Some(Err(Utf16Error { unit: u }))
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (low, high) = self.iter.size_hint();
+        let buffered = if self.buf.is_some() { 1 } else { 0 };
+        // we could be entirely valid surrogates (2 elements per char), or
+        // entirely non-surrogates (1 element per char)
+        (low.saturating_add(buffered) / 2, high.and_then(|h| h.checked_add(buffered)))
+    }
+}
+
+/// An iterator that decodes UTF-16 encoded characters from an iterator of
+/// `u16`s, replacing unpaired surrogates with `REPLACEMENT_CHARACTER`.
+#[deriving(Clone)]
+pub struct DecodeUtf16Lossy<I> {
+    inner: DecodeUtf16<I>
+}
+
+impl<I: Iterator<u16>> Iterator<char> for DecodeUtf16Lossy<I> {
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next().map(|r| r.unwrap_or(REPLACEMENT_CHARACTER))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.inner.size_hint() }
+}
+
+/// An iterator over the characters that represent a `char`, escaped for
+/// debugging output.
+#[deriving(Clone)]
+pub struct DebugEscapedChars {
+    inner: DefaultEscapedChars
+}
+
+impl Iterator<char> for DebugEscapedChars {
+    #[inline]
+    fn next(&mut self) -> Option<char> { self.inner.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.inner.size_hint() }
+}
+
+impl DoubleEndedIterator<char> for DebugEscapedChars {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> { self.inner.next_back() }
+}
+
+impl ExactSizeIterator<char> for DebugEscapedChars {}
+
+/// An iterator over the characters that represent a `char`, escaped for
+/// use in a character literal.
+#[deriving(Clone)]
+pub struct CharLiteralEscapedChars {
+    inner: DefaultEscapedChars
+}
+
+impl Iterator<char> for CharLiteralEscapedChars {
+    #[inline]
+    fn next(&mut self) -> Option<char> { self.inner.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.inner.size_hint() }
+}
+
+impl DoubleEndedIterator<char> for CharLiteralEscapedChars {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> { self.inner.next_back() }
+}
+
+impl ExactSizeIterator<char> for CharLiteralEscapedChars {}
+
+/// An iterator over the characters that represent a `char`, as escaped
+/// for use in a JSON string.
+#[deriving(Clone)]
+pub struct JsonEscape {
+    c: char,
+    // As for `UnicodeEscapedChars`, the positions in `front..back` are those
+    // not yet yielded from either end.
+    front: uint,
+    back: uint,
+}
+
+// Returns the char following the backslash in the JSON escape of `c`, or
+// `None` if `c` is not escaped.
+fn json_escape_kind(c: char) -> Option<char> {
+    match c {
+        '"' | '\\' => Some(c),
+        '\x08' => Some('b'),
+        '\x0c' => Some('f'),
+        '\n' => Some('n'),
+        '\r' => Some('r'),
+        '\t' => Some('t'),
+        '\x00' ... '\x1f' => Some('u'),
+        _ => None
+    }
+}
+
+impl JsonEscape {
+    // Returns the character at position `pos` of the escape.
+    fn char_at(&self, pos: uint) -> char {
+        match (json_escape_kind(self.c), pos) {
+            (None, _) => self.c,
+            (Some(_), 0) => '\\',
+            (Some(kind), 1) => kind,
+            // `\uNNNN`, and `c` is below 0x20 so all but the last two
+            // digits are zero
+            (Some(_), _) => {
+                let v = match ((self.c as u32) >> (4 * (5 - pos))) & 0xf {
+                    i @ 0 ... 9 => '0' as u32 + i,
+                    i => 'a' as u32 + (i - 10)
+                };
+                unsafe { from_u32_unchecked(v) }
+            }
+        }
+    }
+}
+
+impl Iterator<char> for JsonEscape {
+    fn next(&mut self) -> Option<char> {
+        if self.front < self.back {
+            let c = self.char_at(self.front);
+            self.front += 1;
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator<char> for JsonEscape {
+    fn next_back(&mut self) -> Option<char> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.char_at(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator<char> for JsonEscape {}
+
+/// An iterator over the characters that represent a `char`, as escaped
+/// for use in XML or HTML.
+#[deriving(Clone)]
+pub struct XmlEscape {
+    state: XmlEscapeState
+}
+
+#[deriving(Clone)]
+enum XmlEscapeState {
+    Char(char),
+    // the ASCII bytes of the entity not yet yielded from either end
+    Entity(&'static [u8]),
+    Done,
+}
+
+impl Iterator<char> for XmlEscape {
+    fn next(&mut self) -> Option<char> {
+        match self.state {
+            XmlEscapeState::Char(c) => {
+                self.state = XmlEscapeState::Done;
+                Some(c)
+            }
+            XmlEscapeState::Entity(bytes) => match bytes.head() {
+                Some(&b) => {
+                    self.state = XmlEscapeState::Entity(bytes.tail());
+                    Some(b as char)
+                }
+                None => None
+            },
+            XmlEscapeState::Done => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = match self.state {
+            XmlEscapeState::Char(_) => 1,
+            XmlEscapeState::Entity(bytes) => bytes.len(),
+            XmlEscapeState::Done => 0,
+        };
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator<char> for XmlEscape {
+    fn next_back(&mut self) -> Option<char> {
+        match self.state {
+            XmlEscapeState::Char(c) => {
+                self.state = XmlEscapeState::Done;
+                Some(c)
+            }
+            XmlEscapeState::Entity(bytes) => match bytes.last() {
+                Some(&b) => {
+                    self.state = XmlEscapeState::Entity(bytes.init());
+                    Some(b as char)
+                }
+                None => None
+            },
+            XmlEscapeState::Done => None,
+        }
+    }
+}
+
+impl ExactSizeIterator<char> for XmlEscape {}
+
+/// An iterator over a range of `char`s, as returned by `range_chars`.
+#[deriving(Clone)]
+pub struct CharRange {
+    // the chars in `start..end` are those not yet yielded from either end
+    start: char,
+    end: char,
+}
+
+impl Iterator<char> for CharRange {
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if self.start < self.end {
+            let c = self.start;
+            // `c` is below `end`, so it always has a successor
+            self.start = c.successor().unwrap_or(self.end);
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.start < self.end {
+            let mut len = self.end as uint - self.start as uint;
+            if self.start < '\u{d800}' && self.end > '\u{dfff}' {
+                len -= 0x800;
+            }
+            (len, Some(len))
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl DoubleEndedIterator<char> for CharRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        if self.start < self.end {
+            // `end` is above `start`, so it always has a predecessor
+            self.end = self.end.predecessor().unwrap_or(self.start);
+            Some(self.end)
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator<char> for CharRange {}
+
+/// A Unicode code point: either a Unicode scalar value, as held by a
+/// `char`, or a surrogate in the range [0xD800,0xDFFF].
+///
+/// Code points are encoded with the WTF-8 scheme, which is UTF-8 extended
+/// to encode surrogates as three bytes each. This allows ill-formed UTF-16,
+/// such as Windows file names, to be held losslessly.
+#[deriving(Clone, PartialEq, Eq, PartialOrd, Ord, Show)]
+#[unstable = "pending decisions about the WTF-8 API"]
+pub struct CodePoint {
+    value: u32,
 }
 
+impl Copy for CodePoint {}
+
+#[unstable = "pending decisions about the WTF-8 API"]
+impl CodePoint {
+    /// Converts a `char` to the code point it holds.
+    #[inline]
+    pub fn from_char(c: char) -> CodePoint {
+        CodePoint { value: c as u32 }
+    }
+
+    /// Returns the numeric value of the code point.
+    #[inline]
+    pub fn to_u32(self) -> u32 {
+        self.value
+    }
+
+    /// Converts the code point to a `char`, or returns `None` if it is a
+    /// surrogate.
+    #[inline]
+    pub fn to_char(self) -> Option<char> {
+        if is_surrogate(self.value) {
+            None
+        } else {
+            Some(unsafe { from_u32_unchecked(self.value) })
+        }
+    }
+
+    /// Returns the amount of bytes this code point would need if encoded in
+    /// WTF-8.
+    #[inline]
+    pub fn len_wtf8(self) -> uint {
+        utf8_len(self.value)
+    }
+
+    /// Encodes this code point as WTF-8 into the provided byte buffer,
+    /// and then returns the number of bytes written.
+    ///
+    /// Scalar values are encoded exactly as in UTF-8. If the buffer is not
+    /// large enough, nothing will be written into it and a `None` will be
+    /// returned.
+    #[inline]
+    pub fn encode_wtf8(self, dst: &mut [u8]) -> Option<uint> {
+        encode_utf8_raw(self.value, dst)
+    }
+}
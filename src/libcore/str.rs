@@ -832,7 +832,7 @@ impl<I> Iterator<u16> for Utf16Encoder<I> where I: Iterator<char> {
             return Some(tmp);
         }
 
-        let mut buf = [0u16, ..2];
+        let mut buf = [0u16, ..char::MAX_UTF16_LEN];
         self.chars.next().map(|ch| {
             let n = ch.encode_utf16(buf[mut]).unwrap_or(0);
             if n == 2 { self.extra = buf[1]; }
@@ -1007,7 +1007,7 @@ impl Utf16Item {
     pub fn to_char_lossy(&self) -> char {
         match *self {
             ScalarValue(c) => c,
-            LoneSurrogate(_) => '\u{FFFD}'
+            LoneSurrogate(_) => char::REPLACEMENT_CHARACTER
         }
     }
 }
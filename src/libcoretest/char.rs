@@ -10,7 +10,90 @@
 //
 // ignore-lexer-test FIXME #15679
 
-use core::char::{escape_unicode, escape_default};
+use core::char::{escape_unicode, escape_default, from_u32, MIN, MAX};
+use core::char::{parse_unicode_escape, MAX_UTF8_LEN, MAX_UTF16_LEN};
+use core::char::{range_chars, CharWriter, encode_utf8_into, encode_utf8_raw};
+use core::char::{from_u32_unchecked, from_u32_or_replacement};
+use core::char::{from_ascii, from_ascii_unchecked};
+use core::char::{from_u32_detailed, CharTryFromError};
+use core::char::{decode_utf8, decode_utf8_iter, char_indices_utf8, Utf8Error};
+use core::char::{utf8_char_width, is_utf8_continuation, next_code_point};
+use core::char::utf8_char_count;
+use core::char::Utf8Error::{InvalidSequence, TruncatedSequence};
+use core::char::Utf8ErrorKind::{InvalidLeadByte, UnexpectedContinuation, MissingContinuation};
+use core::char::Utf8ErrorKind::{OverlongEncoding, InvalidCodePoint};
+use core::char::{from_digit, try_from_digit, from_digit_base62, RadixError};
+use core::char::Case;
+use core::char::{from_ascii_digit, from_digit_upper, digit_char};
+use core::char::{decode_utf16_pair, is_utf16_high_surrogate, is_utf16_low_surrogate};
+use core::char::{decode_utf16, Utf16Error};
+use core::char::{is_surrogate, is_high_surrogate, is_low_surrogate};
+use core::char::{from_u32_surrogate, CodePoint};
+use core::char::{decode_utf8_lossy, decode_utf16_lossy, REPLACEMENT_CHARACTER};
+
+#[test]
+fn test_is_alphabetic() {
+    assert!('a'.is_alphabetic());
+    assert!('Z'.is_alphabetic());
+    assert!('é'.is_alphabetic());
+    assert!('λ'.is_alphabetic());
+    assert!('Ж'.is_alphabetic());
+    assert!('中'.is_alphabetic());
+    assert!(!'1'.is_alphabetic());
+    assert!(!'٣'.is_alphabetic());
+    assert!(!'_'.is_alphabetic());
+    assert!(!'!'.is_alphabetic());
+    assert!(!' '.is_alphabetic());
+}
+
+#[test]
+fn test_is_alphanumeric() {
+    assert!('a'.is_alphanumeric());
+    assert!('Z'.is_alphanumeric());
+    assert!('0'.is_alphanumeric());
+    assert!('9'.is_alphanumeric());
+    assert!('é'.is_alphanumeric());
+    assert!('中'.is_alphanumeric());
+    assert!('٣'.is_alphanumeric());
+    assert!('Ⅻ'.is_alphanumeric());
+    assert!(!'_'.is_alphanumeric());
+    assert!(!'!'.is_alphanumeric());
+    assert!(!'、'.is_alphanumeric());
+    assert!(!' '.is_alphanumeric());
+    assert!(!'\u{3000}'.is_alphanumeric());
+}
+
+#[test]
+fn test_is_xid_start() {
+    assert!('a'.is_xid_start());
+    assert!('Z'.is_xid_start());
+    assert!('é'.is_xid_start());
+    assert!('中'.is_xid_start());
+    assert!('Ⅰ'.is_xid_start());
+    assert!(!'_'.is_xid_start());
+    assert!(!'1'.is_xid_start());
+    assert!(!'-'.is_xid_start());
+    assert!(!'\u{301}'.is_xid_start());
+    // alphabetic, but not an identifier character
+    assert!('Ⓐ'.is_alphabetic());
+    assert!(!'Ⓐ'.is_xid_start());
+    // excluded by the NFKC modifications
+    assert!(!'\u{309b}'.is_xid_start());
+}
+
+#[test]
+fn test_is_xid_continue() {
+    assert!('a'.is_xid_continue());
+    assert!('中'.is_xid_continue());
+    assert!('_'.is_xid_continue());
+    assert!('1'.is_xid_continue());
+    assert!('\u{301}'.is_xid_continue());
+    assert!('·'.is_xid_continue());
+    assert!(!'-'.is_xid_continue());
+    assert!(!' '.is_xid_continue());
+    assert!(!'Ⓐ'.is_xid_continue());
+    assert!(!'\u{309b}'.is_xid_continue());
+}
 
 #[test]
 fn test_is_lowercase() {
@@ -19,6 +102,11 @@ fn test_is_lowercase() {
     assert!('ß'.is_lowercase());
     assert!(!'Ü'.is_lowercase());
     assert!(!'P'.is_lowercase());
+    // Other_Lowercase, not Ll
+    assert!('ᵃ'.is_lowercase());
+    assert!('ª'.is_lowercase());
+    // titlecase
+    assert!(!'ǅ'.is_lowercase());
 }
 
 #[test]
@@ -28,6 +116,11 @@ fn test_is_uppercase() {
     assert!(!'ß'.is_uppercase());
     assert!('Ö'.is_uppercase());
     assert!('T'.is_uppercase());
+    // Other_Uppercase, not Lu
+    assert!('Ⓐ'.is_uppercase());
+    // titlecase
+    assert!(!'ǅ'.is_uppercase());
+    assert!(!'ᵃ'.is_uppercase());
 }
 
 #[test]
@@ -39,6 +132,20 @@ fn test_is_whitespace() {
     assert!(!'a'.is_whitespace());
     assert!(!'_'.is_whitespace());
     assert!(!'\u0000'.is_whitespace());
+
+    assert!('\r'.is_whitespace());
+    assert!('\x0b'.is_whitespace());
+    assert!('\x0c'.is_whitespace());
+    assert!('\u{85}'.is_whitespace());
+    assert!('\u{a0}'.is_whitespace());
+    assert!('\u{2028}'.is_whitespace());
+    assert!('\u{2029}'.is_whitespace());
+    assert!('\u{1680}'.is_whitespace());
+    assert!('\u{202f}'.is_whitespace());
+    assert!('\u{3000}'.is_whitespace());
+    assert!(!'\u{180e}'.is_whitespace());
+    assert!(!'\u{200b}'.is_whitespace());
+    assert!(!'\u{feff}'.is_whitespace());
 }
 
 #[test]
@@ -55,11 +162,419 @@ fn test_to_digit() {
     assert_eq!('Z'.to_digit(36u), Some(35u));
     assert_eq!(' '.to_digit(10u), None);
     assert_eq!('$'.to_digit(36u), None);
+    assert_eq!('0'.to_digit(0u), None);
+    assert_eq!('0'.to_digit(1u), None);
+    assert_eq!('1'.to_digit(1u), None);
+}
+
+#[test]
+fn test_to_ascii_digit() {
+    assert_eq!('0'.to_ascii_digit(), Some(0));
+    assert_eq!('9'.to_ascii_digit(), Some(9));
+    assert_eq!('/'.to_ascii_digit(), None);
+    assert_eq!(':'.to_ascii_digit(), None);
+    assert_eq!('a'.to_ascii_digit(), None);
+    assert_eq!('٣'.to_ascii_digit(), None);
+    assert_eq!('０'.to_ascii_digit(), None);
+}
+
+#[test]
+fn test_from_ascii_digit() {
+    assert_eq!(from_ascii_digit(0), Some('0'));
+    assert_eq!(from_ascii_digit(9), Some('9'));
+    assert_eq!(from_ascii_digit(10), None);
+    assert_eq!(from_ascii_digit(255), None);
+
+    for d in range(0u8, 10) {
+        assert_eq!(from_ascii_digit(d).and_then(|c| c.to_ascii_digit()), Some(d));
+        assert_eq!(from_ascii_digit(d), from_digit(d as uint, 10));
+    }
+}
+
+#[test]
+fn test_to_digit_base62() {
+    assert_eq!('0'.to_digit_base62(), Some(0));
+    assert_eq!('9'.to_digit_base62(), Some(9));
+    assert_eq!('A'.to_digit_base62(), Some(10));
+    assert_eq!('Z'.to_digit_base62(), Some(35));
+    assert_eq!('a'.to_digit_base62(), Some(36));
+    assert_eq!('z'.to_digit_base62(), Some(61));
+    assert_eq!('-'.to_digit_base62(), None);
+    assert_eq!('_'.to_digit_base62(), None);
+    assert_eq!('é'.to_digit_base62(), None);
+    assert_eq!('０'.to_digit_base62(), None);
+}
+
+#[test]
+fn test_from_digit_base62() {
+    assert_eq!(from_digit_base62(0), Some('0'));
+    assert_eq!(from_digit_base62(10), Some('A'));
+    assert_eq!(from_digit_base62(36), Some('a'));
+    assert_eq!(from_digit_base62(61), Some('z'));
+    assert_eq!(from_digit_base62(62), None);
+
+    for n in range(0u, 62) {
+        assert_eq!(from_digit_base62(n).and_then(|c| c.to_digit_base62()), Some(n));
+    }
+}
+
+#[test]
+fn test_to_digit_checked() {
+    assert_eq!('z'.to_digit_checked(36u), Ok(Some(35u)));
+    assert_eq!('z'.to_digit_checked(35u), Ok(None));
+    assert_eq!('$'.to_digit_checked(10u), Ok(None));
+    assert_eq!('0'.to_digit_checked(0u), Ok(None));
+    assert_eq!('0'.to_digit_checked(1u), Ok(None));
+    assert_eq!('0'.to_digit_checked(37u), Err(RadixError { radix: 37 }));
+    assert!(!'0'.is_digit(1u));
+}
+
+#[test]
+fn test_is_digit_checked() {
+    assert_eq!('7'.is_digit_checked(8u), Ok(true));
+    assert_eq!('8'.is_digit_checked(8u), Ok(false));
+    assert_eq!('z'.is_digit_checked(36u), Ok(true));
+    assert_eq!('0'.is_digit_checked(1u), Ok(false));
+    assert_eq!('0'.is_digit_checked(40u), Err(RadixError { radix: 40 }));
+}
+
+#[test]
+#[should_fail]
+fn test_to_digit_radix_too_high() {
+    '0'.to_digit(37u);
+}
+
+#[test]
+fn test_to_digit_cased() {
+    assert_eq!('A'.to_digit_cased(16), Some((10, Case::Upper)));
+    assert_eq!('a'.to_digit_cased(16), Some((10, Case::Lower)));
+    assert_eq!('7'.to_digit_cased(16), Some((7, Case::NotApplicable)));
+    assert_eq!('g'.to_digit_cased(16), None);
+    assert_eq!('Z'.to_digit_cased(36), Some((35, Case::Upper)));
+}
+
+#[test]
+fn test_to_digit_wide() {
+    assert_eq!('７'.to_digit_wide(10), Some(7));
+    assert_eq!('０'.to_digit_wide(2), Some(0));
+    assert_eq!('９'.to_digit_wide(10), Some(9));
+    assert_eq!('９'.to_digit_wide(8), None);
+    assert_eq!('7'.to_digit_wide(10), Some(7));
+    assert_eq!('f'.to_digit_wide(16), Some(15));
+    // only the fullwidth digits are accepted
+    assert_eq!('Ｆ'.to_digit_wide(16), None);
+    assert_eq!('٣'.to_digit_wide(10), None);
+}
+
+#[test]
+#[should_fail]
+fn test_to_digit_wide_radix_too_high() {
+    '７'.to_digit_wide(37u);
+}
+
+#[test]
+fn test_to_digit_unicode() {
+    assert_eq!('5'.to_digit_unicode(10), Some(5));
+    assert_eq!('z'.to_digit_unicode(36), Some(35));
+    assert_eq!('５'.to_digit_unicode(10), Some(5));
+    assert_eq!('０'.to_digit_unicode(10), Some(0));
+    assert_eq!('９'.to_digit_unicode(10), Some(9));
+    assert_eq!('٣'.to_digit_unicode(10), Some(3));
+    assert_eq!('\u{1d7d7}'.to_digit_unicode(10), Some(9));
+    assert_eq!('９'.to_digit_unicode(8), None);
+    assert_eq!('0'.to_digit_unicode(1), None);
+    assert_eq!('０'.to_digit_unicode(1), None);
+    assert_eq!('٠'.to_digit_unicode(0), None);
+    // only Nd characters are accepted, so not fullwidth letters or other numbers
+    assert_eq!('Ａ'.to_digit_unicode(16), None);
+    assert_eq!('½'.to_digit_unicode(10), None);
+    assert_eq!('Ⅷ'.to_digit_unicode(10), None);
+    assert_eq!('５'.to_digit(10), None);
+
+    let digits: Vec<uint> = "１f７A".chars().map(|c| c.to_digit_unicode(16).unwrap()).collect();
+    assert_eq!(digits, vec![1, 15, 7, 10]);
+}
+
+#[test]
+fn test_numeric_value() {
+    assert_eq!('7'.numeric_value(), Some(7.0));
+    assert_eq!('٣'.numeric_value(), Some(3.0));
+    assert_eq!('½'.numeric_value(), Some(0.5));
+    assert_eq!('¾'.numeric_value(), Some(0.75));
+    assert_eq!('Ⅻ'.numeric_value(), Some(12.0));
+    assert_eq!('௰'.numeric_value(), Some(10.0));
+    assert_eq!('三'.numeric_value(), Some(3.0));
+    assert_eq!('万'.numeric_value(), Some(10000.0));
+    assert_eq!('兆'.numeric_value(), Some(1000000000000.0));
+    assert_eq!('\u{f33}'.numeric_value(), Some(-0.5));
+    assert_eq!('a'.numeric_value(), None);
+    assert_eq!('Ａ'.numeric_value(), None);
+    assert_eq!(' '.numeric_value(), None);
+}
+
+#[test]
+fn test_numeric_value_integer() {
+    assert_eq!('7'.numeric_value_integer(), Some(7));
+    assert_eq!('９'.numeric_value_integer(), Some(9));
+    assert_eq!('Ⅻ'.numeric_value_integer(), Some(12));
+    assert_eq!('万'.numeric_value_integer(), Some(10000));
+    assert_eq!('兆'.numeric_value_integer(), Some(1000000000000));
+    assert_eq!('½'.numeric_value_integer(), None);
+    assert_eq!('\u{f33}'.numeric_value_integer(), None);
+    assert_eq!('a'.numeric_value_integer(), None);
+}
+
+#[test]
+#[should_fail]
+fn test_to_digit_unicode_radix_too_high() {
+    '５'.to_digit_unicode(37u);
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!(MIN as u32, 0);
+    assert_eq!(MAX as u32, 0x10ffff);
+    assert_eq!(from_u32(MIN as u32), Some(MIN));
+    assert_eq!(from_u32(MAX as u32), Some(MAX));
+    assert_eq!(from_u32(MAX as u32 + 1), None);
+}
+
+#[test]
+fn test_is_noncharacter() {
+    assert!('\u{fdd0}'.is_noncharacter());
+    assert!('\u{fdef}'.is_noncharacter());
+    assert!('\u{fffe}'.is_noncharacter());
+    assert!('\u{ffff}'.is_noncharacter());
+    assert!('\u{1fffe}'.is_noncharacter());
+    assert!('\u{10ffff}'.is_noncharacter());
+    assert!(!'a'.is_noncharacter());
+    assert!(!'\u{fdcf}'.is_noncharacter());
+    assert!(!'\u{fdf0}'.is_noncharacter());
+    assert!(!'\u{fffd}'.is_noncharacter());
+    assert!(!'\u{10000}'.is_noncharacter());
+
+    let count = range_chars(MIN, MAX).filter(|c| c.is_noncharacter()).count();
+    // MAX itself is excluded from the range
+    assert_eq!(count + 1, 66);
+}
+
+#[test]
+fn test_approx_width() {
+    assert_eq!('\x00'.approx_width(), Some(0));
+    assert_eq!('\x07'.approx_width(), None);
+    assert_eq!('\x7f'.approx_width(), None);
+    assert_eq!('\u{85}'.approx_width(), None);
+    assert_eq!('a'.approx_width(), Some(1));
+    assert_eq!('\u{e9}'.approx_width(), Some(1));
+    assert_eq!('\u{ff61}'.approx_width(), Some(1));
+    assert_eq!('\u{301}'.approx_width(), Some(0));
+    assert_eq!('\u{200d}'.approx_width(), Some(0));
+    assert_eq!('\u{4e00}'.approx_width(), Some(2));
+    assert_eq!('\u{ac00}'.approx_width(), Some(2));
+    assert_eq!('\u{ff01}'.approx_width(), Some(2));
+    assert_eq!('\u{20000}'.approx_width(), Some(2));
+
+    // agrees with the table-driven width over the ranges it covers
+    for &c in ['a', '\u{301}', '\u{1100}', '\u{1160}', '\u{200b}', '\u{3000}', '\u{3042}',
+               '\u{9fcc}', '\u{d7a3}', '\u{feff}', '\u{ff01}'].iter() {
+        assert_eq!(c.approx_width(), c.width(false));
+    }
+}
+
+#[test]
+fn test_successor() {
+    assert_eq!('\0'.successor(), Some('\x01'));
+    assert_eq!('a'.successor(), Some('b'));
+    assert_eq!('\u{d7fe}'.successor(), Some('\u{d7ff}'));
+    assert_eq!('\u{d7ff}'.successor(), Some('\u{e000}'));
+    assert_eq!('\u{e000}'.successor(), Some('\u{e001}'));
+    assert_eq!('\u{ffff}'.successor(), Some('\U00010000'));
+    assert_eq!('\U0010fffe'.successor(), Some(MAX));
+    assert_eq!(MAX.successor(), None);
+}
+
+#[test]
+fn test_predecessor() {
+    assert_eq!(MIN.predecessor(), None);
+    assert_eq!('\x01'.predecessor(), Some('\0'));
+    assert_eq!('b'.predecessor(), Some('a'));
+    assert_eq!('\u{d7ff}'.predecessor(), Some('\u{d7fe}'));
+    assert_eq!('\u{e000}'.predecessor(), Some('\u{d7ff}'));
+    assert_eq!('\u{e001}'.predecessor(), Some('\u{e000}'));
+    assert_eq!('\U00010000'.predecessor(), Some('\u{ffff}'));
+    assert_eq!(MAX.predecessor(), Some('\U0010fffe'));
+}
+
+#[test]
+fn test_checked_add() {
+    assert_eq!('a'.checked_add(0), Some('a'));
+    assert_eq!('a'.checked_add(13), Some('n'));
+    assert_eq!('\u{d7ff}'.checked_add(1), None);
+    assert_eq!('\u{d000}'.checked_add(0x800), None);
+    assert_eq!('\u{d7ff}'.checked_add(0x801), Some('\u{e000}'));
+    assert_eq!('\U0010fffe'.checked_add(1), Some(MAX));
+    assert_eq!(MAX.checked_add(1), None);
+    assert_eq!('a'.checked_add(0xffffffff), None);
+}
+
+#[test]
+fn test_checked_sub() {
+    assert_eq!('n'.checked_sub(13), Some('a'));
+    assert_eq!('\0'.checked_sub(0), Some('\0'));
+    assert_eq!('\0'.checked_sub(1), None);
+    assert_eq!('a'.checked_sub(0x62), None);
+    assert_eq!('\u{e000}'.checked_sub(1), None);
+    assert_eq!('\u{e000}'.checked_sub(0x801), Some('\u{d7ff}'));
+    assert_eq!(MAX.checked_sub(0x10ffff), Some('\0'));
+}
+
+#[test]
+fn test_range_chars() {
+    fn check(start: char, end: char, expect: &[char]) {
+        let v: Vec<char> = range_chars(start, end).collect();
+        assert_eq!(v.as_slice(), expect);
+        let mut v: Vec<char> = range_chars(start, end).rev().collect();
+        v.reverse();
+        assert_eq!(v.as_slice(), expect);
+        assert_eq!(range_chars(start, end).len(), expect.len());
+    }
+
+    check('a', 'a', &[]);
+    check('b', 'a', &[]);
+    check('a', 'e', &['a', 'b', 'c', 'd']);
+    check('\u{d7fe}', '\u{e001}', &['\u{d7fe}', '\u{d7ff}', '\u{e000}']);
+    check('\u{d7ff}', '\u{e000}', &['\u{d7ff}']);
+    check('\U0010fffd', MAX, &['\U0010fffd', '\U0010fffe']);
+
+    assert!(range_chars('\u{d000}', '\u{f000}').all(|c| !is_surrogate(c as u32)));
+    assert_eq!(range_chars(MIN, MAX).len(), 0x10ffff - 0x800);
+
+    let mut iter = range_chars('\u{d7ff}', '\u{e001}');
+    assert_eq!(iter.next_back(), Some('\u{e000}'));
+    assert_eq!(iter.next(), Some('\u{d7ff}'));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_from_u32_unchecked() {
+    unsafe {
+        assert_eq!(from_u32_unchecked(0x61), 'a');
+        assert_eq!(from_u32_unchecked(0xD7FF), '\u{D7FF}');
+        assert_eq!(from_u32_unchecked(0x1F4A9), '\u{1F4A9}');
+    }
+}
+
+#[test]
+fn test_from_u32_or_replacement() {
+    assert_eq!(from_u32_or_replacement(0x61), 'a');
+    assert_eq!(from_u32_or_replacement(0x10FFFF), MAX);
+    assert_eq!(from_u32_or_replacement(0xD800), REPLACEMENT_CHARACTER);
+    assert_eq!(from_u32_or_replacement(0xDFFF), REPLACEMENT_CHARACTER);
+    assert_eq!(from_u32_or_replacement(0x110000), REPLACEMENT_CHARACTER);
+}
+
+#[test]
+fn test_from_u32_detailed() {
+    assert_eq!(from_u32_detailed(0x61), Ok('a'));
+    assert_eq!(from_u32_detailed(0xD7FF), Ok('\u{D7FF}'));
+    assert_eq!(from_u32_detailed(0x10FFFF), Ok(MAX));
+    assert_eq!(from_u32_detailed(0xD800), Err(CharTryFromError::Surrogate(0xD800)));
+    assert_eq!(from_u32_detailed(0xDFFF), Err(CharTryFromError::Surrogate(0xDFFF)));
+    assert_eq!(from_u32_detailed(0x110000), Err(CharTryFromError::OutOfRange(0x110000)));
+    assert_eq!(from_u32_detailed(0xFFFFFFFF), Err(CharTryFromError::OutOfRange(0xFFFFFFFF)));
+
+    assert_eq!(from_u32_detailed(0xDABC).unwrap_err().value(), 0xDABC);
+    assert_eq!(from_u32_detailed(0x123456).unwrap_err().value(), 0x123456);
+}
+
+#[test]
+fn test_from_ascii() {
+    assert_eq!(from_ascii(0), Some('\0'));
+    assert_eq!(from_ascii(b'a'), Some('a'));
+    assert_eq!(from_ascii(0x7f), Some('\x7f'));
+    assert_eq!(from_ascii(0x80), None);
+    assert_eq!(from_ascii(0xff), None);
+    assert_eq!(unsafe { from_ascii_unchecked(b'Z') }, 'Z');
+}
+
+#[test]
+fn test_is_surrogate() {
+    assert!(!is_surrogate(0xD7FF));
+    assert!(is_surrogate(0xD800));
+    assert!(is_surrogate(0xDBFF));
+    assert!(is_surrogate(0xDC00));
+    assert!(is_surrogate(0xDFFF));
+    assert!(!is_surrogate(0xE000));
+    assert!(!is_surrogate(0x1D800));
+
+    assert!(is_high_surrogate(0xD800));
+    assert!(is_high_surrogate(0xDBFF));
+    assert!(!is_high_surrogate(0xDC00));
+    assert!(!is_high_surrogate(0xD7FF));
+    assert!(is_low_surrogate(0xDC00));
+    assert!(is_low_surrogate(0xDFFF));
+    assert!(!is_low_surrogate(0xDBFF));
+    assert!(!is_low_surrogate(0xE000));
+}
+
+#[test]
+fn test_from_digit() {
+    assert_eq!(from_digit(0, 10), Some('0'));
+    assert_eq!(from_digit(9, 10), Some('9'));
+    assert_eq!(from_digit(11, 16), Some('b'));
+    assert_eq!(from_digit(35, 36), Some('z'));
+    assert_eq!(from_digit(10, 10), None);
+    assert_eq!(from_digit(0, 0), None);
+    assert_eq!(from_digit(0, 1), None);
+}
+
+#[test]
+fn test_from_digit_upper() {
+    assert_eq!(from_digit_upper(0, 10), Some('0'));
+    assert_eq!(from_digit_upper(9, 10), Some('9'));
+    assert_eq!(from_digit_upper(11, 16), Some('B'));
+    assert_eq!(from_digit_upper(35, 36), Some('Z'));
+    assert_eq!(from_digit_upper(16, 16), None);
+    assert_eq!(from_digit_upper(0, 1), None);
+}
+
+#[test]
+#[should_fail]
+fn test_from_digit_upper_radix_too_high() {
+    from_digit_upper(0, 37);
+}
+
+#[test]
+fn test_digit_char() {
+    assert_eq!(digit_char(10, 16, true), Some('A'));
+    assert_eq!(digit_char(10, 16, false), Some('a'));
+    assert_eq!(digit_char(7, 8, true), Some('7'));
+    assert_eq!(digit_char(35, 36, true), Some('Z'));
+    assert_eq!(digit_char(16, 16, false), None);
+}
+
+#[test]
+fn test_try_from_digit() {
+    assert_eq!(try_from_digit(11, 16), Ok(Some('b')));
+    assert_eq!(try_from_digit(16, 16), Ok(None));
+    assert_eq!(try_from_digit(35, 36), Ok(Some('z')));
+    assert_eq!(try_from_digit(1, 37), Err(RadixError { radix: 37 }));
+}
+
+#[test]
+#[should_fail]
+fn test_from_digit_radix_too_high() {
+    from_digit(1, 37);
 }
 
 #[test]
 fn test_to_lowercase() {
     assert_eq!('A'.to_lowercase(), 'a');
+    assert_eq!('Z'.to_lowercase(), 'z');
+    assert_eq!('z'.to_lowercase(), 'z');
+    assert_eq!('@'.to_lowercase(), '@');
+    assert_eq!('['.to_lowercase(), '[');
     assert_eq!('Ö'.to_lowercase(), 'ö');
     assert_eq!('ß'.to_lowercase(), 'ß');
     assert_eq!('Ü'.to_lowercase(), 'ü');
@@ -76,6 +591,10 @@ fn test_to_lowercase() {
 #[test]
 fn test_to_uppercase() {
     assert_eq!('a'.to_uppercase(), 'A');
+    assert_eq!('z'.to_uppercase(), 'Z');
+    assert_eq!('Z'.to_uppercase(), 'Z');
+    assert_eq!('`'.to_uppercase(), '`');
+    assert_eq!('{'.to_uppercase(), '{');
     assert_eq!('ö'.to_uppercase(), 'Ö');
     assert_eq!('ß'.to_uppercase(), 'ß'); // not ẞ: Latin capital letter sharp s
     assert_eq!('ü'.to_uppercase(), 'Ü');
@@ -90,6 +609,228 @@ fn test_to_uppercase() {
     assert_eq!('ς'.to_uppercase(), 'Σ');
 }
 
+#[test]
+fn test_to_titlecase() {
+    assert_eq!('a'.to_titlecase(), 'A');
+    assert_eq!('Z'.to_titlecase(), 'Z');
+    assert_eq!('{'.to_titlecase(), '{');
+    assert_eq!('ö'.to_titlecase(), 'Ö');
+    assert_eq!('ß'.to_titlecase(), 'ß');
+
+    assert_eq!('ǆ'.to_titlecase(), 'ǅ');
+    assert_eq!('Ǆ'.to_titlecase(), 'ǅ');
+    assert_eq!('ǅ'.to_titlecase(), 'ǅ');
+    assert_eq!('ǉ'.to_titlecase(), 'ǈ');
+    assert_eq!('Ǉ'.to_titlecase(), 'ǈ');
+    assert_eq!('ǌ'.to_titlecase(), 'ǋ');
+    assert_eq!('Ǌ'.to_titlecase(), 'ǋ');
+    assert_eq!('ǳ'.to_titlecase(), 'ǲ');
+}
+
+#[test]
+fn test_fold_case() {
+    assert_eq!('A'.fold_case(), 'a');
+    assert_eq!('a'.fold_case(), 'a');
+    assert_eq!('{'.fold_case(), '{');
+    assert_eq!('Ö'.fold_case(), 'ö');
+    // Greek sigma, including the final form
+    assert_eq!('Σ'.fold_case(), 'σ');
+    assert_eq!('ς'.fold_case(), 'σ');
+    assert_eq!('σ'.fold_case(), 'σ');
+    // Kelvin sign
+    assert_eq!('\u{212a}'.fold_case(), 'k');
+    assert_eq!('Д'.fold_case(), 'д');
+    // simple foldings only: the full one for 'ß' is "ss"
+    assert_eq!('ß'.fold_case(), 'ß');
+    assert_eq!('ẞ'.fold_case(), 'ß');
+    // the dotted capital I only has full and Turkic foldings
+    assert_eq!('İ'.fold_case(), 'İ');
+}
+
+#[test]
+fn test_eq_ignore_case() {
+    assert!('K'.eq_ignore_case('k'));
+    assert!('\u{212a}'.eq_ignore_case('K'));
+    assert!('ς'.eq_ignore_case('Σ'));
+    assert!('ς'.eq_ignore_case('σ'));
+    assert!('Ж'.eq_ignore_case('ж'));
+    assert!('ẞ'.eq_ignore_case('ß'));
+    assert!('İ'.eq_ignore_case('İ'));
+    assert!(!'İ'.eq_ignore_case('i'));
+    assert!(!'a'.eq_ignore_case('b'));
+    assert!(!'ж'.eq_ignore_case('з'));
+}
+
+#[test]
+fn test_to_lowercase_full() {
+    fn check(input: char, expect: &str) {
+        let s = input.to_lowercase_full().collect::<String>();
+        assert_eq!(s, expect);
+    }
+    check('A', "a");
+    check('a', "a");
+    check('Ö', "ö");
+    check('Σ', "σ");
+    check('İ', "i\u{307}");
+    check('💩', "💩");
+}
+
+#[test]
+fn test_to_uppercase_full() {
+    fn check(input: char, expect: &str) {
+        let s = input.to_uppercase_full().collect::<String>();
+        assert_eq!(s, expect);
+    }
+    check('a', "A");
+    check('A', "A");
+    check('ö', "Ö");
+    check('ß', "SS");
+    check('ﬁ', "FI");
+    check('ŉ', "ʼN");
+    check('ΐ', "\u{399}\u{308}\u{301}");
+    check('💩', "💩");
+
+    let mut it = 'ß'.to_uppercase_full();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    it.next();
+    assert_eq!(it.size_hint(), (1, Some(1)));
+    it.next();
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!('a'.to_uppercase_full().size_hint(), (1, Some(1)));
+}
+
+#[test]
+fn test_to_ascii_uppercase() {
+    assert_eq!('a'.to_ascii_uppercase(), 'A');
+    assert_eq!('z'.to_ascii_uppercase(), 'Z');
+    assert_eq!('A'.to_ascii_uppercase(), 'A');
+    assert_eq!('0'.to_ascii_uppercase(), '0');
+    assert_eq!('{'.to_ascii_uppercase(), '{');
+    assert_eq!('é'.to_ascii_uppercase(), 'é');
+    assert_eq!('ß'.to_ascii_uppercase(), 'ß');
+}
+
+#[test]
+fn test_to_ascii_lowercase() {
+    assert_eq!('A'.to_ascii_lowercase(), 'a');
+    assert_eq!('Z'.to_ascii_lowercase(), 'z');
+    assert_eq!('a'.to_ascii_lowercase(), 'a');
+    assert_eq!('0'.to_ascii_lowercase(), '0');
+    assert_eq!('@'.to_ascii_lowercase(), '@');
+    assert_eq!('É'.to_ascii_lowercase(), 'É');
+    assert_eq!('\u{212a}'.to_ascii_lowercase(), '\u{212a}');
+}
+
+#[test]
+fn test_eq_ignore_ascii_case() {
+    assert!('a'.eq_ignore_ascii_case(&'A'));
+    assert!('Z'.eq_ignore_ascii_case(&'z'));
+    assert!('q'.eq_ignore_ascii_case(&'q'));
+    assert!('1'.eq_ignore_ascii_case(&'1'));
+    assert!(!'1'.eq_ignore_ascii_case(&'!'));
+    assert!(!'@'.eq_ignore_ascii_case(&'`'));
+    assert!(!'['.eq_ignore_ascii_case(&'{'));
+    assert!(!'K'.eq_ignore_ascii_case(&'\u{212a}'));
+    assert!(!'é'.eq_ignore_ascii_case(&'É'));
+}
+
+#[test]
+fn test_is_combining_mark() {
+    assert!('\u{301}'.is_combining_mark());
+    assert!('\u{903}'.is_combining_mark());
+    assert!('\u{20dd}'.is_combining_mark());
+    assert!('\u{e0100}'.is_combining_mark());
+    assert!(!'\u{e9}'.is_combining_mark());
+    assert!(!'e'.is_combining_mark());
+    assert!(!'^'.is_combining_mark());
+    assert!(!'\u{2c6}'.is_combining_mark());
+}
+
+#[test]
+fn test_canonical_combining_class() {
+    assert_eq!('a'.canonical_combining_class(), 0);
+    assert_eq!('\u{e9}'.canonical_combining_class(), 0);
+    assert_eq!('\u{301}'.canonical_combining_class(), 230);
+    assert_eq!('\u{316}'.canonical_combining_class(), 220);
+    assert_eq!('\u{334}'.canonical_combining_class(), 1);
+    assert_eq!('\u{5b0}'.canonical_combining_class(), 10);
+    // a spacing mark which is still a starter
+    assert_eq!('\u{903}'.canonical_combining_class(), 0);
+}
+
+#[test]
+fn test_bidi_mirror() {
+    assert_eq!('('.bidi_mirror(), Some(')'));
+    assert_eq!(')'.bidi_mirror(), Some('('));
+    assert_eq!('['.bidi_mirror(), Some(']'));
+    assert_eq!('<'.bidi_mirror(), Some('>'));
+    assert_eq!('\u{ab}'.bidi_mirror(), Some('\u{bb}'));
+    assert_eq!('\u{2208}'.bidi_mirror(), Some('\u{220b}'));
+    assert_eq!('a'.bidi_mirror(), None);
+    assert_eq!('/'.bidi_mirror(), None);
+    // mirrored, but without a mirroring glyph
+    assert_eq!('\u{221b}'.bidi_mirror(), None);
+}
+
+#[test]
+fn test_is_emoji() {
+    assert!('\u{1f600}'.is_emoji());
+    assert!('\u{2764}'.is_emoji());
+    // keycap bases are emoji, although they are not displayed as such
+    assert!('#'.is_emoji());
+    assert!('*'.is_emoji());
+    assert!('7'.is_emoji());
+    assert!(!'a'.is_emoji());
+    assert!(!' '.is_emoji());
+    assert!(!'\u{e9}'.is_emoji());
+    // added after UNICODE_VERSION, so not assigned
+    assert!(!'\u{1f32d}'.is_emoji());
+    assert!(!'\u{1f6d2}'.is_emoji());
+}
+
+#[test]
+fn test_is_emoji_assigned() {
+    use std::char::GeneralCategory;
+
+    for c in range_chars(MIN, MAX).filter(|c| c.is_emoji()) {
+        assert!(c.general_category() != GeneralCategory::Cn);
+    }
+}
+
+#[test]
+fn test_is_emoji_presentation() {
+    assert!('\u{1f600}'.is_emoji_presentation());
+    assert!('\u{231a}'.is_emoji_presentation());
+    assert!(!'#'.is_emoji_presentation());
+    // emoji, but displayed as text unless followed by U+FE0F
+    assert!(!'\u{2764}'.is_emoji_presentation());
+    assert!(!'a'.is_emoji_presentation());
+}
+
+#[test]
+fn test_bidi_class() {
+    use std::char::BidiClass;
+
+    assert_eq!('a'.bidi_class(), BidiClass::L);
+    assert_eq!('\u{5d0}'.bidi_class(), BidiClass::R);
+    assert_eq!('\u{627}'.bidi_class(), BidiClass::AL);
+    assert_eq!('5'.bidi_class(), BidiClass::EN);
+    assert_eq!('\u{661}'.bidi_class(), BidiClass::AN);
+    assert_eq!('+'.bidi_class(), BidiClass::ES);
+    assert_eq!('$'.bidi_class(), BidiClass::ET);
+    assert_eq!(','.bidi_class(), BidiClass::CS);
+    assert_eq!('\u{301}'.bidi_class(), BidiClass::NSM);
+    assert_eq!('\n'.bidi_class(), BidiClass::B);
+    assert_eq!(' '.bidi_class(), BidiClass::WS);
+    assert_eq!('('.bidi_class(), BidiClass::ON);
+    assert_eq!('\u{2067}'.bidi_class(), BidiClass::RLI);
+    // unassigned code points in right-to-left blocks default to R or AL
+    assert_eq!('\u{5ff}'.bidi_class(), BidiClass::R);
+    assert_eq!('\u{fdc8}'.bidi_class(), BidiClass::AL);
+}
+
 #[test]
 fn test_is_control() {
     assert!('\u0000'.is_control());
@@ -101,6 +842,15 @@ fn test_is_control() {
     assert!(!'\u0020'.is_control());
     assert!(!'\u0055'.is_control());
     assert!(!'\u0068'.is_control());
+
+    assert!('\t'.is_control());
+    assert!('\x1f'.is_control());
+    assert!('\u{80}'.is_control());
+    assert!('\u{9f}'.is_control());
+    assert!(!' '.is_control());
+    assert!(!'~'.is_control());
+    assert!(!'\u{a0}'.is_control());
+    assert!(!'é'.is_control());
 }
 
 #[test]
@@ -113,6 +863,41 @@ fn test_is_digit() {
    assert!(!'Q'.is_numeric());
 }
 
+#[test]
+fn test_is_radix_digit() {
+    assert!('0'.is_binary_digit());
+    assert!('1'.is_binary_digit());
+    assert!(!'2'.is_binary_digit());
+    assert!('0'.is_octal_digit());
+    assert!('7'.is_octal_digit());
+    assert!(!'8'.is_octal_digit());
+    assert!('9'.is_hex_digit());
+    assert!('f'.is_hex_digit());
+    assert!('F'.is_hex_digit());
+    assert!(!'g'.is_hex_digit());
+    assert!(!'０'.is_hex_digit());
+
+    for c in range_chars('\x00', '\u{100}') {
+        assert_eq!(c.is_binary_digit(), c.is_digit(2));
+        assert_eq!(c.is_octal_digit(), c.is_digit(8));
+        assert_eq!(c.is_hex_digit(), c.is_digit(16));
+    }
+}
+
+#[test]
+fn test_is_numeric() {
+    assert!('5'.is_numeric());
+    assert!('5'.is_digit(10));
+    assert!('Ⅻ'.is_numeric());
+    assert!(!'Ⅻ'.is_digit(36));
+    assert!('½'.is_numeric());
+    assert!('²'.is_numeric());
+    assert!('٣'.is_numeric());
+    assert!(!'a'.is_numeric());
+    assert!('a'.is_digit(16));
+    assert!(!'a'.is_digit(10));
+}
+
 #[test]
 fn test_escape_default() {
     fn string(c: char) -> String {
@@ -148,6 +933,175 @@ fn test_escape_default() {
     assert_eq!(s, "\\U0001d4b6");
 }
 
+#[test]
+fn test_is_ascii_predicates() {
+    assert!('0'.is_ascii_digit());
+    assert!('9'.is_ascii_digit());
+    assert!(!'a'.is_ascii_digit());
+    assert!(!'/'.is_ascii_digit());
+
+    assert!('7'.is_ascii_hexdigit());
+    assert!('a'.is_ascii_hexdigit());
+    assert!('F'.is_ascii_hexdigit());
+    assert!(!'g'.is_ascii_hexdigit());
+    assert!(!'G'.is_ascii_hexdigit());
+
+    assert!('a'.is_ascii_alphabetic());
+    assert!('Z'.is_ascii_alphabetic());
+    assert!(!'0'.is_ascii_alphabetic());
+    assert!(!'@'.is_ascii_alphabetic());
+    assert!(!'['.is_ascii_alphabetic());
+
+    assert!('q'.is_ascii_alphanumeric());
+    assert!('5'.is_ascii_alphanumeric());
+    assert!(!'_'.is_ascii_alphanumeric());
+
+    assert!('!'.is_ascii_punctuation());
+    assert!('@'.is_ascii_punctuation());
+    assert!('_'.is_ascii_punctuation());
+    assert!('~'.is_ascii_punctuation());
+    assert!(!' '.is_ascii_punctuation());
+    assert!(!'a'.is_ascii_punctuation());
+    assert!(!'\x7f'.is_ascii_punctuation());
+
+    assert!(' '.is_ascii_whitespace());
+    assert!('\t'.is_ascii_whitespace());
+    assert!('\n'.is_ascii_whitespace());
+    assert!('\x0c'.is_ascii_whitespace());
+    assert!('\r'.is_ascii_whitespace());
+    assert!(!'\x0b'.is_ascii_whitespace());
+
+    assert!('\x00'.is_ascii_control());
+    assert!('\x1f'.is_ascii_control());
+    assert!('\x7f'.is_ascii_control());
+    assert!(!' '.is_ascii_control());
+    assert!(!'\u{80}'.is_ascii_control());
+
+    // nothing outside ASCII qualifies
+    for &c in ['²', '０', 'ａ', 'é', '\u{a0}', '\u{3000}', '¡', '\u{2014}'].iter() {
+        assert!(!c.is_ascii_digit());
+        assert!(!c.is_ascii_hexdigit());
+        assert!(!c.is_ascii_alphabetic());
+        assert!(!c.is_ascii_alphanumeric());
+        assert!(!c.is_ascii_punctuation());
+        assert!(!c.is_ascii_whitespace());
+        assert!(!c.is_ascii_control());
+    }
+}
+
+#[test]
+fn test_is_ascii_predicates_exhaustive() {
+    for i in range(0u8, 0x80) {
+        let c = i as char;
+        let digit = i >= b'0' && i <= b'9';
+        let lower = i >= b'a' && i <= b'z';
+        let upper = i >= b'A' && i <= b'Z';
+        let hex = digit || (i >= b'a' && i <= b'f') || (i >= b'A' && i <= b'F');
+        let control = i < 0x20 || i == 0x7f;
+        let graphic = i > 0x20 && i < 0x7f;
+
+        assert_eq!(c.is_ascii_digit(), digit);
+        assert_eq!(c.is_ascii_hexdigit(), hex);
+        assert_eq!(c.is_ascii_alphabetic(), lower || upper);
+        assert_eq!(c.is_ascii_alphanumeric(), lower || upper || digit);
+        assert_eq!(c.is_ascii_punctuation(), graphic && !(lower || upper || digit));
+        assert_eq!(c.is_ascii_whitespace(), [b' ', b'\t', b'\n', b'\x0c', b'\r'].contains(&i));
+        assert_eq!(c.is_ascii_control(), control);
+    }
+}
+
+#[test]
+fn test_escape_debug() {
+    fn string(c: char) -> String {
+        c.escape_debug().collect()
+    }
+    assert_eq!(string('\n'), "\\n");
+    assert_eq!(string('\t'), "\\t");
+    assert_eq!(string('\\'), "\\\\");
+    assert_eq!(string('\''), "\\'");
+    assert_eq!(string('"'), "\\\"");
+    assert_eq!(string('a'), "a");
+    assert_eq!(string('~'), "~");
+    assert_eq!(string('\x00'), "\\x00");
+    assert_eq!(string('\x1f'), "\\x1f");
+    assert_eq!(string('\x7f'), "\\x7f");
+    assert_eq!(string('\u0085'), "\\u0085");
+    assert_eq!(string('\u00a0'), "\u00a0");
+    assert_eq!(string('é'), "é");
+    assert_eq!(string('\U0001d4b6'), "\U0001d4b6");
+}
+
+#[test]
+fn test_escape_char_literal() {
+    fn string(c: char) -> String {
+        c.escape_char_literal().collect()
+    }
+    assert_eq!(string('"'), "\"");
+    assert_eq!(string('\''), "\\'");
+    assert_eq!(string('\\'), "\\\\");
+    assert_eq!(string('\n'), "\\n");
+    assert_eq!(string('a'), "a");
+    assert_eq!(string('\x00'), "\\x00");
+    assert_eq!(string('é'), "\\u00e9");
+
+    let s: String = '"'.escape_char_literal().rev().collect();
+    assert_eq!(s, "\"");
+    assert_eq!('"'.escape_char_literal().len(), 1);
+}
+
+#[test]
+fn test_escape_json() {
+    fn string(c: char) -> String {
+        c.escape_json().collect()
+    }
+    assert_eq!(string('\x08'), "\\b");
+    assert_eq!(string('\x0c'), "\\f");
+    assert_eq!(string('\n'), "\\n");
+    assert_eq!(string('\r'), "\\r");
+    assert_eq!(string('\t'), "\\t");
+    assert_eq!(string('"'), "\\\"");
+    assert_eq!(string('\\'), "\\\\");
+    assert_eq!(string('\x00'), "\\u0000");
+    assert_eq!(string('\x1b'), "\\u001b");
+    assert_eq!(string('\x1f'), "\\u001f");
+    assert_eq!(string('\''), "'");
+    assert_eq!(string('/'), "/");
+    assert_eq!(string('a'), "a");
+    assert_eq!(string('\x7f'), "\x7f");
+    assert_eq!(string('é'), "é");
+    assert_eq!(string('\U0001d4b6'), "\U0001d4b6");
+
+    let s: String = '\x1b'.escape_json().rev().collect();
+    assert_eq!(s, "b1000u\\");
+}
+
+#[test]
+fn test_escape_xml() {
+    fn string(c: char) -> String {
+        c.escape_xml().collect()
+    }
+    assert_eq!(string('&'), "&amp;");
+    assert_eq!(string('<'), "&lt;");
+    assert_eq!(string('>'), "&gt;");
+    assert_eq!(string('"'), "&quot;");
+    assert_eq!(string('\''), "&apos;");
+    assert_eq!(string('a'), "a");
+    assert_eq!(string(';'), ";");
+    assert_eq!(string('\n'), "\n");
+    assert_eq!(string('é'), "é");
+
+    let s: String = '&'.escape_xml().rev().collect();
+    assert_eq!(s, ";pma&");
+
+    let mut iter = '<'.escape_xml();
+    assert_eq!(iter.next(), Some('&'));
+    assert_eq!(iter.next_back(), Some(';'));
+    assert_eq!(iter.next(), Some('l'));
+    assert_eq!(iter.next_back(), Some('t'));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
 #[test]
 fn test_escape_unicode() {
     fn string(c: char) -> String {
@@ -169,6 +1123,188 @@ fn test_escape_unicode() {
     assert_eq!(s, "\\U0001d4b6");
 }
 
+#[test]
+fn test_escape_unicode_upper() {
+    fn string(c: char) -> String {
+        c.escape_unicode_upper().collect()
+    }
+    assert_eq!(string('\n'), "\\x0A");
+    assert_eq!(string('a'), "\\x61");
+    assert_eq!(string('\u00e9'), "\\u00E9");
+    assert_eq!(string('\U0001d4b6'), "\\U0001D4B6");
+
+    let s: String = '\u00e9'.escape_unicode_upper().rev().collect();
+    assert_eq!(s, "9E00u\\");
+}
+
+#[test]
+fn test_parse_unicode_escape() {
+    for &c in ['\x00', 'a', '\x7f', '\u{e9}', '\u{ffff}', '\u{1d4b6}', MAX].iter() {
+        let s: String = c.escape_unicode().collect();
+        assert_eq!(parse_unicode_escape(s.as_slice()), Some(c));
+        let s: String = c.escape_unicode_upper().collect();
+        assert_eq!(parse_unicode_escape(s.as_slice()), Some(c));
+        let s: String = c.escape_unicode_rust().collect();
+        assert_eq!(parse_unicode_escape(s.as_slice()), Some(c));
+    }
+
+    assert_eq!(parse_unicode_escape("\\u{000061}"), Some('a'));
+    assert_eq!(parse_unicode_escape("\\U0001F4A9"), Some('\U0001f4a9'));
+
+    // malformed
+    assert_eq!(parse_unicode_escape(""), None);
+    assert_eq!(parse_unicode_escape("\\"), None);
+    assert_eq!(parse_unicode_escape("x61"), None);
+    assert_eq!(parse_unicode_escape("\\q61"), None);
+    assert_eq!(parse_unicode_escape("\\x6"), None);
+    assert_eq!(parse_unicode_escape("\\x611"), None);
+    assert_eq!(parse_unicode_escape("\\xg1"), None);
+    assert_eq!(parse_unicode_escape("\\u061"), None);
+    assert_eq!(parse_unicode_escape("\\u{}"), None);
+    assert_eq!(parse_unicode_escape("\\u{61"), None);
+    assert_eq!(parse_unicode_escape("\\u{+61}"), None);
+    assert_eq!(parse_unicode_escape("\\u{1000000}"), None);
+    assert_eq!(parse_unicode_escape("\\x61 "), None);
+
+    // `\x` is limited to ASCII
+    assert_eq!(parse_unicode_escape("\\x7f"), Some('\x7f'));
+    assert_eq!(parse_unicode_escape("\\x80"), None);
+    assert_eq!(parse_unicode_escape("\\xff"), None);
+
+    // not a char
+    assert_eq!(parse_unicode_escape("\\ud800"), None);
+    assert_eq!(parse_unicode_escape("\\u{dfff}"), None);
+    assert_eq!(parse_unicode_escape("\\u{110000}"), None);
+    assert_eq!(parse_unicode_escape("\\U00110000"), None);
+    assert_eq!(parse_unicode_escape("\\Uffffffff"), None);
+}
+
+#[test]
+fn test_escape_unicode_rust() {
+    fn string(c: char) -> String {
+        c.escape_unicode_rust().collect()
+    }
+    assert_eq!(string('\x00'), "\\u{0}");
+    assert_eq!(string('\n'), "\\u{a}");
+    assert_eq!(string('a'), "\\u{61}");
+    assert_eq!(string('\x7f'), "\\u{7f}");
+    assert_eq!(string('\u{100}'), "\\u{100}");
+    assert_eq!(string('\u{ffff}'), "\\u{ffff}");
+    assert_eq!(string('\u{1d4b6}'), "\\u{1d4b6}");
+    assert_eq!(string(MAX), "\\u{10ffff}");
+
+    let s: String = '\u{1d4b6}'.escape_unicode_rust().rev().collect();
+    assert_eq!(s, "}6b4d1{u\\");
+}
+
+#[test]
+fn test_escape_unicode_rev() {
+    fn check(c: char) {
+        let forward: String = c.escape_unicode().collect();
+        let mut backward: Vec<char> = c.escape_unicode().rev().collect();
+        backward.reverse();
+        assert_eq!(String::from_chars(backward.as_slice()), forward);
+    }
+
+    check('\x00');
+    check('a');
+    check('\u{11b}');
+    check('\u{1d4b6}');
+
+    let mut iter = '\u{11b}'.escape_unicode();
+    assert_eq!(iter.next(), Some('\\'));
+    assert_eq!(iter.next_back(), Some('b'));
+    assert_eq!(iter.next(), Some('u'));
+    assert_eq!(iter.next_back(), Some('1'));
+    assert_eq!(iter.next(), Some('0'));
+    assert_eq!(iter.next(), Some('1'));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_escape_default_rev() {
+    fn check(c: char) {
+        let forward: String = c.escape_default().collect();
+        let mut backward: Vec<char> = c.escape_default().rev().collect();
+        backward.reverse();
+        assert_eq!(String::from_chars(backward.as_slice()), forward);
+    }
+
+    check('a');
+    check('\t');
+    check('\n');
+    check('\'');
+    check('"');
+    check('\\');
+    check('\x1f');
+    check('\u{11b}');
+    check('\u{1d4b6}');
+}
+
+#[test]
+fn test_escape_default_both_ends() {
+    let mut it = '\n'.escape_default();
+    assert_eq!(it.next_back(), Some('n'));
+    assert_eq!(it.next(), Some('\\'));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+
+    let mut it = '\u{11b}'.escape_default();
+    assert_eq!(it.next(), Some('\\'));
+    assert_eq!(it.next_back(), Some('b'));
+    assert_eq!(it.next_back(), Some('1'));
+    assert_eq!(it.next(), Some('u'));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.collect::<String>(), "01".to_string());
+}
+
+#[test]
+fn test_escape_len() {
+    fn check<I: ExactSizeIterator<char>>(mut iter: I, len: uint) {
+        for n in range(0, len + 1).rev() {
+            assert_eq!(iter.size_hint(), (n, Some(n)));
+            assert_eq!(iter.len(), n);
+            iter.next();
+        }
+    }
+
+    check('\x00'.escape_unicode(), 4);
+    check('\u{11b}'.escape_unicode(), 6);
+    check('\u{1d4b6}'.escape_unicode(), 10);
+    check('\x00'.escape_unicode_rust(), 5);
+    check('\u{1d4b6}'.escape_unicode_rust(), 9);
+    check('a'.escape_json(), 1);
+    check('"'.escape_json(), 2);
+    check('\x01'.escape_json(), 6);
+    check('a'.escape_xml(), 1);
+    check('"'.escape_xml(), 6);
+    check('a'.escape_default(), 1);
+    check('\n'.escape_default(), 2);
+    check('\u{11b}'.escape_default(), 6);
+
+    let mut iter = '\''.escape_default();
+    assert_eq!(iter.next_back(), Some('\''));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some('\\'));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_escape_clone() {
+    let mut iter = '\u{11b}'.escape_unicode();
+    iter.next();
+    assert_eq!(iter.clone().count(), 5);
+    assert_eq!(iter.collect::<String>(), "u011b");
+
+    let mut iter = '\t'.escape_default();
+    let copy = iter.clone();
+    iter.next();
+    assert_eq!(copy.collect::<String>(), "\\t");
+    assert_eq!(iter.collect::<String>(), "t");
+}
+
 #[test]
 fn test_encode_utf8() {
     fn check(input: char, expect: &[u8]) {
@@ -183,6 +1319,414 @@ fn test_encode_utf8() {
     check('\U0001f4a9', &[0xf0, 0x9f, 0x92, 0xa9]);
 }
 
+#[test]
+fn test_max_len() {
+    assert_eq!(MAX.len_utf8(), MAX_UTF8_LEN);
+    assert_eq!(MAX.len_utf16(), MAX_UTF16_LEN);
+
+    let mut buf = [0u8, ..MAX_UTF8_LEN];
+    assert_eq!(MAX.encode_utf8(&mut buf), Some(MAX_UTF8_LEN));
+    let mut buf = [0u16, ..MAX_UTF16_LEN];
+    assert_eq!(MAX.encode_utf16(&mut buf), Some(MAX_UTF16_LEN));
+}
+
+#[test]
+fn test_encode_utf8_len() {
+    fn check(input: char, expect: &[u8]) {
+        let mut buf = [0u8, ..4];
+        assert_eq!(input.encode_utf8_len(buf.as_mut_slice()), Ok(expect.len()));
+        assert_eq!(buf[..expect.len()], expect);
+
+        // every shorter buffer is left alone and reports the needed size
+        for n in range(0, expect.len()) {
+            let mut buf = [0u8, ..4];
+            assert_eq!(input.encode_utf8_len(buf[mut ..n]), Err(expect.len()));
+            assert!(buf.iter().all(|&b| b == 0));
+        }
+    }
+
+    check('x', &[0x78]);
+    check('\u00e9', &[0xc3, 0xa9]);
+    check('\ua66e', &[0xea, 0x99, 0xae]);
+    check('\U0001f4a9', &[0xf0, 0x9f, 0x92, 0xa9]);
+}
+
+#[test]
+fn test_encode_utf8_small() {
+    fn check(input: char, expect: &[u8]) {
+        let mut buf = [0u8, ..4];
+        assert_eq!(input.encode_utf8_small(buf.as_mut_slice()), Some(expect.len() as u8));
+        assert_eq!(buf[..expect.len()], expect);
+
+        let mut buf = [0u8, ..4];
+        assert_eq!(input.encode_utf8_small(buf[mut ..expect.len() - 1]), None);
+        assert!(buf.iter().all(|&b| b == 0));
+    }
+
+    check('x', &[0x78]);
+    check('\u00e9', &[0xc3, 0xa9]);
+    check('\ua66e', &[0xea, 0x99, 0xae]);
+    check('\U0001f4a9', &[0xf0, 0x9f, 0x92, 0xa9]);
+}
+
+#[test]
+fn test_encode_utf8_unchecked() {
+    fn check(input: char, expect: &[u8]) {
+        let mut buf = [0u8, ..4];
+        let n = unsafe { input.encode_utf8_unchecked(buf[mut ..expect.len()]) };
+        assert_eq!(buf[..n], expect);
+    }
+
+    check('x', &[0x78]);
+    check('\u00e9', &[0xc3, 0xa9]);
+    check('\ua66e', &[0xea, 0x99, 0xae]);
+    check('\U0001f4a9', &[0xf0, 0x9f, 0x92, 0xa9]);
+}
+
+#[test]
+fn test_encode_utf8_raw() {
+    fn check(input: u32, expect: &[u8]) {
+        let mut buf = [0u8, ..4];
+        let n = encode_utf8_raw(input, buf.as_mut_slice()).unwrap_or(0);
+        assert_eq!(buf[..n], expect);
+    }
+
+    check(0x78, &[0x78]);
+    check(0xe9, &[0xc3, 0xa9]);
+    check(0xa66e, &[0xea, 0x99, 0xae]);
+    check(0x1f4a9, &[0xf0, 0x9f, 0x92, 0xa9]);
+    check(0xd800, &[0xed, 0xa0, 0x80]);
+
+    let mut buf = [0u8, ..2];
+    assert_eq!(encode_utf8_raw(0xa66e, buf.as_mut_slice()), None);
+    assert!(buf.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_encode_utf8_into() {
+    let s = "xé\u{a66e}\u{1f4a9}";
+    let mut buf = [0u8, ..16];
+    assert_eq!(encode_utf8_into(s.chars(), buf.as_mut_slice()), Some(s.len()));
+    assert_eq!(buf[..s.len()], s.as_bytes());
+    assert_eq!(encode_utf8_into("".chars(), buf.as_mut_slice()), Some(0));
+
+    // exactly enough room
+    let mut buf = [0u8, ..10];
+    assert_eq!(encode_utf8_into(s.chars(), buf.as_mut_slice()), Some(10));
+    assert_eq!(buf[], s.as_bytes());
+
+    // only the chars which fit are written
+    let mut buf = [0u8, ..8];
+    assert_eq!(encode_utf8_into(s.chars(), buf.as_mut_slice()), None);
+    assert_eq!(buf[..6], "xé\u{a66e}".as_bytes());
+    assert!(buf[6..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_to_utf8_array() {
+    fn check(input: char, expect: &[u8]) {
+        let (buf, n) = input.to_utf8_array();
+        assert_eq!(n, expect.len());
+        assert_eq!(buf[..n], expect);
+        assert!(buf[n..].iter().all(|&b| b == 0));
+    }
+
+    check('x', &[0x78]);
+    check('\u{e9}', &[0xc3, 0xa9]);
+    check('\u{a66e}', &[0xea, 0x99, 0xae]);
+    check('\u{1f4a9}', &[0xf0, 0x9f, 0x92, 0xa9]);
+}
+
+#[test]
+fn test_encode_utf8_to() {
+    struct Calls { bytes: Vec<u8>, calls: uint }
+
+    impl CharWriter for Calls {
+        fn push_bytes(&mut self, bytes: &[u8]) {
+            self.bytes.push_all(bytes);
+            self.calls += 1;
+        }
+    }
+
+    fn check(input: char, expect: &[u8]) {
+        let mut v = vec![0x61];
+        input.encode_utf8_to(&mut v);
+        assert_eq!(v[1..], expect);
+
+        let mut w = Calls { bytes: Vec::new(), calls: 0 };
+        input.encode_utf8_to(&mut w);
+        assert_eq!(w.bytes.as_slice(), expect);
+        assert_eq!(w.calls, 1);
+    }
+
+    check('x', &[0x78]);
+    check('\u{e9}', &[0xc3, 0xa9]);
+    check('\u{a66e}', &[0xea, 0x99, 0xae]);
+    check('\u{1f4a9}', &[0xf0, 0x9f, 0x92, 0xa9]);
+}
+
+#[test]
+fn test_from_u32_surrogate() {
+    fn check(i: u32, c: Option<char>) {
+        let cp = from_u32_surrogate(i).unwrap();
+        assert_eq!(cp.to_u32(), i);
+        assert_eq!(cp.to_char(), c);
+    }
+
+    check(0, Some('\0'));
+    check(0xd7ff, Some('\u{d7ff}'));
+    check(0xd800, None);
+    check(0xdbff, None);
+    check(0xdc00, None);
+    check(0xdfff, None);
+    check(0xe000, Some('\u{e000}'));
+    check(0x10ffff, Some('\u{10ffff}'));
+    assert_eq!(from_u32_surrogate(0x110000), None);
+    assert_eq!(CodePoint::from_char('é'), from_u32_surrogate(0xe9).unwrap());
+}
+
+#[test]
+fn test_encode_wtf8() {
+    fn check(i: u32, expect: &[u8]) {
+        let cp = from_u32_surrogate(i).unwrap();
+        let mut buf = [0u8, ..4];
+        assert_eq!(cp.len_wtf8(), expect.len());
+        let n = cp.encode_wtf8(buf.as_mut_slice()).unwrap();
+        assert_eq!(buf[..n], expect);
+    }
+
+    // scalar values are encoded as in UTF-8
+    check(0x78, &[0x78]);
+    check(0xe9, &[0xc3, 0xa9]);
+    check(0xa66e, &[0xea, 0x99, 0xae]);
+    check(0x1f4a9, &[0xf0, 0x9f, 0x92, 0xa9]);
+    // surrogates
+    check(0xd800, &[0xed, 0xa0, 0x80]);
+    check(0xdbff, &[0xed, 0xaf, 0xbf]);
+    check(0xdc00, &[0xed, 0xb0, 0x80]);
+    check(0xdfff, &[0xed, 0xbf, 0xbf]);
+
+    let mut buf = [0u8, ..2];
+    assert_eq!(from_u32_surrogate(0xd800).unwrap().encode_wtf8(buf.as_mut_slice()), None);
+    assert!(buf.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_utf8_char_width() {
+    for b in range(0u, 0x100) {
+        let b = b as u8;
+        let expect = match b {
+            0x00 ... 0x7f => Some(1),
+            0x80 ... 0xbf => None,
+            0xc0 ... 0xdf => Some(2),
+            0xe0 ... 0xef => Some(3),
+            0xf0 ... 0xf7 => Some(4),
+            _ => None
+        };
+        assert_eq!(utf8_char_width(b), expect);
+    }
+
+    for c in "aé\u{a66e}\u{1f4a9}".chars() {
+        let (buf, n) = c.to_utf8_array();
+        assert_eq!(utf8_char_width(buf[0]), Some(n));
+    }
+}
+
+#[test]
+fn test_is_utf8_continuation() {
+    for b in range(0u, 0x100) {
+        let b = b as u8;
+        assert_eq!(is_utf8_continuation(b), b >= 0x80 && b <= 0xbf);
+    }
+}
+
+#[test]
+fn test_decode_utf8() {
+    fn check(input: char) {
+        let mut buf = [0u8, ..4];
+        let n = input.encode_utf8(buf.as_mut_slice()).unwrap();
+        assert_eq!(decode_utf8(buf[..n]), Some((input, n)));
+    }
+
+    check('x');
+    check('\u{e9}');
+    check('\u{a66e}');
+    check('\u{1f4a9}');
+    check('\u{10ffff}');
+
+    assert_eq!(decode_utf8(&[0x78, 0xc3, 0xa9]), Some(('x', 1)));
+    assert_eq!(decode_utf8(&[]), None);
+    // incomplete
+    assert_eq!(decode_utf8(&[0xc3]), None);
+    assert_eq!(decode_utf8(&[0xf0, 0x9f, 0x92]), None);
+    // bad continuation bytes
+    assert_eq!(decode_utf8(&[0x80]), None);
+    assert_eq!(decode_utf8(&[0xc3, 0x29]), None);
+    assert_eq!(decode_utf8(&[0xe2, 0x82, 0xc3]), None);
+    // overlong
+    assert_eq!(decode_utf8(&[0xc0, 0x80]), None);
+    assert_eq!(decode_utf8(&[0xe0, 0x80, 0xaf]), None);
+    assert_eq!(decode_utf8(&[0xf0, 0x8f, 0xbf, 0xbf]), None);
+    // surrogates and out of range
+    assert_eq!(decode_utf8(&[0xed, 0xa0, 0x80]), None);
+    assert_eq!(decode_utf8(&[0xf4, 0x90, 0x80, 0x80]), None);
+    assert_eq!(decode_utf8(&[0xff]), None);
+}
+
+#[test]
+fn test_next_code_point() {
+    let mut bytes = "aé\u{1f4a9}".bytes().peekable();
+    assert_eq!(next_code_point(&mut bytes), Some(Ok('a')));
+    assert_eq!(next_code_point(&mut bytes), Some(Ok('é')));
+    assert_eq!(next_code_point(&mut bytes), Some(Ok('\u{1f4a9}')));
+    assert_eq!(next_code_point(&mut bytes), None);
+
+    let input = [0xff, 0xe2, 0x82, 0x61, 0x62, 0xf0, 0x9f, 0x92];
+    let mut bytes = input.iter().map(|&b| b).peekable();
+    assert_eq!(next_code_point(&mut bytes), Some(Err(InvalidSequence(InvalidLeadByte))));
+    // the byte interrupting the sequence is left for the next call
+    assert_eq!(next_code_point(&mut bytes), Some(Err(InvalidSequence(MissingContinuation))));
+    assert_eq!(next_code_point(&mut bytes), Some(Ok('a')));
+    assert_eq!(next_code_point(&mut bytes), Some(Ok('b')));
+    assert_eq!(next_code_point(&mut bytes), Some(Err(TruncatedSequence)));
+    assert_eq!(next_code_point(&mut bytes), None);
+
+    // decoding in a loop gives the same items as decode_utf8_iter
+    fn collect(input: &[u8]) -> Vec<Result<char, Utf8Error>> {
+        let mut bytes = input.iter().map(|&b| b).peekable();
+        let mut v = Vec::new();
+        loop {
+            match next_code_point(&mut bytes) {
+                Some(r) => v.push(r),
+                None => return v
+            }
+        }
+    }
+    assert_eq!(collect(&[0xe2, 0x82, 0x61]),
+               vec![Err(InvalidSequence(MissingContinuation)), Ok('a')]);
+    let inputs: &[&[u8]] = &[&[0xe0, 0x80, 0x61], &[0xf4, 0x90, 0xc3, 0xa9],
+                             &[0xc3, 0xe2, 0x82, 0xac], &[0xf0, 0x9f, 0x92]];
+    for &input in inputs.iter() {
+        let expect: Vec<_> = decode_utf8_iter(input.iter().map(|&b| b)).collect();
+        assert_eq!(collect(input), expect);
+    }
+}
+
+#[test]
+fn test_decode_utf8_iter() {
+    fn check(input: &[u8], expect: &[Result<char, Utf8Error>]) {
+        let v: Vec<_> = decode_utf8_iter(input.iter().map(|&b| b)).collect();
+        assert_eq!(v.as_slice(), expect);
+    }
+
+    check(&[], &[]);
+    check("aé\u{a66e}\u{1f4a9}".as_bytes(),
+          &[Ok('a'), Ok('é'), Ok('\u{a66e}'), Ok('\u{1f4a9}')]);
+    // stray continuation byte and invalid leading bytes
+    check(&[0x80, 0x61], &[Err(InvalidSequence(UnexpectedContinuation)), Ok('a')]);
+    check(&[0xf8, 0xff, 0x61],
+          &[Err(InvalidSequence(InvalidLeadByte)), Err(InvalidSequence(InvalidLeadByte)), Ok('a')]);
+    // the byte ending a malformed sequence is decoded again
+    check(&[0xc3, 0x61], &[Err(InvalidSequence(MissingContinuation)), Ok('a')]);
+    check(&[0xe2, 0x82, 0xc3, 0xa9], &[Err(InvalidSequence(MissingContinuation)), Ok('é')]);
+    // overlong, surrogate and out of range sequences end at the first byte
+    // showing them to be so, and any continuation bytes after it are stray
+    let stray = InvalidSequence(UnexpectedContinuation);
+    check(&[0xc0, 0x80, 0x61], &[Err(InvalidSequence(OverlongEncoding)), Err(stray), Ok('a')]);
+    check(&[0xe0, 0x80, 0xaf], &[Err(InvalidSequence(OverlongEncoding)), Err(stray), Err(stray)]);
+    check(&[0xf0, 0x8f, 0xbf, 0xbf],
+          &[Err(InvalidSequence(OverlongEncoding)), Err(stray), Err(stray), Err(stray)]);
+    check(&[0xed, 0xa0, 0x80], &[Err(InvalidSequence(InvalidCodePoint)), Err(stray), Err(stray)]);
+    check(&[0xf4, 0x90, 0x80, 0x80],
+          &[Err(InvalidSequence(InvalidCodePoint)), Err(stray), Err(stray), Err(stray)]);
+    check(&[0xf7, 0xbf, 0xbf, 0xbf],
+          &[Err(InvalidSequence(InvalidCodePoint)), Err(stray), Err(stray), Err(stray)]);
+    // the boundaries of those ranges
+    check(&[0xe0, 0xa0, 0x80], &[Ok('\u{800}')]);
+    check(&[0xed, 0x9f, 0xbf], &[Ok('\u{d7ff}')]);
+    check(&[0xee, 0x80, 0x80], &[Ok('\u{e000}')]);
+    check(&[0xf0, 0x90, 0x80, 0x80], &[Ok('\u{10000}')]);
+    check(&[0xf4, 0x8f, 0xbf, 0xbf], &[Ok('\u{10ffff}')]);
+    // truncated
+    check(&[0x61, 0xf0, 0x9f, 0x92], &[Ok('a'), Err(TruncatedSequence)]);
+}
+
+#[test]
+fn test_char_indices_utf8() {
+    fn check(input: &[u8], expect: &[(uint, Result<char, Utf8Error>)]) {
+        let v: Vec<_> = char_indices_utf8(input.iter().map(|&b| b)).collect();
+        assert_eq!(v.as_slice(), expect);
+    }
+
+    check(&[], &[]);
+    check("aé\u{a66e}\u{1f4a9}b".as_bytes(),
+          &[(0, Ok('a')), (1, Ok('é')), (3, Ok('\u{a66e}')), (6, Ok('\u{1f4a9}')), (10, Ok('b'))]);
+    check(&[0x80, 0xff, 0x61],
+          &[(0, Err(InvalidSequence(UnexpectedContinuation))),
+            (1, Err(InvalidSequence(InvalidLeadByte))),
+            (2, Ok('a'))]);
+    // the byte ending a malformed sequence starts the next one
+    check(&[0xe2, 0x82, 0xc3, 0xa9],
+          &[(0, Err(InvalidSequence(MissingContinuation))), (2, Ok('é'))]);
+    check(&[0xc0, 0x80, 0x61],
+          &[(0, Err(InvalidSequence(OverlongEncoding))),
+            (1, Err(InvalidSequence(UnexpectedContinuation))),
+            (2, Ok('a'))]);
+    check(&[0xed, 0xa0, 0x61],
+          &[(0, Err(InvalidSequence(InvalidCodePoint))),
+            (1, Err(InvalidSequence(UnexpectedContinuation))),
+            (2, Ok('a'))]);
+    check(&[0x61, 0xf0, 0x9f, 0x92], &[(0, Ok('a')), (1, Err(TruncatedSequence))]);
+}
+
+#[test]
+fn test_utf8_char_count() {
+    assert_eq!(utf8_char_count(&[]), Ok(0));
+    assert_eq!(utf8_char_count(b"hello"), Ok(5));
+    assert_eq!(utf8_char_count("aé\u{a66e}\u{1f4a9}b".as_bytes()), Ok(5));
+
+    // the error is the first one, along with the offset it starts at
+    assert_eq!(utf8_char_count(&[0x80, 0x61]), Err((0, InvalidSequence(UnexpectedContinuation))));
+    assert_eq!(utf8_char_count(&[0x61, 0xc3, 0xa9, 0xff, 0x80]),
+               Err((3, InvalidSequence(InvalidLeadByte))));
+    assert_eq!(utf8_char_count(&[0x61, 0xe2, 0x82, 0x61]),
+               Err((1, InvalidSequence(MissingContinuation))));
+    assert_eq!(utf8_char_count(&[0x61, 0xed, 0xa0, 0x80]),
+               Err((1, InvalidSequence(InvalidCodePoint))));
+    assert_eq!(utf8_char_count(&[0x61, 0x62, 0xf0, 0x9f, 0x92]), Err((2, TruncatedSequence)));
+}
+
+#[test]
+fn test_decode_utf8_lossy() {
+    fn check(input: &[u8], expect: &str) {
+        let s: String = decode_utf8_lossy(input.iter().map(|&b| b)).collect();
+        assert_eq!(s.as_slice(), expect);
+    }
+
+    assert_eq!(REPLACEMENT_CHARACTER, '\ufffd');
+    check(&[], "");
+    check("aé\U0001f4a9".as_bytes(), "aé\U0001f4a9");
+    check(&[0x61, 0xff, 0x62], "a\ufffdb");
+    check(&[0xc3, 0x61], "\ufffda");
+    check(&[0x61, 0xe2, 0x82], "a\ufffd");
+    check(&[0x80, 0x80, 0x61], "\ufffd\ufffda");
+
+    // one replacement per maximal subpart, following the WHATWG Encoding
+    // Standard
+    check(&[0xff, 0x61], "\ufffda");
+    check(&[0xf0, 0x9f, 0x92, 0x61], "\ufffda");
+    check(&[0xf0, 0x9f, 0x92, 0xf0, 0x9f, 0x92, 0xa9], "\ufffd\U0001f4a9");
+    check(&[0xc0, 0xaf], "\ufffd\ufffd");
+    check(&[0xe0, 0x80, 0xaf], "\ufffd\ufffd\ufffd");
+    check(&[0xed, 0xa0, 0x80], "\ufffd\ufffd\ufffd");
+    check(&[0xf4, 0x90, 0x80, 0x80], "\ufffd\ufffd\ufffd\ufffd");
+    check(&[0x61, 0xf1, 0x80, 0x80, 0xe1, 0x80, 0xc2, 0x62, 0x80, 0x63, 0x80, 0xbf, 0x64],
+          "a\ufffd\ufffd\ufffdb\ufffdc\ufffd\ufffdd");
+    // a valid leading byte after an invalid one is not swallowed
+    check(&[0xff, 0xc3, 0xa9], "\ufffdé");
+    check(&[0xe2, 0x82, 0xe2, 0x82, 0xac], "\ufffd€");
+}
+
 #[test]
 fn test_encode_utf16() {
     fn check(input: char, expect: &[u16]) {
@@ -197,6 +1741,112 @@ fn test_encode_utf16() {
     check('\U0001f4a9', &[0xd83d, 0xdca9]);
 }
 
+#[test]
+fn test_encode_utf16_len() {
+    fn check(input: char, expect: &[u16]) {
+        let mut buf = [0u16, ..2];
+        assert_eq!(input.encode_utf16_len(buf.as_mut_slice()), Ok(expect.len()));
+        assert_eq!(buf[..expect.len()], expect);
+
+        let mut buf = [0u16, ..1];
+        let res = input.encode_utf16_len(buf[mut ..expect.len() - 1]);
+        assert_eq!(res, Err(expect.len()));
+        assert_eq!(buf[0], 0);
+    }
+
+    check('x', &[0x0078]);
+    check('\u{ffff}', &[0xffff]);
+    check('\u{1f600}', &[0xd83d, 0xde00]);
+}
+
+#[test]
+fn test_to_utf16_array() {
+    fn check(input: char, expect: &[u16]) {
+        let (buf, n) = input.to_utf16_array();
+        assert_eq!(n, expect.len());
+        assert_eq!(buf[..n], expect);
+        assert!(buf[n..].iter().all(|&u| u == 0));
+    }
+
+    check('x', &[0x0078]);
+    check('\u{e9}', &[0x00e9]);
+    check('\u{ffff}', &[0xffff]);
+    check('\u{10000}', &[0xd800, 0xdc00]);
+    check('\u{1f600}', &[0xd83d, 0xde00]);
+    check('\u{10ffff}', &[0xdbff, 0xdfff]);
+}
+
+#[test]
+fn test_encode_utf32() {
+    fn check(input: char, expect: &[u32]) {
+        let mut buf = [0u32, ..1];
+        let n = input.encode_utf32(buf.as_mut_slice()).unwrap_or(0);
+        assert_eq!(buf[..n], expect);
+    }
+
+    check('x', &[0x78]);
+    check('\u00e9', &[0xe9]);
+    check('\ua66e', &[0xa66e]);
+    check('\U0001f4a9', &[0x1f4a9]);
+
+    let mut empty: [u32, ..0] = [];
+    assert_eq!('x'.encode_utf32(empty.as_mut_slice()), None);
+}
+
+#[test]
+fn test_decode_utf16_pair() {
+    assert_eq!(decode_utf16_pair(0xD800, 0xDC00), Some('\U00010000'));
+    assert_eq!(decode_utf16_pair(0xD83D, 0xDCA9), Some('\U0001f4a9'));
+    assert_eq!(decode_utf16_pair(0xDBFF, 0xDFFF), Some('\U0010ffff'));
+    assert_eq!(decode_utf16_pair(0xDC00, 0xD800), None);
+    assert_eq!(decode_utf16_pair(0xD800, 0xD800), None);
+    assert_eq!(decode_utf16_pair(0xD800, 0x0061), None);
+    assert_eq!(decode_utf16_pair(0x0061, 0xDC00), None);
+
+    assert!(is_utf16_high_surrogate(0xD800));
+    assert!(is_utf16_high_surrogate(0xDBFF));
+    assert!(!is_utf16_high_surrogate(0xDC00));
+    assert!(!is_utf16_high_surrogate(0xD7FF));
+    assert!(is_utf16_low_surrogate(0xDC00));
+    assert!(is_utf16_low_surrogate(0xDFFF));
+    assert!(!is_utf16_low_surrogate(0xDBFF));
+    assert!(!is_utf16_low_surrogate(0xE000));
+}
+
+#[test]
+fn test_decode_utf16() {
+    fn check(input: &[u16], expect: &[Result<char, Utf16Error>]) {
+        let v: Vec<_> = decode_utf16(input.iter().map(|&u| u)).collect();
+        assert_eq!(v.as_slice(), expect);
+    }
+
+    check(&[], &[]);
+    check(&[0x0061, 0xD83D, 0xDCA9, 0xE000],
+          &[Ok('a'), Ok('\U0001f4a9'), Ok('\ue000')]);
+    // lone trailing surrogate
+    check(&[0xDCA9, 0x0061], &[Err(Utf16Error { unit: 0xDCA9 }), Ok('a')]);
+    // leading surrogate followed by something else
+    check(&[0xD83D, 0x0061], &[Err(Utf16Error { unit: 0xD83D }), Ok('a')]);
+    check(&[0xD83D, 0xD83D, 0xDCA9],
+          &[Err(Utf16Error { unit: 0xD83D }), Ok('\U0001f4a9')]);
+    // leading surrogate at the end
+    check(&[0x0061, 0xD83D], &[Ok('a'), Err(Utf16Error { unit: 0xD83D })]);
+}
+
+#[test]
+fn test_decode_utf16_lossy() {
+    fn check(input: &[u16], expect: &str) {
+        let s: String = decode_utf16_lossy(input.iter().map(|&u| u)).collect();
+        assert_eq!(s.as_slice(), expect);
+    }
+
+    check(&[], "");
+    check(&[0x0061, 0xD83D, 0xDCA9], "a\U0001f4a9");
+    check(&[0xDCA9, 0x0061], "\ufffda");
+    check(&[0xD83D, 0xD83D, 0xDCA9], "\ufffd\U0001f4a9");
+    check(&[0x0061, 0xD83D], "a\ufffd");
+}
+
 #[test]
 fn test_len_utf16() {
     assert!('x'.len_utf16() == 1);
@@ -205,6 +1855,53 @@ fn test_len_utf16() {
     assert!('\U0001f4a9'.len_utf16() == 2);
 }
 
+#[test]
+fn test_general_category() {
+    use std::char::GeneralCategory;
+
+    assert_eq!('A'.general_category(), GeneralCategory::Lu);
+    assert_eq!('a'.general_category(), GeneralCategory::Ll);
+    assert_eq!('ǅ'.general_category(), GeneralCategory::Lt);
+    assert_eq!('ʰ'.general_category(), GeneralCategory::Lm);
+    assert_eq!('א'.general_category(), GeneralCategory::Lo);
+    assert_eq!('\u0300'.general_category(), GeneralCategory::Mn);
+    assert_eq!('\u0903'.general_category(), GeneralCategory::Mc);
+    assert_eq!('\u20dd'.general_category(), GeneralCategory::Me);
+    assert_eq!('7'.general_category(), GeneralCategory::Nd);
+    assert_eq!('Ⅻ'.general_category(), GeneralCategory::Nl);
+    assert_eq!('²'.general_category(), GeneralCategory::No);
+    assert_eq!('_'.general_category(), GeneralCategory::Pc);
+    assert_eq!('-'.general_category(), GeneralCategory::Pd);
+    assert_eq!('('.general_category(), GeneralCategory::Ps);
+    assert_eq!(')'.general_category(), GeneralCategory::Pe);
+    assert_eq!('«'.general_category(), GeneralCategory::Pi);
+    assert_eq!('»'.general_category(), GeneralCategory::Pf);
+    assert_eq!('!'.general_category(), GeneralCategory::Po);
+    assert_eq!('+'.general_category(), GeneralCategory::Sm);
+    assert_eq!('$'.general_category(), GeneralCategory::Sc);
+    assert_eq!('^'.general_category(), GeneralCategory::Sk);
+    assert_eq!('©'.general_category(), GeneralCategory::So);
+    assert_eq!(' '.general_category(), GeneralCategory::Zs);
+    assert_eq!('\u2028'.general_category(), GeneralCategory::Zl);
+    assert_eq!('\u2029'.general_category(), GeneralCategory::Zp);
+    assert_eq!('\n'.general_category(), GeneralCategory::Cc);
+    assert_eq!('\u200b'.general_category(), GeneralCategory::Cf);
+    assert_eq!('\ue000'.general_category(), GeneralCategory::Co);
+    assert_eq!('\u0378'.general_category(), GeneralCategory::Cn);
+    assert_eq!(MAX.general_category(), GeneralCategory::Cn);
+
+    // blocks that UnicodeData.txt only lists by their first and last entries
+    assert_eq!('中'.general_category(), GeneralCategory::Lo);
+    assert_eq!('한'.general_category(), GeneralCategory::Lo);
+    assert_eq!('\U00020001'.general_category(), GeneralCategory::Lo);
+    assert_eq!('\uf8fe'.general_category(), GeneralCategory::Co);
+    assert_eq!('\U0010fffc'.general_category(), GeneralCategory::Co);
+    for c in range_chars('\u4e00', '\u9fcd').chain(range_chars('\uac00', '\ud7a4')) {
+        assert_eq!(c.general_category(), GeneralCategory::Lo);
+        assert!(c.is_alphabetic());
+    }
+}
+
 #[test]
 fn test_width() {
     assert_eq!('\x00'.width(false),Some(0));
@@ -219,6 +1916,12 @@ fn test_width() {
     assert_eq!('ｈ'.width(false),Some(2));
     assert_eq!('ｈ'.width(true),Some(2));
 
+    assert_eq!('中'.width(false),Some(2));
+    assert_eq!('中'.width(true),Some(2));
+
+    assert_eq!('\u3000'.width(false),Some(2));
+    assert_eq!('\u3000'.width(true),Some(2));
+
     assert_eq!('\u00AD'.width(false),Some(1));
     assert_eq!('\u00AD'.width(true),Some(1));
 
@@ -224,6 +224,13 @@ mat!(uni_case_upper_nocase_flag, r"(?i)\p{Lu}+", "ΛΘΓΔα", Some((0, 10)))
 mat!(uni_case_upper_nocase, r"\p{L}+", "ΛΘΓΔα", Some((0, 10)))
 mat!(uni_case_lower, r"\p{Ll}+", "ΛΘΓΔα", Some((8, 10)))
 
+// The CJK ideographs, Hangul syllables and private use planes are listed as
+// ranges in UnicodeData.txt; check both ends and the interior of each.
+mat!(uni_range_cjk, r"\p{Lo}+", "一丁鿋鿌", Some((0, 12)))
+mat!(uni_range_hangul, r"\p{Lo}+", "가각힢힣", Some((0, 12)))
+mat!(uni_range_private, r"\p{Co}+", "\U000f0000\U000f1234\U000ffffd", Some((0, 12)))
+mat!(uni_range_not_unassigned, r"\p{Cn}", "一가\U000f1234", None)
+
 // Test the Unicode friendliness of Perl character classes.
 mat!(uni_perl_w, r"\w+", "dδd", Some((0, 4)))
 mat!(uni_perl_w_not, r"\w+", "⥡", None)
@@ -57,9 +57,30 @@ mod u_str;
 /// however the converse is not always true due to the above range limits
 /// and, as such, should be performed via the `from_u32` function..
 pub mod char {
-    pub use core::char::{MAX, from_u32, is_digit_radix, to_digit};
-    pub use core::char::{from_digit, escape_unicode, escape_default};
-    pub use core::char::{len_utf8_bytes, Char};
+    pub use core::char::{MIN, MAX, REPLACEMENT_CHARACTER, MAX_UTF8_LEN, MAX_UTF16_LEN};
+    pub use core::char::{from_u32, from_u32_unchecked, from_u32_or_replacement};
+    pub use core::char::{from_ascii, from_ascii_unchecked};
+    pub use core::char::{from_u32_detailed, CharTryFromError};
+    pub use core::char::{is_surrogate, is_high_surrogate, is_low_surrogate};
+    pub use core::char::{from_u32_surrogate, CodePoint};
+    pub use core::char::{range_chars, CharRange};
+    pub use core::char::{is_digit_radix, to_digit};
+    pub use core::char::{from_digit, try_from_digit, from_digit_base62, RadixError};
+    pub use core::char::Case;
+    pub use core::char::{from_digit_upper, digit_char};
+    pub use core::char::from_ascii_digit;
+    pub use core::char::{escape_unicode, escape_default, parse_unicode_escape};
+    pub use core::char::{len_utf8_bytes, Char, CharWriter};
+    pub use core::char::{encode_utf8_into, encode_utf8_raw};
+    pub use core::char::{utf8_char_width, is_utf8_continuation};
+    pub use core::char::{decode_utf8, decode_utf8_iter, DecodeUtf8, Utf8Error, Utf8ErrorKind};
+    pub use core::char::next_code_point;
+    pub use core::char::{decode_utf16_pair, is_utf16_high_surrogate, is_utf16_low_surrogate};
+    pub use core::char::{decode_utf16, DecodeUtf16, Utf16Error};
+    pub use core::char::{decode_utf8_lossy, DecodeUtf8Lossy};
+    pub use core::char::{char_indices_utf8, CharIndicesUtf8};
+    pub use core::char::utf8_char_count;
+    pub use core::char::{decode_utf16_lossy, DecodeUtf16Lossy};
 
     pub use normalize::{decompose_canonical, decompose_compatible, compose};
 
@@ -70,6 +91,9 @@ pub mod char {
     pub use u_char::{is_lowercase, is_uppercase, is_whitespace};
     pub use u_char::{is_alphanumeric, is_control, is_digit};
     pub use u_char::{to_uppercase, to_lowercase, width, UnicodeChar};
+    pub use u_char::{ToLowercase, ToUppercase};
+    pub use tables::category::GeneralCategory;
+    pub use tables::bidi::BidiClass;
 }
 
 pub mod str {
@@ -80,4 +104,5 @@ pub mod str {
 mod std {
     pub use core::clone;
     pub use core::cmp;
+    pub use core::fmt;
 }
@@ -115,27 +115,26 @@ pub mod general_category {
         '\u{2eff}'), ('\u{2fd6}', '\u{2fef}'), ('\u{2ffc}', '\u{2fff}'), ('\u{3040}', '\u{3040}'),
         ('\u{3097}', '\u{3098}'), ('\u{3100}', '\u{3104}'), ('\u{312e}', '\u{3130}'), ('\u{318f}',
         '\u{318f}'), ('\u{31bb}', '\u{31bf}'), ('\u{31e4}', '\u{31ef}'), ('\u{321f}', '\u{321f}'),
-        ('\u{32ff}', '\u{32ff}'), ('\u{3401}', '\u{4db4}'), ('\u{4db6}', '\u{4dbf}'), ('\u{4e01}',
-        '\u{9fcb}'), ('\u{9fcd}', '\u{9fff}'), ('\u{a48d}', '\u{a48f}'), ('\u{a4c7}', '\u{a4cf}'),
-        ('\u{a62c}', '\u{a63f}'), ('\u{a69e}', '\u{a69e}'), ('\u{a6f8}', '\u{a6ff}'), ('\u{a78f}',
-        '\u{a78f}'), ('\u{a7ae}', '\u{a7af}'), ('\u{a7b2}', '\u{a7f6}'), ('\u{a82c}', '\u{a82f}'),
-        ('\u{a83a}', '\u{a83f}'), ('\u{a878}', '\u{a87f}'), ('\u{a8c5}', '\u{a8cd}'), ('\u{a8da}',
-        '\u{a8df}'), ('\u{a8fc}', '\u{a8ff}'), ('\u{a954}', '\u{a95e}'), ('\u{a97d}', '\u{a97f}'),
-        ('\u{a9ce}', '\u{a9ce}'), ('\u{a9da}', '\u{a9dd}'), ('\u{a9ff}', '\u{a9ff}'), ('\u{aa37}',
-        '\u{aa3f}'), ('\u{aa4e}', '\u{aa4f}'), ('\u{aa5a}', '\u{aa5b}'), ('\u{aac3}', '\u{aada}'),
-        ('\u{aaf7}', '\u{ab00}'), ('\u{ab07}', '\u{ab08}'), ('\u{ab0f}', '\u{ab10}'), ('\u{ab17}',
-        '\u{ab1f}'), ('\u{ab27}', '\u{ab27}'), ('\u{ab2f}', '\u{ab2f}'), ('\u{ab60}', '\u{ab63}'),
-        ('\u{ab66}', '\u{abbf}'), ('\u{abee}', '\u{abef}'), ('\u{abfa}', '\u{abff}'), ('\u{ac01}',
-        '\u{d7a2}'), ('\u{d7a4}', '\u{d7af}'), ('\u{d7c7}', '\u{d7ca}'), ('\u{d7fc}', '\u{d7ff}'),
-        ('\u{e000}', '\u{f8ff}'), ('\u{fa6e}', '\u{fa6f}'), ('\u{fada}', '\u{faff}'), ('\u{fb07}',
-        '\u{fb12}'), ('\u{fb18}', '\u{fb1c}'), ('\u{fb37}', '\u{fb37}'), ('\u{fb3d}', '\u{fb3d}'),
-        ('\u{fb3f}', '\u{fb3f}'), ('\u{fb42}', '\u{fb42}'), ('\u{fb45}', '\u{fb45}'), ('\u{fbc2}',
-        '\u{fbd2}'), ('\u{fd40}', '\u{fd4f}'), ('\u{fd90}', '\u{fd91}'), ('\u{fdc8}', '\u{fdef}'),
-        ('\u{fdfe}', '\u{fdff}'), ('\u{fe1a}', '\u{fe1f}'), ('\u{fe2e}', '\u{fe2f}'), ('\u{fe53}',
-        '\u{fe53}'), ('\u{fe67}', '\u{fe67}'), ('\u{fe6c}', '\u{fe6f}'), ('\u{fe75}', '\u{fe75}'),
-        ('\u{fefd}', '\u{ff00}'), ('\u{ffbf}', '\u{ffc1}'), ('\u{ffc8}', '\u{ffc9}'), ('\u{ffd0}',
-        '\u{ffd1}'), ('\u{ffd8}', '\u{ffd9}'), ('\u{ffdd}', '\u{ffdf}'), ('\u{ffe7}', '\u{ffe7}'),
-        ('\u{ffef}', '\u{fffb}'), ('\u{fffe}', '\u{ffff}'), ('\u{1000c}', '\u{1000c}'),
+        ('\u{32ff}', '\u{32ff}'), ('\u{4db6}', '\u{4dbf}'), ('\u{9fcd}', '\u{9fff}'), ('\u{a48d}',
+        '\u{a48f}'), ('\u{a4c7}', '\u{a4cf}'), ('\u{a62c}', '\u{a63f}'), ('\u{a69e}', '\u{a69e}'),
+        ('\u{a6f8}', '\u{a6ff}'), ('\u{a78f}', '\u{a78f}'), ('\u{a7ae}', '\u{a7af}'), ('\u{a7b2}',
+        '\u{a7f6}'), ('\u{a82c}', '\u{a82f}'), ('\u{a83a}', '\u{a83f}'), ('\u{a878}', '\u{a87f}'),
+        ('\u{a8c5}', '\u{a8cd}'), ('\u{a8da}', '\u{a8df}'), ('\u{a8fc}', '\u{a8ff}'), ('\u{a954}',
+        '\u{a95e}'), ('\u{a97d}', '\u{a97f}'), ('\u{a9ce}', '\u{a9ce}'), ('\u{a9da}', '\u{a9dd}'),
+        ('\u{a9ff}', '\u{a9ff}'), ('\u{aa37}', '\u{aa3f}'), ('\u{aa4e}', '\u{aa4f}'), ('\u{aa5a}',
+        '\u{aa5b}'), ('\u{aac3}', '\u{aada}'), ('\u{aaf7}', '\u{ab00}'), ('\u{ab07}', '\u{ab08}'),
+        ('\u{ab0f}', '\u{ab10}'), ('\u{ab17}', '\u{ab1f}'), ('\u{ab27}', '\u{ab27}'), ('\u{ab2f}',
+        '\u{ab2f}'), ('\u{ab60}', '\u{ab63}'), ('\u{ab66}', '\u{abbf}'), ('\u{abee}', '\u{abef}'),
+        ('\u{abfa}', '\u{abff}'), ('\u{d7a4}', '\u{d7af}'), ('\u{d7c7}', '\u{d7ca}'), ('\u{d7fc}',
+        '\u{d7ff}'), ('\u{e000}', '\u{f8ff}'), ('\u{fa6e}', '\u{fa6f}'), ('\u{fada}', '\u{faff}'),
+        ('\u{fb07}', '\u{fb12}'), ('\u{fb18}', '\u{fb1c}'), ('\u{fb37}', '\u{fb37}'), ('\u{fb3d}',
+        '\u{fb3d}'), ('\u{fb3f}', '\u{fb3f}'), ('\u{fb42}', '\u{fb42}'), ('\u{fb45}', '\u{fb45}'),
+        ('\u{fbc2}', '\u{fbd2}'), ('\u{fd40}', '\u{fd4f}'), ('\u{fd90}', '\u{fd91}'), ('\u{fdc8}',
+        '\u{fdef}'), ('\u{fdfe}', '\u{fdff}'), ('\u{fe1a}', '\u{fe1f}'), ('\u{fe2e}', '\u{fe2f}'),
+        ('\u{fe53}', '\u{fe53}'), ('\u{fe67}', '\u{fe67}'), ('\u{fe6c}', '\u{fe6f}'), ('\u{fe75}',
+        '\u{fe75}'), ('\u{fefd}', '\u{ff00}'), ('\u{ffbf}', '\u{ffc1}'), ('\u{ffc8}', '\u{ffc9}'),
+        ('\u{ffd0}', '\u{ffd1}'), ('\u{ffd8}', '\u{ffd9}'), ('\u{ffdd}', '\u{ffdf}'), ('\u{ffe7}',
+        '\u{ffe7}'), ('\u{ffef}', '\u{fffb}'), ('\u{fffe}', '\u{ffff}'), ('\u{1000c}', '\u{1000c}'),
         ('\u{10027}', '\u{10027}'), ('\u{1003b}', '\u{1003b}'), ('\u{1003e}', '\u{1003e}'),
         ('\u{1004e}', '\u{1004f}'), ('\u{1005e}', '\u{1007f}'), ('\u{100fb}', '\u{100ff}'),
         ('\u{10103}', '\u{10106}'), ('\u{10134}', '\u{10136}'), ('\u{1018d}', '\u{1018f}'),
@@ -210,9 +209,8 @@ pub mod general_category {
         ('\u{1f643}', '\u{1f644}'), ('\u{1f6d0}', '\u{1f6df}'), ('\u{1f6ed}', '\u{1f6ef}'),
         ('\u{1f6f4}', '\u{1f6ff}'), ('\u{1f774}', '\u{1f77f}'), ('\u{1f7d5}', '\u{1f7ff}'),
         ('\u{1f80c}', '\u{1f80f}'), ('\u{1f848}', '\u{1f84f}'), ('\u{1f85a}', '\u{1f85f}'),
-        ('\u{1f888}', '\u{1f88f}'), ('\u{1f8ae}', '\u{1ffff}'), ('\u{20001}', '\u{2a6d5}'),
-        ('\u{2a6d7}', '\u{2a6ff}'), ('\u{2a701}', '\u{2b733}'), ('\u{2b735}', '\u{2b73f}'),
-        ('\u{2b741}', '\u{2b81c}'), ('\u{2b81e}', '\u{2f7ff}'), ('\u{2fa1e}', '\u{e00ff}'),
+        ('\u{1f888}', '\u{1f88f}'), ('\u{1f8ae}', '\u{1ffff}'), ('\u{2a6d7}', '\u{2a6ff}'),
+        ('\u{2b735}', '\u{2b73f}'), ('\u{2b81e}', '\u{2f7ff}'), ('\u{2fa1e}', '\u{e00ff}'),
         ('\u{e01f0}', '\u{10ffff}')
     ];
 
@@ -319,112 +317,108 @@ pub mod general_category {
         ('\u{2e9a}', '\u{2e9a}'), ('\u{2ef4}', '\u{2eff}'), ('\u{2fd6}', '\u{2fef}'), ('\u{2ffc}',
         '\u{2fff}'), ('\u{3040}', '\u{3040}'), ('\u{3097}', '\u{3098}'), ('\u{3100}', '\u{3104}'),
         ('\u{312e}', '\u{3130}'), ('\u{318f}', '\u{318f}'), ('\u{31bb}', '\u{31bf}'), ('\u{31e4}',
-        '\u{31ef}'), ('\u{321f}', '\u{321f}'), ('\u{32ff}', '\u{32ff}'), ('\u{3401}', '\u{4db4}'),
-        ('\u{4db6}', '\u{4dbf}'), ('\u{4e01}', '\u{9fcb}'), ('\u{9fcd}', '\u{9fff}'), ('\u{a48d}',
-        '\u{a48f}'), ('\u{a4c7}', '\u{a4cf}'), ('\u{a62c}', '\u{a63f}'), ('\u{a69e}', '\u{a69e}'),
-        ('\u{a6f8}', '\u{a6ff}'), ('\u{a78f}', '\u{a78f}'), ('\u{a7ae}', '\u{a7af}'), ('\u{a7b2}',
-        '\u{a7f6}'), ('\u{a82c}', '\u{a82f}'), ('\u{a83a}', '\u{a83f}'), ('\u{a878}', '\u{a87f}'),
-        ('\u{a8c5}', '\u{a8cd}'), ('\u{a8da}', '\u{a8df}'), ('\u{a8fc}', '\u{a8ff}'), ('\u{a954}',
-        '\u{a95e}'), ('\u{a97d}', '\u{a97f}'), ('\u{a9ce}', '\u{a9ce}'), ('\u{a9da}', '\u{a9dd}'),
-        ('\u{a9ff}', '\u{a9ff}'), ('\u{aa37}', '\u{aa3f}'), ('\u{aa4e}', '\u{aa4f}'), ('\u{aa5a}',
-        '\u{aa5b}'), ('\u{aac3}', '\u{aada}'), ('\u{aaf7}', '\u{ab00}'), ('\u{ab07}', '\u{ab08}'),
-        ('\u{ab0f}', '\u{ab10}'), ('\u{ab17}', '\u{ab1f}'), ('\u{ab27}', '\u{ab27}'), ('\u{ab2f}',
-        '\u{ab2f}'), ('\u{ab60}', '\u{ab63}'), ('\u{ab66}', '\u{abbf}'), ('\u{abee}', '\u{abef}'),
-        ('\u{abfa}', '\u{abff}'), ('\u{ac01}', '\u{d7a2}'), ('\u{d7a4}', '\u{d7af}'), ('\u{d7c7}',
-        '\u{d7ca}'), ('\u{d7fc}', '\u{d7ff}'), ('\u{e001}', '\u{f8fe}'), ('\u{fa6e}', '\u{fa6f}'),
-        ('\u{fada}', '\u{faff}'), ('\u{fb07}', '\u{fb12}'), ('\u{fb18}', '\u{fb1c}'), ('\u{fb37}',
-        '\u{fb37}'), ('\u{fb3d}', '\u{fb3d}'), ('\u{fb3f}', '\u{fb3f}'), ('\u{fb42}', '\u{fb42}'),
-        ('\u{fb45}', '\u{fb45}'), ('\u{fbc2}', '\u{fbd2}'), ('\u{fd40}', '\u{fd4f}'), ('\u{fd90}',
-        '\u{fd91}'), ('\u{fdc8}', '\u{fdef}'), ('\u{fdfe}', '\u{fdff}'), ('\u{fe1a}', '\u{fe1f}'),
-        ('\u{fe2e}', '\u{fe2f}'), ('\u{fe53}', '\u{fe53}'), ('\u{fe67}', '\u{fe67}'), ('\u{fe6c}',
-        '\u{fe6f}'), ('\u{fe75}', '\u{fe75}'), ('\u{fefd}', '\u{fefe}'), ('\u{ff00}', '\u{ff00}'),
-        ('\u{ffbf}', '\u{ffc1}'), ('\u{ffc8}', '\u{ffc9}'), ('\u{ffd0}', '\u{ffd1}'), ('\u{ffd8}',
-        '\u{ffd9}'), ('\u{ffdd}', '\u{ffdf}'), ('\u{ffe7}', '\u{ffe7}'), ('\u{ffef}', '\u{fff8}'),
-        ('\u{fffe}', '\u{ffff}'), ('\u{1000c}', '\u{1000c}'), ('\u{10027}', '\u{10027}'),
-        ('\u{1003b}', '\u{1003b}'), ('\u{1003e}', '\u{1003e}'), ('\u{1004e}', '\u{1004f}'),
-        ('\u{1005e}', '\u{1007f}'), ('\u{100fb}', '\u{100ff}'), ('\u{10103}', '\u{10106}'),
-        ('\u{10134}', '\u{10136}'), ('\u{1018d}', '\u{1018f}'), ('\u{1019c}', '\u{1019f}'),
-        ('\u{101a1}', '\u{101cf}'), ('\u{101fe}', '\u{1027f}'), ('\u{1029d}', '\u{1029f}'),
-        ('\u{102d1}', '\u{102df}'), ('\u{102fc}', '\u{102ff}'), ('\u{10324}', '\u{1032f}'),
-        ('\u{1034b}', '\u{1034f}'), ('\u{1037b}', '\u{1037f}'), ('\u{1039e}', '\u{1039e}'),
-        ('\u{103c4}', '\u{103c7}'), ('\u{103d6}', '\u{103ff}'), ('\u{1049e}', '\u{1049f}'),
-        ('\u{104aa}', '\u{104ff}'), ('\u{10528}', '\u{1052f}'), ('\u{10564}', '\u{1056e}'),
-        ('\u{10570}', '\u{105ff}'), ('\u{10737}', '\u{1073f}'), ('\u{10756}', '\u{1075f}'),
-        ('\u{10768}', '\u{107ff}'), ('\u{10806}', '\u{10807}'), ('\u{10809}', '\u{10809}'),
-        ('\u{10836}', '\u{10836}'), ('\u{10839}', '\u{1083b}'), ('\u{1083d}', '\u{1083e}'),
-        ('\u{10856}', '\u{10856}'), ('\u{1089f}', '\u{108a6}'), ('\u{108b0}', '\u{108ff}'),
-        ('\u{1091c}', '\u{1091e}'), ('\u{1093a}', '\u{1093e}'), ('\u{10940}', '\u{1097f}'),
-        ('\u{109b8}', '\u{109bd}'), ('\u{109c0}', '\u{109ff}'), ('\u{10a04}', '\u{10a04}'),
-        ('\u{10a07}', '\u{10a0b}'), ('\u{10a14}', '\u{10a14}'), ('\u{10a18}', '\u{10a18}'),
-        ('\u{10a34}', '\u{10a37}'), ('\u{10a3b}', '\u{10a3e}'), ('\u{10a48}', '\u{10a4f}'),
-        ('\u{10a59}', '\u{10a5f}'), ('\u{10aa0}', '\u{10abf}'), ('\u{10ae7}', '\u{10aea}'),
-        ('\u{10af7}', '\u{10aff}'), ('\u{10b36}', '\u{10b38}'), ('\u{10b56}', '\u{10b57}'),
-        ('\u{10b73}', '\u{10b77}'), ('\u{10b92}', '\u{10b98}'), ('\u{10b9d}', '\u{10ba8}'),
-        ('\u{10bb0}', '\u{10bff}'), ('\u{10c49}', '\u{10e5f}'), ('\u{10e7f}', '\u{10fff}'),
-        ('\u{1104e}', '\u{11051}'), ('\u{11070}', '\u{1107e}'), ('\u{110c2}', '\u{110cf}'),
-        ('\u{110e9}', '\u{110ef}'), ('\u{110fa}', '\u{110ff}'), ('\u{11135}', '\u{11135}'),
-        ('\u{11144}', '\u{1114f}'), ('\u{11177}', '\u{1117f}'), ('\u{111c9}', '\u{111cc}'),
-        ('\u{111ce}', '\u{111cf}'), ('\u{111db}', '\u{111e0}'), ('\u{111f5}', '\u{111ff}'),
-        ('\u{11212}', '\u{11212}'), ('\u{1123e}', '\u{112af}'), ('\u{112eb}', '\u{112ef}'),
-        ('\u{112fa}', '\u{11300}'), ('\u{11304}', '\u{11304}'), ('\u{1130d}', '\u{1130e}'),
-        ('\u{11311}', '\u{11312}'), ('\u{11329}', '\u{11329}'), ('\u{11331}', '\u{11331}'),
-        ('\u{11334}', '\u{11334}'), ('\u{1133a}', '\u{1133b}'), ('\u{11345}', '\u{11346}'),
-        ('\u{11349}', '\u{1134a}'), ('\u{1134e}', '\u{11356}'), ('\u{11358}', '\u{1135c}'),
-        ('\u{11364}', '\u{11365}'), ('\u{1136d}', '\u{1136f}'), ('\u{11375}', '\u{1147f}'),
-        ('\u{114c8}', '\u{114cf}'), ('\u{114da}', '\u{1157f}'), ('\u{115b6}', '\u{115b7}'),
-        ('\u{115ca}', '\u{115ff}'), ('\u{11645}', '\u{1164f}'), ('\u{1165a}', '\u{1167f}'),
-        ('\u{116b8}', '\u{116bf}'), ('\u{116ca}', '\u{1189f}'), ('\u{118f3}', '\u{118fe}'),
-        ('\u{11900}', '\u{11abf}'), ('\u{11af9}', '\u{11fff}'), ('\u{12399}', '\u{123ff}'),
-        ('\u{1246f}', '\u{1246f}'), ('\u{12475}', '\u{12fff}'), ('\u{1342f}', '\u{167ff}'),
-        ('\u{16a39}', '\u{16a3f}'), ('\u{16a5f}', '\u{16a5f}'), ('\u{16a6a}', '\u{16a6d}'),
-        ('\u{16a70}', '\u{16acf}'), ('\u{16aee}', '\u{16aef}'), ('\u{16af6}', '\u{16aff}'),
-        ('\u{16b46}', '\u{16b4f}'), ('\u{16b5a}', '\u{16b5a}'), ('\u{16b62}', '\u{16b62}'),
-        ('\u{16b78}', '\u{16b7c}'), ('\u{16b90}', '\u{16eff}'), ('\u{16f45}', '\u{16f4f}'),
-        ('\u{16f7f}', '\u{16f8e}'), ('\u{16fa0}', '\u{1afff}'), ('\u{1b002}', '\u{1bbff}'),
-        ('\u{1bc6b}', '\u{1bc6f}'), ('\u{1bc7d}', '\u{1bc7f}'), ('\u{1bc89}', '\u{1bc8f}'),
-        ('\u{1bc9a}', '\u{1bc9b}'), ('\u{1bca4}', '\u{1cfff}'), ('\u{1d0f6}', '\u{1d0ff}'),
-        ('\u{1d127}', '\u{1d128}'), ('\u{1d1de}', '\u{1d1ff}'), ('\u{1d246}', '\u{1d2ff}'),
-        ('\u{1d357}', '\u{1d35f}'), ('\u{1d372}', '\u{1d3ff}'), ('\u{1d455}', '\u{1d455}'),
-        ('\u{1d49d}', '\u{1d49d}'), ('\u{1d4a0}', '\u{1d4a1}'), ('\u{1d4a3}', '\u{1d4a4}'),
-        ('\u{1d4a7}', '\u{1d4a8}'), ('\u{1d4ad}', '\u{1d4ad}'), ('\u{1d4ba}', '\u{1d4ba}'),
-        ('\u{1d4bc}', '\u{1d4bc}'), ('\u{1d4c4}', '\u{1d4c4}'), ('\u{1d506}', '\u{1d506}'),
-        ('\u{1d50b}', '\u{1d50c}'), ('\u{1d515}', '\u{1d515}'), ('\u{1d51d}', '\u{1d51d}'),
-        ('\u{1d53a}', '\u{1d53a}'), ('\u{1d53f}', '\u{1d53f}'), ('\u{1d545}', '\u{1d545}'),
-        ('\u{1d547}', '\u{1d549}'), ('\u{1d551}', '\u{1d551}'), ('\u{1d6a6}', '\u{1d6a7}'),
-        ('\u{1d7cc}', '\u{1d7cd}'), ('\u{1d800}', '\u{1e7ff}'), ('\u{1e8c5}', '\u{1e8c6}'),
-        ('\u{1e8d7}', '\u{1edff}'), ('\u{1ee04}', '\u{1ee04}'), ('\u{1ee20}', '\u{1ee20}'),
-        ('\u{1ee23}', '\u{1ee23}'), ('\u{1ee25}', '\u{1ee26}'), ('\u{1ee28}', '\u{1ee28}'),
-        ('\u{1ee33}', '\u{1ee33}'), ('\u{1ee38}', '\u{1ee38}'), ('\u{1ee3a}', '\u{1ee3a}'),
-        ('\u{1ee3c}', '\u{1ee41}'), ('\u{1ee43}', '\u{1ee46}'), ('\u{1ee48}', '\u{1ee48}'),
-        ('\u{1ee4a}', '\u{1ee4a}'), ('\u{1ee4c}', '\u{1ee4c}'), ('\u{1ee50}', '\u{1ee50}'),
-        ('\u{1ee53}', '\u{1ee53}'), ('\u{1ee55}', '\u{1ee56}'), ('\u{1ee58}', '\u{1ee58}'),
-        ('\u{1ee5a}', '\u{1ee5a}'), ('\u{1ee5c}', '\u{1ee5c}'), ('\u{1ee5e}', '\u{1ee5e}'),
-        ('\u{1ee60}', '\u{1ee60}'), ('\u{1ee63}', '\u{1ee63}'), ('\u{1ee65}', '\u{1ee66}'),
-        ('\u{1ee6b}', '\u{1ee6b}'), ('\u{1ee73}', '\u{1ee73}'), ('\u{1ee78}', '\u{1ee78}'),
-        ('\u{1ee7d}', '\u{1ee7d}'), ('\u{1ee7f}', '\u{1ee7f}'), ('\u{1ee8a}', '\u{1ee8a}'),
-        ('\u{1ee9c}', '\u{1eea0}'), ('\u{1eea4}', '\u{1eea4}'), ('\u{1eeaa}', '\u{1eeaa}'),
-        ('\u{1eebc}', '\u{1eeef}'), ('\u{1eef2}', '\u{1efff}'), ('\u{1f02c}', '\u{1f02f}'),
-        ('\u{1f094}', '\u{1f09f}'), ('\u{1f0af}', '\u{1f0b0}'), ('\u{1f0c0}', '\u{1f0c0}'),
-        ('\u{1f0d0}', '\u{1f0d0}'), ('\u{1f0f6}', '\u{1f0ff}'), ('\u{1f10d}', '\u{1f10f}'),
-        ('\u{1f12f}', '\u{1f12f}'), ('\u{1f16c}', '\u{1f16f}'), ('\u{1f19b}', '\u{1f1e5}'),
-        ('\u{1f203}', '\u{1f20f}'), ('\u{1f23b}', '\u{1f23f}'), ('\u{1f249}', '\u{1f24f}'),
-        ('\u{1f252}', '\u{1f2ff}'), ('\u{1f32d}', '\u{1f32f}'), ('\u{1f37e}', '\u{1f37f}'),
-        ('\u{1f3cf}', '\u{1f3d3}'), ('\u{1f3f8}', '\u{1f3ff}'), ('\u{1f4ff}', '\u{1f4ff}'),
-        ('\u{1f54b}', '\u{1f54f}'), ('\u{1f57a}', '\u{1f57a}'), ('\u{1f5a4}', '\u{1f5a4}'),
-        ('\u{1f643}', '\u{1f644}'), ('\u{1f6d0}', '\u{1f6df}'), ('\u{1f6ed}', '\u{1f6ef}'),
-        ('\u{1f6f4}', '\u{1f6ff}'), ('\u{1f774}', '\u{1f77f}'), ('\u{1f7d5}', '\u{1f7ff}'),
-        ('\u{1f80c}', '\u{1f80f}'), ('\u{1f848}', '\u{1f84f}'), ('\u{1f85a}', '\u{1f85f}'),
-        ('\u{1f888}', '\u{1f88f}'), ('\u{1f8ae}', '\u{1ffff}'), ('\u{20001}', '\u{2a6d5}'),
-        ('\u{2a6d7}', '\u{2a6ff}'), ('\u{2a701}', '\u{2b733}'), ('\u{2b735}', '\u{2b73f}'),
-        ('\u{2b741}', '\u{2b81c}'), ('\u{2b81e}', '\u{2f7ff}'), ('\u{2fa1e}', '\u{e0000}'),
-        ('\u{e0002}', '\u{e001f}'), ('\u{e0080}', '\u{e00ff}'), ('\u{e01f0}', '\u{effff}'),
-        ('\u{f0001}', '\u{ffffc}'), ('\u{ffffe}', '\u{fffff}'), ('\u{100001}', '\u{10fffc}'),
-        ('\u{10fffe}', '\u{10ffff}')
+        '\u{31ef}'), ('\u{321f}', '\u{321f}'), ('\u{32ff}', '\u{32ff}'), ('\u{4db6}', '\u{4dbf}'),
+        ('\u{9fcd}', '\u{9fff}'), ('\u{a48d}', '\u{a48f}'), ('\u{a4c7}', '\u{a4cf}'), ('\u{a62c}',
+        '\u{a63f}'), ('\u{a69e}', '\u{a69e}'), ('\u{a6f8}', '\u{a6ff}'), ('\u{a78f}', '\u{a78f}'),
+        ('\u{a7ae}', '\u{a7af}'), ('\u{a7b2}', '\u{a7f6}'), ('\u{a82c}', '\u{a82f}'), ('\u{a83a}',
+        '\u{a83f}'), ('\u{a878}', '\u{a87f}'), ('\u{a8c5}', '\u{a8cd}'), ('\u{a8da}', '\u{a8df}'),
+        ('\u{a8fc}', '\u{a8ff}'), ('\u{a954}', '\u{a95e}'), ('\u{a97d}', '\u{a97f}'), ('\u{a9ce}',
+        '\u{a9ce}'), ('\u{a9da}', '\u{a9dd}'), ('\u{a9ff}', '\u{a9ff}'), ('\u{aa37}', '\u{aa3f}'),
+        ('\u{aa4e}', '\u{aa4f}'), ('\u{aa5a}', '\u{aa5b}'), ('\u{aac3}', '\u{aada}'), ('\u{aaf7}',
+        '\u{ab00}'), ('\u{ab07}', '\u{ab08}'), ('\u{ab0f}', '\u{ab10}'), ('\u{ab17}', '\u{ab1f}'),
+        ('\u{ab27}', '\u{ab27}'), ('\u{ab2f}', '\u{ab2f}'), ('\u{ab60}', '\u{ab63}'), ('\u{ab66}',
+        '\u{abbf}'), ('\u{abee}', '\u{abef}'), ('\u{abfa}', '\u{abff}'), ('\u{d7a4}', '\u{d7af}'),
+        ('\u{d7c7}', '\u{d7ca}'), ('\u{d7fc}', '\u{d7ff}'), ('\u{fa6e}', '\u{fa6f}'), ('\u{fada}',
+        '\u{faff}'), ('\u{fb07}', '\u{fb12}'), ('\u{fb18}', '\u{fb1c}'), ('\u{fb37}', '\u{fb37}'),
+        ('\u{fb3d}', '\u{fb3d}'), ('\u{fb3f}', '\u{fb3f}'), ('\u{fb42}', '\u{fb42}'), ('\u{fb45}',
+        '\u{fb45}'), ('\u{fbc2}', '\u{fbd2}'), ('\u{fd40}', '\u{fd4f}'), ('\u{fd90}', '\u{fd91}'),
+        ('\u{fdc8}', '\u{fdef}'), ('\u{fdfe}', '\u{fdff}'), ('\u{fe1a}', '\u{fe1f}'), ('\u{fe2e}',
+        '\u{fe2f}'), ('\u{fe53}', '\u{fe53}'), ('\u{fe67}', '\u{fe67}'), ('\u{fe6c}', '\u{fe6f}'),
+        ('\u{fe75}', '\u{fe75}'), ('\u{fefd}', '\u{fefe}'), ('\u{ff00}', '\u{ff00}'), ('\u{ffbf}',
+        '\u{ffc1}'), ('\u{ffc8}', '\u{ffc9}'), ('\u{ffd0}', '\u{ffd1}'), ('\u{ffd8}', '\u{ffd9}'),
+        ('\u{ffdd}', '\u{ffdf}'), ('\u{ffe7}', '\u{ffe7}'), ('\u{ffef}', '\u{fff8}'), ('\u{fffe}',
+        '\u{ffff}'), ('\u{1000c}', '\u{1000c}'), ('\u{10027}', '\u{10027}'), ('\u{1003b}',
+        '\u{1003b}'), ('\u{1003e}', '\u{1003e}'), ('\u{1004e}', '\u{1004f}'), ('\u{1005e}',
+        '\u{1007f}'), ('\u{100fb}', '\u{100ff}'), ('\u{10103}', '\u{10106}'), ('\u{10134}',
+        '\u{10136}'), ('\u{1018d}', '\u{1018f}'), ('\u{1019c}', '\u{1019f}'), ('\u{101a1}',
+        '\u{101cf}'), ('\u{101fe}', '\u{1027f}'), ('\u{1029d}', '\u{1029f}'), ('\u{102d1}',
+        '\u{102df}'), ('\u{102fc}', '\u{102ff}'), ('\u{10324}', '\u{1032f}'), ('\u{1034b}',
+        '\u{1034f}'), ('\u{1037b}', '\u{1037f}'), ('\u{1039e}', '\u{1039e}'), ('\u{103c4}',
+        '\u{103c7}'), ('\u{103d6}', '\u{103ff}'), ('\u{1049e}', '\u{1049f}'), ('\u{104aa}',
+        '\u{104ff}'), ('\u{10528}', '\u{1052f}'), ('\u{10564}', '\u{1056e}'), ('\u{10570}',
+        '\u{105ff}'), ('\u{10737}', '\u{1073f}'), ('\u{10756}', '\u{1075f}'), ('\u{10768}',
+        '\u{107ff}'), ('\u{10806}', '\u{10807}'), ('\u{10809}', '\u{10809}'), ('\u{10836}',
+        '\u{10836}'), ('\u{10839}', '\u{1083b}'), ('\u{1083d}', '\u{1083e}'), ('\u{10856}',
+        '\u{10856}'), ('\u{1089f}', '\u{108a6}'), ('\u{108b0}', '\u{108ff}'), ('\u{1091c}',
+        '\u{1091e}'), ('\u{1093a}', '\u{1093e}'), ('\u{10940}', '\u{1097f}'), ('\u{109b8}',
+        '\u{109bd}'), ('\u{109c0}', '\u{109ff}'), ('\u{10a04}', '\u{10a04}'), ('\u{10a07}',
+        '\u{10a0b}'), ('\u{10a14}', '\u{10a14}'), ('\u{10a18}', '\u{10a18}'), ('\u{10a34}',
+        '\u{10a37}'), ('\u{10a3b}', '\u{10a3e}'), ('\u{10a48}', '\u{10a4f}'), ('\u{10a59}',
+        '\u{10a5f}'), ('\u{10aa0}', '\u{10abf}'), ('\u{10ae7}', '\u{10aea}'), ('\u{10af7}',
+        '\u{10aff}'), ('\u{10b36}', '\u{10b38}'), ('\u{10b56}', '\u{10b57}'), ('\u{10b73}',
+        '\u{10b77}'), ('\u{10b92}', '\u{10b98}'), ('\u{10b9d}', '\u{10ba8}'), ('\u{10bb0}',
+        '\u{10bff}'), ('\u{10c49}', '\u{10e5f}'), ('\u{10e7f}', '\u{10fff}'), ('\u{1104e}',
+        '\u{11051}'), ('\u{11070}', '\u{1107e}'), ('\u{110c2}', '\u{110cf}'), ('\u{110e9}',
+        '\u{110ef}'), ('\u{110fa}', '\u{110ff}'), ('\u{11135}', '\u{11135}'), ('\u{11144}',
+        '\u{1114f}'), ('\u{11177}', '\u{1117f}'), ('\u{111c9}', '\u{111cc}'), ('\u{111ce}',
+        '\u{111cf}'), ('\u{111db}', '\u{111e0}'), ('\u{111f5}', '\u{111ff}'), ('\u{11212}',
+        '\u{11212}'), ('\u{1123e}', '\u{112af}'), ('\u{112eb}', '\u{112ef}'), ('\u{112fa}',
+        '\u{11300}'), ('\u{11304}', '\u{11304}'), ('\u{1130d}', '\u{1130e}'), ('\u{11311}',
+        '\u{11312}'), ('\u{11329}', '\u{11329}'), ('\u{11331}', '\u{11331}'), ('\u{11334}',
+        '\u{11334}'), ('\u{1133a}', '\u{1133b}'), ('\u{11345}', '\u{11346}'), ('\u{11349}',
+        '\u{1134a}'), ('\u{1134e}', '\u{11356}'), ('\u{11358}', '\u{1135c}'), ('\u{11364}',
+        '\u{11365}'), ('\u{1136d}', '\u{1136f}'), ('\u{11375}', '\u{1147f}'), ('\u{114c8}',
+        '\u{114cf}'), ('\u{114da}', '\u{1157f}'), ('\u{115b6}', '\u{115b7}'), ('\u{115ca}',
+        '\u{115ff}'), ('\u{11645}', '\u{1164f}'), ('\u{1165a}', '\u{1167f}'), ('\u{116b8}',
+        '\u{116bf}'), ('\u{116ca}', '\u{1189f}'), ('\u{118f3}', '\u{118fe}'), ('\u{11900}',
+        '\u{11abf}'), ('\u{11af9}', '\u{11fff}'), ('\u{12399}', '\u{123ff}'), ('\u{1246f}',
+        '\u{1246f}'), ('\u{12475}', '\u{12fff}'), ('\u{1342f}', '\u{167ff}'), ('\u{16a39}',
+        '\u{16a3f}'), ('\u{16a5f}', '\u{16a5f}'), ('\u{16a6a}', '\u{16a6d}'), ('\u{16a70}',
+        '\u{16acf}'), ('\u{16aee}', '\u{16aef}'), ('\u{16af6}', '\u{16aff}'), ('\u{16b46}',
+        '\u{16b4f}'), ('\u{16b5a}', '\u{16b5a}'), ('\u{16b62}', '\u{16b62}'), ('\u{16b78}',
+        '\u{16b7c}'), ('\u{16b90}', '\u{16eff}'), ('\u{16f45}', '\u{16f4f}'), ('\u{16f7f}',
+        '\u{16f8e}'), ('\u{16fa0}', '\u{1afff}'), ('\u{1b002}', '\u{1bbff}'), ('\u{1bc6b}',
+        '\u{1bc6f}'), ('\u{1bc7d}', '\u{1bc7f}'), ('\u{1bc89}', '\u{1bc8f}'), ('\u{1bc9a}',
+        '\u{1bc9b}'), ('\u{1bca4}', '\u{1cfff}'), ('\u{1d0f6}', '\u{1d0ff}'), ('\u{1d127}',
+        '\u{1d128}'), ('\u{1d1de}', '\u{1d1ff}'), ('\u{1d246}', '\u{1d2ff}'), ('\u{1d357}',
+        '\u{1d35f}'), ('\u{1d372}', '\u{1d3ff}'), ('\u{1d455}', '\u{1d455}'), ('\u{1d49d}',
+        '\u{1d49d}'), ('\u{1d4a0}', '\u{1d4a1}'), ('\u{1d4a3}', '\u{1d4a4}'), ('\u{1d4a7}',
+        '\u{1d4a8}'), ('\u{1d4ad}', '\u{1d4ad}'), ('\u{1d4ba}', '\u{1d4ba}'), ('\u{1d4bc}',
+        '\u{1d4bc}'), ('\u{1d4c4}', '\u{1d4c4}'), ('\u{1d506}', '\u{1d506}'), ('\u{1d50b}',
+        '\u{1d50c}'), ('\u{1d515}', '\u{1d515}'), ('\u{1d51d}', '\u{1d51d}'), ('\u{1d53a}',
+        '\u{1d53a}'), ('\u{1d53f}', '\u{1d53f}'), ('\u{1d545}', '\u{1d545}'), ('\u{1d547}',
+        '\u{1d549}'), ('\u{1d551}', '\u{1d551}'), ('\u{1d6a6}', '\u{1d6a7}'), ('\u{1d7cc}',
+        '\u{1d7cd}'), ('\u{1d800}', '\u{1e7ff}'), ('\u{1e8c5}', '\u{1e8c6}'), ('\u{1e8d7}',
+        '\u{1edff}'), ('\u{1ee04}', '\u{1ee04}'), ('\u{1ee20}', '\u{1ee20}'), ('\u{1ee23}',
+        '\u{1ee23}'), ('\u{1ee25}', '\u{1ee26}'), ('\u{1ee28}', '\u{1ee28}'), ('\u{1ee33}',
+        '\u{1ee33}'), ('\u{1ee38}', '\u{1ee38}'), ('\u{1ee3a}', '\u{1ee3a}'), ('\u{1ee3c}',
+        '\u{1ee41}'), ('\u{1ee43}', '\u{1ee46}'), ('\u{1ee48}', '\u{1ee48}'), ('\u{1ee4a}',
+        '\u{1ee4a}'), ('\u{1ee4c}', '\u{1ee4c}'), ('\u{1ee50}', '\u{1ee50}'), ('\u{1ee53}',
+        '\u{1ee53}'), ('\u{1ee55}', '\u{1ee56}'), ('\u{1ee58}', '\u{1ee58}'), ('\u{1ee5a}',
+        '\u{1ee5a}'), ('\u{1ee5c}', '\u{1ee5c}'), ('\u{1ee5e}', '\u{1ee5e}'), ('\u{1ee60}',
+        '\u{1ee60}'), ('\u{1ee63}', '\u{1ee63}'), ('\u{1ee65}', '\u{1ee66}'), ('\u{1ee6b}',
+        '\u{1ee6b}'), ('\u{1ee73}', '\u{1ee73}'), ('\u{1ee78}', '\u{1ee78}'), ('\u{1ee7d}',
+        '\u{1ee7d}'), ('\u{1ee7f}', '\u{1ee7f}'), ('\u{1ee8a}', '\u{1ee8a}'), ('\u{1ee9c}',
+        '\u{1eea0}'), ('\u{1eea4}', '\u{1eea4}'), ('\u{1eeaa}', '\u{1eeaa}'), ('\u{1eebc}',
+        '\u{1eeef}'), ('\u{1eef2}', '\u{1efff}'), ('\u{1f02c}', '\u{1f02f}'), ('\u{1f094}',
+        '\u{1f09f}'), ('\u{1f0af}', '\u{1f0b0}'), ('\u{1f0c0}', '\u{1f0c0}'), ('\u{1f0d0}',
+        '\u{1f0d0}'), ('\u{1f0f6}', '\u{1f0ff}'), ('\u{1f10d}', '\u{1f10f}'), ('\u{1f12f}',
+        '\u{1f12f}'), ('\u{1f16c}', '\u{1f16f}'), ('\u{1f19b}', '\u{1f1e5}'), ('\u{1f203}',
+        '\u{1f20f}'), ('\u{1f23b}', '\u{1f23f}'), ('\u{1f249}', '\u{1f24f}'), ('\u{1f252}',
+        '\u{1f2ff}'), ('\u{1f32d}', '\u{1f32f}'), ('\u{1f37e}', '\u{1f37f}'), ('\u{1f3cf}',
+        '\u{1f3d3}'), ('\u{1f3f8}', '\u{1f3ff}'), ('\u{1f4ff}', '\u{1f4ff}'), ('\u{1f54b}',
+        '\u{1f54f}'), ('\u{1f57a}', '\u{1f57a}'), ('\u{1f5a4}', '\u{1f5a4}'), ('\u{1f643}',
+        '\u{1f644}'), ('\u{1f6d0}', '\u{1f6df}'), ('\u{1f6ed}', '\u{1f6ef}'), ('\u{1f6f4}',
+        '\u{1f6ff}'), ('\u{1f774}', '\u{1f77f}'), ('\u{1f7d5}', '\u{1f7ff}'), ('\u{1f80c}',
+        '\u{1f80f}'), ('\u{1f848}', '\u{1f84f}'), ('\u{1f85a}', '\u{1f85f}'), ('\u{1f888}',
+        '\u{1f88f}'), ('\u{1f8ae}', '\u{1ffff}'), ('\u{2a6d7}', '\u{2a6ff}'), ('\u{2b735}',
+        '\u{2b73f}'), ('\u{2b81e}', '\u{2f7ff}'), ('\u{2fa1e}', '\u{e0000}'), ('\u{e0002}',
+        '\u{e001f}'), ('\u{e0080}', '\u{e00ff}'), ('\u{e01f0}', '\u{effff}'), ('\u{ffffe}',
+        '\u{fffff}'), ('\u{10fffe}', '\u{10ffff}')
     ];
 
     pub static Co_table: &'static [(char, char)] = &[
-        ('\u{e000}', '\u{e000}'), ('\u{f8ff}', '\u{f8ff}'), ('\u{f0000}', '\u{f0000}'),
-        ('\u{ffffd}', '\u{ffffd}'), ('\u{100000}', '\u{100000}'), ('\u{10fffd}', '\u{10fffd}')
+        ('\u{e000}', '\u{f8ff}'), ('\u{f0000}', '\u{ffffd}'), ('\u{100000}', '\u{10fffd}')
     ];
 
     pub static L_table: &'static [(char, char)] = &[
@@ -511,86 +505,84 @@ pub mod general_category {
         ('\u{2e2f}', '\u{2e2f}'), ('\u{3005}', '\u{3006}'), ('\u{3031}', '\u{3035}'), ('\u{303b}',
         '\u{303c}'), ('\u{3041}', '\u{3096}'), ('\u{309d}', '\u{309f}'), ('\u{30a1}', '\u{30fa}'),
         ('\u{30fc}', '\u{30ff}'), ('\u{3105}', '\u{312d}'), ('\u{3131}', '\u{318e}'), ('\u{31a0}',
-        '\u{31ba}'), ('\u{31f0}', '\u{31ff}'), ('\u{3400}', '\u{3400}'), ('\u{4db5}', '\u{4db5}'),
-        ('\u{4e00}', '\u{4e00}'), ('\u{9fcc}', '\u{9fcc}'), ('\u{a000}', '\u{a48c}'), ('\u{a4d0}',
-        '\u{a4fd}'), ('\u{a500}', '\u{a60c}'), ('\u{a610}', '\u{a61f}'), ('\u{a62a}', '\u{a62b}'),
-        ('\u{a640}', '\u{a66e}'), ('\u{a67f}', '\u{a69d}'), ('\u{a6a0}', '\u{a6e5}'), ('\u{a717}',
-        '\u{a71f}'), ('\u{a722}', '\u{a788}'), ('\u{a78b}', '\u{a78e}'), ('\u{a790}', '\u{a7ad}'),
-        ('\u{a7b0}', '\u{a7b1}'), ('\u{a7f7}', '\u{a801}'), ('\u{a803}', '\u{a805}'), ('\u{a807}',
-        '\u{a80a}'), ('\u{a80c}', '\u{a822}'), ('\u{a840}', '\u{a873}'), ('\u{a882}', '\u{a8b3}'),
-        ('\u{a8f2}', '\u{a8f7}'), ('\u{a8fb}', '\u{a8fb}'), ('\u{a90a}', '\u{a925}'), ('\u{a930}',
-        '\u{a946}'), ('\u{a960}', '\u{a97c}'), ('\u{a984}', '\u{a9b2}'), ('\u{a9cf}', '\u{a9cf}'),
-        ('\u{a9e0}', '\u{a9e4}'), ('\u{a9e6}', '\u{a9ef}'), ('\u{a9fa}', '\u{a9fe}'), ('\u{aa00}',
-        '\u{aa28}'), ('\u{aa40}', '\u{aa42}'), ('\u{aa44}', '\u{aa4b}'), ('\u{aa60}', '\u{aa76}'),
-        ('\u{aa7a}', '\u{aa7a}'), ('\u{aa7e}', '\u{aaaf}'), ('\u{aab1}', '\u{aab1}'), ('\u{aab5}',
-        '\u{aab6}'), ('\u{aab9}', '\u{aabd}'), ('\u{aac0}', '\u{aac0}'), ('\u{aac2}', '\u{aac2}'),
-        ('\u{aadb}', '\u{aadd}'), ('\u{aae0}', '\u{aaea}'), ('\u{aaf2}', '\u{aaf4}'), ('\u{ab01}',
-        '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'), ('\u{ab11}', '\u{ab16}'), ('\u{ab20}', '\u{ab26}'),
-        ('\u{ab28}', '\u{ab2e}'), ('\u{ab30}', '\u{ab5a}'), ('\u{ab5c}', '\u{ab5f}'), ('\u{ab64}',
-        '\u{ab65}'), ('\u{abc0}', '\u{abe2}'), ('\u{ac00}', '\u{ac00}'), ('\u{d7a3}', '\u{d7a3}'),
-        ('\u{d7b0}', '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'), ('\u{fa70}',
-        '\u{fad9}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'), ('\u{fb1d}', '\u{fb1d}'),
-        ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'), ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}',
-        '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'), ('\u{fb46}', '\u{fbb1}'),
-        ('\u{fbd3}', '\u{fd3d}'), ('\u{fd50}', '\u{fd8f}'), ('\u{fd92}', '\u{fdc7}'), ('\u{fdf0}',
-        '\u{fdfb}'), ('\u{fe70}', '\u{fe74}'), ('\u{fe76}', '\u{fefc}'), ('\u{ff21}', '\u{ff3a}'),
-        ('\u{ff41}', '\u{ff5a}'), ('\u{ff66}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'), ('\u{ffca}',
-        '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'), ('\u{10000}', '\u{1000b}'),
-        ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'), ('\u{1003c}', '\u{1003d}'),
-        ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'), ('\u{10080}', '\u{100fa}'),
-        ('\u{10280}', '\u{1029c}'), ('\u{102a0}', '\u{102d0}'), ('\u{10300}', '\u{1031f}'),
-        ('\u{10330}', '\u{10340}'), ('\u{10342}', '\u{10349}'), ('\u{10350}', '\u{10375}'),
-        ('\u{10380}', '\u{1039d}'), ('\u{103a0}', '\u{103c3}'), ('\u{103c8}', '\u{103cf}'),
-        ('\u{10400}', '\u{1049d}'), ('\u{10500}', '\u{10527}'), ('\u{10530}', '\u{10563}'),
-        ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'), ('\u{10760}', '\u{10767}'),
-        ('\u{10800}', '\u{10805}'), ('\u{10808}', '\u{10808}'), ('\u{1080a}', '\u{10835}'),
-        ('\u{10837}', '\u{10838}'), ('\u{1083c}', '\u{1083c}'), ('\u{1083f}', '\u{10855}'),
-        ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'), ('\u{10900}', '\u{10915}'),
-        ('\u{10920}', '\u{10939}'), ('\u{10980}', '\u{109b7}'), ('\u{109be}', '\u{109bf}'),
-        ('\u{10a00}', '\u{10a00}'), ('\u{10a10}', '\u{10a13}'), ('\u{10a15}', '\u{10a17}'),
-        ('\u{10a19}', '\u{10a33}'), ('\u{10a60}', '\u{10a7c}'), ('\u{10a80}', '\u{10a9c}'),
-        ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}', '\u{10ae4}'), ('\u{10b00}', '\u{10b35}'),
-        ('\u{10b40}', '\u{10b55}'), ('\u{10b60}', '\u{10b72}'), ('\u{10b80}', '\u{10b91}'),
-        ('\u{10c00}', '\u{10c48}'), ('\u{11003}', '\u{11037}'), ('\u{11083}', '\u{110af}'),
-        ('\u{110d0}', '\u{110e8}'), ('\u{11103}', '\u{11126}'), ('\u{11150}', '\u{11172}'),
-        ('\u{11176}', '\u{11176}'), ('\u{11183}', '\u{111b2}'), ('\u{111c1}', '\u{111c4}'),
-        ('\u{111da}', '\u{111da}'), ('\u{11200}', '\u{11211}'), ('\u{11213}', '\u{1122b}'),
-        ('\u{112b0}', '\u{112de}'), ('\u{11305}', '\u{1130c}'), ('\u{1130f}', '\u{11310}'),
-        ('\u{11313}', '\u{11328}'), ('\u{1132a}', '\u{11330}'), ('\u{11332}', '\u{11333}'),
-        ('\u{11335}', '\u{11339}'), ('\u{1133d}', '\u{1133d}'), ('\u{1135d}', '\u{11361}'),
-        ('\u{11480}', '\u{114af}'), ('\u{114c4}', '\u{114c5}'), ('\u{114c7}', '\u{114c7}'),
-        ('\u{11580}', '\u{115ae}'), ('\u{11600}', '\u{1162f}'), ('\u{11644}', '\u{11644}'),
-        ('\u{11680}', '\u{116aa}'), ('\u{118a0}', '\u{118df}'), ('\u{118ff}', '\u{118ff}'),
-        ('\u{11ac0}', '\u{11af8}'), ('\u{12000}', '\u{12398}'), ('\u{13000}', '\u{1342e}'),
-        ('\u{16800}', '\u{16a38}'), ('\u{16a40}', '\u{16a5e}'), ('\u{16ad0}', '\u{16aed}'),
-        ('\u{16b00}', '\u{16b2f}'), ('\u{16b40}', '\u{16b43}'), ('\u{16b63}', '\u{16b77}'),
-        ('\u{16b7d}', '\u{16b8f}'), ('\u{16f00}', '\u{16f44}'), ('\u{16f50}', '\u{16f50}'),
-        ('\u{16f93}', '\u{16f9f}'), ('\u{1b000}', '\u{1b001}'), ('\u{1bc00}', '\u{1bc6a}'),
-        ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'), ('\u{1bc90}', '\u{1bc99}'),
-        ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'), ('\u{1d49e}', '\u{1d49f}'),
-        ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'),
-        ('\u{1d4ae}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'),
-        ('\u{1d4c5}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'), ('\u{1d50d}', '\u{1d514}'),
-        ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'), ('\u{1d53b}', '\u{1d53e}'),
-        ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'),
-        ('\u{1d552}', '\u{1d6a5}'), ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6c2}', '\u{1d6da}'),
-        ('\u{1d6dc}', '\u{1d6fa}'), ('\u{1d6fc}', '\u{1d714}'), ('\u{1d716}', '\u{1d734}'),
-        ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d76e}'), ('\u{1d770}', '\u{1d788}'),
-        ('\u{1d78a}', '\u{1d7a8}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}', '\u{1d7cb}'),
-        ('\u{1e800}', '\u{1e8c4}'), ('\u{1ee00}', '\u{1ee03}'), ('\u{1ee05}', '\u{1ee1f}'),
-        ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'), ('\u{1ee27}', '\u{1ee27}'),
-        ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'),
-        ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'),
-        ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}', '\u{1ee4b}'), ('\u{1ee4d}', '\u{1ee4f}'),
-        ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'), ('\u{1ee57}', '\u{1ee57}'),
-        ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'),
-        ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'),
-        ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}', '\u{1ee72}'), ('\u{1ee74}', '\u{1ee77}'),
-        ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'), ('\u{1ee80}', '\u{1ee89}'),
-        ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'),
-        ('\u{1eeab}', '\u{1eebb}'), ('\u{20000}', '\u{20000}'), ('\u{2a6d6}', '\u{2a6d6}'),
-        ('\u{2a700}', '\u{2a700}'), ('\u{2b734}', '\u{2b734}'), ('\u{2b740}', '\u{2b740}'),
-        ('\u{2b81d}', '\u{2b81d}'), ('\u{2f800}', '\u{2fa1d}')
+        '\u{31ba}'), ('\u{31f0}', '\u{31ff}'), ('\u{3400}', '\u{4db5}'), ('\u{4e00}', '\u{9fcc}'),
+        ('\u{a000}', '\u{a48c}'), ('\u{a4d0}', '\u{a4fd}'), ('\u{a500}', '\u{a60c}'), ('\u{a610}',
+        '\u{a61f}'), ('\u{a62a}', '\u{a62b}'), ('\u{a640}', '\u{a66e}'), ('\u{a67f}', '\u{a69d}'),
+        ('\u{a6a0}', '\u{a6e5}'), ('\u{a717}', '\u{a71f}'), ('\u{a722}', '\u{a788}'), ('\u{a78b}',
+        '\u{a78e}'), ('\u{a790}', '\u{a7ad}'), ('\u{a7b0}', '\u{a7b1}'), ('\u{a7f7}', '\u{a801}'),
+        ('\u{a803}', '\u{a805}'), ('\u{a807}', '\u{a80a}'), ('\u{a80c}', '\u{a822}'), ('\u{a840}',
+        '\u{a873}'), ('\u{a882}', '\u{a8b3}'), ('\u{a8f2}', '\u{a8f7}'), ('\u{a8fb}', '\u{a8fb}'),
+        ('\u{a90a}', '\u{a925}'), ('\u{a930}', '\u{a946}'), ('\u{a960}', '\u{a97c}'), ('\u{a984}',
+        '\u{a9b2}'), ('\u{a9cf}', '\u{a9cf}'), ('\u{a9e0}', '\u{a9e4}'), ('\u{a9e6}', '\u{a9ef}'),
+        ('\u{a9fa}', '\u{a9fe}'), ('\u{aa00}', '\u{aa28}'), ('\u{aa40}', '\u{aa42}'), ('\u{aa44}',
+        '\u{aa4b}'), ('\u{aa60}', '\u{aa76}'), ('\u{aa7a}', '\u{aa7a}'), ('\u{aa7e}', '\u{aaaf}'),
+        ('\u{aab1}', '\u{aab1}'), ('\u{aab5}', '\u{aab6}'), ('\u{aab9}', '\u{aabd}'), ('\u{aac0}',
+        '\u{aac0}'), ('\u{aac2}', '\u{aac2}'), ('\u{aadb}', '\u{aadd}'), ('\u{aae0}', '\u{aaea}'),
+        ('\u{aaf2}', '\u{aaf4}'), ('\u{ab01}', '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'), ('\u{ab11}',
+        '\u{ab16}'), ('\u{ab20}', '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'), ('\u{ab30}', '\u{ab5a}'),
+        ('\u{ab5c}', '\u{ab5f}'), ('\u{ab64}', '\u{ab65}'), ('\u{abc0}', '\u{abe2}'), ('\u{ac00}',
+        '\u{d7a3}'), ('\u{d7b0}', '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'),
+        ('\u{fa70}', '\u{fad9}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'), ('\u{fb1d}',
+        '\u{fb1d}'), ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'), ('\u{fb38}', '\u{fb3c}'),
+        ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'), ('\u{fb46}',
+        '\u{fbb1}'), ('\u{fbd3}', '\u{fd3d}'), ('\u{fd50}', '\u{fd8f}'), ('\u{fd92}', '\u{fdc7}'),
+        ('\u{fdf0}', '\u{fdfb}'), ('\u{fe70}', '\u{fe74}'), ('\u{fe76}', '\u{fefc}'), ('\u{ff21}',
+        '\u{ff3a}'), ('\u{ff41}', '\u{ff5a}'), ('\u{ff66}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'),
+        ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'), ('\u{10000}',
+        '\u{1000b}'), ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'), ('\u{1003c}',
+        '\u{1003d}'), ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'), ('\u{10080}',
+        '\u{100fa}'), ('\u{10280}', '\u{1029c}'), ('\u{102a0}', '\u{102d0}'), ('\u{10300}',
+        '\u{1031f}'), ('\u{10330}', '\u{10340}'), ('\u{10342}', '\u{10349}'), ('\u{10350}',
+        '\u{10375}'), ('\u{10380}', '\u{1039d}'), ('\u{103a0}', '\u{103c3}'), ('\u{103c8}',
+        '\u{103cf}'), ('\u{10400}', '\u{1049d}'), ('\u{10500}', '\u{10527}'), ('\u{10530}',
+        '\u{10563}'), ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'), ('\u{10760}',
+        '\u{10767}'), ('\u{10800}', '\u{10805}'), ('\u{10808}', '\u{10808}'), ('\u{1080a}',
+        '\u{10835}'), ('\u{10837}', '\u{10838}'), ('\u{1083c}', '\u{1083c}'), ('\u{1083f}',
+        '\u{10855}'), ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'), ('\u{10900}',
+        '\u{10915}'), ('\u{10920}', '\u{10939}'), ('\u{10980}', '\u{109b7}'), ('\u{109be}',
+        '\u{109bf}'), ('\u{10a00}', '\u{10a00}'), ('\u{10a10}', '\u{10a13}'), ('\u{10a15}',
+        '\u{10a17}'), ('\u{10a19}', '\u{10a33}'), ('\u{10a60}', '\u{10a7c}'), ('\u{10a80}',
+        '\u{10a9c}'), ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}', '\u{10ae4}'), ('\u{10b00}',
+        '\u{10b35}'), ('\u{10b40}', '\u{10b55}'), ('\u{10b60}', '\u{10b72}'), ('\u{10b80}',
+        '\u{10b91}'), ('\u{10c00}', '\u{10c48}'), ('\u{11003}', '\u{11037}'), ('\u{11083}',
+        '\u{110af}'), ('\u{110d0}', '\u{110e8}'), ('\u{11103}', '\u{11126}'), ('\u{11150}',
+        '\u{11172}'), ('\u{11176}', '\u{11176}'), ('\u{11183}', '\u{111b2}'), ('\u{111c1}',
+        '\u{111c4}'), ('\u{111da}', '\u{111da}'), ('\u{11200}', '\u{11211}'), ('\u{11213}',
+        '\u{1122b}'), ('\u{112b0}', '\u{112de}'), ('\u{11305}', '\u{1130c}'), ('\u{1130f}',
+        '\u{11310}'), ('\u{11313}', '\u{11328}'), ('\u{1132a}', '\u{11330}'), ('\u{11332}',
+        '\u{11333}'), ('\u{11335}', '\u{11339}'), ('\u{1133d}', '\u{1133d}'), ('\u{1135d}',
+        '\u{11361}'), ('\u{11480}', '\u{114af}'), ('\u{114c4}', '\u{114c5}'), ('\u{114c7}',
+        '\u{114c7}'), ('\u{11580}', '\u{115ae}'), ('\u{11600}', '\u{1162f}'), ('\u{11644}',
+        '\u{11644}'), ('\u{11680}', '\u{116aa}'), ('\u{118a0}', '\u{118df}'), ('\u{118ff}',
+        '\u{118ff}'), ('\u{11ac0}', '\u{11af8}'), ('\u{12000}', '\u{12398}'), ('\u{13000}',
+        '\u{1342e}'), ('\u{16800}', '\u{16a38}'), ('\u{16a40}', '\u{16a5e}'), ('\u{16ad0}',
+        '\u{16aed}'), ('\u{16b00}', '\u{16b2f}'), ('\u{16b40}', '\u{16b43}'), ('\u{16b63}',
+        '\u{16b77}'), ('\u{16b7d}', '\u{16b8f}'), ('\u{16f00}', '\u{16f44}'), ('\u{16f50}',
+        '\u{16f50}'), ('\u{16f93}', '\u{16f9f}'), ('\u{1b000}', '\u{1b001}'), ('\u{1bc00}',
+        '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'), ('\u{1bc90}',
+        '\u{1bc99}'), ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'), ('\u{1d49e}',
+        '\u{1d49f}'), ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}',
+        '\u{1d4ac}'), ('\u{1d4ae}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}',
+        '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d505}'), ('\u{1d507}', '\u{1d50a}'), ('\u{1d50d}',
+        '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'), ('\u{1d53b}',
+        '\u{1d53e}'), ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}',
+        '\u{1d550}'), ('\u{1d552}', '\u{1d6a5}'), ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6c2}',
+        '\u{1d6da}'), ('\u{1d6dc}', '\u{1d6fa}'), ('\u{1d6fc}', '\u{1d714}'), ('\u{1d716}',
+        '\u{1d734}'), ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d76e}'), ('\u{1d770}',
+        '\u{1d788}'), ('\u{1d78a}', '\u{1d7a8}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}',
+        '\u{1d7cb}'), ('\u{1e800}', '\u{1e8c4}'), ('\u{1ee00}', '\u{1ee03}'), ('\u{1ee05}',
+        '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'), ('\u{1ee27}',
+        '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}',
+        '\u{1ee39}'), ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}',
+        '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}', '\u{1ee4b}'), ('\u{1ee4d}',
+        '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'), ('\u{1ee57}',
+        '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}',
+        '\u{1ee5d}'), ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}',
+        '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}', '\u{1ee72}'), ('\u{1ee74}',
+        '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'), ('\u{1ee80}',
+        '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}',
+        '\u{1eea9}'), ('\u{1eeab}', '\u{1eebb}'), ('\u{20000}', '\u{2a6d6}'), ('\u{2a700}',
+        '\u{2b734}'), ('\u{2b740}', '\u{2b81d}'), ('\u{2f800}', '\u{2fa1d}')
     ];
 
     pub static LC_table: &'static [(char, char)] = &[
@@ -896,72 +888,71 @@ pub mod general_category {
         '\u{2dd6}'), ('\u{2dd8}', '\u{2dde}'), ('\u{3006}', '\u{3006}'), ('\u{303c}', '\u{303c}'),
         ('\u{3041}', '\u{3096}'), ('\u{309f}', '\u{309f}'), ('\u{30a1}', '\u{30fa}'), ('\u{30ff}',
         '\u{30ff}'), ('\u{3105}', '\u{312d}'), ('\u{3131}', '\u{318e}'), ('\u{31a0}', '\u{31ba}'),
-        ('\u{31f0}', '\u{31ff}'), ('\u{3400}', '\u{3400}'), ('\u{4db5}', '\u{4db5}'), ('\u{4e00}',
-        '\u{4e00}'), ('\u{9fcc}', '\u{9fcc}'), ('\u{a000}', '\u{a014}'), ('\u{a016}', '\u{a48c}'),
-        ('\u{a4d0}', '\u{a4f7}'), ('\u{a500}', '\u{a60b}'), ('\u{a610}', '\u{a61f}'), ('\u{a62a}',
-        '\u{a62b}'), ('\u{a66e}', '\u{a66e}'), ('\u{a6a0}', '\u{a6e5}'), ('\u{a7f7}', '\u{a7f7}'),
-        ('\u{a7fb}', '\u{a801}'), ('\u{a803}', '\u{a805}'), ('\u{a807}', '\u{a80a}'), ('\u{a80c}',
-        '\u{a822}'), ('\u{a840}', '\u{a873}'), ('\u{a882}', '\u{a8b3}'), ('\u{a8f2}', '\u{a8f7}'),
-        ('\u{a8fb}', '\u{a8fb}'), ('\u{a90a}', '\u{a925}'), ('\u{a930}', '\u{a946}'), ('\u{a960}',
-        '\u{a97c}'), ('\u{a984}', '\u{a9b2}'), ('\u{a9e0}', '\u{a9e4}'), ('\u{a9e7}', '\u{a9ef}'),
-        ('\u{a9fa}', '\u{a9fe}'), ('\u{aa00}', '\u{aa28}'), ('\u{aa40}', '\u{aa42}'), ('\u{aa44}',
-        '\u{aa4b}'), ('\u{aa60}', '\u{aa6f}'), ('\u{aa71}', '\u{aa76}'), ('\u{aa7a}', '\u{aa7a}'),
-        ('\u{aa7e}', '\u{aaaf}'), ('\u{aab1}', '\u{aab1}'), ('\u{aab5}', '\u{aab6}'), ('\u{aab9}',
-        '\u{aabd}'), ('\u{aac0}', '\u{aac0}'), ('\u{aac2}', '\u{aac2}'), ('\u{aadb}', '\u{aadc}'),
-        ('\u{aae0}', '\u{aaea}'), ('\u{aaf2}', '\u{aaf2}'), ('\u{ab01}', '\u{ab06}'), ('\u{ab09}',
-        '\u{ab0e}'), ('\u{ab11}', '\u{ab16}'), ('\u{ab20}', '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'),
-        ('\u{abc0}', '\u{abe2}'), ('\u{ac00}', '\u{ac00}'), ('\u{d7a3}', '\u{d7a3}'), ('\u{d7b0}',
-        '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'), ('\u{fa70}', '\u{fad9}'),
-        ('\u{fb1d}', '\u{fb1d}'), ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'), ('\u{fb38}',
-        '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'),
-        ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fd3d}'), ('\u{fd50}', '\u{fd8f}'), ('\u{fd92}',
-        '\u{fdc7}'), ('\u{fdf0}', '\u{fdfb}'), ('\u{fe70}', '\u{fe74}'), ('\u{fe76}', '\u{fefc}'),
-        ('\u{ff66}', '\u{ff6f}'), ('\u{ff71}', '\u{ff9d}'), ('\u{ffa0}', '\u{ffbe}'), ('\u{ffc2}',
-        '\u{ffc7}'), ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'),
-        ('\u{10000}', '\u{1000b}'), ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'),
-        ('\u{1003c}', '\u{1003d}'), ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'),
-        ('\u{10080}', '\u{100fa}'), ('\u{10280}', '\u{1029c}'), ('\u{102a0}', '\u{102d0}'),
-        ('\u{10300}', '\u{1031f}'), ('\u{10330}', '\u{10340}'), ('\u{10342}', '\u{10349}'),
-        ('\u{10350}', '\u{10375}'), ('\u{10380}', '\u{1039d}'), ('\u{103a0}', '\u{103c3}'),
-        ('\u{103c8}', '\u{103cf}'), ('\u{10450}', '\u{1049d}'), ('\u{10500}', '\u{10527}'),
-        ('\u{10530}', '\u{10563}'), ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'),
-        ('\u{10760}', '\u{10767}'), ('\u{10800}', '\u{10805}'), ('\u{10808}', '\u{10808}'),
-        ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'), ('\u{1083c}', '\u{1083c}'),
-        ('\u{1083f}', '\u{10855}'), ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'),
-        ('\u{10900}', '\u{10915}'), ('\u{10920}', '\u{10939}'), ('\u{10980}', '\u{109b7}'),
-        ('\u{109be}', '\u{109bf}'), ('\u{10a00}', '\u{10a00}'), ('\u{10a10}', '\u{10a13}'),
-        ('\u{10a15}', '\u{10a17}'), ('\u{10a19}', '\u{10a33}'), ('\u{10a60}', '\u{10a7c}'),
-        ('\u{10a80}', '\u{10a9c}'), ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}', '\u{10ae4}'),
-        ('\u{10b00}', '\u{10b35}'), ('\u{10b40}', '\u{10b55}'), ('\u{10b60}', '\u{10b72}'),
-        ('\u{10b80}', '\u{10b91}'), ('\u{10c00}', '\u{10c48}'), ('\u{11003}', '\u{11037}'),
-        ('\u{11083}', '\u{110af}'), ('\u{110d0}', '\u{110e8}'), ('\u{11103}', '\u{11126}'),
-        ('\u{11150}', '\u{11172}'), ('\u{11176}', '\u{11176}'), ('\u{11183}', '\u{111b2}'),
-        ('\u{111c1}', '\u{111c4}'), ('\u{111da}', '\u{111da}'), ('\u{11200}', '\u{11211}'),
-        ('\u{11213}', '\u{1122b}'), ('\u{112b0}', '\u{112de}'), ('\u{11305}', '\u{1130c}'),
-        ('\u{1130f}', '\u{11310}'), ('\u{11313}', '\u{11328}'), ('\u{1132a}', '\u{11330}'),
-        ('\u{11332}', '\u{11333}'), ('\u{11335}', '\u{11339}'), ('\u{1133d}', '\u{1133d}'),
-        ('\u{1135d}', '\u{11361}'), ('\u{11480}', '\u{114af}'), ('\u{114c4}', '\u{114c5}'),
-        ('\u{114c7}', '\u{114c7}'), ('\u{11580}', '\u{115ae}'), ('\u{11600}', '\u{1162f}'),
-        ('\u{11644}', '\u{11644}'), ('\u{11680}', '\u{116aa}'), ('\u{118ff}', '\u{118ff}'),
-        ('\u{11ac0}', '\u{11af8}'), ('\u{12000}', '\u{12398}'), ('\u{13000}', '\u{1342e}'),
-        ('\u{16800}', '\u{16a38}'), ('\u{16a40}', '\u{16a5e}'), ('\u{16ad0}', '\u{16aed}'),
-        ('\u{16b00}', '\u{16b2f}'), ('\u{16b63}', '\u{16b77}'), ('\u{16b7d}', '\u{16b8f}'),
-        ('\u{16f00}', '\u{16f44}'), ('\u{16f50}', '\u{16f50}'), ('\u{1b000}', '\u{1b001}'),
-        ('\u{1bc00}', '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'),
-        ('\u{1bc90}', '\u{1bc99}'), ('\u{1e800}', '\u{1e8c4}'), ('\u{1ee00}', '\u{1ee03}'),
-        ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'),
-        ('\u{1ee27}', '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'),
-        ('\u{1ee39}', '\u{1ee39}'), ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'),
-        ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}', '\u{1ee4b}'),
-        ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'),
-        ('\u{1ee57}', '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'),
-        ('\u{1ee5d}', '\u{1ee5d}'), ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'),
-        ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}', '\u{1ee72}'),
-        ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'),
-        ('\u{1ee80}', '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'),
-        ('\u{1eea5}', '\u{1eea9}'), ('\u{1eeab}', '\u{1eebb}'), ('\u{20000}', '\u{20000}'),
-        ('\u{2a6d6}', '\u{2a6d6}'), ('\u{2a700}', '\u{2a700}'), ('\u{2b734}', '\u{2b734}'),
-        ('\u{2b740}', '\u{2b740}'), ('\u{2b81d}', '\u{2b81d}'), ('\u{2f800}', '\u{2fa1d}')
+        ('\u{31f0}', '\u{31ff}'), ('\u{3400}', '\u{4db5}'), ('\u{4e00}', '\u{9fcc}'), ('\u{a000}',
+        '\u{a014}'), ('\u{a016}', '\u{a48c}'), ('\u{a4d0}', '\u{a4f7}'), ('\u{a500}', '\u{a60b}'),
+        ('\u{a610}', '\u{a61f}'), ('\u{a62a}', '\u{a62b}'), ('\u{a66e}', '\u{a66e}'), ('\u{a6a0}',
+        '\u{a6e5}'), ('\u{a7f7}', '\u{a7f7}'), ('\u{a7fb}', '\u{a801}'), ('\u{a803}', '\u{a805}'),
+        ('\u{a807}', '\u{a80a}'), ('\u{a80c}', '\u{a822}'), ('\u{a840}', '\u{a873}'), ('\u{a882}',
+        '\u{a8b3}'), ('\u{a8f2}', '\u{a8f7}'), ('\u{a8fb}', '\u{a8fb}'), ('\u{a90a}', '\u{a925}'),
+        ('\u{a930}', '\u{a946}'), ('\u{a960}', '\u{a97c}'), ('\u{a984}', '\u{a9b2}'), ('\u{a9e0}',
+        '\u{a9e4}'), ('\u{a9e7}', '\u{a9ef}'), ('\u{a9fa}', '\u{a9fe}'), ('\u{aa00}', '\u{aa28}'),
+        ('\u{aa40}', '\u{aa42}'), ('\u{aa44}', '\u{aa4b}'), ('\u{aa60}', '\u{aa6f}'), ('\u{aa71}',
+        '\u{aa76}'), ('\u{aa7a}', '\u{aa7a}'), ('\u{aa7e}', '\u{aaaf}'), ('\u{aab1}', '\u{aab1}'),
+        ('\u{aab5}', '\u{aab6}'), ('\u{aab9}', '\u{aabd}'), ('\u{aac0}', '\u{aac0}'), ('\u{aac2}',
+        '\u{aac2}'), ('\u{aadb}', '\u{aadc}'), ('\u{aae0}', '\u{aaea}'), ('\u{aaf2}', '\u{aaf2}'),
+        ('\u{ab01}', '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'), ('\u{ab11}', '\u{ab16}'), ('\u{ab20}',
+        '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'), ('\u{abc0}', '\u{abe2}'), ('\u{ac00}', '\u{d7a3}'),
+        ('\u{d7b0}', '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'), ('\u{fa70}',
+        '\u{fad9}'), ('\u{fb1d}', '\u{fb1d}'), ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'),
+        ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}',
+        '\u{fb44}'), ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fd3d}'), ('\u{fd50}', '\u{fd8f}'),
+        ('\u{fd92}', '\u{fdc7}'), ('\u{fdf0}', '\u{fdfb}'), ('\u{fe70}', '\u{fe74}'), ('\u{fe76}',
+        '\u{fefc}'), ('\u{ff66}', '\u{ff6f}'), ('\u{ff71}', '\u{ff9d}'), ('\u{ffa0}', '\u{ffbe}'),
+        ('\u{ffc2}', '\u{ffc7}'), ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}',
+        '\u{ffdc}'), ('\u{10000}', '\u{1000b}'), ('\u{1000d}', '\u{10026}'), ('\u{10028}',
+        '\u{1003a}'), ('\u{1003c}', '\u{1003d}'), ('\u{1003f}', '\u{1004d}'), ('\u{10050}',
+        '\u{1005d}'), ('\u{10080}', '\u{100fa}'), ('\u{10280}', '\u{1029c}'), ('\u{102a0}',
+        '\u{102d0}'), ('\u{10300}', '\u{1031f}'), ('\u{10330}', '\u{10340}'), ('\u{10342}',
+        '\u{10349}'), ('\u{10350}', '\u{10375}'), ('\u{10380}', '\u{1039d}'), ('\u{103a0}',
+        '\u{103c3}'), ('\u{103c8}', '\u{103cf}'), ('\u{10450}', '\u{1049d}'), ('\u{10500}',
+        '\u{10527}'), ('\u{10530}', '\u{10563}'), ('\u{10600}', '\u{10736}'), ('\u{10740}',
+        '\u{10755}'), ('\u{10760}', '\u{10767}'), ('\u{10800}', '\u{10805}'), ('\u{10808}',
+        '\u{10808}'), ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'), ('\u{1083c}',
+        '\u{1083c}'), ('\u{1083f}', '\u{10855}'), ('\u{10860}', '\u{10876}'), ('\u{10880}',
+        '\u{1089e}'), ('\u{10900}', '\u{10915}'), ('\u{10920}', '\u{10939}'), ('\u{10980}',
+        '\u{109b7}'), ('\u{109be}', '\u{109bf}'), ('\u{10a00}', '\u{10a00}'), ('\u{10a10}',
+        '\u{10a13}'), ('\u{10a15}', '\u{10a17}'), ('\u{10a19}', '\u{10a33}'), ('\u{10a60}',
+        '\u{10a7c}'), ('\u{10a80}', '\u{10a9c}'), ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}',
+        '\u{10ae4}'), ('\u{10b00}', '\u{10b35}'), ('\u{10b40}', '\u{10b55}'), ('\u{10b60}',
+        '\u{10b72}'), ('\u{10b80}', '\u{10b91}'), ('\u{10c00}', '\u{10c48}'), ('\u{11003}',
+        '\u{11037}'), ('\u{11083}', '\u{110af}'), ('\u{110d0}', '\u{110e8}'), ('\u{11103}',
+        '\u{11126}'), ('\u{11150}', '\u{11172}'), ('\u{11176}', '\u{11176}'), ('\u{11183}',
+        '\u{111b2}'), ('\u{111c1}', '\u{111c4}'), ('\u{111da}', '\u{111da}'), ('\u{11200}',
+        '\u{11211}'), ('\u{11213}', '\u{1122b}'), ('\u{112b0}', '\u{112de}'), ('\u{11305}',
+        '\u{1130c}'), ('\u{1130f}', '\u{11310}'), ('\u{11313}', '\u{11328}'), ('\u{1132a}',
+        '\u{11330}'), ('\u{11332}', '\u{11333}'), ('\u{11335}', '\u{11339}'), ('\u{1133d}',
+        '\u{1133d}'), ('\u{1135d}', '\u{11361}'), ('\u{11480}', '\u{114af}'), ('\u{114c4}',
+        '\u{114c5}'), ('\u{114c7}', '\u{114c7}'), ('\u{11580}', '\u{115ae}'), ('\u{11600}',
+        '\u{1162f}'), ('\u{11644}', '\u{11644}'), ('\u{11680}', '\u{116aa}'), ('\u{118ff}',
+        '\u{118ff}'), ('\u{11ac0}', '\u{11af8}'), ('\u{12000}', '\u{12398}'), ('\u{13000}',
+        '\u{1342e}'), ('\u{16800}', '\u{16a38}'), ('\u{16a40}', '\u{16a5e}'), ('\u{16ad0}',
+        '\u{16aed}'), ('\u{16b00}', '\u{16b2f}'), ('\u{16b63}', '\u{16b77}'), ('\u{16b7d}',
+        '\u{16b8f}'), ('\u{16f00}', '\u{16f44}'), ('\u{16f50}', '\u{16f50}'), ('\u{1b000}',
+        '\u{1b001}'), ('\u{1bc00}', '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}',
+        '\u{1bc88}'), ('\u{1bc90}', '\u{1bc99}'), ('\u{1e800}', '\u{1e8c4}'), ('\u{1ee00}',
+        '\u{1ee03}'), ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}',
+        '\u{1ee24}'), ('\u{1ee27}', '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}',
+        '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'), ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}',
+        '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}',
+        '\u{1ee4b}'), ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}',
+        '\u{1ee54}'), ('\u{1ee57}', '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}',
+        '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'), ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}',
+        '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}',
+        '\u{1ee72}'), ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}',
+        '\u{1ee7e}'), ('\u{1ee80}', '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}',
+        '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'), ('\u{1eeab}', '\u{1eebb}'), ('\u{20000}',
+        '\u{2a6d6}'), ('\u{2a700}', '\u{2b734}'), ('\u{2b740}', '\u{2b81d}'), ('\u{2f800}',
+        '\u{2fa1d}')
     ];
 
     pub static Lt_table: &'static [(char, char)] = &[
@@ -1224,6 +1215,10 @@ pub mod general_category {
         ('\u{1e8d0}', '\u{1e8d6}'), ('\u{e0100}', '\u{e01ef}')
     ];
 
+    pub fn M(c: char) -> bool {
+        super::bsearch_range_table(c, M_table)
+    }
+
     pub static Mc_table: &'static [(char, char)] = &[
         ('\u{903}', '\u{903}'), ('\u{93b}', '\u{93b}'), ('\u{93e}', '\u{940}'), ('\u{949}',
         '\u{94c}'), ('\u{94e}', '\u{94f}'), ('\u{982}', '\u{983}'), ('\u{9be}', '\u{9c0}'),
@@ -3953,6 +3948,1165 @@ pub mod property {
 
 }
 
+pub mod emoji {
+    pub static Emoji_table: &'static [(char, char)] = &[
+        ('\u{23}', '\u{23}'), ('\u{2a}', '\u{2a}'), ('\u{30}', '\u{39}'), ('\u{a9}', '\u{a9}'),
+        ('\u{ae}', '\u{ae}'), ('\u{203c}', '\u{203c}'), ('\u{2049}', '\u{2049}'), ('\u{2122}',
+        '\u{2122}'), ('\u{2139}', '\u{2139}'), ('\u{2194}', '\u{2199}'), ('\u{21a9}', '\u{21aa}'),
+        ('\u{231a}', '\u{231b}'), ('\u{2328}', '\u{2328}'), ('\u{23cf}', '\u{23cf}'), ('\u{23e9}',
+        '\u{23f3}'), ('\u{23f8}', '\u{23fa}'), ('\u{24c2}', '\u{24c2}'), ('\u{25aa}', '\u{25ab}'),
+        ('\u{25b6}', '\u{25b6}'), ('\u{25c0}', '\u{25c0}'), ('\u{25fb}', '\u{25fe}'), ('\u{2600}',
+        '\u{2604}'), ('\u{260e}', '\u{260e}'), ('\u{2611}', '\u{2611}'), ('\u{2614}', '\u{2615}'),
+        ('\u{2618}', '\u{2618}'), ('\u{261d}', '\u{261d}'), ('\u{2620}', '\u{2620}'), ('\u{2622}',
+        '\u{2623}'), ('\u{2626}', '\u{2626}'), ('\u{262a}', '\u{262a}'), ('\u{262e}', '\u{262f}'),
+        ('\u{2638}', '\u{263a}'), ('\u{2648}', '\u{2653}'), ('\u{2660}', '\u{2660}'), ('\u{2663}',
+        '\u{2663}'), ('\u{2665}', '\u{2666}'), ('\u{2668}', '\u{2668}'), ('\u{267b}', '\u{267b}'),
+        ('\u{267f}', '\u{267f}'), ('\u{2692}', '\u{2694}'), ('\u{2696}', '\u{2697}'), ('\u{2699}',
+        '\u{2699}'), ('\u{269b}', '\u{269c}'), ('\u{26a0}', '\u{26a1}'), ('\u{26a7}', '\u{26a7}'),
+        ('\u{26aa}', '\u{26ab}'), ('\u{26b0}', '\u{26b1}'), ('\u{26bd}', '\u{26be}'), ('\u{26c4}',
+        '\u{26c5}'), ('\u{26c8}', '\u{26c8}'), ('\u{26ce}', '\u{26cf}'), ('\u{26d1}', '\u{26d1}'),
+        ('\u{26d3}', '\u{26d4}'), ('\u{26e9}', '\u{26ea}'), ('\u{26f0}', '\u{26f5}'), ('\u{26f7}',
+        '\u{26fa}'), ('\u{26fd}', '\u{26fd}'), ('\u{2702}', '\u{2702}'), ('\u{2705}', '\u{2705}'),
+        ('\u{2708}', '\u{270d}'), ('\u{270f}', '\u{270f}'), ('\u{2712}', '\u{2712}'), ('\u{2714}',
+        '\u{2714}'), ('\u{2716}', '\u{2716}'), ('\u{271d}', '\u{271d}'), ('\u{2721}', '\u{2721}'),
+        ('\u{2728}', '\u{2728}'), ('\u{2733}', '\u{2734}'), ('\u{2744}', '\u{2744}'), ('\u{2747}',
+        '\u{2747}'), ('\u{274c}', '\u{274c}'), ('\u{274e}', '\u{274e}'), ('\u{2753}', '\u{2755}'),
+        ('\u{2757}', '\u{2757}'), ('\u{2763}', '\u{2764}'), ('\u{2795}', '\u{2797}'), ('\u{27a1}',
+        '\u{27a1}'), ('\u{27b0}', '\u{27b0}'), ('\u{27bf}', '\u{27bf}'), ('\u{2934}', '\u{2935}'),
+        ('\u{2b05}', '\u{2b07}'), ('\u{2b1b}', '\u{2b1c}'), ('\u{2b50}', '\u{2b50}'), ('\u{2b55}',
+        '\u{2b55}'), ('\u{3030}', '\u{3030}'), ('\u{303d}', '\u{303d}'), ('\u{3297}', '\u{3297}'),
+        ('\u{3299}', '\u{3299}'), ('\u{1f004}', '\u{1f004}'), ('\u{1f0cf}', '\u{1f0cf}'),
+        ('\u{1f170}', '\u{1f171}'), ('\u{1f17e}', '\u{1f17f}'), ('\u{1f18e}', '\u{1f18e}'),
+        ('\u{1f191}', '\u{1f19a}'), ('\u{1f1e6}', '\u{1f1ff}'), ('\u{1f201}', '\u{1f202}'),
+        ('\u{1f21a}', '\u{1f21a}'), ('\u{1f22f}', '\u{1f22f}'), ('\u{1f232}', '\u{1f23a}'),
+        ('\u{1f250}', '\u{1f251}'), ('\u{1f300}', '\u{1f321}'), ('\u{1f324}', '\u{1f32c}'),
+        ('\u{1f330}', '\u{1f37d}'), ('\u{1f380}', '\u{1f393}'), ('\u{1f396}', '\u{1f397}'),
+        ('\u{1f399}', '\u{1f39b}'), ('\u{1f39e}', '\u{1f3ce}'), ('\u{1f3d4}', '\u{1f3f0}'),
+        ('\u{1f3f3}', '\u{1f3f5}'), ('\u{1f3f7}', '\u{1f3f7}'), ('\u{1f400}', '\u{1f4fd}'),
+        ('\u{1f500}', '\u{1f53d}'), ('\u{1f549}', '\u{1f54a}'), ('\u{1f550}', '\u{1f567}'),
+        ('\u{1f56f}', '\u{1f570}'), ('\u{1f573}', '\u{1f579}'), ('\u{1f587}', '\u{1f587}'),
+        ('\u{1f58a}', '\u{1f58d}'), ('\u{1f590}', '\u{1f590}'), ('\u{1f595}', '\u{1f596}'),
+        ('\u{1f5a5}', '\u{1f5a5}'), ('\u{1f5a8}', '\u{1f5a8}'), ('\u{1f5b1}', '\u{1f5b2}'),
+        ('\u{1f5bc}', '\u{1f5bc}'), ('\u{1f5c2}', '\u{1f5c4}'), ('\u{1f5d1}', '\u{1f5d3}'),
+        ('\u{1f5dc}', '\u{1f5de}'), ('\u{1f5e1}', '\u{1f5e1}'), ('\u{1f5e3}', '\u{1f5e3}'),
+        ('\u{1f5e8}', '\u{1f5e8}'), ('\u{1f5ef}', '\u{1f5ef}'), ('\u{1f5f3}', '\u{1f5f3}'),
+        ('\u{1f5fa}', '\u{1f642}'), ('\u{1f645}', '\u{1f64f}'), ('\u{1f680}', '\u{1f6c5}'),
+        ('\u{1f6cb}', '\u{1f6cf}'), ('\u{1f6e0}', '\u{1f6e5}'), ('\u{1f6e9}', '\u{1f6e9}'),
+        ('\u{1f6eb}', '\u{1f6ec}'), ('\u{1f6f0}', '\u{1f6f0}'), ('\u{1f6f3}', '\u{1f6f3}')
+    ];
+
+    pub fn Emoji(c: char) -> bool {
+        super::bsearch_range_table(c, Emoji_table)
+    }
+
+    pub static Emoji_Presentation_table: &'static [(char, char)] = &[
+        ('\u{231a}', '\u{231b}'), ('\u{23e9}', '\u{23ec}'), ('\u{23f0}', '\u{23f0}'), ('\u{23f3}',
+        '\u{23f3}'), ('\u{25fd}', '\u{25fe}'), ('\u{2614}', '\u{2615}'), ('\u{2648}', '\u{2653}'),
+        ('\u{267f}', '\u{267f}'), ('\u{2693}', '\u{2693}'), ('\u{26a1}', '\u{26a1}'), ('\u{26aa}',
+        '\u{26ab}'), ('\u{26bd}', '\u{26be}'), ('\u{26c4}', '\u{26c5}'), ('\u{26ce}', '\u{26ce}'),
+        ('\u{26d4}', '\u{26d4}'), ('\u{26ea}', '\u{26ea}'), ('\u{26f2}', '\u{26f3}'), ('\u{26f5}',
+        '\u{26f5}'), ('\u{26fa}', '\u{26fa}'), ('\u{26fd}', '\u{26fd}'), ('\u{2705}', '\u{2705}'),
+        ('\u{270a}', '\u{270b}'), ('\u{2728}', '\u{2728}'), ('\u{274c}', '\u{274c}'), ('\u{274e}',
+        '\u{274e}'), ('\u{2753}', '\u{2755}'), ('\u{2757}', '\u{2757}'), ('\u{2795}', '\u{2797}'),
+        ('\u{27b0}', '\u{27b0}'), ('\u{27bf}', '\u{27bf}'), ('\u{2b1b}', '\u{2b1c}'), ('\u{2b50}',
+        '\u{2b50}'), ('\u{2b55}', '\u{2b55}'), ('\u{1f004}', '\u{1f004}'), ('\u{1f0cf}',
+        '\u{1f0cf}'), ('\u{1f18e}', '\u{1f18e}'), ('\u{1f191}', '\u{1f19a}'), ('\u{1f1e6}',
+        '\u{1f1ff}'), ('\u{1f201}', '\u{1f201}'), ('\u{1f21a}', '\u{1f21a}'), ('\u{1f22f}',
+        '\u{1f22f}'), ('\u{1f232}', '\u{1f236}'), ('\u{1f238}', '\u{1f23a}'), ('\u{1f250}',
+        '\u{1f251}'), ('\u{1f300}', '\u{1f320}'), ('\u{1f330}', '\u{1f335}'), ('\u{1f337}',
+        '\u{1f37c}'), ('\u{1f380}', '\u{1f393}'), ('\u{1f3a0}', '\u{1f3ca}'), ('\u{1f3e0}',
+        '\u{1f3f0}'), ('\u{1f3f4}', '\u{1f3f4}'), ('\u{1f400}', '\u{1f43e}'), ('\u{1f440}',
+        '\u{1f440}'), ('\u{1f442}', '\u{1f4fc}'), ('\u{1f500}', '\u{1f53d}'), ('\u{1f550}',
+        '\u{1f567}'), ('\u{1f595}', '\u{1f596}'), ('\u{1f5fb}', '\u{1f642}'), ('\u{1f645}',
+        '\u{1f64f}'), ('\u{1f680}', '\u{1f6c5}'), ('\u{1f6cc}', '\u{1f6cc}'), ('\u{1f6eb}',
+        '\u{1f6ec}')
+    ];
+
+    pub fn Emoji_Presentation(c: char) -> bool {
+        super::bsearch_range_table(c, Emoji_Presentation_table)
+    }
+
+}
+
+pub mod category {
+    use core::kinds::Copy;
+    use core::slice::SlicePrelude;
+    use core::slice;
+    pub use self::GeneralCategory::*;
+
+    /// The Unicode General_Category of a character
+    #[deriving(Clone, PartialEq, Eq, Show)]
+    pub enum GeneralCategory {
+        /// Uppercase_Letter
+        Lu,
+        /// Lowercase_Letter
+        Ll,
+        /// Titlecase_Letter
+        Lt,
+        /// Modifier_Letter
+        Lm,
+        /// Other_Letter
+        Lo,
+        /// Nonspacing_Mark
+        Mn,
+        /// Spacing_Mark
+        Mc,
+        /// Enclosing_Mark
+        Me,
+        /// Decimal_Number
+        Nd,
+        /// Letter_Number
+        Nl,
+        /// Other_Number
+        No,
+        /// Connector_Punctuation
+        Pc,
+        /// Dash_Punctuation
+        Pd,
+        /// Open_Punctuation
+        Ps,
+        /// Close_Punctuation
+        Pe,
+        /// Initial_Punctuation
+        Pi,
+        /// Final_Punctuation
+        Pf,
+        /// Other_Punctuation
+        Po,
+        /// Math_Symbol
+        Sm,
+        /// Currency_Symbol
+        Sc,
+        /// Modifier_Symbol
+        Sk,
+        /// Other_Symbol
+        So,
+        /// Space_Separator
+        Zs,
+        /// Line_Separator
+        Zl,
+        /// Paragraph_Separator
+        Zp,
+        /// Control
+        Cc,
+        /// Format
+        Cf,
+        /// Surrogate
+        Cs,
+        /// Private_Use
+        Co,
+        /// Unassigned
+        Cn,
+    }
+
+    impl Copy for GeneralCategory {}
+
+    fn bsearch_range_value_table(c: char, r: &'static [(char, char, GeneralCategory)])
+                                 -> GeneralCategory {
+        use core::cmp::Ordering::{Equal, Less, Greater};
+        match r.binary_search(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(idx) => {
+                let (_, _, cat) = r[idx];
+                cat
+            }
+            slice::BinarySearchResult::NotFound(_) => Cn
+        }
+    }
+
+    pub fn general_category(c: char) -> GeneralCategory {
+        bsearch_range_value_table(c, general_category_table)
+    }
+
+    static general_category_table: &'static [(char, char, GeneralCategory)] = &[
+        ('\u{0}', '\u{1f}', Cc), ('\u{20}', '\u{20}', Zs), ('\u{21}', '\u{23}', Po), ('\u{24}',
+        '\u{24}', Sc), ('\u{25}', '\u{27}', Po), ('\u{28}', '\u{28}', Ps), ('\u{29}', '\u{29}', Pe),
+        ('\u{2a}', '\u{2a}', Po), ('\u{2b}', '\u{2b}', Sm), ('\u{2c}', '\u{2c}', Po), ('\u{2d}',
+        '\u{2d}', Pd), ('\u{2e}', '\u{2f}', Po), ('\u{30}', '\u{39}', Nd), ('\u{3a}', '\u{3b}', Po),
+        ('\u{3c}', '\u{3e}', Sm), ('\u{3f}', '\u{40}', Po), ('\u{41}', '\u{5a}', Lu), ('\u{5b}',
+        '\u{5b}', Ps), ('\u{5c}', '\u{5c}', Po), ('\u{5d}', '\u{5d}', Pe), ('\u{5e}', '\u{5e}', Sk),
+        ('\u{5f}', '\u{5f}', Pc), ('\u{60}', '\u{60}', Sk), ('\u{61}', '\u{7a}', Ll), ('\u{7b}',
+        '\u{7b}', Ps), ('\u{7c}', '\u{7c}', Sm), ('\u{7d}', '\u{7d}', Pe), ('\u{7e}', '\u{7e}', Sm),
+        ('\u{7f}', '\u{9f}', Cc), ('\u{a0}', '\u{a0}', Zs), ('\u{a1}', '\u{a1}', Po), ('\u{a2}',
+        '\u{a5}', Sc), ('\u{a6}', '\u{a6}', So), ('\u{a7}', '\u{a7}', Po), ('\u{a8}', '\u{a8}', Sk),
+        ('\u{a9}', '\u{a9}', So), ('\u{aa}', '\u{aa}', Lo), ('\u{ab}', '\u{ab}', Pi), ('\u{ac}',
+        '\u{ac}', Sm), ('\u{ad}', '\u{ad}', Cf), ('\u{ae}', '\u{ae}', So), ('\u{af}', '\u{af}', Sk),
+        ('\u{b0}', '\u{b0}', So), ('\u{b1}', '\u{b1}', Sm), ('\u{b2}', '\u{b3}', No), ('\u{b4}',
+        '\u{b4}', Sk), ('\u{b5}', '\u{b5}', Ll), ('\u{b6}', '\u{b7}', Po), ('\u{b8}', '\u{b8}', Sk),
+        ('\u{b9}', '\u{b9}', No), ('\u{ba}', '\u{ba}', Lo), ('\u{bb}', '\u{bb}', Pf), ('\u{bc}',
+        '\u{be}', No), ('\u{bf}', '\u{bf}', Po), ('\u{c0}', '\u{d6}', Lu), ('\u{d7}', '\u{d7}', Sm),
+        ('\u{d8}', '\u{de}', Lu), ('\u{df}', '\u{f6}', Ll), ('\u{f7}', '\u{f7}', Sm), ('\u{f8}',
+        '\u{ff}', Ll), ('\u{100}', '\u{100}', Lu), ('\u{101}', '\u{101}', Ll), ('\u{102}',
+        '\u{102}', Lu), ('\u{103}', '\u{103}', Ll), ('\u{104}', '\u{104}', Lu), ('\u{105}',
+        '\u{105}', Ll), ('\u{106}', '\u{106}', Lu), ('\u{107}', '\u{107}', Ll), ('\u{108}',
+        '\u{108}', Lu), ('\u{109}', '\u{109}', Ll), ('\u{10a}', '\u{10a}', Lu), ('\u{10b}',
+        '\u{10b}', Ll), ('\u{10c}', '\u{10c}', Lu), ('\u{10d}', '\u{10d}', Ll), ('\u{10e}',
+        '\u{10e}', Lu), ('\u{10f}', '\u{10f}', Ll), ('\u{110}', '\u{110}', Lu), ('\u{111}',
+        '\u{111}', Ll), ('\u{112}', '\u{112}', Lu), ('\u{113}', '\u{113}', Ll), ('\u{114}',
+        '\u{114}', Lu), ('\u{115}', '\u{115}', Ll), ('\u{116}', '\u{116}', Lu), ('\u{117}',
+        '\u{117}', Ll), ('\u{118}', '\u{118}', Lu), ('\u{119}', '\u{119}', Ll), ('\u{11a}',
+        '\u{11a}', Lu), ('\u{11b}', '\u{11b}', Ll), ('\u{11c}', '\u{11c}', Lu), ('\u{11d}',
+        '\u{11d}', Ll), ('\u{11e}', '\u{11e}', Lu), ('\u{11f}', '\u{11f}', Ll), ('\u{120}',
+        '\u{120}', Lu), ('\u{121}', '\u{121}', Ll), ('\u{122}', '\u{122}', Lu), ('\u{123}',
+        '\u{123}', Ll), ('\u{124}', '\u{124}', Lu), ('\u{125}', '\u{125}', Ll), ('\u{126}',
+        '\u{126}', Lu), ('\u{127}', '\u{127}', Ll), ('\u{128}', '\u{128}', Lu), ('\u{129}',
+        '\u{129}', Ll), ('\u{12a}', '\u{12a}', Lu), ('\u{12b}', '\u{12b}', Ll), ('\u{12c}',
+        '\u{12c}', Lu), ('\u{12d}', '\u{12d}', Ll), ('\u{12e}', '\u{12e}', Lu), ('\u{12f}',
+        '\u{12f}', Ll), ('\u{130}', '\u{130}', Lu), ('\u{131}', '\u{131}', Ll), ('\u{132}',
+        '\u{132}', Lu), ('\u{133}', '\u{133}', Ll), ('\u{134}', '\u{134}', Lu), ('\u{135}',
+        '\u{135}', Ll), ('\u{136}', '\u{136}', Lu), ('\u{137}', '\u{138}', Ll), ('\u{139}',
+        '\u{139}', Lu), ('\u{13a}', '\u{13a}', Ll), ('\u{13b}', '\u{13b}', Lu), ('\u{13c}',
+        '\u{13c}', Ll), ('\u{13d}', '\u{13d}', Lu), ('\u{13e}', '\u{13e}', Ll), ('\u{13f}',
+        '\u{13f}', Lu), ('\u{140}', '\u{140}', Ll), ('\u{141}', '\u{141}', Lu), ('\u{142}',
+        '\u{142}', Ll), ('\u{143}', '\u{143}', Lu), ('\u{144}', '\u{144}', Ll), ('\u{145}',
+        '\u{145}', Lu), ('\u{146}', '\u{146}', Ll), ('\u{147}', '\u{147}', Lu), ('\u{148}',
+        '\u{149}', Ll), ('\u{14a}', '\u{14a}', Lu), ('\u{14b}', '\u{14b}', Ll), ('\u{14c}',
+        '\u{14c}', Lu), ('\u{14d}', '\u{14d}', Ll), ('\u{14e}', '\u{14e}', Lu), ('\u{14f}',
+        '\u{14f}', Ll), ('\u{150}', '\u{150}', Lu), ('\u{151}', '\u{151}', Ll), ('\u{152}',
+        '\u{152}', Lu), ('\u{153}', '\u{153}', Ll), ('\u{154}', '\u{154}', Lu), ('\u{155}',
+        '\u{155}', Ll), ('\u{156}', '\u{156}', Lu), ('\u{157}', '\u{157}', Ll), ('\u{158}',
+        '\u{158}', Lu), ('\u{159}', '\u{159}', Ll), ('\u{15a}', '\u{15a}', Lu), ('\u{15b}',
+        '\u{15b}', Ll), ('\u{15c}', '\u{15c}', Lu), ('\u{15d}', '\u{15d}', Ll), ('\u{15e}',
+        '\u{15e}', Lu), ('\u{15f}', '\u{15f}', Ll), ('\u{160}', '\u{160}', Lu), ('\u{161}',
+        '\u{161}', Ll), ('\u{162}', '\u{162}', Lu), ('\u{163}', '\u{163}', Ll), ('\u{164}',
+        '\u{164}', Lu), ('\u{165}', '\u{165}', Ll), ('\u{166}', '\u{166}', Lu), ('\u{167}',
+        '\u{167}', Ll), ('\u{168}', '\u{168}', Lu), ('\u{169}', '\u{169}', Ll), ('\u{16a}',
+        '\u{16a}', Lu), ('\u{16b}', '\u{16b}', Ll), ('\u{16c}', '\u{16c}', Lu), ('\u{16d}',
+        '\u{16d}', Ll), ('\u{16e}', '\u{16e}', Lu), ('\u{16f}', '\u{16f}', Ll), ('\u{170}',
+        '\u{170}', Lu), ('\u{171}', '\u{171}', Ll), ('\u{172}', '\u{172}', Lu), ('\u{173}',
+        '\u{173}', Ll), ('\u{174}', '\u{174}', Lu), ('\u{175}', '\u{175}', Ll), ('\u{176}',
+        '\u{176}', Lu), ('\u{177}', '\u{177}', Ll), ('\u{178}', '\u{179}', Lu), ('\u{17a}',
+        '\u{17a}', Ll), ('\u{17b}', '\u{17b}', Lu), ('\u{17c}', '\u{17c}', Ll), ('\u{17d}',
+        '\u{17d}', Lu), ('\u{17e}', '\u{180}', Ll), ('\u{181}', '\u{182}', Lu), ('\u{183}',
+        '\u{183}', Ll), ('\u{184}', '\u{184}', Lu), ('\u{185}', '\u{185}', Ll), ('\u{186}',
+        '\u{187}', Lu), ('\u{188}', '\u{188}', Ll), ('\u{189}', '\u{18b}', Lu), ('\u{18c}',
+        '\u{18d}', Ll), ('\u{18e}', '\u{191}', Lu), ('\u{192}', '\u{192}', Ll), ('\u{193}',
+        '\u{194}', Lu), ('\u{195}', '\u{195}', Ll), ('\u{196}', '\u{198}', Lu), ('\u{199}',
+        '\u{19b}', Ll), ('\u{19c}', '\u{19d}', Lu), ('\u{19e}', '\u{19e}', Ll), ('\u{19f}',
+        '\u{1a0}', Lu), ('\u{1a1}', '\u{1a1}', Ll), ('\u{1a2}', '\u{1a2}', Lu), ('\u{1a3}',
+        '\u{1a3}', Ll), ('\u{1a4}', '\u{1a4}', Lu), ('\u{1a5}', '\u{1a5}', Ll), ('\u{1a6}',
+        '\u{1a7}', Lu), ('\u{1a8}', '\u{1a8}', Ll), ('\u{1a9}', '\u{1a9}', Lu), ('\u{1aa}',
+        '\u{1ab}', Ll), ('\u{1ac}', '\u{1ac}', Lu), ('\u{1ad}', '\u{1ad}', Ll), ('\u{1ae}',
+        '\u{1af}', Lu), ('\u{1b0}', '\u{1b0}', Ll), ('\u{1b1}', '\u{1b3}', Lu), ('\u{1b4}',
+        '\u{1b4}', Ll), ('\u{1b5}', '\u{1b5}', Lu), ('\u{1b6}', '\u{1b6}', Ll), ('\u{1b7}',
+        '\u{1b8}', Lu), ('\u{1b9}', '\u{1ba}', Ll), ('\u{1bb}', '\u{1bb}', Lo), ('\u{1bc}',
+        '\u{1bc}', Lu), ('\u{1bd}', '\u{1bf}', Ll), ('\u{1c0}', '\u{1c3}', Lo), ('\u{1c4}',
+        '\u{1c4}', Lu), ('\u{1c5}', '\u{1c5}', Lt), ('\u{1c6}', '\u{1c6}', Ll), ('\u{1c7}',
+        '\u{1c7}', Lu), ('\u{1c8}', '\u{1c8}', Lt), ('\u{1c9}', '\u{1c9}', Ll), ('\u{1ca}',
+        '\u{1ca}', Lu), ('\u{1cb}', '\u{1cb}', Lt), ('\u{1cc}', '\u{1cc}', Ll), ('\u{1cd}',
+        '\u{1cd}', Lu), ('\u{1ce}', '\u{1ce}', Ll), ('\u{1cf}', '\u{1cf}', Lu), ('\u{1d0}',
+        '\u{1d0}', Ll), ('\u{1d1}', '\u{1d1}', Lu), ('\u{1d2}', '\u{1d2}', Ll), ('\u{1d3}',
+        '\u{1d3}', Lu), ('\u{1d4}', '\u{1d4}', Ll), ('\u{1d5}', '\u{1d5}', Lu), ('\u{1d6}',
+        '\u{1d6}', Ll), ('\u{1d7}', '\u{1d7}', Lu), ('\u{1d8}', '\u{1d8}', Ll), ('\u{1d9}',
+        '\u{1d9}', Lu), ('\u{1da}', '\u{1da}', Ll), ('\u{1db}', '\u{1db}', Lu), ('\u{1dc}',
+        '\u{1dd}', Ll), ('\u{1de}', '\u{1de}', Lu), ('\u{1df}', '\u{1df}', Ll), ('\u{1e0}',
+        '\u{1e0}', Lu), ('\u{1e1}', '\u{1e1}', Ll), ('\u{1e2}', '\u{1e2}', Lu), ('\u{1e3}',
+        '\u{1e3}', Ll), ('\u{1e4}', '\u{1e4}', Lu), ('\u{1e5}', '\u{1e5}', Ll), ('\u{1e6}',
+        '\u{1e6}', Lu), ('\u{1e7}', '\u{1e7}', Ll), ('\u{1e8}', '\u{1e8}', Lu), ('\u{1e9}',
+        '\u{1e9}', Ll), ('\u{1ea}', '\u{1ea}', Lu), ('\u{1eb}', '\u{1eb}', Ll), ('\u{1ec}',
+        '\u{1ec}', Lu), ('\u{1ed}', '\u{1ed}', Ll), ('\u{1ee}', '\u{1ee}', Lu), ('\u{1ef}',
+        '\u{1f0}', Ll), ('\u{1f1}', '\u{1f1}', Lu), ('\u{1f2}', '\u{1f2}', Lt), ('\u{1f3}',
+        '\u{1f3}', Ll), ('\u{1f4}', '\u{1f4}', Lu), ('\u{1f5}', '\u{1f5}', Ll), ('\u{1f6}',
+        '\u{1f8}', Lu), ('\u{1f9}', '\u{1f9}', Ll), ('\u{1fa}', '\u{1fa}', Lu), ('\u{1fb}',
+        '\u{1fb}', Ll), ('\u{1fc}', '\u{1fc}', Lu), ('\u{1fd}', '\u{1fd}', Ll), ('\u{1fe}',
+        '\u{1fe}', Lu), ('\u{1ff}', '\u{1ff}', Ll), ('\u{200}', '\u{200}', Lu), ('\u{201}',
+        '\u{201}', Ll), ('\u{202}', '\u{202}', Lu), ('\u{203}', '\u{203}', Ll), ('\u{204}',
+        '\u{204}', Lu), ('\u{205}', '\u{205}', Ll), ('\u{206}', '\u{206}', Lu), ('\u{207}',
+        '\u{207}', Ll), ('\u{208}', '\u{208}', Lu), ('\u{209}', '\u{209}', Ll), ('\u{20a}',
+        '\u{20a}', Lu), ('\u{20b}', '\u{20b}', Ll), ('\u{20c}', '\u{20c}', Lu), ('\u{20d}',
+        '\u{20d}', Ll), ('\u{20e}', '\u{20e}', Lu), ('\u{20f}', '\u{20f}', Ll), ('\u{210}',
+        '\u{210}', Lu), ('\u{211}', '\u{211}', Ll), ('\u{212}', '\u{212}', Lu), ('\u{213}',
+        '\u{213}', Ll), ('\u{214}', '\u{214}', Lu), ('\u{215}', '\u{215}', Ll), ('\u{216}',
+        '\u{216}', Lu), ('\u{217}', '\u{217}', Ll), ('\u{218}', '\u{218}', Lu), ('\u{219}',
+        '\u{219}', Ll), ('\u{21a}', '\u{21a}', Lu), ('\u{21b}', '\u{21b}', Ll), ('\u{21c}',
+        '\u{21c}', Lu), ('\u{21d}', '\u{21d}', Ll), ('\u{21e}', '\u{21e}', Lu), ('\u{21f}',
+        '\u{21f}', Ll), ('\u{220}', '\u{220}', Lu), ('\u{221}', '\u{221}', Ll), ('\u{222}',
+        '\u{222}', Lu), ('\u{223}', '\u{223}', Ll), ('\u{224}', '\u{224}', Lu), ('\u{225}',
+        '\u{225}', Ll), ('\u{226}', '\u{226}', Lu), ('\u{227}', '\u{227}', Ll), ('\u{228}',
+        '\u{228}', Lu), ('\u{229}', '\u{229}', Ll), ('\u{22a}', '\u{22a}', Lu), ('\u{22b}',
+        '\u{22b}', Ll), ('\u{22c}', '\u{22c}', Lu), ('\u{22d}', '\u{22d}', Ll), ('\u{22e}',
+        '\u{22e}', Lu), ('\u{22f}', '\u{22f}', Ll), ('\u{230}', '\u{230}', Lu), ('\u{231}',
+        '\u{231}', Ll), ('\u{232}', '\u{232}', Lu), ('\u{233}', '\u{239}', Ll), ('\u{23a}',
+        '\u{23b}', Lu), ('\u{23c}', '\u{23c}', Ll), ('\u{23d}', '\u{23e}', Lu), ('\u{23f}',
+        '\u{240}', Ll), ('\u{241}', '\u{241}', Lu), ('\u{242}', '\u{242}', Ll), ('\u{243}',
+        '\u{246}', Lu), ('\u{247}', '\u{247}', Ll), ('\u{248}', '\u{248}', Lu), ('\u{249}',
+        '\u{249}', Ll), ('\u{24a}', '\u{24a}', Lu), ('\u{24b}', '\u{24b}', Ll), ('\u{24c}',
+        '\u{24c}', Lu), ('\u{24d}', '\u{24d}', Ll), ('\u{24e}', '\u{24e}', Lu), ('\u{24f}',
+        '\u{293}', Ll), ('\u{294}', '\u{294}', Lo), ('\u{295}', '\u{2af}', Ll), ('\u{2b0}',
+        '\u{2c1}', Lm), ('\u{2c2}', '\u{2c5}', Sk), ('\u{2c6}', '\u{2d1}', Lm), ('\u{2d2}',
+        '\u{2df}', Sk), ('\u{2e0}', '\u{2e4}', Lm), ('\u{2e5}', '\u{2eb}', Sk), ('\u{2ec}',
+        '\u{2ec}', Lm), ('\u{2ed}', '\u{2ed}', Sk), ('\u{2ee}', '\u{2ee}', Lm), ('\u{2ef}',
+        '\u{2ff}', Sk), ('\u{300}', '\u{36f}', Mn), ('\u{370}', '\u{370}', Lu), ('\u{371}',
+        '\u{371}', Ll), ('\u{372}', '\u{372}', Lu), ('\u{373}', '\u{373}', Ll), ('\u{374}',
+        '\u{374}', Lm), ('\u{375}', '\u{375}', Sk), ('\u{376}', '\u{376}', Lu), ('\u{377}',
+        '\u{377}', Ll), ('\u{37a}', '\u{37a}', Lm), ('\u{37b}', '\u{37d}', Ll), ('\u{37e}',
+        '\u{37e}', Po), ('\u{37f}', '\u{37f}', Lu), ('\u{384}', '\u{385}', Sk), ('\u{386}',
+        '\u{386}', Lu), ('\u{387}', '\u{387}', Po), ('\u{388}', '\u{38a}', Lu), ('\u{38c}',
+        '\u{38c}', Lu), ('\u{38e}', '\u{38f}', Lu), ('\u{390}', '\u{390}', Ll), ('\u{391}',
+        '\u{3a1}', Lu), ('\u{3a3}', '\u{3ab}', Lu), ('\u{3ac}', '\u{3ce}', Ll), ('\u{3cf}',
+        '\u{3cf}', Lu), ('\u{3d0}', '\u{3d1}', Ll), ('\u{3d2}', '\u{3d4}', Lu), ('\u{3d5}',
+        '\u{3d7}', Ll), ('\u{3d8}', '\u{3d8}', Lu), ('\u{3d9}', '\u{3d9}', Ll), ('\u{3da}',
+        '\u{3da}', Lu), ('\u{3db}', '\u{3db}', Ll), ('\u{3dc}', '\u{3dc}', Lu), ('\u{3dd}',
+        '\u{3dd}', Ll), ('\u{3de}', '\u{3de}', Lu), ('\u{3df}', '\u{3df}', Ll), ('\u{3e0}',
+        '\u{3e0}', Lu), ('\u{3e1}', '\u{3e1}', Ll), ('\u{3e2}', '\u{3e2}', Lu), ('\u{3e3}',
+        '\u{3e3}', Ll), ('\u{3e4}', '\u{3e4}', Lu), ('\u{3e5}', '\u{3e5}', Ll), ('\u{3e6}',
+        '\u{3e6}', Lu), ('\u{3e7}', '\u{3e7}', Ll), ('\u{3e8}', '\u{3e8}', Lu), ('\u{3e9}',
+        '\u{3e9}', Ll), ('\u{3ea}', '\u{3ea}', Lu), ('\u{3eb}', '\u{3eb}', Ll), ('\u{3ec}',
+        '\u{3ec}', Lu), ('\u{3ed}', '\u{3ed}', Ll), ('\u{3ee}', '\u{3ee}', Lu), ('\u{3ef}',
+        '\u{3f3}', Ll), ('\u{3f4}', '\u{3f4}', Lu), ('\u{3f5}', '\u{3f5}', Ll), ('\u{3f6}',
+        '\u{3f6}', Sm), ('\u{3f7}', '\u{3f7}', Lu), ('\u{3f8}', '\u{3f8}', Ll), ('\u{3f9}',
+        '\u{3fa}', Lu), ('\u{3fb}', '\u{3fc}', Ll), ('\u{3fd}', '\u{42f}', Lu), ('\u{430}',
+        '\u{45f}', Ll), ('\u{460}', '\u{460}', Lu), ('\u{461}', '\u{461}', Ll), ('\u{462}',
+        '\u{462}', Lu), ('\u{463}', '\u{463}', Ll), ('\u{464}', '\u{464}', Lu), ('\u{465}',
+        '\u{465}', Ll), ('\u{466}', '\u{466}', Lu), ('\u{467}', '\u{467}', Ll), ('\u{468}',
+        '\u{468}', Lu), ('\u{469}', '\u{469}', Ll), ('\u{46a}', '\u{46a}', Lu), ('\u{46b}',
+        '\u{46b}', Ll), ('\u{46c}', '\u{46c}', Lu), ('\u{46d}', '\u{46d}', Ll), ('\u{46e}',
+        '\u{46e}', Lu), ('\u{46f}', '\u{46f}', Ll), ('\u{470}', '\u{470}', Lu), ('\u{471}',
+        '\u{471}', Ll), ('\u{472}', '\u{472}', Lu), ('\u{473}', '\u{473}', Ll), ('\u{474}',
+        '\u{474}', Lu), ('\u{475}', '\u{475}', Ll), ('\u{476}', '\u{476}', Lu), ('\u{477}',
+        '\u{477}', Ll), ('\u{478}', '\u{478}', Lu), ('\u{479}', '\u{479}', Ll), ('\u{47a}',
+        '\u{47a}', Lu), ('\u{47b}', '\u{47b}', Ll), ('\u{47c}', '\u{47c}', Lu), ('\u{47d}',
+        '\u{47d}', Ll), ('\u{47e}', '\u{47e}', Lu), ('\u{47f}', '\u{47f}', Ll), ('\u{480}',
+        '\u{480}', Lu), ('\u{481}', '\u{481}', Ll), ('\u{482}', '\u{482}', So), ('\u{483}',
+        '\u{487}', Mn), ('\u{488}', '\u{489}', Me), ('\u{48a}', '\u{48a}', Lu), ('\u{48b}',
+        '\u{48b}', Ll), ('\u{48c}', '\u{48c}', Lu), ('\u{48d}', '\u{48d}', Ll), ('\u{48e}',
+        '\u{48e}', Lu), ('\u{48f}', '\u{48f}', Ll), ('\u{490}', '\u{490}', Lu), ('\u{491}',
+        '\u{491}', Ll), ('\u{492}', '\u{492}', Lu), ('\u{493}', '\u{493}', Ll), ('\u{494}',
+        '\u{494}', Lu), ('\u{495}', '\u{495}', Ll), ('\u{496}', '\u{496}', Lu), ('\u{497}',
+        '\u{497}', Ll), ('\u{498}', '\u{498}', Lu), ('\u{499}', '\u{499}', Ll), ('\u{49a}',
+        '\u{49a}', Lu), ('\u{49b}', '\u{49b}', Ll), ('\u{49c}', '\u{49c}', Lu), ('\u{49d}',
+        '\u{49d}', Ll), ('\u{49e}', '\u{49e}', Lu), ('\u{49f}', '\u{49f}', Ll), ('\u{4a0}',
+        '\u{4a0}', Lu), ('\u{4a1}', '\u{4a1}', Ll), ('\u{4a2}', '\u{4a2}', Lu), ('\u{4a3}',
+        '\u{4a3}', Ll), ('\u{4a4}', '\u{4a4}', Lu), ('\u{4a5}', '\u{4a5}', Ll), ('\u{4a6}',
+        '\u{4a6}', Lu), ('\u{4a7}', '\u{4a7}', Ll), ('\u{4a8}', '\u{4a8}', Lu), ('\u{4a9}',
+        '\u{4a9}', Ll), ('\u{4aa}', '\u{4aa}', Lu), ('\u{4ab}', '\u{4ab}', Ll), ('\u{4ac}',
+        '\u{4ac}', Lu), ('\u{4ad}', '\u{4ad}', Ll), ('\u{4ae}', '\u{4ae}', Lu), ('\u{4af}',
+        '\u{4af}', Ll), ('\u{4b0}', '\u{4b0}', Lu), ('\u{4b1}', '\u{4b1}', Ll), ('\u{4b2}',
+        '\u{4b2}', Lu), ('\u{4b3}', '\u{4b3}', Ll), ('\u{4b4}', '\u{4b4}', Lu), ('\u{4b5}',
+        '\u{4b5}', Ll), ('\u{4b6}', '\u{4b6}', Lu), ('\u{4b7}', '\u{4b7}', Ll), ('\u{4b8}',
+        '\u{4b8}', Lu), ('\u{4b9}', '\u{4b9}', Ll), ('\u{4ba}', '\u{4ba}', Lu), ('\u{4bb}',
+        '\u{4bb}', Ll), ('\u{4bc}', '\u{4bc}', Lu), ('\u{4bd}', '\u{4bd}', Ll), ('\u{4be}',
+        '\u{4be}', Lu), ('\u{4bf}', '\u{4bf}', Ll), ('\u{4c0}', '\u{4c1}', Lu), ('\u{4c2}',
+        '\u{4c2}', Ll), ('\u{4c3}', '\u{4c3}', Lu), ('\u{4c4}', '\u{4c4}', Ll), ('\u{4c5}',
+        '\u{4c5}', Lu), ('\u{4c6}', '\u{4c6}', Ll), ('\u{4c7}', '\u{4c7}', Lu), ('\u{4c8}',
+        '\u{4c8}', Ll), ('\u{4c9}', '\u{4c9}', Lu), ('\u{4ca}', '\u{4ca}', Ll), ('\u{4cb}',
+        '\u{4cb}', Lu), ('\u{4cc}', '\u{4cc}', Ll), ('\u{4cd}', '\u{4cd}', Lu), ('\u{4ce}',
+        '\u{4cf}', Ll), ('\u{4d0}', '\u{4d0}', Lu), ('\u{4d1}', '\u{4d1}', Ll), ('\u{4d2}',
+        '\u{4d2}', Lu), ('\u{4d3}', '\u{4d3}', Ll), ('\u{4d4}', '\u{4d4}', Lu), ('\u{4d5}',
+        '\u{4d5}', Ll), ('\u{4d6}', '\u{4d6}', Lu), ('\u{4d7}', '\u{4d7}', Ll), ('\u{4d8}',
+        '\u{4d8}', Lu), ('\u{4d9}', '\u{4d9}', Ll), ('\u{4da}', '\u{4da}', Lu), ('\u{4db}',
+        '\u{4db}', Ll), ('\u{4dc}', '\u{4dc}', Lu), ('\u{4dd}', '\u{4dd}', Ll), ('\u{4de}',
+        '\u{4de}', Lu), ('\u{4df}', '\u{4df}', Ll), ('\u{4e0}', '\u{4e0}', Lu), ('\u{4e1}',
+        '\u{4e1}', Ll), ('\u{4e2}', '\u{4e2}', Lu), ('\u{4e3}', '\u{4e3}', Ll), ('\u{4e4}',
+        '\u{4e4}', Lu), ('\u{4e5}', '\u{4e5}', Ll), ('\u{4e6}', '\u{4e6}', Lu), ('\u{4e7}',
+        '\u{4e7}', Ll), ('\u{4e8}', '\u{4e8}', Lu), ('\u{4e9}', '\u{4e9}', Ll), ('\u{4ea}',
+        '\u{4ea}', Lu), ('\u{4eb}', '\u{4eb}', Ll), ('\u{4ec}', '\u{4ec}', Lu), ('\u{4ed}',
+        '\u{4ed}', Ll), ('\u{4ee}', '\u{4ee}', Lu), ('\u{4ef}', '\u{4ef}', Ll), ('\u{4f0}',
+        '\u{4f0}', Lu), ('\u{4f1}', '\u{4f1}', Ll), ('\u{4f2}', '\u{4f2}', Lu), ('\u{4f3}',
+        '\u{4f3}', Ll), ('\u{4f4}', '\u{4f4}', Lu), ('\u{4f5}', '\u{4f5}', Ll), ('\u{4f6}',
+        '\u{4f6}', Lu), ('\u{4f7}', '\u{4f7}', Ll), ('\u{4f8}', '\u{4f8}', Lu), ('\u{4f9}',
+        '\u{4f9}', Ll), ('\u{4fa}', '\u{4fa}', Lu), ('\u{4fb}', '\u{4fb}', Ll), ('\u{4fc}',
+        '\u{4fc}', Lu), ('\u{4fd}', '\u{4fd}', Ll), ('\u{4fe}', '\u{4fe}', Lu), ('\u{4ff}',
+        '\u{4ff}', Ll), ('\u{500}', '\u{500}', Lu), ('\u{501}', '\u{501}', Ll), ('\u{502}',
+        '\u{502}', Lu), ('\u{503}', '\u{503}', Ll), ('\u{504}', '\u{504}', Lu), ('\u{505}',
+        '\u{505}', Ll), ('\u{506}', '\u{506}', Lu), ('\u{507}', '\u{507}', Ll), ('\u{508}',
+        '\u{508}', Lu), ('\u{509}', '\u{509}', Ll), ('\u{50a}', '\u{50a}', Lu), ('\u{50b}',
+        '\u{50b}', Ll), ('\u{50c}', '\u{50c}', Lu), ('\u{50d}', '\u{50d}', Ll), ('\u{50e}',
+        '\u{50e}', Lu), ('\u{50f}', '\u{50f}', Ll), ('\u{510}', '\u{510}', Lu), ('\u{511}',
+        '\u{511}', Ll), ('\u{512}', '\u{512}', Lu), ('\u{513}', '\u{513}', Ll), ('\u{514}',
+        '\u{514}', Lu), ('\u{515}', '\u{515}', Ll), ('\u{516}', '\u{516}', Lu), ('\u{517}',
+        '\u{517}', Ll), ('\u{518}', '\u{518}', Lu), ('\u{519}', '\u{519}', Ll), ('\u{51a}',
+        '\u{51a}', Lu), ('\u{51b}', '\u{51b}', Ll), ('\u{51c}', '\u{51c}', Lu), ('\u{51d}',
+        '\u{51d}', Ll), ('\u{51e}', '\u{51e}', Lu), ('\u{51f}', '\u{51f}', Ll), ('\u{520}',
+        '\u{520}', Lu), ('\u{521}', '\u{521}', Ll), ('\u{522}', '\u{522}', Lu), ('\u{523}',
+        '\u{523}', Ll), ('\u{524}', '\u{524}', Lu), ('\u{525}', '\u{525}', Ll), ('\u{526}',
+        '\u{526}', Lu), ('\u{527}', '\u{527}', Ll), ('\u{528}', '\u{528}', Lu), ('\u{529}',
+        '\u{529}', Ll), ('\u{52a}', '\u{52a}', Lu), ('\u{52b}', '\u{52b}', Ll), ('\u{52c}',
+        '\u{52c}', Lu), ('\u{52d}', '\u{52d}', Ll), ('\u{52e}', '\u{52e}', Lu), ('\u{52f}',
+        '\u{52f}', Ll), ('\u{531}', '\u{556}', Lu), ('\u{559}', '\u{559}', Lm), ('\u{55a}',
+        '\u{55f}', Po), ('\u{561}', '\u{587}', Ll), ('\u{589}', '\u{589}', Po), ('\u{58a}',
+        '\u{58a}', Pd), ('\u{58d}', '\u{58e}', So), ('\u{58f}', '\u{58f}', Sc), ('\u{591}',
+        '\u{5bd}', Mn), ('\u{5be}', '\u{5be}', Pd), ('\u{5bf}', '\u{5bf}', Mn), ('\u{5c0}',
+        '\u{5c0}', Po), ('\u{5c1}', '\u{5c2}', Mn), ('\u{5c3}', '\u{5c3}', Po), ('\u{5c4}',
+        '\u{5c5}', Mn), ('\u{5c6}', '\u{5c6}', Po), ('\u{5c7}', '\u{5c7}', Mn), ('\u{5d0}',
+        '\u{5ea}', Lo), ('\u{5f0}', '\u{5f2}', Lo), ('\u{5f3}', '\u{5f4}', Po), ('\u{600}',
+        '\u{605}', Cf), ('\u{606}', '\u{608}', Sm), ('\u{609}', '\u{60a}', Po), ('\u{60b}',
+        '\u{60b}', Sc), ('\u{60c}', '\u{60d}', Po), ('\u{60e}', '\u{60f}', So), ('\u{610}',
+        '\u{61a}', Mn), ('\u{61b}', '\u{61b}', Po), ('\u{61c}', '\u{61c}', Cf), ('\u{61e}',
+        '\u{61f}', Po), ('\u{620}', '\u{63f}', Lo), ('\u{640}', '\u{640}', Lm), ('\u{641}',
+        '\u{64a}', Lo), ('\u{64b}', '\u{65f}', Mn), ('\u{660}', '\u{669}', Nd), ('\u{66a}',
+        '\u{66d}', Po), ('\u{66e}', '\u{66f}', Lo), ('\u{670}', '\u{670}', Mn), ('\u{671}',
+        '\u{6d3}', Lo), ('\u{6d4}', '\u{6d4}', Po), ('\u{6d5}', '\u{6d5}', Lo), ('\u{6d6}',
+        '\u{6dc}', Mn), ('\u{6dd}', '\u{6dd}', Cf), ('\u{6de}', '\u{6de}', So), ('\u{6df}',
+        '\u{6e4}', Mn), ('\u{6e5}', '\u{6e6}', Lm), ('\u{6e7}', '\u{6e8}', Mn), ('\u{6e9}',
+        '\u{6e9}', So), ('\u{6ea}', '\u{6ed}', Mn), ('\u{6ee}', '\u{6ef}', Lo), ('\u{6f0}',
+        '\u{6f9}', Nd), ('\u{6fa}', '\u{6fc}', Lo), ('\u{6fd}', '\u{6fe}', So), ('\u{6ff}',
+        '\u{6ff}', Lo), ('\u{700}', '\u{70d}', Po), ('\u{70f}', '\u{70f}', Cf), ('\u{710}',
+        '\u{710}', Lo), ('\u{711}', '\u{711}', Mn), ('\u{712}', '\u{72f}', Lo), ('\u{730}',
+        '\u{74a}', Mn), ('\u{74d}', '\u{7a5}', Lo), ('\u{7a6}', '\u{7b0}', Mn), ('\u{7b1}',
+        '\u{7b1}', Lo), ('\u{7c0}', '\u{7c9}', Nd), ('\u{7ca}', '\u{7ea}', Lo), ('\u{7eb}',
+        '\u{7f3}', Mn), ('\u{7f4}', '\u{7f5}', Lm), ('\u{7f6}', '\u{7f6}', So), ('\u{7f7}',
+        '\u{7f9}', Po), ('\u{7fa}', '\u{7fa}', Lm), ('\u{800}', '\u{815}', Lo), ('\u{816}',
+        '\u{819}', Mn), ('\u{81a}', '\u{81a}', Lm), ('\u{81b}', '\u{823}', Mn), ('\u{824}',
+        '\u{824}', Lm), ('\u{825}', '\u{827}', Mn), ('\u{828}', '\u{828}', Lm), ('\u{829}',
+        '\u{82d}', Mn), ('\u{830}', '\u{83e}', Po), ('\u{840}', '\u{858}', Lo), ('\u{859}',
+        '\u{85b}', Mn), ('\u{85e}', '\u{85e}', Po), ('\u{8a0}', '\u{8b2}', Lo), ('\u{8e4}',
+        '\u{902}', Mn), ('\u{903}', '\u{903}', Mc), ('\u{904}', '\u{939}', Lo), ('\u{93a}',
+        '\u{93a}', Mn), ('\u{93b}', '\u{93b}', Mc), ('\u{93c}', '\u{93c}', Mn), ('\u{93d}',
+        '\u{93d}', Lo), ('\u{93e}', '\u{940}', Mc), ('\u{941}', '\u{948}', Mn), ('\u{949}',
+        '\u{94c}', Mc), ('\u{94d}', '\u{94d}', Mn), ('\u{94e}', '\u{94f}', Mc), ('\u{950}',
+        '\u{950}', Lo), ('\u{951}', '\u{957}', Mn), ('\u{958}', '\u{961}', Lo), ('\u{962}',
+        '\u{963}', Mn), ('\u{964}', '\u{965}', Po), ('\u{966}', '\u{96f}', Nd), ('\u{970}',
+        '\u{970}', Po), ('\u{971}', '\u{971}', Lm), ('\u{972}', '\u{980}', Lo), ('\u{981}',
+        '\u{981}', Mn), ('\u{982}', '\u{983}', Mc), ('\u{985}', '\u{98c}', Lo), ('\u{98f}',
+        '\u{990}', Lo), ('\u{993}', '\u{9a8}', Lo), ('\u{9aa}', '\u{9b0}', Lo), ('\u{9b2}',
+        '\u{9b2}', Lo), ('\u{9b6}', '\u{9b9}', Lo), ('\u{9bc}', '\u{9bc}', Mn), ('\u{9bd}',
+        '\u{9bd}', Lo), ('\u{9be}', '\u{9c0}', Mc), ('\u{9c1}', '\u{9c4}', Mn), ('\u{9c7}',
+        '\u{9c8}', Mc), ('\u{9cb}', '\u{9cc}', Mc), ('\u{9cd}', '\u{9cd}', Mn), ('\u{9ce}',
+        '\u{9ce}', Lo), ('\u{9d7}', '\u{9d7}', Mc), ('\u{9dc}', '\u{9dd}', Lo), ('\u{9df}',
+        '\u{9e1}', Lo), ('\u{9e2}', '\u{9e3}', Mn), ('\u{9e6}', '\u{9ef}', Nd), ('\u{9f0}',
+        '\u{9f1}', Lo), ('\u{9f2}', '\u{9f3}', Sc), ('\u{9f4}', '\u{9f9}', No), ('\u{9fa}',
+        '\u{9fa}', So), ('\u{9fb}', '\u{9fb}', Sc), ('\u{a01}', '\u{a02}', Mn), ('\u{a03}',
+        '\u{a03}', Mc), ('\u{a05}', '\u{a0a}', Lo), ('\u{a0f}', '\u{a10}', Lo), ('\u{a13}',
+        '\u{a28}', Lo), ('\u{a2a}', '\u{a30}', Lo), ('\u{a32}', '\u{a33}', Lo), ('\u{a35}',
+        '\u{a36}', Lo), ('\u{a38}', '\u{a39}', Lo), ('\u{a3c}', '\u{a3c}', Mn), ('\u{a3e}',
+        '\u{a40}', Mc), ('\u{a41}', '\u{a42}', Mn), ('\u{a47}', '\u{a48}', Mn), ('\u{a4b}',
+        '\u{a4d}', Mn), ('\u{a51}', '\u{a51}', Mn), ('\u{a59}', '\u{a5c}', Lo), ('\u{a5e}',
+        '\u{a5e}', Lo), ('\u{a66}', '\u{a6f}', Nd), ('\u{a70}', '\u{a71}', Mn), ('\u{a72}',
+        '\u{a74}', Lo), ('\u{a75}', '\u{a75}', Mn), ('\u{a81}', '\u{a82}', Mn), ('\u{a83}',
+        '\u{a83}', Mc), ('\u{a85}', '\u{a8d}', Lo), ('\u{a8f}', '\u{a91}', Lo), ('\u{a93}',
+        '\u{aa8}', Lo), ('\u{aaa}', '\u{ab0}', Lo), ('\u{ab2}', '\u{ab3}', Lo), ('\u{ab5}',
+        '\u{ab9}', Lo), ('\u{abc}', '\u{abc}', Mn), ('\u{abd}', '\u{abd}', Lo), ('\u{abe}',
+        '\u{ac0}', Mc), ('\u{ac1}', '\u{ac5}', Mn), ('\u{ac7}', '\u{ac8}', Mn), ('\u{ac9}',
+        '\u{ac9}', Mc), ('\u{acb}', '\u{acc}', Mc), ('\u{acd}', '\u{acd}', Mn), ('\u{ad0}',
+        '\u{ad0}', Lo), ('\u{ae0}', '\u{ae1}', Lo), ('\u{ae2}', '\u{ae3}', Mn), ('\u{ae6}',
+        '\u{aef}', Nd), ('\u{af0}', '\u{af0}', Po), ('\u{af1}', '\u{af1}', Sc), ('\u{b01}',
+        '\u{b01}', Mn), ('\u{b02}', '\u{b03}', Mc), ('\u{b05}', '\u{b0c}', Lo), ('\u{b0f}',
+        '\u{b10}', Lo), ('\u{b13}', '\u{b28}', Lo), ('\u{b2a}', '\u{b30}', Lo), ('\u{b32}',
+        '\u{b33}', Lo), ('\u{b35}', '\u{b39}', Lo), ('\u{b3c}', '\u{b3c}', Mn), ('\u{b3d}',
+        '\u{b3d}', Lo), ('\u{b3e}', '\u{b3e}', Mc), ('\u{b3f}', '\u{b3f}', Mn), ('\u{b40}',
+        '\u{b40}', Mc), ('\u{b41}', '\u{b44}', Mn), ('\u{b47}', '\u{b48}', Mc), ('\u{b4b}',
+        '\u{b4c}', Mc), ('\u{b4d}', '\u{b4d}', Mn), ('\u{b56}', '\u{b56}', Mn), ('\u{b57}',
+        '\u{b57}', Mc), ('\u{b5c}', '\u{b5d}', Lo), ('\u{b5f}', '\u{b61}', Lo), ('\u{b62}',
+        '\u{b63}', Mn), ('\u{b66}', '\u{b6f}', Nd), ('\u{b70}', '\u{b70}', So), ('\u{b71}',
+        '\u{b71}', Lo), ('\u{b72}', '\u{b77}', No), ('\u{b82}', '\u{b82}', Mn), ('\u{b83}',
+        '\u{b83}', Lo), ('\u{b85}', '\u{b8a}', Lo), ('\u{b8e}', '\u{b90}', Lo), ('\u{b92}',
+        '\u{b95}', Lo), ('\u{b99}', '\u{b9a}', Lo), ('\u{b9c}', '\u{b9c}', Lo), ('\u{b9e}',
+        '\u{b9f}', Lo), ('\u{ba3}', '\u{ba4}', Lo), ('\u{ba8}', '\u{baa}', Lo), ('\u{bae}',
+        '\u{bb9}', Lo), ('\u{bbe}', '\u{bbf}', Mc), ('\u{bc0}', '\u{bc0}', Mn), ('\u{bc1}',
+        '\u{bc2}', Mc), ('\u{bc6}', '\u{bc8}', Mc), ('\u{bca}', '\u{bcc}', Mc), ('\u{bcd}',
+        '\u{bcd}', Mn), ('\u{bd0}', '\u{bd0}', Lo), ('\u{bd7}', '\u{bd7}', Mc), ('\u{be6}',
+        '\u{bef}', Nd), ('\u{bf0}', '\u{bf2}', No), ('\u{bf3}', '\u{bf8}', So), ('\u{bf9}',
+        '\u{bf9}', Sc), ('\u{bfa}', '\u{bfa}', So), ('\u{c00}', '\u{c00}', Mn), ('\u{c01}',
+        '\u{c03}', Mc), ('\u{c05}', '\u{c0c}', Lo), ('\u{c0e}', '\u{c10}', Lo), ('\u{c12}',
+        '\u{c28}', Lo), ('\u{c2a}', '\u{c39}', Lo), ('\u{c3d}', '\u{c3d}', Lo), ('\u{c3e}',
+        '\u{c40}', Mn), ('\u{c41}', '\u{c44}', Mc), ('\u{c46}', '\u{c48}', Mn), ('\u{c4a}',
+        '\u{c4d}', Mn), ('\u{c55}', '\u{c56}', Mn), ('\u{c58}', '\u{c59}', Lo), ('\u{c60}',
+        '\u{c61}', Lo), ('\u{c62}', '\u{c63}', Mn), ('\u{c66}', '\u{c6f}', Nd), ('\u{c78}',
+        '\u{c7e}', No), ('\u{c7f}', '\u{c7f}', So), ('\u{c81}', '\u{c81}', Mn), ('\u{c82}',
+        '\u{c83}', Mc), ('\u{c85}', '\u{c8c}', Lo), ('\u{c8e}', '\u{c90}', Lo), ('\u{c92}',
+        '\u{ca8}', Lo), ('\u{caa}', '\u{cb3}', Lo), ('\u{cb5}', '\u{cb9}', Lo), ('\u{cbc}',
+        '\u{cbc}', Mn), ('\u{cbd}', '\u{cbd}', Lo), ('\u{cbe}', '\u{cbe}', Mc), ('\u{cbf}',
+        '\u{cbf}', Mn), ('\u{cc0}', '\u{cc4}', Mc), ('\u{cc6}', '\u{cc6}', Mn), ('\u{cc7}',
+        '\u{cc8}', Mc), ('\u{cca}', '\u{ccb}', Mc), ('\u{ccc}', '\u{ccd}', Mn), ('\u{cd5}',
+        '\u{cd6}', Mc), ('\u{cde}', '\u{cde}', Lo), ('\u{ce0}', '\u{ce1}', Lo), ('\u{ce2}',
+        '\u{ce3}', Mn), ('\u{ce6}', '\u{cef}', Nd), ('\u{cf1}', '\u{cf2}', Lo), ('\u{d01}',
+        '\u{d01}', Mn), ('\u{d02}', '\u{d03}', Mc), ('\u{d05}', '\u{d0c}', Lo), ('\u{d0e}',
+        '\u{d10}', Lo), ('\u{d12}', '\u{d3a}', Lo), ('\u{d3d}', '\u{d3d}', Lo), ('\u{d3e}',
+        '\u{d40}', Mc), ('\u{d41}', '\u{d44}', Mn), ('\u{d46}', '\u{d48}', Mc), ('\u{d4a}',
+        '\u{d4c}', Mc), ('\u{d4d}', '\u{d4d}', Mn), ('\u{d4e}', '\u{d4e}', Lo), ('\u{d57}',
+        '\u{d57}', Mc), ('\u{d60}', '\u{d61}', Lo), ('\u{d62}', '\u{d63}', Mn), ('\u{d66}',
+        '\u{d6f}', Nd), ('\u{d70}', '\u{d75}', No), ('\u{d79}', '\u{d79}', So), ('\u{d7a}',
+        '\u{d7f}', Lo), ('\u{d82}', '\u{d83}', Mc), ('\u{d85}', '\u{d96}', Lo), ('\u{d9a}',
+        '\u{db1}', Lo), ('\u{db3}', '\u{dbb}', Lo), ('\u{dbd}', '\u{dbd}', Lo), ('\u{dc0}',
+        '\u{dc6}', Lo), ('\u{dca}', '\u{dca}', Mn), ('\u{dcf}', '\u{dd1}', Mc), ('\u{dd2}',
+        '\u{dd4}', Mn), ('\u{dd6}', '\u{dd6}', Mn), ('\u{dd8}', '\u{ddf}', Mc), ('\u{de6}',
+        '\u{def}', Nd), ('\u{df2}', '\u{df3}', Mc), ('\u{df4}', '\u{df4}', Po), ('\u{e01}',
+        '\u{e30}', Lo), ('\u{e31}', '\u{e31}', Mn), ('\u{e32}', '\u{e33}', Lo), ('\u{e34}',
+        '\u{e3a}', Mn), ('\u{e3f}', '\u{e3f}', Sc), ('\u{e40}', '\u{e45}', Lo), ('\u{e46}',
+        '\u{e46}', Lm), ('\u{e47}', '\u{e4e}', Mn), ('\u{e4f}', '\u{e4f}', Po), ('\u{e50}',
+        '\u{e59}', Nd), ('\u{e5a}', '\u{e5b}', Po), ('\u{e81}', '\u{e82}', Lo), ('\u{e84}',
+        '\u{e84}', Lo), ('\u{e87}', '\u{e88}', Lo), ('\u{e8a}', '\u{e8a}', Lo), ('\u{e8d}',
+        '\u{e8d}', Lo), ('\u{e94}', '\u{e97}', Lo), ('\u{e99}', '\u{e9f}', Lo), ('\u{ea1}',
+        '\u{ea3}', Lo), ('\u{ea5}', '\u{ea5}', Lo), ('\u{ea7}', '\u{ea7}', Lo), ('\u{eaa}',
+        '\u{eab}', Lo), ('\u{ead}', '\u{eb0}', Lo), ('\u{eb1}', '\u{eb1}', Mn), ('\u{eb2}',
+        '\u{eb3}', Lo), ('\u{eb4}', '\u{eb9}', Mn), ('\u{ebb}', '\u{ebc}', Mn), ('\u{ebd}',
+        '\u{ebd}', Lo), ('\u{ec0}', '\u{ec4}', Lo), ('\u{ec6}', '\u{ec6}', Lm), ('\u{ec8}',
+        '\u{ecd}', Mn), ('\u{ed0}', '\u{ed9}', Nd), ('\u{edc}', '\u{edf}', Lo), ('\u{f00}',
+        '\u{f00}', Lo), ('\u{f01}', '\u{f03}', So), ('\u{f04}', '\u{f12}', Po), ('\u{f13}',
+        '\u{f13}', So), ('\u{f14}', '\u{f14}', Po), ('\u{f15}', '\u{f17}', So), ('\u{f18}',
+        '\u{f19}', Mn), ('\u{f1a}', '\u{f1f}', So), ('\u{f20}', '\u{f29}', Nd), ('\u{f2a}',
+        '\u{f33}', No), ('\u{f34}', '\u{f34}', So), ('\u{f35}', '\u{f35}', Mn), ('\u{f36}',
+        '\u{f36}', So), ('\u{f37}', '\u{f37}', Mn), ('\u{f38}', '\u{f38}', So), ('\u{f39}',
+        '\u{f39}', Mn), ('\u{f3a}', '\u{f3a}', Ps), ('\u{f3b}', '\u{f3b}', Pe), ('\u{f3c}',
+        '\u{f3c}', Ps), ('\u{f3d}', '\u{f3d}', Pe), ('\u{f3e}', '\u{f3f}', Mc), ('\u{f40}',
+        '\u{f47}', Lo), ('\u{f49}', '\u{f6c}', Lo), ('\u{f71}', '\u{f7e}', Mn), ('\u{f7f}',
+        '\u{f7f}', Mc), ('\u{f80}', '\u{f84}', Mn), ('\u{f85}', '\u{f85}', Po), ('\u{f86}',
+        '\u{f87}', Mn), ('\u{f88}', '\u{f8c}', Lo), ('\u{f8d}', '\u{f97}', Mn), ('\u{f99}',
+        '\u{fbc}', Mn), ('\u{fbe}', '\u{fc5}', So), ('\u{fc6}', '\u{fc6}', Mn), ('\u{fc7}',
+        '\u{fcc}', So), ('\u{fce}', '\u{fcf}', So), ('\u{fd0}', '\u{fd4}', Po), ('\u{fd5}',
+        '\u{fd8}', So), ('\u{fd9}', '\u{fda}', Po), ('\u{1000}', '\u{102a}', Lo), ('\u{102b}',
+        '\u{102c}', Mc), ('\u{102d}', '\u{1030}', Mn), ('\u{1031}', '\u{1031}', Mc), ('\u{1032}',
+        '\u{1037}', Mn), ('\u{1038}', '\u{1038}', Mc), ('\u{1039}', '\u{103a}', Mn), ('\u{103b}',
+        '\u{103c}', Mc), ('\u{103d}', '\u{103e}', Mn), ('\u{103f}', '\u{103f}', Lo), ('\u{1040}',
+        '\u{1049}', Nd), ('\u{104a}', '\u{104f}', Po), ('\u{1050}', '\u{1055}', Lo), ('\u{1056}',
+        '\u{1057}', Mc), ('\u{1058}', '\u{1059}', Mn), ('\u{105a}', '\u{105d}', Lo), ('\u{105e}',
+        '\u{1060}', Mn), ('\u{1061}', '\u{1061}', Lo), ('\u{1062}', '\u{1064}', Mc), ('\u{1065}',
+        '\u{1066}', Lo), ('\u{1067}', '\u{106d}', Mc), ('\u{106e}', '\u{1070}', Lo), ('\u{1071}',
+        '\u{1074}', Mn), ('\u{1075}', '\u{1081}', Lo), ('\u{1082}', '\u{1082}', Mn), ('\u{1083}',
+        '\u{1084}', Mc), ('\u{1085}', '\u{1086}', Mn), ('\u{1087}', '\u{108c}', Mc), ('\u{108d}',
+        '\u{108d}', Mn), ('\u{108e}', '\u{108e}', Lo), ('\u{108f}', '\u{108f}', Mc), ('\u{1090}',
+        '\u{1099}', Nd), ('\u{109a}', '\u{109c}', Mc), ('\u{109d}', '\u{109d}', Mn), ('\u{109e}',
+        '\u{109f}', So), ('\u{10a0}', '\u{10c5}', Lu), ('\u{10c7}', '\u{10c7}', Lu), ('\u{10cd}',
+        '\u{10cd}', Lu), ('\u{10d0}', '\u{10fa}', Lo), ('\u{10fb}', '\u{10fb}', Po), ('\u{10fc}',
+        '\u{10fc}', Lm), ('\u{10fd}', '\u{1248}', Lo), ('\u{124a}', '\u{124d}', Lo), ('\u{1250}',
+        '\u{1256}', Lo), ('\u{1258}', '\u{1258}', Lo), ('\u{125a}', '\u{125d}', Lo), ('\u{1260}',
+        '\u{1288}', Lo), ('\u{128a}', '\u{128d}', Lo), ('\u{1290}', '\u{12b0}', Lo), ('\u{12b2}',
+        '\u{12b5}', Lo), ('\u{12b8}', '\u{12be}', Lo), ('\u{12c0}', '\u{12c0}', Lo), ('\u{12c2}',
+        '\u{12c5}', Lo), ('\u{12c8}', '\u{12d6}', Lo), ('\u{12d8}', '\u{1310}', Lo), ('\u{1312}',
+        '\u{1315}', Lo), ('\u{1318}', '\u{135a}', Lo), ('\u{135d}', '\u{135f}', Mn), ('\u{1360}',
+        '\u{1368}', Po), ('\u{1369}', '\u{137c}', No), ('\u{1380}', '\u{138f}', Lo), ('\u{1390}',
+        '\u{1399}', So), ('\u{13a0}', '\u{13f4}', Lo), ('\u{1400}', '\u{1400}', Pd), ('\u{1401}',
+        '\u{166c}', Lo), ('\u{166d}', '\u{166e}', Po), ('\u{166f}', '\u{167f}', Lo), ('\u{1680}',
+        '\u{1680}', Zs), ('\u{1681}', '\u{169a}', Lo), ('\u{169b}', '\u{169b}', Ps), ('\u{169c}',
+        '\u{169c}', Pe), ('\u{16a0}', '\u{16ea}', Lo), ('\u{16eb}', '\u{16ed}', Po), ('\u{16ee}',
+        '\u{16f0}', Nl), ('\u{16f1}', '\u{16f8}', Lo), ('\u{1700}', '\u{170c}', Lo), ('\u{170e}',
+        '\u{1711}', Lo), ('\u{1712}', '\u{1714}', Mn), ('\u{1720}', '\u{1731}', Lo), ('\u{1732}',
+        '\u{1734}', Mn), ('\u{1735}', '\u{1736}', Po), ('\u{1740}', '\u{1751}', Lo), ('\u{1752}',
+        '\u{1753}', Mn), ('\u{1760}', '\u{176c}', Lo), ('\u{176e}', '\u{1770}', Lo), ('\u{1772}',
+        '\u{1773}', Mn), ('\u{1780}', '\u{17b3}', Lo), ('\u{17b4}', '\u{17b5}', Mn), ('\u{17b6}',
+        '\u{17b6}', Mc), ('\u{17b7}', '\u{17bd}', Mn), ('\u{17be}', '\u{17c5}', Mc), ('\u{17c6}',
+        '\u{17c6}', Mn), ('\u{17c7}', '\u{17c8}', Mc), ('\u{17c9}', '\u{17d3}', Mn), ('\u{17d4}',
+        '\u{17d6}', Po), ('\u{17d7}', '\u{17d7}', Lm), ('\u{17d8}', '\u{17da}', Po), ('\u{17db}',
+        '\u{17db}', Sc), ('\u{17dc}', '\u{17dc}', Lo), ('\u{17dd}', '\u{17dd}', Mn), ('\u{17e0}',
+        '\u{17e9}', Nd), ('\u{17f0}', '\u{17f9}', No), ('\u{1800}', '\u{1805}', Po), ('\u{1806}',
+        '\u{1806}', Pd), ('\u{1807}', '\u{180a}', Po), ('\u{180b}', '\u{180d}', Mn), ('\u{180e}',
+        '\u{180e}', Cf), ('\u{1810}', '\u{1819}', Nd), ('\u{1820}', '\u{1842}', Lo), ('\u{1843}',
+        '\u{1843}', Lm), ('\u{1844}', '\u{1877}', Lo), ('\u{1880}', '\u{18a8}', Lo), ('\u{18a9}',
+        '\u{18a9}', Mn), ('\u{18aa}', '\u{18aa}', Lo), ('\u{18b0}', '\u{18f5}', Lo), ('\u{1900}',
+        '\u{191e}', Lo), ('\u{1920}', '\u{1922}', Mn), ('\u{1923}', '\u{1926}', Mc), ('\u{1927}',
+        '\u{1928}', Mn), ('\u{1929}', '\u{192b}', Mc), ('\u{1930}', '\u{1931}', Mc), ('\u{1932}',
+        '\u{1932}', Mn), ('\u{1933}', '\u{1938}', Mc), ('\u{1939}', '\u{193b}', Mn), ('\u{1940}',
+        '\u{1940}', So), ('\u{1944}', '\u{1945}', Po), ('\u{1946}', '\u{194f}', Nd), ('\u{1950}',
+        '\u{196d}', Lo), ('\u{1970}', '\u{1974}', Lo), ('\u{1980}', '\u{19ab}', Lo), ('\u{19b0}',
+        '\u{19c0}', Mc), ('\u{19c1}', '\u{19c7}', Lo), ('\u{19c8}', '\u{19c9}', Mc), ('\u{19d0}',
+        '\u{19d9}', Nd), ('\u{19da}', '\u{19da}', No), ('\u{19de}', '\u{19ff}', So), ('\u{1a00}',
+        '\u{1a16}', Lo), ('\u{1a17}', '\u{1a18}', Mn), ('\u{1a19}', '\u{1a1a}', Mc), ('\u{1a1b}',
+        '\u{1a1b}', Mn), ('\u{1a1e}', '\u{1a1f}', Po), ('\u{1a20}', '\u{1a54}', Lo), ('\u{1a55}',
+        '\u{1a55}', Mc), ('\u{1a56}', '\u{1a56}', Mn), ('\u{1a57}', '\u{1a57}', Mc), ('\u{1a58}',
+        '\u{1a5e}', Mn), ('\u{1a60}', '\u{1a60}', Mn), ('\u{1a61}', '\u{1a61}', Mc), ('\u{1a62}',
+        '\u{1a62}', Mn), ('\u{1a63}', '\u{1a64}', Mc), ('\u{1a65}', '\u{1a6c}', Mn), ('\u{1a6d}',
+        '\u{1a72}', Mc), ('\u{1a73}', '\u{1a7c}', Mn), ('\u{1a7f}', '\u{1a7f}', Mn), ('\u{1a80}',
+        '\u{1a89}', Nd), ('\u{1a90}', '\u{1a99}', Nd), ('\u{1aa0}', '\u{1aa6}', Po), ('\u{1aa7}',
+        '\u{1aa7}', Lm), ('\u{1aa8}', '\u{1aad}', Po), ('\u{1ab0}', '\u{1abd}', Mn), ('\u{1abe}',
+        '\u{1abe}', Me), ('\u{1b00}', '\u{1b03}', Mn), ('\u{1b04}', '\u{1b04}', Mc), ('\u{1b05}',
+        '\u{1b33}', Lo), ('\u{1b34}', '\u{1b34}', Mn), ('\u{1b35}', '\u{1b35}', Mc), ('\u{1b36}',
+        '\u{1b3a}', Mn), ('\u{1b3b}', '\u{1b3b}', Mc), ('\u{1b3c}', '\u{1b3c}', Mn), ('\u{1b3d}',
+        '\u{1b41}', Mc), ('\u{1b42}', '\u{1b42}', Mn), ('\u{1b43}', '\u{1b44}', Mc), ('\u{1b45}',
+        '\u{1b4b}', Lo), ('\u{1b50}', '\u{1b59}', Nd), ('\u{1b5a}', '\u{1b60}', Po), ('\u{1b61}',
+        '\u{1b6a}', So), ('\u{1b6b}', '\u{1b73}', Mn), ('\u{1b74}', '\u{1b7c}', So), ('\u{1b80}',
+        '\u{1b81}', Mn), ('\u{1b82}', '\u{1b82}', Mc), ('\u{1b83}', '\u{1ba0}', Lo), ('\u{1ba1}',
+        '\u{1ba1}', Mc), ('\u{1ba2}', '\u{1ba5}', Mn), ('\u{1ba6}', '\u{1ba7}', Mc), ('\u{1ba8}',
+        '\u{1ba9}', Mn), ('\u{1baa}', '\u{1baa}', Mc), ('\u{1bab}', '\u{1bad}', Mn), ('\u{1bae}',
+        '\u{1baf}', Lo), ('\u{1bb0}', '\u{1bb9}', Nd), ('\u{1bba}', '\u{1be5}', Lo), ('\u{1be6}',
+        '\u{1be6}', Mn), ('\u{1be7}', '\u{1be7}', Mc), ('\u{1be8}', '\u{1be9}', Mn), ('\u{1bea}',
+        '\u{1bec}', Mc), ('\u{1bed}', '\u{1bed}', Mn), ('\u{1bee}', '\u{1bee}', Mc), ('\u{1bef}',
+        '\u{1bf1}', Mn), ('\u{1bf2}', '\u{1bf3}', Mc), ('\u{1bfc}', '\u{1bff}', Po), ('\u{1c00}',
+        '\u{1c23}', Lo), ('\u{1c24}', '\u{1c2b}', Mc), ('\u{1c2c}', '\u{1c33}', Mn), ('\u{1c34}',
+        '\u{1c35}', Mc), ('\u{1c36}', '\u{1c37}', Mn), ('\u{1c3b}', '\u{1c3f}', Po), ('\u{1c40}',
+        '\u{1c49}', Nd), ('\u{1c4d}', '\u{1c4f}', Lo), ('\u{1c50}', '\u{1c59}', Nd), ('\u{1c5a}',
+        '\u{1c77}', Lo), ('\u{1c78}', '\u{1c7d}', Lm), ('\u{1c7e}', '\u{1c7f}', Po), ('\u{1cc0}',
+        '\u{1cc7}', Po), ('\u{1cd0}', '\u{1cd2}', Mn), ('\u{1cd3}', '\u{1cd3}', Po), ('\u{1cd4}',
+        '\u{1ce0}', Mn), ('\u{1ce1}', '\u{1ce1}', Mc), ('\u{1ce2}', '\u{1ce8}', Mn), ('\u{1ce9}',
+        '\u{1cec}', Lo), ('\u{1ced}', '\u{1ced}', Mn), ('\u{1cee}', '\u{1cf1}', Lo), ('\u{1cf2}',
+        '\u{1cf3}', Mc), ('\u{1cf4}', '\u{1cf4}', Mn), ('\u{1cf5}', '\u{1cf6}', Lo), ('\u{1cf8}',
+        '\u{1cf9}', Mn), ('\u{1d00}', '\u{1d2b}', Ll), ('\u{1d2c}', '\u{1d6a}', Lm), ('\u{1d6b}',
+        '\u{1d77}', Ll), ('\u{1d78}', '\u{1d78}', Lm), ('\u{1d79}', '\u{1d9a}', Ll), ('\u{1d9b}',
+        '\u{1dbf}', Lm), ('\u{1dc0}', '\u{1df5}', Mn), ('\u{1dfc}', '\u{1dff}', Mn), ('\u{1e00}',
+        '\u{1e00}', Lu), ('\u{1e01}', '\u{1e01}', Ll), ('\u{1e02}', '\u{1e02}', Lu), ('\u{1e03}',
+        '\u{1e03}', Ll), ('\u{1e04}', '\u{1e04}', Lu), ('\u{1e05}', '\u{1e05}', Ll), ('\u{1e06}',
+        '\u{1e06}', Lu), ('\u{1e07}', '\u{1e07}', Ll), ('\u{1e08}', '\u{1e08}', Lu), ('\u{1e09}',
+        '\u{1e09}', Ll), ('\u{1e0a}', '\u{1e0a}', Lu), ('\u{1e0b}', '\u{1e0b}', Ll), ('\u{1e0c}',
+        '\u{1e0c}', Lu), ('\u{1e0d}', '\u{1e0d}', Ll), ('\u{1e0e}', '\u{1e0e}', Lu), ('\u{1e0f}',
+        '\u{1e0f}', Ll), ('\u{1e10}', '\u{1e10}', Lu), ('\u{1e11}', '\u{1e11}', Ll), ('\u{1e12}',
+        '\u{1e12}', Lu), ('\u{1e13}', '\u{1e13}', Ll), ('\u{1e14}', '\u{1e14}', Lu), ('\u{1e15}',
+        '\u{1e15}', Ll), ('\u{1e16}', '\u{1e16}', Lu), ('\u{1e17}', '\u{1e17}', Ll), ('\u{1e18}',
+        '\u{1e18}', Lu), ('\u{1e19}', '\u{1e19}', Ll), ('\u{1e1a}', '\u{1e1a}', Lu), ('\u{1e1b}',
+        '\u{1e1b}', Ll), ('\u{1e1c}', '\u{1e1c}', Lu), ('\u{1e1d}', '\u{1e1d}', Ll), ('\u{1e1e}',
+        '\u{1e1e}', Lu), ('\u{1e1f}', '\u{1e1f}', Ll), ('\u{1e20}', '\u{1e20}', Lu), ('\u{1e21}',
+        '\u{1e21}', Ll), ('\u{1e22}', '\u{1e22}', Lu), ('\u{1e23}', '\u{1e23}', Ll), ('\u{1e24}',
+        '\u{1e24}', Lu), ('\u{1e25}', '\u{1e25}', Ll), ('\u{1e26}', '\u{1e26}', Lu), ('\u{1e27}',
+        '\u{1e27}', Ll), ('\u{1e28}', '\u{1e28}', Lu), ('\u{1e29}', '\u{1e29}', Ll), ('\u{1e2a}',
+        '\u{1e2a}', Lu), ('\u{1e2b}', '\u{1e2b}', Ll), ('\u{1e2c}', '\u{1e2c}', Lu), ('\u{1e2d}',
+        '\u{1e2d}', Ll), ('\u{1e2e}', '\u{1e2e}', Lu), ('\u{1e2f}', '\u{1e2f}', Ll), ('\u{1e30}',
+        '\u{1e30}', Lu), ('\u{1e31}', '\u{1e31}', Ll), ('\u{1e32}', '\u{1e32}', Lu), ('\u{1e33}',
+        '\u{1e33}', Ll), ('\u{1e34}', '\u{1e34}', Lu), ('\u{1e35}', '\u{1e35}', Ll), ('\u{1e36}',
+        '\u{1e36}', Lu), ('\u{1e37}', '\u{1e37}', Ll), ('\u{1e38}', '\u{1e38}', Lu), ('\u{1e39}',
+        '\u{1e39}', Ll), ('\u{1e3a}', '\u{1e3a}', Lu), ('\u{1e3b}', '\u{1e3b}', Ll), ('\u{1e3c}',
+        '\u{1e3c}', Lu), ('\u{1e3d}', '\u{1e3d}', Ll), ('\u{1e3e}', '\u{1e3e}', Lu), ('\u{1e3f}',
+        '\u{1e3f}', Ll), ('\u{1e40}', '\u{1e40}', Lu), ('\u{1e41}', '\u{1e41}', Ll), ('\u{1e42}',
+        '\u{1e42}', Lu), ('\u{1e43}', '\u{1e43}', Ll), ('\u{1e44}', '\u{1e44}', Lu), ('\u{1e45}',
+        '\u{1e45}', Ll), ('\u{1e46}', '\u{1e46}', Lu), ('\u{1e47}', '\u{1e47}', Ll), ('\u{1e48}',
+        '\u{1e48}', Lu), ('\u{1e49}', '\u{1e49}', Ll), ('\u{1e4a}', '\u{1e4a}', Lu), ('\u{1e4b}',
+        '\u{1e4b}', Ll), ('\u{1e4c}', '\u{1e4c}', Lu), ('\u{1e4d}', '\u{1e4d}', Ll), ('\u{1e4e}',
+        '\u{1e4e}', Lu), ('\u{1e4f}', '\u{1e4f}', Ll), ('\u{1e50}', '\u{1e50}', Lu), ('\u{1e51}',
+        '\u{1e51}', Ll), ('\u{1e52}', '\u{1e52}', Lu), ('\u{1e53}', '\u{1e53}', Ll), ('\u{1e54}',
+        '\u{1e54}', Lu), ('\u{1e55}', '\u{1e55}', Ll), ('\u{1e56}', '\u{1e56}', Lu), ('\u{1e57}',
+        '\u{1e57}', Ll), ('\u{1e58}', '\u{1e58}', Lu), ('\u{1e59}', '\u{1e59}', Ll), ('\u{1e5a}',
+        '\u{1e5a}', Lu), ('\u{1e5b}', '\u{1e5b}', Ll), ('\u{1e5c}', '\u{1e5c}', Lu), ('\u{1e5d}',
+        '\u{1e5d}', Ll), ('\u{1e5e}', '\u{1e5e}', Lu), ('\u{1e5f}', '\u{1e5f}', Ll), ('\u{1e60}',
+        '\u{1e60}', Lu), ('\u{1e61}', '\u{1e61}', Ll), ('\u{1e62}', '\u{1e62}', Lu), ('\u{1e63}',
+        '\u{1e63}', Ll), ('\u{1e64}', '\u{1e64}', Lu), ('\u{1e65}', '\u{1e65}', Ll), ('\u{1e66}',
+        '\u{1e66}', Lu), ('\u{1e67}', '\u{1e67}', Ll), ('\u{1e68}', '\u{1e68}', Lu), ('\u{1e69}',
+        '\u{1e69}', Ll), ('\u{1e6a}', '\u{1e6a}', Lu), ('\u{1e6b}', '\u{1e6b}', Ll), ('\u{1e6c}',
+        '\u{1e6c}', Lu), ('\u{1e6d}', '\u{1e6d}', Ll), ('\u{1e6e}', '\u{1e6e}', Lu), ('\u{1e6f}',
+        '\u{1e6f}', Ll), ('\u{1e70}', '\u{1e70}', Lu), ('\u{1e71}', '\u{1e71}', Ll), ('\u{1e72}',
+        '\u{1e72}', Lu), ('\u{1e73}', '\u{1e73}', Ll), ('\u{1e74}', '\u{1e74}', Lu), ('\u{1e75}',
+        '\u{1e75}', Ll), ('\u{1e76}', '\u{1e76}', Lu), ('\u{1e77}', '\u{1e77}', Ll), ('\u{1e78}',
+        '\u{1e78}', Lu), ('\u{1e79}', '\u{1e79}', Ll), ('\u{1e7a}', '\u{1e7a}', Lu), ('\u{1e7b}',
+        '\u{1e7b}', Ll), ('\u{1e7c}', '\u{1e7c}', Lu), ('\u{1e7d}', '\u{1e7d}', Ll), ('\u{1e7e}',
+        '\u{1e7e}', Lu), ('\u{1e7f}', '\u{1e7f}', Ll), ('\u{1e80}', '\u{1e80}', Lu), ('\u{1e81}',
+        '\u{1e81}', Ll), ('\u{1e82}', '\u{1e82}', Lu), ('\u{1e83}', '\u{1e83}', Ll), ('\u{1e84}',
+        '\u{1e84}', Lu), ('\u{1e85}', '\u{1e85}', Ll), ('\u{1e86}', '\u{1e86}', Lu), ('\u{1e87}',
+        '\u{1e87}', Ll), ('\u{1e88}', '\u{1e88}', Lu), ('\u{1e89}', '\u{1e89}', Ll), ('\u{1e8a}',
+        '\u{1e8a}', Lu), ('\u{1e8b}', '\u{1e8b}', Ll), ('\u{1e8c}', '\u{1e8c}', Lu), ('\u{1e8d}',
+        '\u{1e8d}', Ll), ('\u{1e8e}', '\u{1e8e}', Lu), ('\u{1e8f}', '\u{1e8f}', Ll), ('\u{1e90}',
+        '\u{1e90}', Lu), ('\u{1e91}', '\u{1e91}', Ll), ('\u{1e92}', '\u{1e92}', Lu), ('\u{1e93}',
+        '\u{1e93}', Ll), ('\u{1e94}', '\u{1e94}', Lu), ('\u{1e95}', '\u{1e9d}', Ll), ('\u{1e9e}',
+        '\u{1e9e}', Lu), ('\u{1e9f}', '\u{1e9f}', Ll), ('\u{1ea0}', '\u{1ea0}', Lu), ('\u{1ea1}',
+        '\u{1ea1}', Ll), ('\u{1ea2}', '\u{1ea2}', Lu), ('\u{1ea3}', '\u{1ea3}', Ll), ('\u{1ea4}',
+        '\u{1ea4}', Lu), ('\u{1ea5}', '\u{1ea5}', Ll), ('\u{1ea6}', '\u{1ea6}', Lu), ('\u{1ea7}',
+        '\u{1ea7}', Ll), ('\u{1ea8}', '\u{1ea8}', Lu), ('\u{1ea9}', '\u{1ea9}', Ll), ('\u{1eaa}',
+        '\u{1eaa}', Lu), ('\u{1eab}', '\u{1eab}', Ll), ('\u{1eac}', '\u{1eac}', Lu), ('\u{1ead}',
+        '\u{1ead}', Ll), ('\u{1eae}', '\u{1eae}', Lu), ('\u{1eaf}', '\u{1eaf}', Ll), ('\u{1eb0}',
+        '\u{1eb0}', Lu), ('\u{1eb1}', '\u{1eb1}', Ll), ('\u{1eb2}', '\u{1eb2}', Lu), ('\u{1eb3}',
+        '\u{1eb3}', Ll), ('\u{1eb4}', '\u{1eb4}', Lu), ('\u{1eb5}', '\u{1eb5}', Ll), ('\u{1eb6}',
+        '\u{1eb6}', Lu), ('\u{1eb7}', '\u{1eb7}', Ll), ('\u{1eb8}', '\u{1eb8}', Lu), ('\u{1eb9}',
+        '\u{1eb9}', Ll), ('\u{1eba}', '\u{1eba}', Lu), ('\u{1ebb}', '\u{1ebb}', Ll), ('\u{1ebc}',
+        '\u{1ebc}', Lu), ('\u{1ebd}', '\u{1ebd}', Ll), ('\u{1ebe}', '\u{1ebe}', Lu), ('\u{1ebf}',
+        '\u{1ebf}', Ll), ('\u{1ec0}', '\u{1ec0}', Lu), ('\u{1ec1}', '\u{1ec1}', Ll), ('\u{1ec2}',
+        '\u{1ec2}', Lu), ('\u{1ec3}', '\u{1ec3}', Ll), ('\u{1ec4}', '\u{1ec4}', Lu), ('\u{1ec5}',
+        '\u{1ec5}', Ll), ('\u{1ec6}', '\u{1ec6}', Lu), ('\u{1ec7}', '\u{1ec7}', Ll), ('\u{1ec8}',
+        '\u{1ec8}', Lu), ('\u{1ec9}', '\u{1ec9}', Ll), ('\u{1eca}', '\u{1eca}', Lu), ('\u{1ecb}',
+        '\u{1ecb}', Ll), ('\u{1ecc}', '\u{1ecc}', Lu), ('\u{1ecd}', '\u{1ecd}', Ll), ('\u{1ece}',
+        '\u{1ece}', Lu), ('\u{1ecf}', '\u{1ecf}', Ll), ('\u{1ed0}', '\u{1ed0}', Lu), ('\u{1ed1}',
+        '\u{1ed1}', Ll), ('\u{1ed2}', '\u{1ed2}', Lu), ('\u{1ed3}', '\u{1ed3}', Ll), ('\u{1ed4}',
+        '\u{1ed4}', Lu), ('\u{1ed5}', '\u{1ed5}', Ll), ('\u{1ed6}', '\u{1ed6}', Lu), ('\u{1ed7}',
+        '\u{1ed7}', Ll), ('\u{1ed8}', '\u{1ed8}', Lu), ('\u{1ed9}', '\u{1ed9}', Ll), ('\u{1eda}',
+        '\u{1eda}', Lu), ('\u{1edb}', '\u{1edb}', Ll), ('\u{1edc}', '\u{1edc}', Lu), ('\u{1edd}',
+        '\u{1edd}', Ll), ('\u{1ede}', '\u{1ede}', Lu), ('\u{1edf}', '\u{1edf}', Ll), ('\u{1ee0}',
+        '\u{1ee0}', Lu), ('\u{1ee1}', '\u{1ee1}', Ll), ('\u{1ee2}', '\u{1ee2}', Lu), ('\u{1ee3}',
+        '\u{1ee3}', Ll), ('\u{1ee4}', '\u{1ee4}', Lu), ('\u{1ee5}', '\u{1ee5}', Ll), ('\u{1ee6}',
+        '\u{1ee6}', Lu), ('\u{1ee7}', '\u{1ee7}', Ll), ('\u{1ee8}', '\u{1ee8}', Lu), ('\u{1ee9}',
+        '\u{1ee9}', Ll), ('\u{1eea}', '\u{1eea}', Lu), ('\u{1eeb}', '\u{1eeb}', Ll), ('\u{1eec}',
+        '\u{1eec}', Lu), ('\u{1eed}', '\u{1eed}', Ll), ('\u{1eee}', '\u{1eee}', Lu), ('\u{1eef}',
+        '\u{1eef}', Ll), ('\u{1ef0}', '\u{1ef0}', Lu), ('\u{1ef1}', '\u{1ef1}', Ll), ('\u{1ef2}',
+        '\u{1ef2}', Lu), ('\u{1ef3}', '\u{1ef3}', Ll), ('\u{1ef4}', '\u{1ef4}', Lu), ('\u{1ef5}',
+        '\u{1ef5}', Ll), ('\u{1ef6}', '\u{1ef6}', Lu), ('\u{1ef7}', '\u{1ef7}', Ll), ('\u{1ef8}',
+        '\u{1ef8}', Lu), ('\u{1ef9}', '\u{1ef9}', Ll), ('\u{1efa}', '\u{1efa}', Lu), ('\u{1efb}',
+        '\u{1efb}', Ll), ('\u{1efc}', '\u{1efc}', Lu), ('\u{1efd}', '\u{1efd}', Ll), ('\u{1efe}',
+        '\u{1efe}', Lu), ('\u{1eff}', '\u{1f07}', Ll), ('\u{1f08}', '\u{1f0f}', Lu), ('\u{1f10}',
+        '\u{1f15}', Ll), ('\u{1f18}', '\u{1f1d}', Lu), ('\u{1f20}', '\u{1f27}', Ll), ('\u{1f28}',
+        '\u{1f2f}', Lu), ('\u{1f30}', '\u{1f37}', Ll), ('\u{1f38}', '\u{1f3f}', Lu), ('\u{1f40}',
+        '\u{1f45}', Ll), ('\u{1f48}', '\u{1f4d}', Lu), ('\u{1f50}', '\u{1f57}', Ll), ('\u{1f59}',
+        '\u{1f59}', Lu), ('\u{1f5b}', '\u{1f5b}', Lu), ('\u{1f5d}', '\u{1f5d}', Lu), ('\u{1f5f}',
+        '\u{1f5f}', Lu), ('\u{1f60}', '\u{1f67}', Ll), ('\u{1f68}', '\u{1f6f}', Lu), ('\u{1f70}',
+        '\u{1f7d}', Ll), ('\u{1f80}', '\u{1f87}', Ll), ('\u{1f88}', '\u{1f8f}', Lt), ('\u{1f90}',
+        '\u{1f97}', Ll), ('\u{1f98}', '\u{1f9f}', Lt), ('\u{1fa0}', '\u{1fa7}', Ll), ('\u{1fa8}',
+        '\u{1faf}', Lt), ('\u{1fb0}', '\u{1fb4}', Ll), ('\u{1fb6}', '\u{1fb7}', Ll), ('\u{1fb8}',
+        '\u{1fbb}', Lu), ('\u{1fbc}', '\u{1fbc}', Lt), ('\u{1fbd}', '\u{1fbd}', Sk), ('\u{1fbe}',
+        '\u{1fbe}', Ll), ('\u{1fbf}', '\u{1fc1}', Sk), ('\u{1fc2}', '\u{1fc4}', Ll), ('\u{1fc6}',
+        '\u{1fc7}', Ll), ('\u{1fc8}', '\u{1fcb}', Lu), ('\u{1fcc}', '\u{1fcc}', Lt), ('\u{1fcd}',
+        '\u{1fcf}', Sk), ('\u{1fd0}', '\u{1fd3}', Ll), ('\u{1fd6}', '\u{1fd7}', Ll), ('\u{1fd8}',
+        '\u{1fdb}', Lu), ('\u{1fdd}', '\u{1fdf}', Sk), ('\u{1fe0}', '\u{1fe7}', Ll), ('\u{1fe8}',
+        '\u{1fec}', Lu), ('\u{1fed}', '\u{1fef}', Sk), ('\u{1ff2}', '\u{1ff4}', Ll), ('\u{1ff6}',
+        '\u{1ff7}', Ll), ('\u{1ff8}', '\u{1ffb}', Lu), ('\u{1ffc}', '\u{1ffc}', Lt), ('\u{1ffd}',
+        '\u{1ffe}', Sk), ('\u{2000}', '\u{200a}', Zs), ('\u{200b}', '\u{200f}', Cf), ('\u{2010}',
+        '\u{2015}', Pd), ('\u{2016}', '\u{2017}', Po), ('\u{2018}', '\u{2018}', Pi), ('\u{2019}',
+        '\u{2019}', Pf), ('\u{201a}', '\u{201a}', Ps), ('\u{201b}', '\u{201c}', Pi), ('\u{201d}',
+        '\u{201d}', Pf), ('\u{201e}', '\u{201e}', Ps), ('\u{201f}', '\u{201f}', Pi), ('\u{2020}',
+        '\u{2027}', Po), ('\u{2028}', '\u{2028}', Zl), ('\u{2029}', '\u{2029}', Zp), ('\u{202a}',
+        '\u{202e}', Cf), ('\u{202f}', '\u{202f}', Zs), ('\u{2030}', '\u{2038}', Po), ('\u{2039}',
+        '\u{2039}', Pi), ('\u{203a}', '\u{203a}', Pf), ('\u{203b}', '\u{203e}', Po), ('\u{203f}',
+        '\u{2040}', Pc), ('\u{2041}', '\u{2043}', Po), ('\u{2044}', '\u{2044}', Sm), ('\u{2045}',
+        '\u{2045}', Ps), ('\u{2046}', '\u{2046}', Pe), ('\u{2047}', '\u{2051}', Po), ('\u{2052}',
+        '\u{2052}', Sm), ('\u{2053}', '\u{2053}', Po), ('\u{2054}', '\u{2054}', Pc), ('\u{2055}',
+        '\u{205e}', Po), ('\u{205f}', '\u{205f}', Zs), ('\u{2060}', '\u{2064}', Cf), ('\u{2066}',
+        '\u{206f}', Cf), ('\u{2070}', '\u{2070}', No), ('\u{2071}', '\u{2071}', Lm), ('\u{2074}',
+        '\u{2079}', No), ('\u{207a}', '\u{207c}', Sm), ('\u{207d}', '\u{207d}', Ps), ('\u{207e}',
+        '\u{207e}', Pe), ('\u{207f}', '\u{207f}', Lm), ('\u{2080}', '\u{2089}', No), ('\u{208a}',
+        '\u{208c}', Sm), ('\u{208d}', '\u{208d}', Ps), ('\u{208e}', '\u{208e}', Pe), ('\u{2090}',
+        '\u{209c}', Lm), ('\u{20a0}', '\u{20bd}', Sc), ('\u{20d0}', '\u{20dc}', Mn), ('\u{20dd}',
+        '\u{20e0}', Me), ('\u{20e1}', '\u{20e1}', Mn), ('\u{20e2}', '\u{20e4}', Me), ('\u{20e5}',
+        '\u{20f0}', Mn), ('\u{2100}', '\u{2101}', So), ('\u{2102}', '\u{2102}', Lu), ('\u{2103}',
+        '\u{2106}', So), ('\u{2107}', '\u{2107}', Lu), ('\u{2108}', '\u{2109}', So), ('\u{210a}',
+        '\u{210a}', Ll), ('\u{210b}', '\u{210d}', Lu), ('\u{210e}', '\u{210f}', Ll), ('\u{2110}',
+        '\u{2112}', Lu), ('\u{2113}', '\u{2113}', Ll), ('\u{2114}', '\u{2114}', So), ('\u{2115}',
+        '\u{2115}', Lu), ('\u{2116}', '\u{2117}', So), ('\u{2118}', '\u{2118}', Sm), ('\u{2119}',
+        '\u{211d}', Lu), ('\u{211e}', '\u{2123}', So), ('\u{2124}', '\u{2124}', Lu), ('\u{2125}',
+        '\u{2125}', So), ('\u{2126}', '\u{2126}', Lu), ('\u{2127}', '\u{2127}', So), ('\u{2128}',
+        '\u{2128}', Lu), ('\u{2129}', '\u{2129}', So), ('\u{212a}', '\u{212d}', Lu), ('\u{212e}',
+        '\u{212e}', So), ('\u{212f}', '\u{212f}', Ll), ('\u{2130}', '\u{2133}', Lu), ('\u{2134}',
+        '\u{2134}', Ll), ('\u{2135}', '\u{2138}', Lo), ('\u{2139}', '\u{2139}', Ll), ('\u{213a}',
+        '\u{213b}', So), ('\u{213c}', '\u{213d}', Ll), ('\u{213e}', '\u{213f}', Lu), ('\u{2140}',
+        '\u{2144}', Sm), ('\u{2145}', '\u{2145}', Lu), ('\u{2146}', '\u{2149}', Ll), ('\u{214a}',
+        '\u{214a}', So), ('\u{214b}', '\u{214b}', Sm), ('\u{214c}', '\u{214d}', So), ('\u{214e}',
+        '\u{214e}', Ll), ('\u{214f}', '\u{214f}', So), ('\u{2150}', '\u{215f}', No), ('\u{2160}',
+        '\u{2182}', Nl), ('\u{2183}', '\u{2183}', Lu), ('\u{2184}', '\u{2184}', Ll), ('\u{2185}',
+        '\u{2188}', Nl), ('\u{2189}', '\u{2189}', No), ('\u{2190}', '\u{2194}', Sm), ('\u{2195}',
+        '\u{2199}', So), ('\u{219a}', '\u{219b}', Sm), ('\u{219c}', '\u{219f}', So), ('\u{21a0}',
+        '\u{21a0}', Sm), ('\u{21a1}', '\u{21a2}', So), ('\u{21a3}', '\u{21a3}', Sm), ('\u{21a4}',
+        '\u{21a5}', So), ('\u{21a6}', '\u{21a6}', Sm), ('\u{21a7}', '\u{21ad}', So), ('\u{21ae}',
+        '\u{21ae}', Sm), ('\u{21af}', '\u{21cd}', So), ('\u{21ce}', '\u{21cf}', Sm), ('\u{21d0}',
+        '\u{21d1}', So), ('\u{21d2}', '\u{21d2}', Sm), ('\u{21d3}', '\u{21d3}', So), ('\u{21d4}',
+        '\u{21d4}', Sm), ('\u{21d5}', '\u{21f3}', So), ('\u{21f4}', '\u{22ff}', Sm), ('\u{2300}',
+        '\u{2307}', So), ('\u{2308}', '\u{2308}', Ps), ('\u{2309}', '\u{2309}', Pe), ('\u{230a}',
+        '\u{230a}', Ps), ('\u{230b}', '\u{230b}', Pe), ('\u{230c}', '\u{231f}', So), ('\u{2320}',
+        '\u{2321}', Sm), ('\u{2322}', '\u{2328}', So), ('\u{2329}', '\u{2329}', Ps), ('\u{232a}',
+        '\u{232a}', Pe), ('\u{232b}', '\u{237b}', So), ('\u{237c}', '\u{237c}', Sm), ('\u{237d}',
+        '\u{239a}', So), ('\u{239b}', '\u{23b3}', Sm), ('\u{23b4}', '\u{23db}', So), ('\u{23dc}',
+        '\u{23e1}', Sm), ('\u{23e2}', '\u{23fa}', So), ('\u{2400}', '\u{2426}', So), ('\u{2440}',
+        '\u{244a}', So), ('\u{2460}', '\u{249b}', No), ('\u{249c}', '\u{24e9}', So), ('\u{24ea}',
+        '\u{24ff}', No), ('\u{2500}', '\u{25b6}', So), ('\u{25b7}', '\u{25b7}', Sm), ('\u{25b8}',
+        '\u{25c0}', So), ('\u{25c1}', '\u{25c1}', Sm), ('\u{25c2}', '\u{25f7}', So), ('\u{25f8}',
+        '\u{25ff}', Sm), ('\u{2600}', '\u{266e}', So), ('\u{266f}', '\u{266f}', Sm), ('\u{2670}',
+        '\u{2767}', So), ('\u{2768}', '\u{2768}', Ps), ('\u{2769}', '\u{2769}', Pe), ('\u{276a}',
+        '\u{276a}', Ps), ('\u{276b}', '\u{276b}', Pe), ('\u{276c}', '\u{276c}', Ps), ('\u{276d}',
+        '\u{276d}', Pe), ('\u{276e}', '\u{276e}', Ps), ('\u{276f}', '\u{276f}', Pe), ('\u{2770}',
+        '\u{2770}', Ps), ('\u{2771}', '\u{2771}', Pe), ('\u{2772}', '\u{2772}', Ps), ('\u{2773}',
+        '\u{2773}', Pe), ('\u{2774}', '\u{2774}', Ps), ('\u{2775}', '\u{2775}', Pe), ('\u{2776}',
+        '\u{2793}', No), ('\u{2794}', '\u{27bf}', So), ('\u{27c0}', '\u{27c4}', Sm), ('\u{27c5}',
+        '\u{27c5}', Ps), ('\u{27c6}', '\u{27c6}', Pe), ('\u{27c7}', '\u{27e5}', Sm), ('\u{27e6}',
+        '\u{27e6}', Ps), ('\u{27e7}', '\u{27e7}', Pe), ('\u{27e8}', '\u{27e8}', Ps), ('\u{27e9}',
+        '\u{27e9}', Pe), ('\u{27ea}', '\u{27ea}', Ps), ('\u{27eb}', '\u{27eb}', Pe), ('\u{27ec}',
+        '\u{27ec}', Ps), ('\u{27ed}', '\u{27ed}', Pe), ('\u{27ee}', '\u{27ee}', Ps), ('\u{27ef}',
+        '\u{27ef}', Pe), ('\u{27f0}', '\u{27ff}', Sm), ('\u{2800}', '\u{28ff}', So), ('\u{2900}',
+        '\u{2982}', Sm), ('\u{2983}', '\u{2983}', Ps), ('\u{2984}', '\u{2984}', Pe), ('\u{2985}',
+        '\u{2985}', Ps), ('\u{2986}', '\u{2986}', Pe), ('\u{2987}', '\u{2987}', Ps), ('\u{2988}',
+        '\u{2988}', Pe), ('\u{2989}', '\u{2989}', Ps), ('\u{298a}', '\u{298a}', Pe), ('\u{298b}',
+        '\u{298b}', Ps), ('\u{298c}', '\u{298c}', Pe), ('\u{298d}', '\u{298d}', Ps), ('\u{298e}',
+        '\u{298e}', Pe), ('\u{298f}', '\u{298f}', Ps), ('\u{2990}', '\u{2990}', Pe), ('\u{2991}',
+        '\u{2991}', Ps), ('\u{2992}', '\u{2992}', Pe), ('\u{2993}', '\u{2993}', Ps), ('\u{2994}',
+        '\u{2994}', Pe), ('\u{2995}', '\u{2995}', Ps), ('\u{2996}', '\u{2996}', Pe), ('\u{2997}',
+        '\u{2997}', Ps), ('\u{2998}', '\u{2998}', Pe), ('\u{2999}', '\u{29d7}', Sm), ('\u{29d8}',
+        '\u{29d8}', Ps), ('\u{29d9}', '\u{29d9}', Pe), ('\u{29da}', '\u{29da}', Ps), ('\u{29db}',
+        '\u{29db}', Pe), ('\u{29dc}', '\u{29fb}', Sm), ('\u{29fc}', '\u{29fc}', Ps), ('\u{29fd}',
+        '\u{29fd}', Pe), ('\u{29fe}', '\u{2aff}', Sm), ('\u{2b00}', '\u{2b2f}', So), ('\u{2b30}',
+        '\u{2b44}', Sm), ('\u{2b45}', '\u{2b46}', So), ('\u{2b47}', '\u{2b4c}', Sm), ('\u{2b4d}',
+        '\u{2b73}', So), ('\u{2b76}', '\u{2b95}', So), ('\u{2b98}', '\u{2bb9}', So), ('\u{2bbd}',
+        '\u{2bc8}', So), ('\u{2bca}', '\u{2bd1}', So), ('\u{2c00}', '\u{2c2e}', Lu), ('\u{2c30}',
+        '\u{2c5e}', Ll), ('\u{2c60}', '\u{2c60}', Lu), ('\u{2c61}', '\u{2c61}', Ll), ('\u{2c62}',
+        '\u{2c64}', Lu), ('\u{2c65}', '\u{2c66}', Ll), ('\u{2c67}', '\u{2c67}', Lu), ('\u{2c68}',
+        '\u{2c68}', Ll), ('\u{2c69}', '\u{2c69}', Lu), ('\u{2c6a}', '\u{2c6a}', Ll), ('\u{2c6b}',
+        '\u{2c6b}', Lu), ('\u{2c6c}', '\u{2c6c}', Ll), ('\u{2c6d}', '\u{2c70}', Lu), ('\u{2c71}',
+        '\u{2c71}', Ll), ('\u{2c72}', '\u{2c72}', Lu), ('\u{2c73}', '\u{2c74}', Ll), ('\u{2c75}',
+        '\u{2c75}', Lu), ('\u{2c76}', '\u{2c7b}', Ll), ('\u{2c7c}', '\u{2c7d}', Lm), ('\u{2c7e}',
+        '\u{2c80}', Lu), ('\u{2c81}', '\u{2c81}', Ll), ('\u{2c82}', '\u{2c82}', Lu), ('\u{2c83}',
+        '\u{2c83}', Ll), ('\u{2c84}', '\u{2c84}', Lu), ('\u{2c85}', '\u{2c85}', Ll), ('\u{2c86}',
+        '\u{2c86}', Lu), ('\u{2c87}', '\u{2c87}', Ll), ('\u{2c88}', '\u{2c88}', Lu), ('\u{2c89}',
+        '\u{2c89}', Ll), ('\u{2c8a}', '\u{2c8a}', Lu), ('\u{2c8b}', '\u{2c8b}', Ll), ('\u{2c8c}',
+        '\u{2c8c}', Lu), ('\u{2c8d}', '\u{2c8d}', Ll), ('\u{2c8e}', '\u{2c8e}', Lu), ('\u{2c8f}',
+        '\u{2c8f}', Ll), ('\u{2c90}', '\u{2c90}', Lu), ('\u{2c91}', '\u{2c91}', Ll), ('\u{2c92}',
+        '\u{2c92}', Lu), ('\u{2c93}', '\u{2c93}', Ll), ('\u{2c94}', '\u{2c94}', Lu), ('\u{2c95}',
+        '\u{2c95}', Ll), ('\u{2c96}', '\u{2c96}', Lu), ('\u{2c97}', '\u{2c97}', Ll), ('\u{2c98}',
+        '\u{2c98}', Lu), ('\u{2c99}', '\u{2c99}', Ll), ('\u{2c9a}', '\u{2c9a}', Lu), ('\u{2c9b}',
+        '\u{2c9b}', Ll), ('\u{2c9c}', '\u{2c9c}', Lu), ('\u{2c9d}', '\u{2c9d}', Ll), ('\u{2c9e}',
+        '\u{2c9e}', Lu), ('\u{2c9f}', '\u{2c9f}', Ll), ('\u{2ca0}', '\u{2ca0}', Lu), ('\u{2ca1}',
+        '\u{2ca1}', Ll), ('\u{2ca2}', '\u{2ca2}', Lu), ('\u{2ca3}', '\u{2ca3}', Ll), ('\u{2ca4}',
+        '\u{2ca4}', Lu), ('\u{2ca5}', '\u{2ca5}', Ll), ('\u{2ca6}', '\u{2ca6}', Lu), ('\u{2ca7}',
+        '\u{2ca7}', Ll), ('\u{2ca8}', '\u{2ca8}', Lu), ('\u{2ca9}', '\u{2ca9}', Ll), ('\u{2caa}',
+        '\u{2caa}', Lu), ('\u{2cab}', '\u{2cab}', Ll), ('\u{2cac}', '\u{2cac}', Lu), ('\u{2cad}',
+        '\u{2cad}', Ll), ('\u{2cae}', '\u{2cae}', Lu), ('\u{2caf}', '\u{2caf}', Ll), ('\u{2cb0}',
+        '\u{2cb0}', Lu), ('\u{2cb1}', '\u{2cb1}', Ll), ('\u{2cb2}', '\u{2cb2}', Lu), ('\u{2cb3}',
+        '\u{2cb3}', Ll), ('\u{2cb4}', '\u{2cb4}', Lu), ('\u{2cb5}', '\u{2cb5}', Ll), ('\u{2cb6}',
+        '\u{2cb6}', Lu), ('\u{2cb7}', '\u{2cb7}', Ll), ('\u{2cb8}', '\u{2cb8}', Lu), ('\u{2cb9}',
+        '\u{2cb9}', Ll), ('\u{2cba}', '\u{2cba}', Lu), ('\u{2cbb}', '\u{2cbb}', Ll), ('\u{2cbc}',
+        '\u{2cbc}', Lu), ('\u{2cbd}', '\u{2cbd}', Ll), ('\u{2cbe}', '\u{2cbe}', Lu), ('\u{2cbf}',
+        '\u{2cbf}', Ll), ('\u{2cc0}', '\u{2cc0}', Lu), ('\u{2cc1}', '\u{2cc1}', Ll), ('\u{2cc2}',
+        '\u{2cc2}', Lu), ('\u{2cc3}', '\u{2cc3}', Ll), ('\u{2cc4}', '\u{2cc4}', Lu), ('\u{2cc5}',
+        '\u{2cc5}', Ll), ('\u{2cc6}', '\u{2cc6}', Lu), ('\u{2cc7}', '\u{2cc7}', Ll), ('\u{2cc8}',
+        '\u{2cc8}', Lu), ('\u{2cc9}', '\u{2cc9}', Ll), ('\u{2cca}', '\u{2cca}', Lu), ('\u{2ccb}',
+        '\u{2ccb}', Ll), ('\u{2ccc}', '\u{2ccc}', Lu), ('\u{2ccd}', '\u{2ccd}', Ll), ('\u{2cce}',
+        '\u{2cce}', Lu), ('\u{2ccf}', '\u{2ccf}', Ll), ('\u{2cd0}', '\u{2cd0}', Lu), ('\u{2cd1}',
+        '\u{2cd1}', Ll), ('\u{2cd2}', '\u{2cd2}', Lu), ('\u{2cd3}', '\u{2cd3}', Ll), ('\u{2cd4}',
+        '\u{2cd4}', Lu), ('\u{2cd5}', '\u{2cd5}', Ll), ('\u{2cd6}', '\u{2cd6}', Lu), ('\u{2cd7}',
+        '\u{2cd7}', Ll), ('\u{2cd8}', '\u{2cd8}', Lu), ('\u{2cd9}', '\u{2cd9}', Ll), ('\u{2cda}',
+        '\u{2cda}', Lu), ('\u{2cdb}', '\u{2cdb}', Ll), ('\u{2cdc}', '\u{2cdc}', Lu), ('\u{2cdd}',
+        '\u{2cdd}', Ll), ('\u{2cde}', '\u{2cde}', Lu), ('\u{2cdf}', '\u{2cdf}', Ll), ('\u{2ce0}',
+        '\u{2ce0}', Lu), ('\u{2ce1}', '\u{2ce1}', Ll), ('\u{2ce2}', '\u{2ce2}', Lu), ('\u{2ce3}',
+        '\u{2ce4}', Ll), ('\u{2ce5}', '\u{2cea}', So), ('\u{2ceb}', '\u{2ceb}', Lu), ('\u{2cec}',
+        '\u{2cec}', Ll), ('\u{2ced}', '\u{2ced}', Lu), ('\u{2cee}', '\u{2cee}', Ll), ('\u{2cef}',
+        '\u{2cf1}', Mn), ('\u{2cf2}', '\u{2cf2}', Lu), ('\u{2cf3}', '\u{2cf3}', Ll), ('\u{2cf9}',
+        '\u{2cfc}', Po), ('\u{2cfd}', '\u{2cfd}', No), ('\u{2cfe}', '\u{2cff}', Po), ('\u{2d00}',
+        '\u{2d25}', Ll), ('\u{2d27}', '\u{2d27}', Ll), ('\u{2d2d}', '\u{2d2d}', Ll), ('\u{2d30}',
+        '\u{2d67}', Lo), ('\u{2d6f}', '\u{2d6f}', Lm), ('\u{2d70}', '\u{2d70}', Po), ('\u{2d7f}',
+        '\u{2d7f}', Mn), ('\u{2d80}', '\u{2d96}', Lo), ('\u{2da0}', '\u{2da6}', Lo), ('\u{2da8}',
+        '\u{2dae}', Lo), ('\u{2db0}', '\u{2db6}', Lo), ('\u{2db8}', '\u{2dbe}', Lo), ('\u{2dc0}',
+        '\u{2dc6}', Lo), ('\u{2dc8}', '\u{2dce}', Lo), ('\u{2dd0}', '\u{2dd6}', Lo), ('\u{2dd8}',
+        '\u{2dde}', Lo), ('\u{2de0}', '\u{2dff}', Mn), ('\u{2e00}', '\u{2e01}', Po), ('\u{2e02}',
+        '\u{2e02}', Pi), ('\u{2e03}', '\u{2e03}', Pf), ('\u{2e04}', '\u{2e04}', Pi), ('\u{2e05}',
+        '\u{2e05}', Pf), ('\u{2e06}', '\u{2e08}', Po), ('\u{2e09}', '\u{2e09}', Pi), ('\u{2e0a}',
+        '\u{2e0a}', Pf), ('\u{2e0b}', '\u{2e0b}', Po), ('\u{2e0c}', '\u{2e0c}', Pi), ('\u{2e0d}',
+        '\u{2e0d}', Pf), ('\u{2e0e}', '\u{2e16}', Po), ('\u{2e17}', '\u{2e17}', Pd), ('\u{2e18}',
+        '\u{2e19}', Po), ('\u{2e1a}', '\u{2e1a}', Pd), ('\u{2e1b}', '\u{2e1b}', Po), ('\u{2e1c}',
+        '\u{2e1c}', Pi), ('\u{2e1d}', '\u{2e1d}', Pf), ('\u{2e1e}', '\u{2e1f}', Po), ('\u{2e20}',
+        '\u{2e20}', Pi), ('\u{2e21}', '\u{2e21}', Pf), ('\u{2e22}', '\u{2e22}', Ps), ('\u{2e23}',
+        '\u{2e23}', Pe), ('\u{2e24}', '\u{2e24}', Ps), ('\u{2e25}', '\u{2e25}', Pe), ('\u{2e26}',
+        '\u{2e26}', Ps), ('\u{2e27}', '\u{2e27}', Pe), ('\u{2e28}', '\u{2e28}', Ps), ('\u{2e29}',
+        '\u{2e29}', Pe), ('\u{2e2a}', '\u{2e2e}', Po), ('\u{2e2f}', '\u{2e2f}', Lm), ('\u{2e30}',
+        '\u{2e39}', Po), ('\u{2e3a}', '\u{2e3b}', Pd), ('\u{2e3c}', '\u{2e3f}', Po), ('\u{2e40}',
+        '\u{2e40}', Pd), ('\u{2e41}', '\u{2e41}', Po), ('\u{2e42}', '\u{2e42}', Ps), ('\u{2e80}',
+        '\u{2e99}', So), ('\u{2e9b}', '\u{2ef3}', So), ('\u{2f00}', '\u{2fd5}', So), ('\u{2ff0}',
+        '\u{2ffb}', So), ('\u{3000}', '\u{3000}', Zs), ('\u{3001}', '\u{3003}', Po), ('\u{3004}',
+        '\u{3004}', So), ('\u{3005}', '\u{3005}', Lm), ('\u{3006}', '\u{3006}', Lo), ('\u{3007}',
+        '\u{3007}', Nl), ('\u{3008}', '\u{3008}', Ps), ('\u{3009}', '\u{3009}', Pe), ('\u{300a}',
+        '\u{300a}', Ps), ('\u{300b}', '\u{300b}', Pe), ('\u{300c}', '\u{300c}', Ps), ('\u{300d}',
+        '\u{300d}', Pe), ('\u{300e}', '\u{300e}', Ps), ('\u{300f}', '\u{300f}', Pe), ('\u{3010}',
+        '\u{3010}', Ps), ('\u{3011}', '\u{3011}', Pe), ('\u{3012}', '\u{3013}', So), ('\u{3014}',
+        '\u{3014}', Ps), ('\u{3015}', '\u{3015}', Pe), ('\u{3016}', '\u{3016}', Ps), ('\u{3017}',
+        '\u{3017}', Pe), ('\u{3018}', '\u{3018}', Ps), ('\u{3019}', '\u{3019}', Pe), ('\u{301a}',
+        '\u{301a}', Ps), ('\u{301b}', '\u{301b}', Pe), ('\u{301c}', '\u{301c}', Pd), ('\u{301d}',
+        '\u{301d}', Ps), ('\u{301e}', '\u{301f}', Pe), ('\u{3020}', '\u{3020}', So), ('\u{3021}',
+        '\u{3029}', Nl), ('\u{302a}', '\u{302d}', Mn), ('\u{302e}', '\u{302f}', Mc), ('\u{3030}',
+        '\u{3030}', Pd), ('\u{3031}', '\u{3035}', Lm), ('\u{3036}', '\u{3037}', So), ('\u{3038}',
+        '\u{303a}', Nl), ('\u{303b}', '\u{303b}', Lm), ('\u{303c}', '\u{303c}', Lo), ('\u{303d}',
+        '\u{303d}', Po), ('\u{303e}', '\u{303f}', So), ('\u{3041}', '\u{3096}', Lo), ('\u{3099}',
+        '\u{309a}', Mn), ('\u{309b}', '\u{309c}', Sk), ('\u{309d}', '\u{309e}', Lm), ('\u{309f}',
+        '\u{309f}', Lo), ('\u{30a0}', '\u{30a0}', Pd), ('\u{30a1}', '\u{30fa}', Lo), ('\u{30fb}',
+        '\u{30fb}', Po), ('\u{30fc}', '\u{30fe}', Lm), ('\u{30ff}', '\u{30ff}', Lo), ('\u{3105}',
+        '\u{312d}', Lo), ('\u{3131}', '\u{318e}', Lo), ('\u{3190}', '\u{3191}', So), ('\u{3192}',
+        '\u{3195}', No), ('\u{3196}', '\u{319f}', So), ('\u{31a0}', '\u{31ba}', Lo), ('\u{31c0}',
+        '\u{31e3}', So), ('\u{31f0}', '\u{31ff}', Lo), ('\u{3200}', '\u{321e}', So), ('\u{3220}',
+        '\u{3229}', No), ('\u{322a}', '\u{3247}', So), ('\u{3248}', '\u{324f}', No), ('\u{3250}',
+        '\u{3250}', So), ('\u{3251}', '\u{325f}', No), ('\u{3260}', '\u{327f}', So), ('\u{3280}',
+        '\u{3289}', No), ('\u{328a}', '\u{32b0}', So), ('\u{32b1}', '\u{32bf}', No), ('\u{32c0}',
+        '\u{32fe}', So), ('\u{3300}', '\u{33ff}', So), ('\u{3400}', '\u{4db5}', Lo), ('\u{4dc0}',
+        '\u{4dff}', So), ('\u{4e00}', '\u{9fcc}', Lo), ('\u{a000}', '\u{a014}', Lo), ('\u{a015}',
+        '\u{a015}', Lm), ('\u{a016}', '\u{a48c}', Lo), ('\u{a490}', '\u{a4c6}', So), ('\u{a4d0}',
+        '\u{a4f7}', Lo), ('\u{a4f8}', '\u{a4fd}', Lm), ('\u{a4fe}', '\u{a4ff}', Po), ('\u{a500}',
+        '\u{a60b}', Lo), ('\u{a60c}', '\u{a60c}', Lm), ('\u{a60d}', '\u{a60f}', Po), ('\u{a610}',
+        '\u{a61f}', Lo), ('\u{a620}', '\u{a629}', Nd), ('\u{a62a}', '\u{a62b}', Lo), ('\u{a640}',
+        '\u{a640}', Lu), ('\u{a641}', '\u{a641}', Ll), ('\u{a642}', '\u{a642}', Lu), ('\u{a643}',
+        '\u{a643}', Ll), ('\u{a644}', '\u{a644}', Lu), ('\u{a645}', '\u{a645}', Ll), ('\u{a646}',
+        '\u{a646}', Lu), ('\u{a647}', '\u{a647}', Ll), ('\u{a648}', '\u{a648}', Lu), ('\u{a649}',
+        '\u{a649}', Ll), ('\u{a64a}', '\u{a64a}', Lu), ('\u{a64b}', '\u{a64b}', Ll), ('\u{a64c}',
+        '\u{a64c}', Lu), ('\u{a64d}', '\u{a64d}', Ll), ('\u{a64e}', '\u{a64e}', Lu), ('\u{a64f}',
+        '\u{a64f}', Ll), ('\u{a650}', '\u{a650}', Lu), ('\u{a651}', '\u{a651}', Ll), ('\u{a652}',
+        '\u{a652}', Lu), ('\u{a653}', '\u{a653}', Ll), ('\u{a654}', '\u{a654}', Lu), ('\u{a655}',
+        '\u{a655}', Ll), ('\u{a656}', '\u{a656}', Lu), ('\u{a657}', '\u{a657}', Ll), ('\u{a658}',
+        '\u{a658}', Lu), ('\u{a659}', '\u{a659}', Ll), ('\u{a65a}', '\u{a65a}', Lu), ('\u{a65b}',
+        '\u{a65b}', Ll), ('\u{a65c}', '\u{a65c}', Lu), ('\u{a65d}', '\u{a65d}', Ll), ('\u{a65e}',
+        '\u{a65e}', Lu), ('\u{a65f}', '\u{a65f}', Ll), ('\u{a660}', '\u{a660}', Lu), ('\u{a661}',
+        '\u{a661}', Ll), ('\u{a662}', '\u{a662}', Lu), ('\u{a663}', '\u{a663}', Ll), ('\u{a664}',
+        '\u{a664}', Lu), ('\u{a665}', '\u{a665}', Ll), ('\u{a666}', '\u{a666}', Lu), ('\u{a667}',
+        '\u{a667}', Ll), ('\u{a668}', '\u{a668}', Lu), ('\u{a669}', '\u{a669}', Ll), ('\u{a66a}',
+        '\u{a66a}', Lu), ('\u{a66b}', '\u{a66b}', Ll), ('\u{a66c}', '\u{a66c}', Lu), ('\u{a66d}',
+        '\u{a66d}', Ll), ('\u{a66e}', '\u{a66e}', Lo), ('\u{a66f}', '\u{a66f}', Mn), ('\u{a670}',
+        '\u{a672}', Me), ('\u{a673}', '\u{a673}', Po), ('\u{a674}', '\u{a67d}', Mn), ('\u{a67e}',
+        '\u{a67e}', Po), ('\u{a67f}', '\u{a67f}', Lm), ('\u{a680}', '\u{a680}', Lu), ('\u{a681}',
+        '\u{a681}', Ll), ('\u{a682}', '\u{a682}', Lu), ('\u{a683}', '\u{a683}', Ll), ('\u{a684}',
+        '\u{a684}', Lu), ('\u{a685}', '\u{a685}', Ll), ('\u{a686}', '\u{a686}', Lu), ('\u{a687}',
+        '\u{a687}', Ll), ('\u{a688}', '\u{a688}', Lu), ('\u{a689}', '\u{a689}', Ll), ('\u{a68a}',
+        '\u{a68a}', Lu), ('\u{a68b}', '\u{a68b}', Ll), ('\u{a68c}', '\u{a68c}', Lu), ('\u{a68d}',
+        '\u{a68d}', Ll), ('\u{a68e}', '\u{a68e}', Lu), ('\u{a68f}', '\u{a68f}', Ll), ('\u{a690}',
+        '\u{a690}', Lu), ('\u{a691}', '\u{a691}', Ll), ('\u{a692}', '\u{a692}', Lu), ('\u{a693}',
+        '\u{a693}', Ll), ('\u{a694}', '\u{a694}', Lu), ('\u{a695}', '\u{a695}', Ll), ('\u{a696}',
+        '\u{a696}', Lu), ('\u{a697}', '\u{a697}', Ll), ('\u{a698}', '\u{a698}', Lu), ('\u{a699}',
+        '\u{a699}', Ll), ('\u{a69a}', '\u{a69a}', Lu), ('\u{a69b}', '\u{a69b}', Ll), ('\u{a69c}',
+        '\u{a69d}', Lm), ('\u{a69f}', '\u{a69f}', Mn), ('\u{a6a0}', '\u{a6e5}', Lo), ('\u{a6e6}',
+        '\u{a6ef}', Nl), ('\u{a6f0}', '\u{a6f1}', Mn), ('\u{a6f2}', '\u{a6f7}', Po), ('\u{a700}',
+        '\u{a716}', Sk), ('\u{a717}', '\u{a71f}', Lm), ('\u{a720}', '\u{a721}', Sk), ('\u{a722}',
+        '\u{a722}', Lu), ('\u{a723}', '\u{a723}', Ll), ('\u{a724}', '\u{a724}', Lu), ('\u{a725}',
+        '\u{a725}', Ll), ('\u{a726}', '\u{a726}', Lu), ('\u{a727}', '\u{a727}', Ll), ('\u{a728}',
+        '\u{a728}', Lu), ('\u{a729}', '\u{a729}', Ll), ('\u{a72a}', '\u{a72a}', Lu), ('\u{a72b}',
+        '\u{a72b}', Ll), ('\u{a72c}', '\u{a72c}', Lu), ('\u{a72d}', '\u{a72d}', Ll), ('\u{a72e}',
+        '\u{a72e}', Lu), ('\u{a72f}', '\u{a731}', Ll), ('\u{a732}', '\u{a732}', Lu), ('\u{a733}',
+        '\u{a733}', Ll), ('\u{a734}', '\u{a734}', Lu), ('\u{a735}', '\u{a735}', Ll), ('\u{a736}',
+        '\u{a736}', Lu), ('\u{a737}', '\u{a737}', Ll), ('\u{a738}', '\u{a738}', Lu), ('\u{a739}',
+        '\u{a739}', Ll), ('\u{a73a}', '\u{a73a}', Lu), ('\u{a73b}', '\u{a73b}', Ll), ('\u{a73c}',
+        '\u{a73c}', Lu), ('\u{a73d}', '\u{a73d}', Ll), ('\u{a73e}', '\u{a73e}', Lu), ('\u{a73f}',
+        '\u{a73f}', Ll), ('\u{a740}', '\u{a740}', Lu), ('\u{a741}', '\u{a741}', Ll), ('\u{a742}',
+        '\u{a742}', Lu), ('\u{a743}', '\u{a743}', Ll), ('\u{a744}', '\u{a744}', Lu), ('\u{a745}',
+        '\u{a745}', Ll), ('\u{a746}', '\u{a746}', Lu), ('\u{a747}', '\u{a747}', Ll), ('\u{a748}',
+        '\u{a748}', Lu), ('\u{a749}', '\u{a749}', Ll), ('\u{a74a}', '\u{a74a}', Lu), ('\u{a74b}',
+        '\u{a74b}', Ll), ('\u{a74c}', '\u{a74c}', Lu), ('\u{a74d}', '\u{a74d}', Ll), ('\u{a74e}',
+        '\u{a74e}', Lu), ('\u{a74f}', '\u{a74f}', Ll), ('\u{a750}', '\u{a750}', Lu), ('\u{a751}',
+        '\u{a751}', Ll), ('\u{a752}', '\u{a752}', Lu), ('\u{a753}', '\u{a753}', Ll), ('\u{a754}',
+        '\u{a754}', Lu), ('\u{a755}', '\u{a755}', Ll), ('\u{a756}', '\u{a756}', Lu), ('\u{a757}',
+        '\u{a757}', Ll), ('\u{a758}', '\u{a758}', Lu), ('\u{a759}', '\u{a759}', Ll), ('\u{a75a}',
+        '\u{a75a}', Lu), ('\u{a75b}', '\u{a75b}', Ll), ('\u{a75c}', '\u{a75c}', Lu), ('\u{a75d}',
+        '\u{a75d}', Ll), ('\u{a75e}', '\u{a75e}', Lu), ('\u{a75f}', '\u{a75f}', Ll), ('\u{a760}',
+        '\u{a760}', Lu), ('\u{a761}', '\u{a761}', Ll), ('\u{a762}', '\u{a762}', Lu), ('\u{a763}',
+        '\u{a763}', Ll), ('\u{a764}', '\u{a764}', Lu), ('\u{a765}', '\u{a765}', Ll), ('\u{a766}',
+        '\u{a766}', Lu), ('\u{a767}', '\u{a767}', Ll), ('\u{a768}', '\u{a768}', Lu), ('\u{a769}',
+        '\u{a769}', Ll), ('\u{a76a}', '\u{a76a}', Lu), ('\u{a76b}', '\u{a76b}', Ll), ('\u{a76c}',
+        '\u{a76c}', Lu), ('\u{a76d}', '\u{a76d}', Ll), ('\u{a76e}', '\u{a76e}', Lu), ('\u{a76f}',
+        '\u{a76f}', Ll), ('\u{a770}', '\u{a770}', Lm), ('\u{a771}', '\u{a778}', Ll), ('\u{a779}',
+        '\u{a779}', Lu), ('\u{a77a}', '\u{a77a}', Ll), ('\u{a77b}', '\u{a77b}', Lu), ('\u{a77c}',
+        '\u{a77c}', Ll), ('\u{a77d}', '\u{a77e}', Lu), ('\u{a77f}', '\u{a77f}', Ll), ('\u{a780}',
+        '\u{a780}', Lu), ('\u{a781}', '\u{a781}', Ll), ('\u{a782}', '\u{a782}', Lu), ('\u{a783}',
+        '\u{a783}', Ll), ('\u{a784}', '\u{a784}', Lu), ('\u{a785}', '\u{a785}', Ll), ('\u{a786}',
+        '\u{a786}', Lu), ('\u{a787}', '\u{a787}', Ll), ('\u{a788}', '\u{a788}', Lm), ('\u{a789}',
+        '\u{a78a}', Sk), ('\u{a78b}', '\u{a78b}', Lu), ('\u{a78c}', '\u{a78c}', Ll), ('\u{a78d}',
+        '\u{a78d}', Lu), ('\u{a78e}', '\u{a78e}', Ll), ('\u{a790}', '\u{a790}', Lu), ('\u{a791}',
+        '\u{a791}', Ll), ('\u{a792}', '\u{a792}', Lu), ('\u{a793}', '\u{a795}', Ll), ('\u{a796}',
+        '\u{a796}', Lu), ('\u{a797}', '\u{a797}', Ll), ('\u{a798}', '\u{a798}', Lu), ('\u{a799}',
+        '\u{a799}', Ll), ('\u{a79a}', '\u{a79a}', Lu), ('\u{a79b}', '\u{a79b}', Ll), ('\u{a79c}',
+        '\u{a79c}', Lu), ('\u{a79d}', '\u{a79d}', Ll), ('\u{a79e}', '\u{a79e}', Lu), ('\u{a79f}',
+        '\u{a79f}', Ll), ('\u{a7a0}', '\u{a7a0}', Lu), ('\u{a7a1}', '\u{a7a1}', Ll), ('\u{a7a2}',
+        '\u{a7a2}', Lu), ('\u{a7a3}', '\u{a7a3}', Ll), ('\u{a7a4}', '\u{a7a4}', Lu), ('\u{a7a5}',
+        '\u{a7a5}', Ll), ('\u{a7a6}', '\u{a7a6}', Lu), ('\u{a7a7}', '\u{a7a7}', Ll), ('\u{a7a8}',
+        '\u{a7a8}', Lu), ('\u{a7a9}', '\u{a7a9}', Ll), ('\u{a7aa}', '\u{a7ad}', Lu), ('\u{a7b0}',
+        '\u{a7b1}', Lu), ('\u{a7f7}', '\u{a7f7}', Lo), ('\u{a7f8}', '\u{a7f9}', Lm), ('\u{a7fa}',
+        '\u{a7fa}', Ll), ('\u{a7fb}', '\u{a801}', Lo), ('\u{a802}', '\u{a802}', Mn), ('\u{a803}',
+        '\u{a805}', Lo), ('\u{a806}', '\u{a806}', Mn), ('\u{a807}', '\u{a80a}', Lo), ('\u{a80b}',
+        '\u{a80b}', Mn), ('\u{a80c}', '\u{a822}', Lo), ('\u{a823}', '\u{a824}', Mc), ('\u{a825}',
+        '\u{a826}', Mn), ('\u{a827}', '\u{a827}', Mc), ('\u{a828}', '\u{a82b}', So), ('\u{a830}',
+        '\u{a835}', No), ('\u{a836}', '\u{a837}', So), ('\u{a838}', '\u{a838}', Sc), ('\u{a839}',
+        '\u{a839}', So), ('\u{a840}', '\u{a873}', Lo), ('\u{a874}', '\u{a877}', Po), ('\u{a880}',
+        '\u{a881}', Mc), ('\u{a882}', '\u{a8b3}', Lo), ('\u{a8b4}', '\u{a8c3}', Mc), ('\u{a8c4}',
+        '\u{a8c4}', Mn), ('\u{a8ce}', '\u{a8cf}', Po), ('\u{a8d0}', '\u{a8d9}', Nd), ('\u{a8e0}',
+        '\u{a8f1}', Mn), ('\u{a8f2}', '\u{a8f7}', Lo), ('\u{a8f8}', '\u{a8fa}', Po), ('\u{a8fb}',
+        '\u{a8fb}', Lo), ('\u{a900}', '\u{a909}', Nd), ('\u{a90a}', '\u{a925}', Lo), ('\u{a926}',
+        '\u{a92d}', Mn), ('\u{a92e}', '\u{a92f}', Po), ('\u{a930}', '\u{a946}', Lo), ('\u{a947}',
+        '\u{a951}', Mn), ('\u{a952}', '\u{a953}', Mc), ('\u{a95f}', '\u{a95f}', Po), ('\u{a960}',
+        '\u{a97c}', Lo), ('\u{a980}', '\u{a982}', Mn), ('\u{a983}', '\u{a983}', Mc), ('\u{a984}',
+        '\u{a9b2}', Lo), ('\u{a9b3}', '\u{a9b3}', Mn), ('\u{a9b4}', '\u{a9b5}', Mc), ('\u{a9b6}',
+        '\u{a9b9}', Mn), ('\u{a9ba}', '\u{a9bb}', Mc), ('\u{a9bc}', '\u{a9bc}', Mn), ('\u{a9bd}',
+        '\u{a9c0}', Mc), ('\u{a9c1}', '\u{a9cd}', Po), ('\u{a9cf}', '\u{a9cf}', Lm), ('\u{a9d0}',
+        '\u{a9d9}', Nd), ('\u{a9de}', '\u{a9df}', Po), ('\u{a9e0}', '\u{a9e4}', Lo), ('\u{a9e5}',
+        '\u{a9e5}', Mn), ('\u{a9e6}', '\u{a9e6}', Lm), ('\u{a9e7}', '\u{a9ef}', Lo), ('\u{a9f0}',
+        '\u{a9f9}', Nd), ('\u{a9fa}', '\u{a9fe}', Lo), ('\u{aa00}', '\u{aa28}', Lo), ('\u{aa29}',
+        '\u{aa2e}', Mn), ('\u{aa2f}', '\u{aa30}', Mc), ('\u{aa31}', '\u{aa32}', Mn), ('\u{aa33}',
+        '\u{aa34}', Mc), ('\u{aa35}', '\u{aa36}', Mn), ('\u{aa40}', '\u{aa42}', Lo), ('\u{aa43}',
+        '\u{aa43}', Mn), ('\u{aa44}', '\u{aa4b}', Lo), ('\u{aa4c}', '\u{aa4c}', Mn), ('\u{aa4d}',
+        '\u{aa4d}', Mc), ('\u{aa50}', '\u{aa59}', Nd), ('\u{aa5c}', '\u{aa5f}', Po), ('\u{aa60}',
+        '\u{aa6f}', Lo), ('\u{aa70}', '\u{aa70}', Lm), ('\u{aa71}', '\u{aa76}', Lo), ('\u{aa77}',
+        '\u{aa79}', So), ('\u{aa7a}', '\u{aa7a}', Lo), ('\u{aa7b}', '\u{aa7b}', Mc), ('\u{aa7c}',
+        '\u{aa7c}', Mn), ('\u{aa7d}', '\u{aa7d}', Mc), ('\u{aa7e}', '\u{aaaf}', Lo), ('\u{aab0}',
+        '\u{aab0}', Mn), ('\u{aab1}', '\u{aab1}', Lo), ('\u{aab2}', '\u{aab4}', Mn), ('\u{aab5}',
+        '\u{aab6}', Lo), ('\u{aab7}', '\u{aab8}', Mn), ('\u{aab9}', '\u{aabd}', Lo), ('\u{aabe}',
+        '\u{aabf}', Mn), ('\u{aac0}', '\u{aac0}', Lo), ('\u{aac1}', '\u{aac1}', Mn), ('\u{aac2}',
+        '\u{aac2}', Lo), ('\u{aadb}', '\u{aadc}', Lo), ('\u{aadd}', '\u{aadd}', Lm), ('\u{aade}',
+        '\u{aadf}', Po), ('\u{aae0}', '\u{aaea}', Lo), ('\u{aaeb}', '\u{aaeb}', Mc), ('\u{aaec}',
+        '\u{aaed}', Mn), ('\u{aaee}', '\u{aaef}', Mc), ('\u{aaf0}', '\u{aaf1}', Po), ('\u{aaf2}',
+        '\u{aaf2}', Lo), ('\u{aaf3}', '\u{aaf4}', Lm), ('\u{aaf5}', '\u{aaf5}', Mc), ('\u{aaf6}',
+        '\u{aaf6}', Mn), ('\u{ab01}', '\u{ab06}', Lo), ('\u{ab09}', '\u{ab0e}', Lo), ('\u{ab11}',
+        '\u{ab16}', Lo), ('\u{ab20}', '\u{ab26}', Lo), ('\u{ab28}', '\u{ab2e}', Lo), ('\u{ab30}',
+        '\u{ab5a}', Ll), ('\u{ab5b}', '\u{ab5b}', Sk), ('\u{ab5c}', '\u{ab5f}', Lm), ('\u{ab64}',
+        '\u{ab65}', Ll), ('\u{abc0}', '\u{abe2}', Lo), ('\u{abe3}', '\u{abe4}', Mc), ('\u{abe5}',
+        '\u{abe5}', Mn), ('\u{abe6}', '\u{abe7}', Mc), ('\u{abe8}', '\u{abe8}', Mn), ('\u{abe9}',
+        '\u{abea}', Mc), ('\u{abeb}', '\u{abeb}', Po), ('\u{abec}', '\u{abec}', Mc), ('\u{abed}',
+        '\u{abed}', Mn), ('\u{abf0}', '\u{abf9}', Nd), ('\u{ac00}', '\u{d7a3}', Lo), ('\u{d7b0}',
+        '\u{d7c6}', Lo), ('\u{d7cb}', '\u{d7fb}', Lo), ('\u{e000}', '\u{f8ff}', Co), ('\u{f900}',
+        '\u{fa6d}', Lo), ('\u{fa70}', '\u{fad9}', Lo), ('\u{fb00}', '\u{fb06}', Ll), ('\u{fb13}',
+        '\u{fb17}', Ll), ('\u{fb1d}', '\u{fb1d}', Lo), ('\u{fb1e}', '\u{fb1e}', Mn), ('\u{fb1f}',
+        '\u{fb28}', Lo), ('\u{fb29}', '\u{fb29}', Sm), ('\u{fb2a}', '\u{fb36}', Lo), ('\u{fb38}',
+        '\u{fb3c}', Lo), ('\u{fb3e}', '\u{fb3e}', Lo), ('\u{fb40}', '\u{fb41}', Lo), ('\u{fb43}',
+        '\u{fb44}', Lo), ('\u{fb46}', '\u{fbb1}', Lo), ('\u{fbb2}', '\u{fbc1}', Sk), ('\u{fbd3}',
+        '\u{fd3d}', Lo), ('\u{fd3e}', '\u{fd3e}', Pe), ('\u{fd3f}', '\u{fd3f}', Ps), ('\u{fd50}',
+        '\u{fd8f}', Lo), ('\u{fd92}', '\u{fdc7}', Lo), ('\u{fdf0}', '\u{fdfb}', Lo), ('\u{fdfc}',
+        '\u{fdfc}', Sc), ('\u{fdfd}', '\u{fdfd}', So), ('\u{fe00}', '\u{fe0f}', Mn), ('\u{fe10}',
+        '\u{fe16}', Po), ('\u{fe17}', '\u{fe17}', Ps), ('\u{fe18}', '\u{fe18}', Pe), ('\u{fe19}',
+        '\u{fe19}', Po), ('\u{fe20}', '\u{fe2d}', Mn), ('\u{fe30}', '\u{fe30}', Po), ('\u{fe31}',
+        '\u{fe32}', Pd), ('\u{fe33}', '\u{fe34}', Pc), ('\u{fe35}', '\u{fe35}', Ps), ('\u{fe36}',
+        '\u{fe36}', Pe), ('\u{fe37}', '\u{fe37}', Ps), ('\u{fe38}', '\u{fe38}', Pe), ('\u{fe39}',
+        '\u{fe39}', Ps), ('\u{fe3a}', '\u{fe3a}', Pe), ('\u{fe3b}', '\u{fe3b}', Ps), ('\u{fe3c}',
+        '\u{fe3c}', Pe), ('\u{fe3d}', '\u{fe3d}', Ps), ('\u{fe3e}', '\u{fe3e}', Pe), ('\u{fe3f}',
+        '\u{fe3f}', Ps), ('\u{fe40}', '\u{fe40}', Pe), ('\u{fe41}', '\u{fe41}', Ps), ('\u{fe42}',
+        '\u{fe42}', Pe), ('\u{fe43}', '\u{fe43}', Ps), ('\u{fe44}', '\u{fe44}', Pe), ('\u{fe45}',
+        '\u{fe46}', Po), ('\u{fe47}', '\u{fe47}', Ps), ('\u{fe48}', '\u{fe48}', Pe), ('\u{fe49}',
+        '\u{fe4c}', Po), ('\u{fe4d}', '\u{fe4f}', Pc), ('\u{fe50}', '\u{fe52}', Po), ('\u{fe54}',
+        '\u{fe57}', Po), ('\u{fe58}', '\u{fe58}', Pd), ('\u{fe59}', '\u{fe59}', Ps), ('\u{fe5a}',
+        '\u{fe5a}', Pe), ('\u{fe5b}', '\u{fe5b}', Ps), ('\u{fe5c}', '\u{fe5c}', Pe), ('\u{fe5d}',
+        '\u{fe5d}', Ps), ('\u{fe5e}', '\u{fe5e}', Pe), ('\u{fe5f}', '\u{fe61}', Po), ('\u{fe62}',
+        '\u{fe62}', Sm), ('\u{fe63}', '\u{fe63}', Pd), ('\u{fe64}', '\u{fe66}', Sm), ('\u{fe68}',
+        '\u{fe68}', Po), ('\u{fe69}', '\u{fe69}', Sc), ('\u{fe6a}', '\u{fe6b}', Po), ('\u{fe70}',
+        '\u{fe74}', Lo), ('\u{fe76}', '\u{fefc}', Lo), ('\u{feff}', '\u{feff}', Cf), ('\u{ff01}',
+        '\u{ff03}', Po), ('\u{ff04}', '\u{ff04}', Sc), ('\u{ff05}', '\u{ff07}', Po), ('\u{ff08}',
+        '\u{ff08}', Ps), ('\u{ff09}', '\u{ff09}', Pe), ('\u{ff0a}', '\u{ff0a}', Po), ('\u{ff0b}',
+        '\u{ff0b}', Sm), ('\u{ff0c}', '\u{ff0c}', Po), ('\u{ff0d}', '\u{ff0d}', Pd), ('\u{ff0e}',
+        '\u{ff0f}', Po), ('\u{ff10}', '\u{ff19}', Nd), ('\u{ff1a}', '\u{ff1b}', Po), ('\u{ff1c}',
+        '\u{ff1e}', Sm), ('\u{ff1f}', '\u{ff20}', Po), ('\u{ff21}', '\u{ff3a}', Lu), ('\u{ff3b}',
+        '\u{ff3b}', Ps), ('\u{ff3c}', '\u{ff3c}', Po), ('\u{ff3d}', '\u{ff3d}', Pe), ('\u{ff3e}',
+        '\u{ff3e}', Sk), ('\u{ff3f}', '\u{ff3f}', Pc), ('\u{ff40}', '\u{ff40}', Sk), ('\u{ff41}',
+        '\u{ff5a}', Ll), ('\u{ff5b}', '\u{ff5b}', Ps), ('\u{ff5c}', '\u{ff5c}', Sm), ('\u{ff5d}',
+        '\u{ff5d}', Pe), ('\u{ff5e}', '\u{ff5e}', Sm), ('\u{ff5f}', '\u{ff5f}', Ps), ('\u{ff60}',
+        '\u{ff60}', Pe), ('\u{ff61}', '\u{ff61}', Po), ('\u{ff62}', '\u{ff62}', Ps), ('\u{ff63}',
+        '\u{ff63}', Pe), ('\u{ff64}', '\u{ff65}', Po), ('\u{ff66}', '\u{ff6f}', Lo), ('\u{ff70}',
+        '\u{ff70}', Lm), ('\u{ff71}', '\u{ff9d}', Lo), ('\u{ff9e}', '\u{ff9f}', Lm), ('\u{ffa0}',
+        '\u{ffbe}', Lo), ('\u{ffc2}', '\u{ffc7}', Lo), ('\u{ffca}', '\u{ffcf}', Lo), ('\u{ffd2}',
+        '\u{ffd7}', Lo), ('\u{ffda}', '\u{ffdc}', Lo), ('\u{ffe0}', '\u{ffe1}', Sc), ('\u{ffe2}',
+        '\u{ffe2}', Sm), ('\u{ffe3}', '\u{ffe3}', Sk), ('\u{ffe4}', '\u{ffe4}', So), ('\u{ffe5}',
+        '\u{ffe6}', Sc), ('\u{ffe8}', '\u{ffe8}', So), ('\u{ffe9}', '\u{ffec}', Sm), ('\u{ffed}',
+        '\u{ffee}', So), ('\u{fff9}', '\u{fffb}', Cf), ('\u{fffc}', '\u{fffd}', So), ('\u{10000}',
+        '\u{1000b}', Lo), ('\u{1000d}', '\u{10026}', Lo), ('\u{10028}', '\u{1003a}', Lo),
+        ('\u{1003c}', '\u{1003d}', Lo), ('\u{1003f}', '\u{1004d}', Lo), ('\u{10050}', '\u{1005d}',
+        Lo), ('\u{10080}', '\u{100fa}', Lo), ('\u{10100}', '\u{10102}', Po), ('\u{10107}',
+        '\u{10133}', No), ('\u{10137}', '\u{1013f}', So), ('\u{10140}', '\u{10174}', Nl),
+        ('\u{10175}', '\u{10178}', No), ('\u{10179}', '\u{10189}', So), ('\u{1018a}', '\u{1018b}',
+        No), ('\u{1018c}', '\u{1018c}', So), ('\u{10190}', '\u{1019b}', So), ('\u{101a0}',
+        '\u{101a0}', So), ('\u{101d0}', '\u{101fc}', So), ('\u{101fd}', '\u{101fd}', Mn),
+        ('\u{10280}', '\u{1029c}', Lo), ('\u{102a0}', '\u{102d0}', Lo), ('\u{102e0}', '\u{102e0}',
+        Mn), ('\u{102e1}', '\u{102fb}', No), ('\u{10300}', '\u{1031f}', Lo), ('\u{10320}',
+        '\u{10323}', No), ('\u{10330}', '\u{10340}', Lo), ('\u{10341}', '\u{10341}', Nl),
+        ('\u{10342}', '\u{10349}', Lo), ('\u{1034a}', '\u{1034a}', Nl), ('\u{10350}', '\u{10375}',
+        Lo), ('\u{10376}', '\u{1037a}', Mn), ('\u{10380}', '\u{1039d}', Lo), ('\u{1039f}',
+        '\u{1039f}', Po), ('\u{103a0}', '\u{103c3}', Lo), ('\u{103c8}', '\u{103cf}', Lo),
+        ('\u{103d0}', '\u{103d0}', Po), ('\u{103d1}', '\u{103d5}', Nl), ('\u{10400}', '\u{10427}',
+        Lu), ('\u{10428}', '\u{1044f}', Ll), ('\u{10450}', '\u{1049d}', Lo), ('\u{104a0}',
+        '\u{104a9}', Nd), ('\u{10500}', '\u{10527}', Lo), ('\u{10530}', '\u{10563}', Lo),
+        ('\u{1056f}', '\u{1056f}', Po), ('\u{10600}', '\u{10736}', Lo), ('\u{10740}', '\u{10755}',
+        Lo), ('\u{10760}', '\u{10767}', Lo), ('\u{10800}', '\u{10805}', Lo), ('\u{10808}',
+        '\u{10808}', Lo), ('\u{1080a}', '\u{10835}', Lo), ('\u{10837}', '\u{10838}', Lo),
+        ('\u{1083c}', '\u{1083c}', Lo), ('\u{1083f}', '\u{10855}', Lo), ('\u{10857}', '\u{10857}',
+        Po), ('\u{10858}', '\u{1085f}', No), ('\u{10860}', '\u{10876}', Lo), ('\u{10877}',
+        '\u{10878}', So), ('\u{10879}', '\u{1087f}', No), ('\u{10880}', '\u{1089e}', Lo),
+        ('\u{108a7}', '\u{108af}', No), ('\u{10900}', '\u{10915}', Lo), ('\u{10916}', '\u{1091b}',
+        No), ('\u{1091f}', '\u{1091f}', Po), ('\u{10920}', '\u{10939}', Lo), ('\u{1093f}',
+        '\u{1093f}', Po), ('\u{10980}', '\u{109b7}', Lo), ('\u{109be}', '\u{109bf}', Lo),
+        ('\u{10a00}', '\u{10a00}', Lo), ('\u{10a01}', '\u{10a03}', Mn), ('\u{10a05}', '\u{10a06}',
+        Mn), ('\u{10a0c}', '\u{10a0f}', Mn), ('\u{10a10}', '\u{10a13}', Lo), ('\u{10a15}',
+        '\u{10a17}', Lo), ('\u{10a19}', '\u{10a33}', Lo), ('\u{10a38}', '\u{10a3a}', Mn),
+        ('\u{10a3f}', '\u{10a3f}', Mn), ('\u{10a40}', '\u{10a47}', No), ('\u{10a50}', '\u{10a58}',
+        Po), ('\u{10a60}', '\u{10a7c}', Lo), ('\u{10a7d}', '\u{10a7e}', No), ('\u{10a7f}',
+        '\u{10a7f}', Po), ('\u{10a80}', '\u{10a9c}', Lo), ('\u{10a9d}', '\u{10a9f}', No),
+        ('\u{10ac0}', '\u{10ac7}', Lo), ('\u{10ac8}', '\u{10ac8}', So), ('\u{10ac9}', '\u{10ae4}',
+        Lo), ('\u{10ae5}', '\u{10ae6}', Mn), ('\u{10aeb}', '\u{10aef}', No), ('\u{10af0}',
+        '\u{10af6}', Po), ('\u{10b00}', '\u{10b35}', Lo), ('\u{10b39}', '\u{10b3f}', Po),
+        ('\u{10b40}', '\u{10b55}', Lo), ('\u{10b58}', '\u{10b5f}', No), ('\u{10b60}', '\u{10b72}',
+        Lo), ('\u{10b78}', '\u{10b7f}', No), ('\u{10b80}', '\u{10b91}', Lo), ('\u{10b99}',
+        '\u{10b9c}', Po), ('\u{10ba9}', '\u{10baf}', No), ('\u{10c00}', '\u{10c48}', Lo),
+        ('\u{10e60}', '\u{10e7e}', No), ('\u{11000}', '\u{11000}', Mc), ('\u{11001}', '\u{11001}',
+        Mn), ('\u{11002}', '\u{11002}', Mc), ('\u{11003}', '\u{11037}', Lo), ('\u{11038}',
+        '\u{11046}', Mn), ('\u{11047}', '\u{1104d}', Po), ('\u{11052}', '\u{11065}', No),
+        ('\u{11066}', '\u{1106f}', Nd), ('\u{1107f}', '\u{11081}', Mn), ('\u{11082}', '\u{11082}',
+        Mc), ('\u{11083}', '\u{110af}', Lo), ('\u{110b0}', '\u{110b2}', Mc), ('\u{110b3}',
+        '\u{110b6}', Mn), ('\u{110b7}', '\u{110b8}', Mc), ('\u{110b9}', '\u{110ba}', Mn),
+        ('\u{110bb}', '\u{110bc}', Po), ('\u{110bd}', '\u{110bd}', Cf), ('\u{110be}', '\u{110c1}',
+        Po), ('\u{110d0}', '\u{110e8}', Lo), ('\u{110f0}', '\u{110f9}', Nd), ('\u{11100}',
+        '\u{11102}', Mn), ('\u{11103}', '\u{11126}', Lo), ('\u{11127}', '\u{1112b}', Mn),
+        ('\u{1112c}', '\u{1112c}', Mc), ('\u{1112d}', '\u{11134}', Mn), ('\u{11136}', '\u{1113f}',
+        Nd), ('\u{11140}', '\u{11143}', Po), ('\u{11150}', '\u{11172}', Lo), ('\u{11173}',
+        '\u{11173}', Mn), ('\u{11174}', '\u{11175}', Po), ('\u{11176}', '\u{11176}', Lo),
+        ('\u{11180}', '\u{11181}', Mn), ('\u{11182}', '\u{11182}', Mc), ('\u{11183}', '\u{111b2}',
+        Lo), ('\u{111b3}', '\u{111b5}', Mc), ('\u{111b6}', '\u{111be}', Mn), ('\u{111bf}',
+        '\u{111c0}', Mc), ('\u{111c1}', '\u{111c4}', Lo), ('\u{111c5}', '\u{111c8}', Po),
+        ('\u{111cd}', '\u{111cd}', Po), ('\u{111d0}', '\u{111d9}', Nd), ('\u{111da}', '\u{111da}',
+        Lo), ('\u{111e1}', '\u{111f4}', No), ('\u{11200}', '\u{11211}', Lo), ('\u{11213}',
+        '\u{1122b}', Lo), ('\u{1122c}', '\u{1122e}', Mc), ('\u{1122f}', '\u{11231}', Mn),
+        ('\u{11232}', '\u{11233}', Mc), ('\u{11234}', '\u{11234}', Mn), ('\u{11235}', '\u{11235}',
+        Mc), ('\u{11236}', '\u{11237}', Mn), ('\u{11238}', '\u{1123d}', Po), ('\u{112b0}',
+        '\u{112de}', Lo), ('\u{112df}', '\u{112df}', Mn), ('\u{112e0}', '\u{112e2}', Mc),
+        ('\u{112e3}', '\u{112ea}', Mn), ('\u{112f0}', '\u{112f9}', Nd), ('\u{11301}', '\u{11301}',
+        Mn), ('\u{11302}', '\u{11303}', Mc), ('\u{11305}', '\u{1130c}', Lo), ('\u{1130f}',
+        '\u{11310}', Lo), ('\u{11313}', '\u{11328}', Lo), ('\u{1132a}', '\u{11330}', Lo),
+        ('\u{11332}', '\u{11333}', Lo), ('\u{11335}', '\u{11339}', Lo), ('\u{1133c}', '\u{1133c}',
+        Mn), ('\u{1133d}', '\u{1133d}', Lo), ('\u{1133e}', '\u{1133f}', Mc), ('\u{11340}',
+        '\u{11340}', Mn), ('\u{11341}', '\u{11344}', Mc), ('\u{11347}', '\u{11348}', Mc),
+        ('\u{1134b}', '\u{1134d}', Mc), ('\u{11357}', '\u{11357}', Mc), ('\u{1135d}', '\u{11361}',
+        Lo), ('\u{11362}', '\u{11363}', Mc), ('\u{11366}', '\u{1136c}', Mn), ('\u{11370}',
+        '\u{11374}', Mn), ('\u{11480}', '\u{114af}', Lo), ('\u{114b0}', '\u{114b2}', Mc),
+        ('\u{114b3}', '\u{114b8}', Mn), ('\u{114b9}', '\u{114b9}', Mc), ('\u{114ba}', '\u{114ba}',
+        Mn), ('\u{114bb}', '\u{114be}', Mc), ('\u{114bf}', '\u{114c0}', Mn), ('\u{114c1}',
+        '\u{114c1}', Mc), ('\u{114c2}', '\u{114c3}', Mn), ('\u{114c4}', '\u{114c5}', Lo),
+        ('\u{114c6}', '\u{114c6}', Po), ('\u{114c7}', '\u{114c7}', Lo), ('\u{114d0}', '\u{114d9}',
+        Nd), ('\u{11580}', '\u{115ae}', Lo), ('\u{115af}', '\u{115b1}', Mc), ('\u{115b2}',
+        '\u{115b5}', Mn), ('\u{115b8}', '\u{115bb}', Mc), ('\u{115bc}', '\u{115bd}', Mn),
+        ('\u{115be}', '\u{115be}', Mc), ('\u{115bf}', '\u{115c0}', Mn), ('\u{115c1}', '\u{115c9}',
+        Po), ('\u{11600}', '\u{1162f}', Lo), ('\u{11630}', '\u{11632}', Mc), ('\u{11633}',
+        '\u{1163a}', Mn), ('\u{1163b}', '\u{1163c}', Mc), ('\u{1163d}', '\u{1163d}', Mn),
+        ('\u{1163e}', '\u{1163e}', Mc), ('\u{1163f}', '\u{11640}', Mn), ('\u{11641}', '\u{11643}',
+        Po), ('\u{11644}', '\u{11644}', Lo), ('\u{11650}', '\u{11659}', Nd), ('\u{11680}',
+        '\u{116aa}', Lo), ('\u{116ab}', '\u{116ab}', Mn), ('\u{116ac}', '\u{116ac}', Mc),
+        ('\u{116ad}', '\u{116ad}', Mn), ('\u{116ae}', '\u{116af}', Mc), ('\u{116b0}', '\u{116b5}',
+        Mn), ('\u{116b6}', '\u{116b6}', Mc), ('\u{116b7}', '\u{116b7}', Mn), ('\u{116c0}',
+        '\u{116c9}', Nd), ('\u{118a0}', '\u{118bf}', Lu), ('\u{118c0}', '\u{118df}', Ll),
+        ('\u{118e0}', '\u{118e9}', Nd), ('\u{118ea}', '\u{118f2}', No), ('\u{118ff}', '\u{118ff}',
+        Lo), ('\u{11ac0}', '\u{11af8}', Lo), ('\u{12000}', '\u{12398}', Lo), ('\u{12400}',
+        '\u{1246e}', Nl), ('\u{12470}', '\u{12474}', Po), ('\u{13000}', '\u{1342e}', Lo),
+        ('\u{16800}', '\u{16a38}', Lo), ('\u{16a40}', '\u{16a5e}', Lo), ('\u{16a60}', '\u{16a69}',
+        Nd), ('\u{16a6e}', '\u{16a6f}', Po), ('\u{16ad0}', '\u{16aed}', Lo), ('\u{16af0}',
+        '\u{16af4}', Mn), ('\u{16af5}', '\u{16af5}', Po), ('\u{16b00}', '\u{16b2f}', Lo),
+        ('\u{16b30}', '\u{16b36}', Mn), ('\u{16b37}', '\u{16b3b}', Po), ('\u{16b3c}', '\u{16b3f}',
+        So), ('\u{16b40}', '\u{16b43}', Lm), ('\u{16b44}', '\u{16b44}', Po), ('\u{16b45}',
+        '\u{16b45}', So), ('\u{16b50}', '\u{16b59}', Nd), ('\u{16b5b}', '\u{16b61}', No),
+        ('\u{16b63}', '\u{16b77}', Lo), ('\u{16b7d}', '\u{16b8f}', Lo), ('\u{16f00}', '\u{16f44}',
+        Lo), ('\u{16f50}', '\u{16f50}', Lo), ('\u{16f51}', '\u{16f7e}', Mc), ('\u{16f8f}',
+        '\u{16f92}', Mn), ('\u{16f93}', '\u{16f9f}', Lm), ('\u{1b000}', '\u{1b001}', Lo),
+        ('\u{1bc00}', '\u{1bc6a}', Lo), ('\u{1bc70}', '\u{1bc7c}', Lo), ('\u{1bc80}', '\u{1bc88}',
+        Lo), ('\u{1bc90}', '\u{1bc99}', Lo), ('\u{1bc9c}', '\u{1bc9c}', So), ('\u{1bc9d}',
+        '\u{1bc9e}', Mn), ('\u{1bc9f}', '\u{1bc9f}', Po), ('\u{1bca0}', '\u{1bca3}', Cf),
+        ('\u{1d000}', '\u{1d0f5}', So), ('\u{1d100}', '\u{1d126}', So), ('\u{1d129}', '\u{1d164}',
+        So), ('\u{1d165}', '\u{1d166}', Mc), ('\u{1d167}', '\u{1d169}', Mn), ('\u{1d16a}',
+        '\u{1d16c}', So), ('\u{1d16d}', '\u{1d172}', Mc), ('\u{1d173}', '\u{1d17a}', Cf),
+        ('\u{1d17b}', '\u{1d182}', Mn), ('\u{1d183}', '\u{1d184}', So), ('\u{1d185}', '\u{1d18b}',
+        Mn), ('\u{1d18c}', '\u{1d1a9}', So), ('\u{1d1aa}', '\u{1d1ad}', Mn), ('\u{1d1ae}',
+        '\u{1d1dd}', So), ('\u{1d200}', '\u{1d241}', So), ('\u{1d242}', '\u{1d244}', Mn),
+        ('\u{1d245}', '\u{1d245}', So), ('\u{1d300}', '\u{1d356}', So), ('\u{1d360}', '\u{1d371}',
+        No), ('\u{1d400}', '\u{1d419}', Lu), ('\u{1d41a}', '\u{1d433}', Ll), ('\u{1d434}',
+        '\u{1d44d}', Lu), ('\u{1d44e}', '\u{1d454}', Ll), ('\u{1d456}', '\u{1d467}', Ll),
+        ('\u{1d468}', '\u{1d481}', Lu), ('\u{1d482}', '\u{1d49b}', Ll), ('\u{1d49c}', '\u{1d49c}',
+        Lu), ('\u{1d49e}', '\u{1d49f}', Lu), ('\u{1d4a2}', '\u{1d4a2}', Lu), ('\u{1d4a5}',
+        '\u{1d4a6}', Lu), ('\u{1d4a9}', '\u{1d4ac}', Lu), ('\u{1d4ae}', '\u{1d4b5}', Lu),
+        ('\u{1d4b6}', '\u{1d4b9}', Ll), ('\u{1d4bb}', '\u{1d4bb}', Ll), ('\u{1d4bd}', '\u{1d4c3}',
+        Ll), ('\u{1d4c5}', '\u{1d4cf}', Ll), ('\u{1d4d0}', '\u{1d4e9}', Lu), ('\u{1d4ea}',
+        '\u{1d503}', Ll), ('\u{1d504}', '\u{1d505}', Lu), ('\u{1d507}', '\u{1d50a}', Lu),
+        ('\u{1d50d}', '\u{1d514}', Lu), ('\u{1d516}', '\u{1d51c}', Lu), ('\u{1d51e}', '\u{1d537}',
+        Ll), ('\u{1d538}', '\u{1d539}', Lu), ('\u{1d53b}', '\u{1d53e}', Lu), ('\u{1d540}',
+        '\u{1d544}', Lu), ('\u{1d546}', '\u{1d546}', Lu), ('\u{1d54a}', '\u{1d550}', Lu),
+        ('\u{1d552}', '\u{1d56b}', Ll), ('\u{1d56c}', '\u{1d585}', Lu), ('\u{1d586}', '\u{1d59f}',
+        Ll), ('\u{1d5a0}', '\u{1d5b9}', Lu), ('\u{1d5ba}', '\u{1d5d3}', Ll), ('\u{1d5d4}',
+        '\u{1d5ed}', Lu), ('\u{1d5ee}', '\u{1d607}', Ll), ('\u{1d608}', '\u{1d621}', Lu),
+        ('\u{1d622}', '\u{1d63b}', Ll), ('\u{1d63c}', '\u{1d655}', Lu), ('\u{1d656}', '\u{1d66f}',
+        Ll), ('\u{1d670}', '\u{1d689}', Lu), ('\u{1d68a}', '\u{1d6a5}', Ll), ('\u{1d6a8}',
+        '\u{1d6c0}', Lu), ('\u{1d6c1}', '\u{1d6c1}', Sm), ('\u{1d6c2}', '\u{1d6da}', Ll),
+        ('\u{1d6db}', '\u{1d6db}', Sm), ('\u{1d6dc}', '\u{1d6e1}', Ll), ('\u{1d6e2}', '\u{1d6fa}',
+        Lu), ('\u{1d6fb}', '\u{1d6fb}', Sm), ('\u{1d6fc}', '\u{1d714}', Ll), ('\u{1d715}',
+        '\u{1d715}', Sm), ('\u{1d716}', '\u{1d71b}', Ll), ('\u{1d71c}', '\u{1d734}', Lu),
+        ('\u{1d735}', '\u{1d735}', Sm), ('\u{1d736}', '\u{1d74e}', Ll), ('\u{1d74f}', '\u{1d74f}',
+        Sm), ('\u{1d750}', '\u{1d755}', Ll), ('\u{1d756}', '\u{1d76e}', Lu), ('\u{1d76f}',
+        '\u{1d76f}', Sm), ('\u{1d770}', '\u{1d788}', Ll), ('\u{1d789}', '\u{1d789}', Sm),
+        ('\u{1d78a}', '\u{1d78f}', Ll), ('\u{1d790}', '\u{1d7a8}', Lu), ('\u{1d7a9}', '\u{1d7a9}',
+        Sm), ('\u{1d7aa}', '\u{1d7c2}', Ll), ('\u{1d7c3}', '\u{1d7c3}', Sm), ('\u{1d7c4}',
+        '\u{1d7c9}', Ll), ('\u{1d7ca}', '\u{1d7ca}', Lu), ('\u{1d7cb}', '\u{1d7cb}', Ll),
+        ('\u{1d7ce}', '\u{1d7ff}', Nd), ('\u{1e800}', '\u{1e8c4}', Lo), ('\u{1e8c7}', '\u{1e8cf}',
+        No), ('\u{1e8d0}', '\u{1e8d6}', Mn), ('\u{1ee00}', '\u{1ee03}', Lo), ('\u{1ee05}',
+        '\u{1ee1f}', Lo), ('\u{1ee21}', '\u{1ee22}', Lo), ('\u{1ee24}', '\u{1ee24}', Lo),
+        ('\u{1ee27}', '\u{1ee27}', Lo), ('\u{1ee29}', '\u{1ee32}', Lo), ('\u{1ee34}', '\u{1ee37}',
+        Lo), ('\u{1ee39}', '\u{1ee39}', Lo), ('\u{1ee3b}', '\u{1ee3b}', Lo), ('\u{1ee42}',
+        '\u{1ee42}', Lo), ('\u{1ee47}', '\u{1ee47}', Lo), ('\u{1ee49}', '\u{1ee49}', Lo),
+        ('\u{1ee4b}', '\u{1ee4b}', Lo), ('\u{1ee4d}', '\u{1ee4f}', Lo), ('\u{1ee51}', '\u{1ee52}',
+        Lo), ('\u{1ee54}', '\u{1ee54}', Lo), ('\u{1ee57}', '\u{1ee57}', Lo), ('\u{1ee59}',
+        '\u{1ee59}', Lo), ('\u{1ee5b}', '\u{1ee5b}', Lo), ('\u{1ee5d}', '\u{1ee5d}', Lo),
+        ('\u{1ee5f}', '\u{1ee5f}', Lo), ('\u{1ee61}', '\u{1ee62}', Lo), ('\u{1ee64}', '\u{1ee64}',
+        Lo), ('\u{1ee67}', '\u{1ee6a}', Lo), ('\u{1ee6c}', '\u{1ee72}', Lo), ('\u{1ee74}',
+        '\u{1ee77}', Lo), ('\u{1ee79}', '\u{1ee7c}', Lo), ('\u{1ee7e}', '\u{1ee7e}', Lo),
+        ('\u{1ee80}', '\u{1ee89}', Lo), ('\u{1ee8b}', '\u{1ee9b}', Lo), ('\u{1eea1}', '\u{1eea3}',
+        Lo), ('\u{1eea5}', '\u{1eea9}', Lo), ('\u{1eeab}', '\u{1eebb}', Lo), ('\u{1eef0}',
+        '\u{1eef1}', Sm), ('\u{1f000}', '\u{1f02b}', So), ('\u{1f030}', '\u{1f093}', So),
+        ('\u{1f0a0}', '\u{1f0ae}', So), ('\u{1f0b1}', '\u{1f0bf}', So), ('\u{1f0c1}', '\u{1f0cf}',
+        So), ('\u{1f0d1}', '\u{1f0f5}', So), ('\u{1f100}', '\u{1f10c}', No), ('\u{1f110}',
+        '\u{1f12e}', So), ('\u{1f130}', '\u{1f16b}', So), ('\u{1f170}', '\u{1f19a}', So),
+        ('\u{1f1e6}', '\u{1f202}', So), ('\u{1f210}', '\u{1f23a}', So), ('\u{1f240}', '\u{1f248}',
+        So), ('\u{1f250}', '\u{1f251}', So), ('\u{1f300}', '\u{1f32c}', So), ('\u{1f330}',
+        '\u{1f37d}', So), ('\u{1f380}', '\u{1f3ce}', So), ('\u{1f3d4}', '\u{1f3f7}', So),
+        ('\u{1f400}', '\u{1f4fe}', So), ('\u{1f500}', '\u{1f54a}', So), ('\u{1f550}', '\u{1f579}',
+        So), ('\u{1f57b}', '\u{1f5a3}', So), ('\u{1f5a5}', '\u{1f642}', So), ('\u{1f645}',
+        '\u{1f6cf}', So), ('\u{1f6e0}', '\u{1f6ec}', So), ('\u{1f6f0}', '\u{1f6f3}', So),
+        ('\u{1f700}', '\u{1f773}', So), ('\u{1f780}', '\u{1f7d4}', So), ('\u{1f800}', '\u{1f80b}',
+        So), ('\u{1f810}', '\u{1f847}', So), ('\u{1f850}', '\u{1f859}', So), ('\u{1f860}',
+        '\u{1f887}', So), ('\u{1f890}', '\u{1f8ad}', So), ('\u{20000}', '\u{2a6d6}', Lo),
+        ('\u{2a700}', '\u{2b734}', Lo), ('\u{2b740}', '\u{2b81d}', Lo), ('\u{2f800}', '\u{2fa1d}',
+        Lo), ('\u{e0001}', '\u{e0001}', Cf), ('\u{e0020}', '\u{e007f}', Cf), ('\u{e0100}',
+        '\u{e01ef}', Mn), ('\u{f0000}', '\u{ffffd}', Co), ('\u{100000}', '\u{10fffd}', Co)
+    ];
+
+}
+
 pub mod regex {
     pub static UNICODE_CLASSES: &'static [(&'static str, &'static &'static [(char, char)])] = &[
         ("Alphabetic", &super::derived_property::Alphabetic_table), ("Arabic",
@@ -6978,6 +8132,28 @@ pub mod conversions {
         }
     }
 
+    pub fn to_title(c: char) -> char {
+        match bsearch_case_table(c, title_table) {
+            None        => to_upper(c),
+            Some(index) => title_table[index].val1()
+        }
+    }
+
+    pub fn fold_case(c: char) -> char {
+        match bsearch_case_table(c, fold_table) {
+            None        => c,
+            Some(index) => fold_table[index].val1()
+        }
+    }
+
+    pub fn to_lower_full(c: char) -> Option<&'static [char]> {
+        bsearch_full_case_table(c, lower_full_table)
+    }
+
+    pub fn to_upper_full(c: char) -> Option<&'static [char]> {
+        bsearch_full_case_table(c, upper_full_table)
+    }
+
     fn bsearch_case_table(c: char, table: &'static [(char, char)]) -> Option<uint> {
         match table.binary_search(|&(key, _)| {
             if c == key { Equal }
@@ -6989,6 +8165,18 @@ pub mod conversions {
         }
     }
 
+    fn bsearch_full_case_table(c: char, table: &'static [(char, &'static [char])])
+                               -> Option<&'static [char]> {
+        match table.binary_search(|&(key, _)| {
+            if c == key { Equal }
+            else if key < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(i) => Some(table[i].val1()),
+            slice::BinarySearchResult::NotFound(_) => None,
+        }
+    }
+
     static LuLl_table: &'static [(char, char)] = &[
         ('\u{41}', '\u{61}'), ('\u{42}', '\u{62}'), ('\u{43}', '\u{63}'), ('\u{44}', '\u{64}'),
         ('\u{45}', '\u{65}'), ('\u{46}', '\u{66}'), ('\u{47}', '\u{67}'), ('\u{48}', '\u{68}'),
@@ -7591,6 +8779,1359 @@ pub mod conversions {
         '\u{118bf}')
     ];
 
+    static title_table: &'static [(char, char)] = &[
+        ('\u{1c4}', '\u{1c5}'), ('\u{1c6}', '\u{1c5}'), ('\u{1c7}', '\u{1c8}'), ('\u{1c9}',
+        '\u{1c8}'), ('\u{1ca}', '\u{1cb}'), ('\u{1cc}', '\u{1cb}'), ('\u{1f1}', '\u{1f2}'),
+        ('\u{1f3}', '\u{1f2}')
+    ];
+
+    static fold_table: &'static [(char, char)] = &[
+        ('\u{41}', '\u{61}'), ('\u{42}', '\u{62}'), ('\u{43}', '\u{63}'), ('\u{44}', '\u{64}'),
+        ('\u{45}', '\u{65}'), ('\u{46}', '\u{66}'), ('\u{47}', '\u{67}'), ('\u{48}', '\u{68}'),
+        ('\u{49}', '\u{69}'), ('\u{4a}', '\u{6a}'), ('\u{4b}', '\u{6b}'), ('\u{4c}', '\u{6c}'),
+        ('\u{4d}', '\u{6d}'), ('\u{4e}', '\u{6e}'), ('\u{4f}', '\u{6f}'), ('\u{50}', '\u{70}'),
+        ('\u{51}', '\u{71}'), ('\u{52}', '\u{72}'), ('\u{53}', '\u{73}'), ('\u{54}', '\u{74}'),
+        ('\u{55}', '\u{75}'), ('\u{56}', '\u{76}'), ('\u{57}', '\u{77}'), ('\u{58}', '\u{78}'),
+        ('\u{59}', '\u{79}'), ('\u{5a}', '\u{7a}'), ('\u{b5}', '\u{3bc}'), ('\u{c0}', '\u{e0}'),
+        ('\u{c1}', '\u{e1}'), ('\u{c2}', '\u{e2}'), ('\u{c3}', '\u{e3}'), ('\u{c4}', '\u{e4}'),
+        ('\u{c5}', '\u{e5}'), ('\u{c6}', '\u{e6}'), ('\u{c7}', '\u{e7}'), ('\u{c8}', '\u{e8}'),
+        ('\u{c9}', '\u{e9}'), ('\u{ca}', '\u{ea}'), ('\u{cb}', '\u{eb}'), ('\u{cc}', '\u{ec}'),
+        ('\u{cd}', '\u{ed}'), ('\u{ce}', '\u{ee}'), ('\u{cf}', '\u{ef}'), ('\u{d0}', '\u{f0}'),
+        ('\u{d1}', '\u{f1}'), ('\u{d2}', '\u{f2}'), ('\u{d3}', '\u{f3}'), ('\u{d4}', '\u{f4}'),
+        ('\u{d5}', '\u{f5}'), ('\u{d6}', '\u{f6}'), ('\u{d8}', '\u{f8}'), ('\u{d9}', '\u{f9}'),
+        ('\u{da}', '\u{fa}'), ('\u{db}', '\u{fb}'), ('\u{dc}', '\u{fc}'), ('\u{dd}', '\u{fd}'),
+        ('\u{de}', '\u{fe}'), ('\u{100}', '\u{101}'), ('\u{102}', '\u{103}'), ('\u{104}',
+        '\u{105}'), ('\u{106}', '\u{107}'), ('\u{108}', '\u{109}'), ('\u{10a}', '\u{10b}'),
+        ('\u{10c}', '\u{10d}'), ('\u{10e}', '\u{10f}'), ('\u{110}', '\u{111}'), ('\u{112}',
+        '\u{113}'), ('\u{114}', '\u{115}'), ('\u{116}', '\u{117}'), ('\u{118}', '\u{119}'),
+        ('\u{11a}', '\u{11b}'), ('\u{11c}', '\u{11d}'), ('\u{11e}', '\u{11f}'), ('\u{120}',
+        '\u{121}'), ('\u{122}', '\u{123}'), ('\u{124}', '\u{125}'), ('\u{126}', '\u{127}'),
+        ('\u{128}', '\u{129}'), ('\u{12a}', '\u{12b}'), ('\u{12c}', '\u{12d}'), ('\u{12e}',
+        '\u{12f}'), ('\u{132}', '\u{133}'), ('\u{134}', '\u{135}'), ('\u{136}', '\u{137}'),
+        ('\u{139}', '\u{13a}'), ('\u{13b}', '\u{13c}'), ('\u{13d}', '\u{13e}'), ('\u{13f}',
+        '\u{140}'), ('\u{141}', '\u{142}'), ('\u{143}', '\u{144}'), ('\u{145}', '\u{146}'),
+        ('\u{147}', '\u{148}'), ('\u{14a}', '\u{14b}'), ('\u{14c}', '\u{14d}'), ('\u{14e}',
+        '\u{14f}'), ('\u{150}', '\u{151}'), ('\u{152}', '\u{153}'), ('\u{154}', '\u{155}'),
+        ('\u{156}', '\u{157}'), ('\u{158}', '\u{159}'), ('\u{15a}', '\u{15b}'), ('\u{15c}',
+        '\u{15d}'), ('\u{15e}', '\u{15f}'), ('\u{160}', '\u{161}'), ('\u{162}', '\u{163}'),
+        ('\u{164}', '\u{165}'), ('\u{166}', '\u{167}'), ('\u{168}', '\u{169}'), ('\u{16a}',
+        '\u{16b}'), ('\u{16c}', '\u{16d}'), ('\u{16e}', '\u{16f}'), ('\u{170}', '\u{171}'),
+        ('\u{172}', '\u{173}'), ('\u{174}', '\u{175}'), ('\u{176}', '\u{177}'), ('\u{178}',
+        '\u{ff}'), ('\u{179}', '\u{17a}'), ('\u{17b}', '\u{17c}'), ('\u{17d}', '\u{17e}'),
+        ('\u{17f}', '\u{73}'), ('\u{181}', '\u{253}'), ('\u{182}', '\u{183}'), ('\u{184}',
+        '\u{185}'), ('\u{186}', '\u{254}'), ('\u{187}', '\u{188}'), ('\u{189}', '\u{256}'),
+        ('\u{18a}', '\u{257}'), ('\u{18b}', '\u{18c}'), ('\u{18e}', '\u{1dd}'), ('\u{18f}',
+        '\u{259}'), ('\u{190}', '\u{25b}'), ('\u{191}', '\u{192}'), ('\u{193}', '\u{260}'),
+        ('\u{194}', '\u{263}'), ('\u{196}', '\u{269}'), ('\u{197}', '\u{268}'), ('\u{198}',
+        '\u{199}'), ('\u{19c}', '\u{26f}'), ('\u{19d}', '\u{272}'), ('\u{19f}', '\u{275}'),
+        ('\u{1a0}', '\u{1a1}'), ('\u{1a2}', '\u{1a3}'), ('\u{1a4}', '\u{1a5}'), ('\u{1a6}',
+        '\u{280}'), ('\u{1a7}', '\u{1a8}'), ('\u{1a9}', '\u{283}'), ('\u{1ac}', '\u{1ad}'),
+        ('\u{1ae}', '\u{288}'), ('\u{1af}', '\u{1b0}'), ('\u{1b1}', '\u{28a}'), ('\u{1b2}',
+        '\u{28b}'), ('\u{1b3}', '\u{1b4}'), ('\u{1b5}', '\u{1b6}'), ('\u{1b7}', '\u{292}'),
+        ('\u{1b8}', '\u{1b9}'), ('\u{1bc}', '\u{1bd}'), ('\u{1c4}', '\u{1c6}'), ('\u{1c5}',
+        '\u{1c6}'), ('\u{1c7}', '\u{1c9}'), ('\u{1c8}', '\u{1c9}'), ('\u{1ca}', '\u{1cc}'),
+        ('\u{1cb}', '\u{1cc}'), ('\u{1cd}', '\u{1ce}'), ('\u{1cf}', '\u{1d0}'), ('\u{1d1}',
+        '\u{1d2}'), ('\u{1d3}', '\u{1d4}'), ('\u{1d5}', '\u{1d6}'), ('\u{1d7}', '\u{1d8}'),
+        ('\u{1d9}', '\u{1da}'), ('\u{1db}', '\u{1dc}'), ('\u{1de}', '\u{1df}'), ('\u{1e0}',
+        '\u{1e1}'), ('\u{1e2}', '\u{1e3}'), ('\u{1e4}', '\u{1e5}'), ('\u{1e6}', '\u{1e7}'),
+        ('\u{1e8}', '\u{1e9}'), ('\u{1ea}', '\u{1eb}'), ('\u{1ec}', '\u{1ed}'), ('\u{1ee}',
+        '\u{1ef}'), ('\u{1f1}', '\u{1f3}'), ('\u{1f2}', '\u{1f3}'), ('\u{1f4}', '\u{1f5}'),
+        ('\u{1f6}', '\u{195}'), ('\u{1f7}', '\u{1bf}'), ('\u{1f8}', '\u{1f9}'), ('\u{1fa}',
+        '\u{1fb}'), ('\u{1fc}', '\u{1fd}'), ('\u{1fe}', '\u{1ff}'), ('\u{200}', '\u{201}'),
+        ('\u{202}', '\u{203}'), ('\u{204}', '\u{205}'), ('\u{206}', '\u{207}'), ('\u{208}',
+        '\u{209}'), ('\u{20a}', '\u{20b}'), ('\u{20c}', '\u{20d}'), ('\u{20e}', '\u{20f}'),
+        ('\u{210}', '\u{211}'), ('\u{212}', '\u{213}'), ('\u{214}', '\u{215}'), ('\u{216}',
+        '\u{217}'), ('\u{218}', '\u{219}'), ('\u{21a}', '\u{21b}'), ('\u{21c}', '\u{21d}'),
+        ('\u{21e}', '\u{21f}'), ('\u{220}', '\u{19e}'), ('\u{222}', '\u{223}'), ('\u{224}',
+        '\u{225}'), ('\u{226}', '\u{227}'), ('\u{228}', '\u{229}'), ('\u{22a}', '\u{22b}'),
+        ('\u{22c}', '\u{22d}'), ('\u{22e}', '\u{22f}'), ('\u{230}', '\u{231}'), ('\u{232}',
+        '\u{233}'), ('\u{23a}', '\u{2c65}'), ('\u{23b}', '\u{23c}'), ('\u{23d}', '\u{19a}'),
+        ('\u{23e}', '\u{2c66}'), ('\u{241}', '\u{242}'), ('\u{243}', '\u{180}'), ('\u{244}',
+        '\u{289}'), ('\u{245}', '\u{28c}'), ('\u{246}', '\u{247}'), ('\u{248}', '\u{249}'),
+        ('\u{24a}', '\u{24b}'), ('\u{24c}', '\u{24d}'), ('\u{24e}', '\u{24f}'), ('\u{345}',
+        '\u{3b9}'), ('\u{370}', '\u{371}'), ('\u{372}', '\u{373}'), ('\u{376}', '\u{377}'),
+        ('\u{37f}', '\u{3f3}'), ('\u{386}', '\u{3ac}'), ('\u{388}', '\u{3ad}'), ('\u{389}',
+        '\u{3ae}'), ('\u{38a}', '\u{3af}'), ('\u{38c}', '\u{3cc}'), ('\u{38e}', '\u{3cd}'),
+        ('\u{38f}', '\u{3ce}'), ('\u{391}', '\u{3b1}'), ('\u{392}', '\u{3b2}'), ('\u{393}',
+        '\u{3b3}'), ('\u{394}', '\u{3b4}'), ('\u{395}', '\u{3b5}'), ('\u{396}', '\u{3b6}'),
+        ('\u{397}', '\u{3b7}'), ('\u{398}', '\u{3b8}'), ('\u{399}', '\u{3b9}'), ('\u{39a}',
+        '\u{3ba}'), ('\u{39b}', '\u{3bb}'), ('\u{39c}', '\u{3bc}'), ('\u{39d}', '\u{3bd}'),
+        ('\u{39e}', '\u{3be}'), ('\u{39f}', '\u{3bf}'), ('\u{3a0}', '\u{3c0}'), ('\u{3a1}',
+        '\u{3c1}'), ('\u{3a3}', '\u{3c3}'), ('\u{3a4}', '\u{3c4}'), ('\u{3a5}', '\u{3c5}'),
+        ('\u{3a6}', '\u{3c6}'), ('\u{3a7}', '\u{3c7}'), ('\u{3a8}', '\u{3c8}'), ('\u{3a9}',
+        '\u{3c9}'), ('\u{3aa}', '\u{3ca}'), ('\u{3ab}', '\u{3cb}'), ('\u{3c2}', '\u{3c3}'),
+        ('\u{3cf}', '\u{3d7}'), ('\u{3d0}', '\u{3b2}'), ('\u{3d1}', '\u{3b8}'), ('\u{3d5}',
+        '\u{3c6}'), ('\u{3d6}', '\u{3c0}'), ('\u{3d8}', '\u{3d9}'), ('\u{3da}', '\u{3db}'),
+        ('\u{3dc}', '\u{3dd}'), ('\u{3de}', '\u{3df}'), ('\u{3e0}', '\u{3e1}'), ('\u{3e2}',
+        '\u{3e3}'), ('\u{3e4}', '\u{3e5}'), ('\u{3e6}', '\u{3e7}'), ('\u{3e8}', '\u{3e9}'),
+        ('\u{3ea}', '\u{3eb}'), ('\u{3ec}', '\u{3ed}'), ('\u{3ee}', '\u{3ef}'), ('\u{3f0}',
+        '\u{3ba}'), ('\u{3f1}', '\u{3c1}'), ('\u{3f4}', '\u{3b8}'), ('\u{3f5}', '\u{3b5}'),
+        ('\u{3f7}', '\u{3f8}'), ('\u{3f9}', '\u{3f2}'), ('\u{3fa}', '\u{3fb}'), ('\u{3fd}',
+        '\u{37b}'), ('\u{3fe}', '\u{37c}'), ('\u{3ff}', '\u{37d}'), ('\u{400}', '\u{450}'),
+        ('\u{401}', '\u{451}'), ('\u{402}', '\u{452}'), ('\u{403}', '\u{453}'), ('\u{404}',
+        '\u{454}'), ('\u{405}', '\u{455}'), ('\u{406}', '\u{456}'), ('\u{407}', '\u{457}'),
+        ('\u{408}', '\u{458}'), ('\u{409}', '\u{459}'), ('\u{40a}', '\u{45a}'), ('\u{40b}',
+        '\u{45b}'), ('\u{40c}', '\u{45c}'), ('\u{40d}', '\u{45d}'), ('\u{40e}', '\u{45e}'),
+        ('\u{40f}', '\u{45f}'), ('\u{410}', '\u{430}'), ('\u{411}', '\u{431}'), ('\u{412}',
+        '\u{432}'), ('\u{413}', '\u{433}'), ('\u{414}', '\u{434}'), ('\u{415}', '\u{435}'),
+        ('\u{416}', '\u{436}'), ('\u{417}', '\u{437}'), ('\u{418}', '\u{438}'), ('\u{419}',
+        '\u{439}'), ('\u{41a}', '\u{43a}'), ('\u{41b}', '\u{43b}'), ('\u{41c}', '\u{43c}'),
+        ('\u{41d}', '\u{43d}'), ('\u{41e}', '\u{43e}'), ('\u{41f}', '\u{43f}'), ('\u{420}',
+        '\u{440}'), ('\u{421}', '\u{441}'), ('\u{422}', '\u{442}'), ('\u{423}', '\u{443}'),
+        ('\u{424}', '\u{444}'), ('\u{425}', '\u{445}'), ('\u{426}', '\u{446}'), ('\u{427}',
+        '\u{447}'), ('\u{428}', '\u{448}'), ('\u{429}', '\u{449}'), ('\u{42a}', '\u{44a}'),
+        ('\u{42b}', '\u{44b}'), ('\u{42c}', '\u{44c}'), ('\u{42d}', '\u{44d}'), ('\u{42e}',
+        '\u{44e}'), ('\u{42f}', '\u{44f}'), ('\u{460}', '\u{461}'), ('\u{462}', '\u{463}'),
+        ('\u{464}', '\u{465}'), ('\u{466}', '\u{467}'), ('\u{468}', '\u{469}'), ('\u{46a}',
+        '\u{46b}'), ('\u{46c}', '\u{46d}'), ('\u{46e}', '\u{46f}'), ('\u{470}', '\u{471}'),
+        ('\u{472}', '\u{473}'), ('\u{474}', '\u{475}'), ('\u{476}', '\u{477}'), ('\u{478}',
+        '\u{479}'), ('\u{47a}', '\u{47b}'), ('\u{47c}', '\u{47d}'), ('\u{47e}', '\u{47f}'),
+        ('\u{480}', '\u{481}'), ('\u{48a}', '\u{48b}'), ('\u{48c}', '\u{48d}'), ('\u{48e}',
+        '\u{48f}'), ('\u{490}', '\u{491}'), ('\u{492}', '\u{493}'), ('\u{494}', '\u{495}'),
+        ('\u{496}', '\u{497}'), ('\u{498}', '\u{499}'), ('\u{49a}', '\u{49b}'), ('\u{49c}',
+        '\u{49d}'), ('\u{49e}', '\u{49f}'), ('\u{4a0}', '\u{4a1}'), ('\u{4a2}', '\u{4a3}'),
+        ('\u{4a4}', '\u{4a5}'), ('\u{4a6}', '\u{4a7}'), ('\u{4a8}', '\u{4a9}'), ('\u{4aa}',
+        '\u{4ab}'), ('\u{4ac}', '\u{4ad}'), ('\u{4ae}', '\u{4af}'), ('\u{4b0}', '\u{4b1}'),
+        ('\u{4b2}', '\u{4b3}'), ('\u{4b4}', '\u{4b5}'), ('\u{4b6}', '\u{4b7}'), ('\u{4b8}',
+        '\u{4b9}'), ('\u{4ba}', '\u{4bb}'), ('\u{4bc}', '\u{4bd}'), ('\u{4be}', '\u{4bf}'),
+        ('\u{4c0}', '\u{4cf}'), ('\u{4c1}', '\u{4c2}'), ('\u{4c3}', '\u{4c4}'), ('\u{4c5}',
+        '\u{4c6}'), ('\u{4c7}', '\u{4c8}'), ('\u{4c9}', '\u{4ca}'), ('\u{4cb}', '\u{4cc}'),
+        ('\u{4cd}', '\u{4ce}'), ('\u{4d0}', '\u{4d1}'), ('\u{4d2}', '\u{4d3}'), ('\u{4d4}',
+        '\u{4d5}'), ('\u{4d6}', '\u{4d7}'), ('\u{4d8}', '\u{4d9}'), ('\u{4da}', '\u{4db}'),
+        ('\u{4dc}', '\u{4dd}'), ('\u{4de}', '\u{4df}'), ('\u{4e0}', '\u{4e1}'), ('\u{4e2}',
+        '\u{4e3}'), ('\u{4e4}', '\u{4e5}'), ('\u{4e6}', '\u{4e7}'), ('\u{4e8}', '\u{4e9}'),
+        ('\u{4ea}', '\u{4eb}'), ('\u{4ec}', '\u{4ed}'), ('\u{4ee}', '\u{4ef}'), ('\u{4f0}',
+        '\u{4f1}'), ('\u{4f2}', '\u{4f3}'), ('\u{4f4}', '\u{4f5}'), ('\u{4f6}', '\u{4f7}'),
+        ('\u{4f8}', '\u{4f9}'), ('\u{4fa}', '\u{4fb}'), ('\u{4fc}', '\u{4fd}'), ('\u{4fe}',
+        '\u{4ff}'), ('\u{500}', '\u{501}'), ('\u{502}', '\u{503}'), ('\u{504}', '\u{505}'),
+        ('\u{506}', '\u{507}'), ('\u{508}', '\u{509}'), ('\u{50a}', '\u{50b}'), ('\u{50c}',
+        '\u{50d}'), ('\u{50e}', '\u{50f}'), ('\u{510}', '\u{511}'), ('\u{512}', '\u{513}'),
+        ('\u{514}', '\u{515}'), ('\u{516}', '\u{517}'), ('\u{518}', '\u{519}'), ('\u{51a}',
+        '\u{51b}'), ('\u{51c}', '\u{51d}'), ('\u{51e}', '\u{51f}'), ('\u{520}', '\u{521}'),
+        ('\u{522}', '\u{523}'), ('\u{524}', '\u{525}'), ('\u{526}', '\u{527}'), ('\u{528}',
+        '\u{529}'), ('\u{52a}', '\u{52b}'), ('\u{52c}', '\u{52d}'), ('\u{52e}', '\u{52f}'),
+        ('\u{531}', '\u{561}'), ('\u{532}', '\u{562}'), ('\u{533}', '\u{563}'), ('\u{534}',
+        '\u{564}'), ('\u{535}', '\u{565}'), ('\u{536}', '\u{566}'), ('\u{537}', '\u{567}'),
+        ('\u{538}', '\u{568}'), ('\u{539}', '\u{569}'), ('\u{53a}', '\u{56a}'), ('\u{53b}',
+        '\u{56b}'), ('\u{53c}', '\u{56c}'), ('\u{53d}', '\u{56d}'), ('\u{53e}', '\u{56e}'),
+        ('\u{53f}', '\u{56f}'), ('\u{540}', '\u{570}'), ('\u{541}', '\u{571}'), ('\u{542}',
+        '\u{572}'), ('\u{543}', '\u{573}'), ('\u{544}', '\u{574}'), ('\u{545}', '\u{575}'),
+        ('\u{546}', '\u{576}'), ('\u{547}', '\u{577}'), ('\u{548}', '\u{578}'), ('\u{549}',
+        '\u{579}'), ('\u{54a}', '\u{57a}'), ('\u{54b}', '\u{57b}'), ('\u{54c}', '\u{57c}'),
+        ('\u{54d}', '\u{57d}'), ('\u{54e}', '\u{57e}'), ('\u{54f}', '\u{57f}'), ('\u{550}',
+        '\u{580}'), ('\u{551}', '\u{581}'), ('\u{552}', '\u{582}'), ('\u{553}', '\u{583}'),
+        ('\u{554}', '\u{584}'), ('\u{555}', '\u{585}'), ('\u{556}', '\u{586}'), ('\u{10a0}',
+        '\u{2d00}'), ('\u{10a1}', '\u{2d01}'), ('\u{10a2}', '\u{2d02}'), ('\u{10a3}', '\u{2d03}'),
+        ('\u{10a4}', '\u{2d04}'), ('\u{10a5}', '\u{2d05}'), ('\u{10a6}', '\u{2d06}'), ('\u{10a7}',
+        '\u{2d07}'), ('\u{10a8}', '\u{2d08}'), ('\u{10a9}', '\u{2d09}'), ('\u{10aa}', '\u{2d0a}'),
+        ('\u{10ab}', '\u{2d0b}'), ('\u{10ac}', '\u{2d0c}'), ('\u{10ad}', '\u{2d0d}'), ('\u{10ae}',
+        '\u{2d0e}'), ('\u{10af}', '\u{2d0f}'), ('\u{10b0}', '\u{2d10}'), ('\u{10b1}', '\u{2d11}'),
+        ('\u{10b2}', '\u{2d12}'), ('\u{10b3}', '\u{2d13}'), ('\u{10b4}', '\u{2d14}'), ('\u{10b5}',
+        '\u{2d15}'), ('\u{10b6}', '\u{2d16}'), ('\u{10b7}', '\u{2d17}'), ('\u{10b8}', '\u{2d18}'),
+        ('\u{10b9}', '\u{2d19}'), ('\u{10ba}', '\u{2d1a}'), ('\u{10bb}', '\u{2d1b}'), ('\u{10bc}',
+        '\u{2d1c}'), ('\u{10bd}', '\u{2d1d}'), ('\u{10be}', '\u{2d1e}'), ('\u{10bf}', '\u{2d1f}'),
+        ('\u{10c0}', '\u{2d20}'), ('\u{10c1}', '\u{2d21}'), ('\u{10c2}', '\u{2d22}'), ('\u{10c3}',
+        '\u{2d23}'), ('\u{10c4}', '\u{2d24}'), ('\u{10c5}', '\u{2d25}'), ('\u{10c7}', '\u{2d27}'),
+        ('\u{10cd}', '\u{2d2d}'), ('\u{1e00}', '\u{1e01}'), ('\u{1e02}', '\u{1e03}'), ('\u{1e04}',
+        '\u{1e05}'), ('\u{1e06}', '\u{1e07}'), ('\u{1e08}', '\u{1e09}'), ('\u{1e0a}', '\u{1e0b}'),
+        ('\u{1e0c}', '\u{1e0d}'), ('\u{1e0e}', '\u{1e0f}'), ('\u{1e10}', '\u{1e11}'), ('\u{1e12}',
+        '\u{1e13}'), ('\u{1e14}', '\u{1e15}'), ('\u{1e16}', '\u{1e17}'), ('\u{1e18}', '\u{1e19}'),
+        ('\u{1e1a}', '\u{1e1b}'), ('\u{1e1c}', '\u{1e1d}'), ('\u{1e1e}', '\u{1e1f}'), ('\u{1e20}',
+        '\u{1e21}'), ('\u{1e22}', '\u{1e23}'), ('\u{1e24}', '\u{1e25}'), ('\u{1e26}', '\u{1e27}'),
+        ('\u{1e28}', '\u{1e29}'), ('\u{1e2a}', '\u{1e2b}'), ('\u{1e2c}', '\u{1e2d}'), ('\u{1e2e}',
+        '\u{1e2f}'), ('\u{1e30}', '\u{1e31}'), ('\u{1e32}', '\u{1e33}'), ('\u{1e34}', '\u{1e35}'),
+        ('\u{1e36}', '\u{1e37}'), ('\u{1e38}', '\u{1e39}'), ('\u{1e3a}', '\u{1e3b}'), ('\u{1e3c}',
+        '\u{1e3d}'), ('\u{1e3e}', '\u{1e3f}'), ('\u{1e40}', '\u{1e41}'), ('\u{1e42}', '\u{1e43}'),
+        ('\u{1e44}', '\u{1e45}'), ('\u{1e46}', '\u{1e47}'), ('\u{1e48}', '\u{1e49}'), ('\u{1e4a}',
+        '\u{1e4b}'), ('\u{1e4c}', '\u{1e4d}'), ('\u{1e4e}', '\u{1e4f}'), ('\u{1e50}', '\u{1e51}'),
+        ('\u{1e52}', '\u{1e53}'), ('\u{1e54}', '\u{1e55}'), ('\u{1e56}', '\u{1e57}'), ('\u{1e58}',
+        '\u{1e59}'), ('\u{1e5a}', '\u{1e5b}'), ('\u{1e5c}', '\u{1e5d}'), ('\u{1e5e}', '\u{1e5f}'),
+        ('\u{1e60}', '\u{1e61}'), ('\u{1e62}', '\u{1e63}'), ('\u{1e64}', '\u{1e65}'), ('\u{1e66}',
+        '\u{1e67}'), ('\u{1e68}', '\u{1e69}'), ('\u{1e6a}', '\u{1e6b}'), ('\u{1e6c}', '\u{1e6d}'),
+        ('\u{1e6e}', '\u{1e6f}'), ('\u{1e70}', '\u{1e71}'), ('\u{1e72}', '\u{1e73}'), ('\u{1e74}',
+        '\u{1e75}'), ('\u{1e76}', '\u{1e77}'), ('\u{1e78}', '\u{1e79}'), ('\u{1e7a}', '\u{1e7b}'),
+        ('\u{1e7c}', '\u{1e7d}'), ('\u{1e7e}', '\u{1e7f}'), ('\u{1e80}', '\u{1e81}'), ('\u{1e82}',
+        '\u{1e83}'), ('\u{1e84}', '\u{1e85}'), ('\u{1e86}', '\u{1e87}'), ('\u{1e88}', '\u{1e89}'),
+        ('\u{1e8a}', '\u{1e8b}'), ('\u{1e8c}', '\u{1e8d}'), ('\u{1e8e}', '\u{1e8f}'), ('\u{1e90}',
+        '\u{1e91}'), ('\u{1e92}', '\u{1e93}'), ('\u{1e94}', '\u{1e95}'), ('\u{1e9b}', '\u{1e61}'),
+        ('\u{1e9e}', '\u{df}'), ('\u{1ea0}', '\u{1ea1}'), ('\u{1ea2}', '\u{1ea3}'), ('\u{1ea4}',
+        '\u{1ea5}'), ('\u{1ea6}', '\u{1ea7}'), ('\u{1ea8}', '\u{1ea9}'), ('\u{1eaa}', '\u{1eab}'),
+        ('\u{1eac}', '\u{1ead}'), ('\u{1eae}', '\u{1eaf}'), ('\u{1eb0}', '\u{1eb1}'), ('\u{1eb2}',
+        '\u{1eb3}'), ('\u{1eb4}', '\u{1eb5}'), ('\u{1eb6}', '\u{1eb7}'), ('\u{1eb8}', '\u{1eb9}'),
+        ('\u{1eba}', '\u{1ebb}'), ('\u{1ebc}', '\u{1ebd}'), ('\u{1ebe}', '\u{1ebf}'), ('\u{1ec0}',
+        '\u{1ec1}'), ('\u{1ec2}', '\u{1ec3}'), ('\u{1ec4}', '\u{1ec5}'), ('\u{1ec6}', '\u{1ec7}'),
+        ('\u{1ec8}', '\u{1ec9}'), ('\u{1eca}', '\u{1ecb}'), ('\u{1ecc}', '\u{1ecd}'), ('\u{1ece}',
+        '\u{1ecf}'), ('\u{1ed0}', '\u{1ed1}'), ('\u{1ed2}', '\u{1ed3}'), ('\u{1ed4}', '\u{1ed5}'),
+        ('\u{1ed6}', '\u{1ed7}'), ('\u{1ed8}', '\u{1ed9}'), ('\u{1eda}', '\u{1edb}'), ('\u{1edc}',
+        '\u{1edd}'), ('\u{1ede}', '\u{1edf}'), ('\u{1ee0}', '\u{1ee1}'), ('\u{1ee2}', '\u{1ee3}'),
+        ('\u{1ee4}', '\u{1ee5}'), ('\u{1ee6}', '\u{1ee7}'), ('\u{1ee8}', '\u{1ee9}'), ('\u{1eea}',
+        '\u{1eeb}'), ('\u{1eec}', '\u{1eed}'), ('\u{1eee}', '\u{1eef}'), ('\u{1ef0}', '\u{1ef1}'),
+        ('\u{1ef2}', '\u{1ef3}'), ('\u{1ef4}', '\u{1ef5}'), ('\u{1ef6}', '\u{1ef7}'), ('\u{1ef8}',
+        '\u{1ef9}'), ('\u{1efa}', '\u{1efb}'), ('\u{1efc}', '\u{1efd}'), ('\u{1efe}', '\u{1eff}'),
+        ('\u{1f08}', '\u{1f00}'), ('\u{1f09}', '\u{1f01}'), ('\u{1f0a}', '\u{1f02}'), ('\u{1f0b}',
+        '\u{1f03}'), ('\u{1f0c}', '\u{1f04}'), ('\u{1f0d}', '\u{1f05}'), ('\u{1f0e}', '\u{1f06}'),
+        ('\u{1f0f}', '\u{1f07}'), ('\u{1f18}', '\u{1f10}'), ('\u{1f19}', '\u{1f11}'), ('\u{1f1a}',
+        '\u{1f12}'), ('\u{1f1b}', '\u{1f13}'), ('\u{1f1c}', '\u{1f14}'), ('\u{1f1d}', '\u{1f15}'),
+        ('\u{1f28}', '\u{1f20}'), ('\u{1f29}', '\u{1f21}'), ('\u{1f2a}', '\u{1f22}'), ('\u{1f2b}',
+        '\u{1f23}'), ('\u{1f2c}', '\u{1f24}'), ('\u{1f2d}', '\u{1f25}'), ('\u{1f2e}', '\u{1f26}'),
+        ('\u{1f2f}', '\u{1f27}'), ('\u{1f38}', '\u{1f30}'), ('\u{1f39}', '\u{1f31}'), ('\u{1f3a}',
+        '\u{1f32}'), ('\u{1f3b}', '\u{1f33}'), ('\u{1f3c}', '\u{1f34}'), ('\u{1f3d}', '\u{1f35}'),
+        ('\u{1f3e}', '\u{1f36}'), ('\u{1f3f}', '\u{1f37}'), ('\u{1f48}', '\u{1f40}'), ('\u{1f49}',
+        '\u{1f41}'), ('\u{1f4a}', '\u{1f42}'), ('\u{1f4b}', '\u{1f43}'), ('\u{1f4c}', '\u{1f44}'),
+        ('\u{1f4d}', '\u{1f45}'), ('\u{1f59}', '\u{1f51}'), ('\u{1f5b}', '\u{1f53}'), ('\u{1f5d}',
+        '\u{1f55}'), ('\u{1f5f}', '\u{1f57}'), ('\u{1f68}', '\u{1f60}'), ('\u{1f69}', '\u{1f61}'),
+        ('\u{1f6a}', '\u{1f62}'), ('\u{1f6b}', '\u{1f63}'), ('\u{1f6c}', '\u{1f64}'), ('\u{1f6d}',
+        '\u{1f65}'), ('\u{1f6e}', '\u{1f66}'), ('\u{1f6f}', '\u{1f67}'), ('\u{1f88}', '\u{1f80}'),
+        ('\u{1f89}', '\u{1f81}'), ('\u{1f8a}', '\u{1f82}'), ('\u{1f8b}', '\u{1f83}'), ('\u{1f8c}',
+        '\u{1f84}'), ('\u{1f8d}', '\u{1f85}'), ('\u{1f8e}', '\u{1f86}'), ('\u{1f8f}', '\u{1f87}'),
+        ('\u{1f98}', '\u{1f90}'), ('\u{1f99}', '\u{1f91}'), ('\u{1f9a}', '\u{1f92}'), ('\u{1f9b}',
+        '\u{1f93}'), ('\u{1f9c}', '\u{1f94}'), ('\u{1f9d}', '\u{1f95}'), ('\u{1f9e}', '\u{1f96}'),
+        ('\u{1f9f}', '\u{1f97}'), ('\u{1fa8}', '\u{1fa0}'), ('\u{1fa9}', '\u{1fa1}'), ('\u{1faa}',
+        '\u{1fa2}'), ('\u{1fab}', '\u{1fa3}'), ('\u{1fac}', '\u{1fa4}'), ('\u{1fad}', '\u{1fa5}'),
+        ('\u{1fae}', '\u{1fa6}'), ('\u{1faf}', '\u{1fa7}'), ('\u{1fb8}', '\u{1fb0}'), ('\u{1fb9}',
+        '\u{1fb1}'), ('\u{1fba}', '\u{1f70}'), ('\u{1fbb}', '\u{1f71}'), ('\u{1fbc}', '\u{1fb3}'),
+        ('\u{1fbe}', '\u{3b9}'), ('\u{1fc8}', '\u{1f72}'), ('\u{1fc9}', '\u{1f73}'), ('\u{1fca}',
+        '\u{1f74}'), ('\u{1fcb}', '\u{1f75}'), ('\u{1fcc}', '\u{1fc3}'), ('\u{1fd8}', '\u{1fd0}'),
+        ('\u{1fd9}', '\u{1fd1}'), ('\u{1fda}', '\u{1f76}'), ('\u{1fdb}', '\u{1f77}'), ('\u{1fe8}',
+        '\u{1fe0}'), ('\u{1fe9}', '\u{1fe1}'), ('\u{1fea}', '\u{1f7a}'), ('\u{1feb}', '\u{1f7b}'),
+        ('\u{1fec}', '\u{1fe5}'), ('\u{1ff8}', '\u{1f78}'), ('\u{1ff9}', '\u{1f79}'), ('\u{1ffa}',
+        '\u{1f7c}'), ('\u{1ffb}', '\u{1f7d}'), ('\u{1ffc}', '\u{1ff3}'), ('\u{2126}', '\u{3c9}'),
+        ('\u{212a}', '\u{6b}'), ('\u{212b}', '\u{e5}'), ('\u{2132}', '\u{214e}'), ('\u{2160}',
+        '\u{2170}'), ('\u{2161}', '\u{2171}'), ('\u{2162}', '\u{2172}'), ('\u{2163}', '\u{2173}'),
+        ('\u{2164}', '\u{2174}'), ('\u{2165}', '\u{2175}'), ('\u{2166}', '\u{2176}'), ('\u{2167}',
+        '\u{2177}'), ('\u{2168}', '\u{2178}'), ('\u{2169}', '\u{2179}'), ('\u{216a}', '\u{217a}'),
+        ('\u{216b}', '\u{217b}'), ('\u{216c}', '\u{217c}'), ('\u{216d}', '\u{217d}'), ('\u{216e}',
+        '\u{217e}'), ('\u{216f}', '\u{217f}'), ('\u{2183}', '\u{2184}'), ('\u{24b6}', '\u{24d0}'),
+        ('\u{24b7}', '\u{24d1}'), ('\u{24b8}', '\u{24d2}'), ('\u{24b9}', '\u{24d3}'), ('\u{24ba}',
+        '\u{24d4}'), ('\u{24bb}', '\u{24d5}'), ('\u{24bc}', '\u{24d6}'), ('\u{24bd}', '\u{24d7}'),
+        ('\u{24be}', '\u{24d8}'), ('\u{24bf}', '\u{24d9}'), ('\u{24c0}', '\u{24da}'), ('\u{24c1}',
+        '\u{24db}'), ('\u{24c2}', '\u{24dc}'), ('\u{24c3}', '\u{24dd}'), ('\u{24c4}', '\u{24de}'),
+        ('\u{24c5}', '\u{24df}'), ('\u{24c6}', '\u{24e0}'), ('\u{24c7}', '\u{24e1}'), ('\u{24c8}',
+        '\u{24e2}'), ('\u{24c9}', '\u{24e3}'), ('\u{24ca}', '\u{24e4}'), ('\u{24cb}', '\u{24e5}'),
+        ('\u{24cc}', '\u{24e6}'), ('\u{24cd}', '\u{24e7}'), ('\u{24ce}', '\u{24e8}'), ('\u{24cf}',
+        '\u{24e9}'), ('\u{2c00}', '\u{2c30}'), ('\u{2c01}', '\u{2c31}'), ('\u{2c02}', '\u{2c32}'),
+        ('\u{2c03}', '\u{2c33}'), ('\u{2c04}', '\u{2c34}'), ('\u{2c05}', '\u{2c35}'), ('\u{2c06}',
+        '\u{2c36}'), ('\u{2c07}', '\u{2c37}'), ('\u{2c08}', '\u{2c38}'), ('\u{2c09}', '\u{2c39}'),
+        ('\u{2c0a}', '\u{2c3a}'), ('\u{2c0b}', '\u{2c3b}'), ('\u{2c0c}', '\u{2c3c}'), ('\u{2c0d}',
+        '\u{2c3d}'), ('\u{2c0e}', '\u{2c3e}'), ('\u{2c0f}', '\u{2c3f}'), ('\u{2c10}', '\u{2c40}'),
+        ('\u{2c11}', '\u{2c41}'), ('\u{2c12}', '\u{2c42}'), ('\u{2c13}', '\u{2c43}'), ('\u{2c14}',
+        '\u{2c44}'), ('\u{2c15}', '\u{2c45}'), ('\u{2c16}', '\u{2c46}'), ('\u{2c17}', '\u{2c47}'),
+        ('\u{2c18}', '\u{2c48}'), ('\u{2c19}', '\u{2c49}'), ('\u{2c1a}', '\u{2c4a}'), ('\u{2c1b}',
+        '\u{2c4b}'), ('\u{2c1c}', '\u{2c4c}'), ('\u{2c1d}', '\u{2c4d}'), ('\u{2c1e}', '\u{2c4e}'),
+        ('\u{2c1f}', '\u{2c4f}'), ('\u{2c20}', '\u{2c50}'), ('\u{2c21}', '\u{2c51}'), ('\u{2c22}',
+        '\u{2c52}'), ('\u{2c23}', '\u{2c53}'), ('\u{2c24}', '\u{2c54}'), ('\u{2c25}', '\u{2c55}'),
+        ('\u{2c26}', '\u{2c56}'), ('\u{2c27}', '\u{2c57}'), ('\u{2c28}', '\u{2c58}'), ('\u{2c29}',
+        '\u{2c59}'), ('\u{2c2a}', '\u{2c5a}'), ('\u{2c2b}', '\u{2c5b}'), ('\u{2c2c}', '\u{2c5c}'),
+        ('\u{2c2d}', '\u{2c5d}'), ('\u{2c2e}', '\u{2c5e}'), ('\u{2c60}', '\u{2c61}'), ('\u{2c62}',
+        '\u{26b}'), ('\u{2c63}', '\u{1d7d}'), ('\u{2c64}', '\u{27d}'), ('\u{2c67}', '\u{2c68}'),
+        ('\u{2c69}', '\u{2c6a}'), ('\u{2c6b}', '\u{2c6c}'), ('\u{2c6d}', '\u{251}'), ('\u{2c6e}',
+        '\u{271}'), ('\u{2c6f}', '\u{250}'), ('\u{2c70}', '\u{252}'), ('\u{2c72}', '\u{2c73}'),
+        ('\u{2c75}', '\u{2c76}'), ('\u{2c7e}', '\u{23f}'), ('\u{2c7f}', '\u{240}'), ('\u{2c80}',
+        '\u{2c81}'), ('\u{2c82}', '\u{2c83}'), ('\u{2c84}', '\u{2c85}'), ('\u{2c86}', '\u{2c87}'),
+        ('\u{2c88}', '\u{2c89}'), ('\u{2c8a}', '\u{2c8b}'), ('\u{2c8c}', '\u{2c8d}'), ('\u{2c8e}',
+        '\u{2c8f}'), ('\u{2c90}', '\u{2c91}'), ('\u{2c92}', '\u{2c93}'), ('\u{2c94}', '\u{2c95}'),
+        ('\u{2c96}', '\u{2c97}'), ('\u{2c98}', '\u{2c99}'), ('\u{2c9a}', '\u{2c9b}'), ('\u{2c9c}',
+        '\u{2c9d}'), ('\u{2c9e}', '\u{2c9f}'), ('\u{2ca0}', '\u{2ca1}'), ('\u{2ca2}', '\u{2ca3}'),
+        ('\u{2ca4}', '\u{2ca5}'), ('\u{2ca6}', '\u{2ca7}'), ('\u{2ca8}', '\u{2ca9}'), ('\u{2caa}',
+        '\u{2cab}'), ('\u{2cac}', '\u{2cad}'), ('\u{2cae}', '\u{2caf}'), ('\u{2cb0}', '\u{2cb1}'),
+        ('\u{2cb2}', '\u{2cb3}'), ('\u{2cb4}', '\u{2cb5}'), ('\u{2cb6}', '\u{2cb7}'), ('\u{2cb8}',
+        '\u{2cb9}'), ('\u{2cba}', '\u{2cbb}'), ('\u{2cbc}', '\u{2cbd}'), ('\u{2cbe}', '\u{2cbf}'),
+        ('\u{2cc0}', '\u{2cc1}'), ('\u{2cc2}', '\u{2cc3}'), ('\u{2cc4}', '\u{2cc5}'), ('\u{2cc6}',
+        '\u{2cc7}'), ('\u{2cc8}', '\u{2cc9}'), ('\u{2cca}', '\u{2ccb}'), ('\u{2ccc}', '\u{2ccd}'),
+        ('\u{2cce}', '\u{2ccf}'), ('\u{2cd0}', '\u{2cd1}'), ('\u{2cd2}', '\u{2cd3}'), ('\u{2cd4}',
+        '\u{2cd5}'), ('\u{2cd6}', '\u{2cd7}'), ('\u{2cd8}', '\u{2cd9}'), ('\u{2cda}', '\u{2cdb}'),
+        ('\u{2cdc}', '\u{2cdd}'), ('\u{2cde}', '\u{2cdf}'), ('\u{2ce0}', '\u{2ce1}'), ('\u{2ce2}',
+        '\u{2ce3}'), ('\u{2ceb}', '\u{2cec}'), ('\u{2ced}', '\u{2cee}'), ('\u{2cf2}', '\u{2cf3}'),
+        ('\u{a640}', '\u{a641}'), ('\u{a642}', '\u{a643}'), ('\u{a644}', '\u{a645}'), ('\u{a646}',
+        '\u{a647}'), ('\u{a648}', '\u{a649}'), ('\u{a64a}', '\u{a64b}'), ('\u{a64c}', '\u{a64d}'),
+        ('\u{a64e}', '\u{a64f}'), ('\u{a650}', '\u{a651}'), ('\u{a652}', '\u{a653}'), ('\u{a654}',
+        '\u{a655}'), ('\u{a656}', '\u{a657}'), ('\u{a658}', '\u{a659}'), ('\u{a65a}', '\u{a65b}'),
+        ('\u{a65c}', '\u{a65d}'), ('\u{a65e}', '\u{a65f}'), ('\u{a660}', '\u{a661}'), ('\u{a662}',
+        '\u{a663}'), ('\u{a664}', '\u{a665}'), ('\u{a666}', '\u{a667}'), ('\u{a668}', '\u{a669}'),
+        ('\u{a66a}', '\u{a66b}'), ('\u{a66c}', '\u{a66d}'), ('\u{a680}', '\u{a681}'), ('\u{a682}',
+        '\u{a683}'), ('\u{a684}', '\u{a685}'), ('\u{a686}', '\u{a687}'), ('\u{a688}', '\u{a689}'),
+        ('\u{a68a}', '\u{a68b}'), ('\u{a68c}', '\u{a68d}'), ('\u{a68e}', '\u{a68f}'), ('\u{a690}',
+        '\u{a691}'), ('\u{a692}', '\u{a693}'), ('\u{a694}', '\u{a695}'), ('\u{a696}', '\u{a697}'),
+        ('\u{a698}', '\u{a699}'), ('\u{a69a}', '\u{a69b}'), ('\u{a722}', '\u{a723}'), ('\u{a724}',
+        '\u{a725}'), ('\u{a726}', '\u{a727}'), ('\u{a728}', '\u{a729}'), ('\u{a72a}', '\u{a72b}'),
+        ('\u{a72c}', '\u{a72d}'), ('\u{a72e}', '\u{a72f}'), ('\u{a732}', '\u{a733}'), ('\u{a734}',
+        '\u{a735}'), ('\u{a736}', '\u{a737}'), ('\u{a738}', '\u{a739}'), ('\u{a73a}', '\u{a73b}'),
+        ('\u{a73c}', '\u{a73d}'), ('\u{a73e}', '\u{a73f}'), ('\u{a740}', '\u{a741}'), ('\u{a742}',
+        '\u{a743}'), ('\u{a744}', '\u{a745}'), ('\u{a746}', '\u{a747}'), ('\u{a748}', '\u{a749}'),
+        ('\u{a74a}', '\u{a74b}'), ('\u{a74c}', '\u{a74d}'), ('\u{a74e}', '\u{a74f}'), ('\u{a750}',
+        '\u{a751}'), ('\u{a752}', '\u{a753}'), ('\u{a754}', '\u{a755}'), ('\u{a756}', '\u{a757}'),
+        ('\u{a758}', '\u{a759}'), ('\u{a75a}', '\u{a75b}'), ('\u{a75c}', '\u{a75d}'), ('\u{a75e}',
+        '\u{a75f}'), ('\u{a760}', '\u{a761}'), ('\u{a762}', '\u{a763}'), ('\u{a764}', '\u{a765}'),
+        ('\u{a766}', '\u{a767}'), ('\u{a768}', '\u{a769}'), ('\u{a76a}', '\u{a76b}'), ('\u{a76c}',
+        '\u{a76d}'), ('\u{a76e}', '\u{a76f}'), ('\u{a779}', '\u{a77a}'), ('\u{a77b}', '\u{a77c}'),
+        ('\u{a77d}', '\u{1d79}'), ('\u{a77e}', '\u{a77f}'), ('\u{a780}', '\u{a781}'), ('\u{a782}',
+        '\u{a783}'), ('\u{a784}', '\u{a785}'), ('\u{a786}', '\u{a787}'), ('\u{a78b}', '\u{a78c}'),
+        ('\u{a78d}', '\u{265}'), ('\u{a790}', '\u{a791}'), ('\u{a792}', '\u{a793}'), ('\u{a796}',
+        '\u{a797}'), ('\u{a798}', '\u{a799}'), ('\u{a79a}', '\u{a79b}'), ('\u{a79c}', '\u{a79d}'),
+        ('\u{a79e}', '\u{a79f}'), ('\u{a7a0}', '\u{a7a1}'), ('\u{a7a2}', '\u{a7a3}'), ('\u{a7a4}',
+        '\u{a7a5}'), ('\u{a7a6}', '\u{a7a7}'), ('\u{a7a8}', '\u{a7a9}'), ('\u{a7aa}', '\u{266}'),
+        ('\u{a7ab}', '\u{25c}'), ('\u{a7ac}', '\u{261}'), ('\u{a7ad}', '\u{26c}'), ('\u{a7b0}',
+        '\u{29e}'), ('\u{a7b1}', '\u{287}'), ('\u{ff21}', '\u{ff41}'), ('\u{ff22}', '\u{ff42}'),
+        ('\u{ff23}', '\u{ff43}'), ('\u{ff24}', '\u{ff44}'), ('\u{ff25}', '\u{ff45}'), ('\u{ff26}',
+        '\u{ff46}'), ('\u{ff27}', '\u{ff47}'), ('\u{ff28}', '\u{ff48}'), ('\u{ff29}', '\u{ff49}'),
+        ('\u{ff2a}', '\u{ff4a}'), ('\u{ff2b}', '\u{ff4b}'), ('\u{ff2c}', '\u{ff4c}'), ('\u{ff2d}',
+        '\u{ff4d}'), ('\u{ff2e}', '\u{ff4e}'), ('\u{ff2f}', '\u{ff4f}'), ('\u{ff30}', '\u{ff50}'),
+        ('\u{ff31}', '\u{ff51}'), ('\u{ff32}', '\u{ff52}'), ('\u{ff33}', '\u{ff53}'), ('\u{ff34}',
+        '\u{ff54}'), ('\u{ff35}', '\u{ff55}'), ('\u{ff36}', '\u{ff56}'), ('\u{ff37}', '\u{ff57}'),
+        ('\u{ff38}', '\u{ff58}'), ('\u{ff39}', '\u{ff59}'), ('\u{ff3a}', '\u{ff5a}'), ('\u{10400}',
+        '\u{10428}'), ('\u{10401}', '\u{10429}'), ('\u{10402}', '\u{1042a}'), ('\u{10403}',
+        '\u{1042b}'), ('\u{10404}', '\u{1042c}'), ('\u{10405}', '\u{1042d}'), ('\u{10406}',
+        '\u{1042e}'), ('\u{10407}', '\u{1042f}'), ('\u{10408}', '\u{10430}'), ('\u{10409}',
+        '\u{10431}'), ('\u{1040a}', '\u{10432}'), ('\u{1040b}', '\u{10433}'), ('\u{1040c}',
+        '\u{10434}'), ('\u{1040d}', '\u{10435}'), ('\u{1040e}', '\u{10436}'), ('\u{1040f}',
+        '\u{10437}'), ('\u{10410}', '\u{10438}'), ('\u{10411}', '\u{10439}'), ('\u{10412}',
+        '\u{1043a}'), ('\u{10413}', '\u{1043b}'), ('\u{10414}', '\u{1043c}'), ('\u{10415}',
+        '\u{1043d}'), ('\u{10416}', '\u{1043e}'), ('\u{10417}', '\u{1043f}'), ('\u{10418}',
+        '\u{10440}'), ('\u{10419}', '\u{10441}'), ('\u{1041a}', '\u{10442}'), ('\u{1041b}',
+        '\u{10443}'), ('\u{1041c}', '\u{10444}'), ('\u{1041d}', '\u{10445}'), ('\u{1041e}',
+        '\u{10446}'), ('\u{1041f}', '\u{10447}'), ('\u{10420}', '\u{10448}'), ('\u{10421}',
+        '\u{10449}'), ('\u{10422}', '\u{1044a}'), ('\u{10423}', '\u{1044b}'), ('\u{10424}',
+        '\u{1044c}'), ('\u{10425}', '\u{1044d}'), ('\u{10426}', '\u{1044e}'), ('\u{10427}',
+        '\u{1044f}'), ('\u{118a0}', '\u{118c0}'), ('\u{118a1}', '\u{118c1}'), ('\u{118a2}',
+        '\u{118c2}'), ('\u{118a3}', '\u{118c3}'), ('\u{118a4}', '\u{118c4}'), ('\u{118a5}',
+        '\u{118c5}'), ('\u{118a6}', '\u{118c6}'), ('\u{118a7}', '\u{118c7}'), ('\u{118a8}',
+        '\u{118c8}'), ('\u{118a9}', '\u{118c9}'), ('\u{118aa}', '\u{118ca}'), ('\u{118ab}',
+        '\u{118cb}'), ('\u{118ac}', '\u{118cc}'), ('\u{118ad}', '\u{118cd}'), ('\u{118ae}',
+        '\u{118ce}'), ('\u{118af}', '\u{118cf}'), ('\u{118b0}', '\u{118d0}'), ('\u{118b1}',
+        '\u{118d1}'), ('\u{118b2}', '\u{118d2}'), ('\u{118b3}', '\u{118d3}'), ('\u{118b4}',
+        '\u{118d4}'), ('\u{118b5}', '\u{118d5}'), ('\u{118b6}', '\u{118d6}'), ('\u{118b7}',
+        '\u{118d7}'), ('\u{118b8}', '\u{118d8}'), ('\u{118b9}', '\u{118d9}'), ('\u{118ba}',
+        '\u{118da}'), ('\u{118bb}', '\u{118db}'), ('\u{118bc}', '\u{118dc}'), ('\u{118bd}',
+        '\u{118dd}'), ('\u{118be}', '\u{118de}'), ('\u{118bf}', '\u{118df}')
+    ];
+
+    static lower_full_table: &'static [(char, &'static [char])] = &[
+        ('\u{130}', &['\u{69}', '\u{307}'])
+    ];
+
+    static upper_full_table: &'static [(char, &'static [char])] = &[
+        ('\u{df}', &['\u{53}', '\u{53}']), ('\u{149}', &['\u{2bc}', '\u{4e}']), ('\u{1f0}',
+        &['\u{4a}', '\u{30c}']), ('\u{390}', &['\u{399}', '\u{308}', '\u{301}']), ('\u{3b0}',
+        &['\u{3a5}', '\u{308}', '\u{301}']), ('\u{587}', &['\u{535}', '\u{552}']), ('\u{1e96}',
+        &['\u{48}', '\u{331}']), ('\u{1e97}', &['\u{54}', '\u{308}']), ('\u{1e98}', &['\u{57}',
+        '\u{30a}']), ('\u{1e99}', &['\u{59}', '\u{30a}']), ('\u{1e9a}', &['\u{41}', '\u{2be}']),
+        ('\u{1f50}', &['\u{3a5}', '\u{313}']), ('\u{1f52}', &['\u{3a5}', '\u{313}', '\u{300}']),
+        ('\u{1f54}', &['\u{3a5}', '\u{313}', '\u{301}']), ('\u{1f56}', &['\u{3a5}', '\u{313}',
+        '\u{342}']), ('\u{1f80}', &['\u{1f08}', '\u{399}']), ('\u{1f81}', &['\u{1f09}', '\u{399}']),
+        ('\u{1f82}', &['\u{1f0a}', '\u{399}']), ('\u{1f83}', &['\u{1f0b}', '\u{399}']), ('\u{1f84}',
+        &['\u{1f0c}', '\u{399}']), ('\u{1f85}', &['\u{1f0d}', '\u{399}']), ('\u{1f86}',
+        &['\u{1f0e}', '\u{399}']), ('\u{1f87}', &['\u{1f0f}', '\u{399}']), ('\u{1f88}',
+        &['\u{1f08}', '\u{399}']), ('\u{1f89}', &['\u{1f09}', '\u{399}']), ('\u{1f8a}',
+        &['\u{1f0a}', '\u{399}']), ('\u{1f8b}', &['\u{1f0b}', '\u{399}']), ('\u{1f8c}',
+        &['\u{1f0c}', '\u{399}']), ('\u{1f8d}', &['\u{1f0d}', '\u{399}']), ('\u{1f8e}',
+        &['\u{1f0e}', '\u{399}']), ('\u{1f8f}', &['\u{1f0f}', '\u{399}']), ('\u{1f90}',
+        &['\u{1f28}', '\u{399}']), ('\u{1f91}', &['\u{1f29}', '\u{399}']), ('\u{1f92}',
+        &['\u{1f2a}', '\u{399}']), ('\u{1f93}', &['\u{1f2b}', '\u{399}']), ('\u{1f94}',
+        &['\u{1f2c}', '\u{399}']), ('\u{1f95}', &['\u{1f2d}', '\u{399}']), ('\u{1f96}',
+        &['\u{1f2e}', '\u{399}']), ('\u{1f97}', &['\u{1f2f}', '\u{399}']), ('\u{1f98}',
+        &['\u{1f28}', '\u{399}']), ('\u{1f99}', &['\u{1f29}', '\u{399}']), ('\u{1f9a}',
+        &['\u{1f2a}', '\u{399}']), ('\u{1f9b}', &['\u{1f2b}', '\u{399}']), ('\u{1f9c}',
+        &['\u{1f2c}', '\u{399}']), ('\u{1f9d}', &['\u{1f2d}', '\u{399}']), ('\u{1f9e}',
+        &['\u{1f2e}', '\u{399}']), ('\u{1f9f}', &['\u{1f2f}', '\u{399}']), ('\u{1fa0}',
+        &['\u{1f68}', '\u{399}']), ('\u{1fa1}', &['\u{1f69}', '\u{399}']), ('\u{1fa2}',
+        &['\u{1f6a}', '\u{399}']), ('\u{1fa3}', &['\u{1f6b}', '\u{399}']), ('\u{1fa4}',
+        &['\u{1f6c}', '\u{399}']), ('\u{1fa5}', &['\u{1f6d}', '\u{399}']), ('\u{1fa6}',
+        &['\u{1f6e}', '\u{399}']), ('\u{1fa7}', &['\u{1f6f}', '\u{399}']), ('\u{1fa8}',
+        &['\u{1f68}', '\u{399}']), ('\u{1fa9}', &['\u{1f69}', '\u{399}']), ('\u{1faa}',
+        &['\u{1f6a}', '\u{399}']), ('\u{1fab}', &['\u{1f6b}', '\u{399}']), ('\u{1fac}',
+        &['\u{1f6c}', '\u{399}']), ('\u{1fad}', &['\u{1f6d}', '\u{399}']), ('\u{1fae}',
+        &['\u{1f6e}', '\u{399}']), ('\u{1faf}', &['\u{1f6f}', '\u{399}']), ('\u{1fb2}',
+        &['\u{1fba}', '\u{399}']), ('\u{1fb3}', &['\u{391}', '\u{399}']), ('\u{1fb4}', &['\u{386}',
+        '\u{399}']), ('\u{1fb6}', &['\u{391}', '\u{342}']), ('\u{1fb7}', &['\u{391}', '\u{342}',
+        '\u{399}']), ('\u{1fbc}', &['\u{391}', '\u{399}']), ('\u{1fc2}', &['\u{1fca}', '\u{399}']),
+        ('\u{1fc3}', &['\u{397}', '\u{399}']), ('\u{1fc4}', &['\u{389}', '\u{399}']), ('\u{1fc6}',
+        &['\u{397}', '\u{342}']), ('\u{1fc7}', &['\u{397}', '\u{342}', '\u{399}']), ('\u{1fcc}',
+        &['\u{397}', '\u{399}']), ('\u{1fd2}', &['\u{399}', '\u{308}', '\u{300}']), ('\u{1fd3}',
+        &['\u{399}', '\u{308}', '\u{301}']), ('\u{1fd6}', &['\u{399}', '\u{342}']), ('\u{1fd7}',
+        &['\u{399}', '\u{308}', '\u{342}']), ('\u{1fe2}', &['\u{3a5}', '\u{308}', '\u{300}']),
+        ('\u{1fe3}', &['\u{3a5}', '\u{308}', '\u{301}']), ('\u{1fe4}', &['\u{3a1}', '\u{313}']),
+        ('\u{1fe6}', &['\u{3a5}', '\u{342}']), ('\u{1fe7}', &['\u{3a5}', '\u{308}', '\u{342}']),
+        ('\u{1ff2}', &['\u{1ffa}', '\u{399}']), ('\u{1ff3}', &['\u{3a9}', '\u{399}']), ('\u{1ff4}',
+        &['\u{38f}', '\u{399}']), ('\u{1ff6}', &['\u{3a9}', '\u{342}']), ('\u{1ff7}', &['\u{3a9}',
+        '\u{342}', '\u{399}']), ('\u{1ffc}', &['\u{3a9}', '\u{399}']), ('\u{fb00}', &['\u{46}',
+        '\u{46}']), ('\u{fb01}', &['\u{46}', '\u{49}']), ('\u{fb02}', &['\u{46}', '\u{4c}']),
+        ('\u{fb03}', &['\u{46}', '\u{46}', '\u{49}']), ('\u{fb04}', &['\u{46}', '\u{46}',
+        '\u{4c}']), ('\u{fb05}', &['\u{53}', '\u{54}']), ('\u{fb06}', &['\u{53}', '\u{54}']),
+        ('\u{fb13}', &['\u{544}', '\u{546}']), ('\u{fb14}', &['\u{544}', '\u{535}']), ('\u{fb15}',
+        &['\u{544}', '\u{53b}']), ('\u{fb16}', &['\u{54e}', '\u{546}']), ('\u{fb17}', &['\u{544}',
+        '\u{53d}'])
+    ];
+
+}
+
+pub mod bidi {
+    use core::cmp::Ordering::{Equal, Less, Greater};
+    use core::kinds::Copy;
+    use core::slice::SlicePrelude;
+    use core::tuple::Tuple2;
+    use core::option::Option;
+    use core::option::Option::{Some, None};
+    use core::slice;
+    pub use self::BidiClass::*;
+
+    /// The Unicode Bidi_Class of a character
+    #[deriving(Clone, PartialEq, Eq, Show)]
+    pub enum BidiClass {
+        /// Left_To_Right
+        L,
+        /// Right_To_Left
+        R,
+        /// Arabic_Letter
+        AL,
+        /// European_Number
+        EN,
+        /// European_Separator
+        ES,
+        /// European_Terminator
+        ET,
+        /// Arabic_Number
+        AN,
+        /// Common_Separator
+        CS,
+        /// Nonspacing_Mark
+        NSM,
+        /// Boundary_Neutral
+        BN,
+        /// Paragraph_Separator
+        B,
+        /// Segment_Separator
+        S,
+        /// White_Space
+        WS,
+        /// Other_Neutral
+        ON,
+        /// Left_To_Right_Embedding
+        LRE,
+        /// Left_To_Right_Override
+        LRO,
+        /// Right_To_Left_Embedding
+        RLE,
+        /// Right_To_Left_Override
+        RLO,
+        /// Pop_Directional_Format
+        PDF,
+        /// Left_To_Right_Isolate
+        LRI,
+        /// Right_To_Left_Isolate
+        RLI,
+        /// First_Strong_Isolate
+        FSI,
+        /// Pop_Directional_Isolate
+        PDI,
+    }
+
+    impl Copy for BidiClass {}
+
+    pub fn bidi_class(c: char) -> BidiClass {
+        match bidi_class_table.binary_search(|&(lo, hi, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(idx) => {
+                let (_, _, cls) = bidi_class_table[idx];
+                cls
+            }
+            slice::BinarySearchResult::NotFound(_) => L
+        }
+    }
+
+    pub fn mirror(c: char) -> Option<char> {
+        match mirror_table.binary_search(|&(key, _)| {
+            if c == key { Equal }
+            else if key < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(i) => Some(mirror_table[i].val1()),
+            slice::BinarySearchResult::NotFound(_) => None,
+        }
+    }
+
+    static bidi_class_table: &'static [(char, char, BidiClass)] = &[
+        ('\u{0}', '\u{8}', BN), ('\u{9}', '\u{9}', S), ('\u{a}', '\u{a}', B), ('\u{b}', '\u{b}', S),
+        ('\u{c}', '\u{c}', WS), ('\u{d}', '\u{d}', B), ('\u{e}', '\u{1b}', BN), ('\u{1c}', '\u{1e}',
+        B), ('\u{1f}', '\u{1f}', S), ('\u{20}', '\u{20}', WS), ('\u{21}', '\u{22}', ON), ('\u{23}',
+        '\u{25}', ET), ('\u{26}', '\u{2a}', ON), ('\u{2b}', '\u{2b}', ES), ('\u{2c}', '\u{2c}', CS),
+        ('\u{2d}', '\u{2d}', ES), ('\u{2e}', '\u{2f}', CS), ('\u{30}', '\u{39}', EN), ('\u{3a}',
+        '\u{3a}', CS), ('\u{3b}', '\u{40}', ON), ('\u{5b}', '\u{60}', ON), ('\u{7b}', '\u{7e}', ON),
+        ('\u{7f}', '\u{84}', BN), ('\u{85}', '\u{85}', B), ('\u{86}', '\u{9f}', BN), ('\u{a0}',
+        '\u{a0}', CS), ('\u{a1}', '\u{a1}', ON), ('\u{a2}', '\u{a5}', ET), ('\u{a6}', '\u{a9}', ON),
+        ('\u{ab}', '\u{ac}', ON), ('\u{ad}', '\u{ad}', BN), ('\u{ae}', '\u{af}', ON), ('\u{b0}',
+        '\u{b1}', ET), ('\u{b2}', '\u{b3}', EN), ('\u{b4}', '\u{b4}', ON), ('\u{b6}', '\u{b8}', ON),
+        ('\u{b9}', '\u{b9}', EN), ('\u{bb}', '\u{bf}', ON), ('\u{d7}', '\u{d7}', ON), ('\u{f7}',
+        '\u{f7}', ON), ('\u{2b9}', '\u{2ba}', ON), ('\u{2c2}', '\u{2cf}', ON), ('\u{2d2}',
+        '\u{2df}', ON), ('\u{2e5}', '\u{2ed}', ON), ('\u{2ef}', '\u{2ff}', ON), ('\u{300}',
+        '\u{36f}', NSM), ('\u{374}', '\u{375}', ON), ('\u{37e}', '\u{37e}', ON), ('\u{384}',
+        '\u{385}', ON), ('\u{387}', '\u{387}', ON), ('\u{3f6}', '\u{3f6}', ON), ('\u{483}',
+        '\u{489}', NSM), ('\u{58a}', '\u{58a}', ON), ('\u{58d}', '\u{58e}', ON), ('\u{58f}',
+        '\u{58f}', ET), ('\u{590}', '\u{590}', R), ('\u{591}', '\u{5bd}', NSM), ('\u{5be}',
+        '\u{5be}', R), ('\u{5bf}', '\u{5bf}', NSM), ('\u{5c0}', '\u{5c0}', R), ('\u{5c1}',
+        '\u{5c2}', NSM), ('\u{5c3}', '\u{5c3}', R), ('\u{5c4}', '\u{5c5}', NSM), ('\u{5c6}',
+        '\u{5c6}', R), ('\u{5c7}', '\u{5c7}', NSM), ('\u{5c8}', '\u{5ff}', R), ('\u{600}',
+        '\u{605}', AN), ('\u{606}', '\u{607}', ON), ('\u{608}', '\u{608}', AL), ('\u{609}',
+        '\u{60a}', ET), ('\u{60b}', '\u{60b}', AL), ('\u{60c}', '\u{60c}', CS), ('\u{60d}',
+        '\u{60d}', AL), ('\u{60e}', '\u{60f}', ON), ('\u{610}', '\u{61a}', NSM), ('\u{61b}',
+        '\u{61c}', AL), ('\u{61d}', '\u{61d}', R), ('\u{61e}', '\u{64a}', AL), ('\u{64b}',
+        '\u{65f}', NSM), ('\u{660}', '\u{669}', AN), ('\u{66a}', '\u{66a}', ET), ('\u{66b}',
+        '\u{66c}', AN), ('\u{66d}', '\u{66f}', AL), ('\u{670}', '\u{670}', NSM), ('\u{671}',
+        '\u{6d5}', AL), ('\u{6d6}', '\u{6dc}', NSM), ('\u{6dd}', '\u{6dd}', AN), ('\u{6de}',
+        '\u{6de}', ON), ('\u{6df}', '\u{6e4}', NSM), ('\u{6e5}', '\u{6e6}', AL), ('\u{6e7}',
+        '\u{6e8}', NSM), ('\u{6e9}', '\u{6e9}', ON), ('\u{6ea}', '\u{6ed}', NSM), ('\u{6ee}',
+        '\u{6ef}', AL), ('\u{6f0}', '\u{6f9}', EN), ('\u{6fa}', '\u{710}', AL), ('\u{711}',
+        '\u{711}', NSM), ('\u{712}', '\u{72f}', AL), ('\u{730}', '\u{74a}', NSM), ('\u{74b}',
+        '\u{7a5}', AL), ('\u{7a6}', '\u{7b0}', NSM), ('\u{7b1}', '\u{7bf}', AL), ('\u{7c0}',
+        '\u{7ea}', R), ('\u{7eb}', '\u{7f3}', NSM), ('\u{7f4}', '\u{7f5}', R), ('\u{7f6}',
+        '\u{7f9}', ON), ('\u{7fa}', '\u{815}', R), ('\u{816}', '\u{819}', NSM), ('\u{81a}',
+        '\u{81a}', R), ('\u{81b}', '\u{823}', NSM), ('\u{824}', '\u{824}', R), ('\u{825}',
+        '\u{827}', NSM), ('\u{828}', '\u{828}', R), ('\u{829}', '\u{82d}', NSM), ('\u{82e}',
+        '\u{858}', R), ('\u{859}', '\u{85b}', NSM), ('\u{85c}', '\u{865}', R), ('\u{866}',
+        '\u{8e3}', AL), ('\u{8e4}', '\u{902}', NSM), ('\u{93a}', '\u{93a}', NSM), ('\u{93c}',
+        '\u{93c}', NSM), ('\u{941}', '\u{948}', NSM), ('\u{94d}', '\u{94d}', NSM), ('\u{951}',
+        '\u{957}', NSM), ('\u{962}', '\u{963}', NSM), ('\u{981}', '\u{981}', NSM), ('\u{9bc}',
+        '\u{9bc}', NSM), ('\u{9c1}', '\u{9c4}', NSM), ('\u{9cd}', '\u{9cd}', NSM), ('\u{9e2}',
+        '\u{9e3}', NSM), ('\u{9f2}', '\u{9f3}', ET), ('\u{9fb}', '\u{9fb}', ET), ('\u{a01}',
+        '\u{a02}', NSM), ('\u{a3c}', '\u{a3c}', NSM), ('\u{a41}', '\u{a42}', NSM), ('\u{a47}',
+        '\u{a48}', NSM), ('\u{a4b}', '\u{a4d}', NSM), ('\u{a51}', '\u{a51}', NSM), ('\u{a70}',
+        '\u{a71}', NSM), ('\u{a75}', '\u{a75}', NSM), ('\u{a81}', '\u{a82}', NSM), ('\u{abc}',
+        '\u{abc}', NSM), ('\u{ac1}', '\u{ac5}', NSM), ('\u{ac7}', '\u{ac8}', NSM), ('\u{acd}',
+        '\u{acd}', NSM), ('\u{ae2}', '\u{ae3}', NSM), ('\u{af1}', '\u{af1}', ET), ('\u{b01}',
+        '\u{b01}', NSM), ('\u{b3c}', '\u{b3c}', NSM), ('\u{b3f}', '\u{b3f}', NSM), ('\u{b41}',
+        '\u{b44}', NSM), ('\u{b4d}', '\u{b4d}', NSM), ('\u{b56}', '\u{b56}', NSM), ('\u{b62}',
+        '\u{b63}', NSM), ('\u{b82}', '\u{b82}', NSM), ('\u{bc0}', '\u{bc0}', NSM), ('\u{bcd}',
+        '\u{bcd}', NSM), ('\u{bf3}', '\u{bf8}', ON), ('\u{bf9}', '\u{bf9}', ET), ('\u{bfa}',
+        '\u{bfa}', ON), ('\u{c00}', '\u{c00}', NSM), ('\u{c3e}', '\u{c40}', NSM), ('\u{c46}',
+        '\u{c48}', NSM), ('\u{c4a}', '\u{c4d}', NSM), ('\u{c55}', '\u{c56}', NSM), ('\u{c62}',
+        '\u{c63}', NSM), ('\u{c78}', '\u{c7e}', ON), ('\u{c81}', '\u{c81}', NSM), ('\u{cbc}',
+        '\u{cbc}', NSM), ('\u{ccc}', '\u{ccd}', NSM), ('\u{ce2}', '\u{ce3}', NSM), ('\u{d01}',
+        '\u{d01}', NSM), ('\u{d41}', '\u{d44}', NSM), ('\u{d4d}', '\u{d4d}', NSM), ('\u{d62}',
+        '\u{d63}', NSM), ('\u{dca}', '\u{dca}', NSM), ('\u{dd2}', '\u{dd4}', NSM), ('\u{dd6}',
+        '\u{dd6}', NSM), ('\u{e31}', '\u{e31}', NSM), ('\u{e34}', '\u{e3a}', NSM), ('\u{e3f}',
+        '\u{e3f}', ET), ('\u{e47}', '\u{e4e}', NSM), ('\u{eb1}', '\u{eb1}', NSM), ('\u{eb4}',
+        '\u{eb9}', NSM), ('\u{ebb}', '\u{ebc}', NSM), ('\u{ec8}', '\u{ecd}', NSM), ('\u{f18}',
+        '\u{f19}', NSM), ('\u{f35}', '\u{f35}', NSM), ('\u{f37}', '\u{f37}', NSM), ('\u{f39}',
+        '\u{f39}', NSM), ('\u{f3a}', '\u{f3d}', ON), ('\u{f71}', '\u{f7e}', NSM), ('\u{f80}',
+        '\u{f84}', NSM), ('\u{f86}', '\u{f87}', NSM), ('\u{f8d}', '\u{f97}', NSM), ('\u{f99}',
+        '\u{fbc}', NSM), ('\u{fc6}', '\u{fc6}', NSM), ('\u{102d}', '\u{1030}', NSM), ('\u{1032}',
+        '\u{1037}', NSM), ('\u{1039}', '\u{103a}', NSM), ('\u{103d}', '\u{103e}', NSM), ('\u{1058}',
+        '\u{1059}', NSM), ('\u{105e}', '\u{1060}', NSM), ('\u{1071}', '\u{1074}', NSM), ('\u{1082}',
+        '\u{1082}', NSM), ('\u{1085}', '\u{1086}', NSM), ('\u{108d}', '\u{108d}', NSM), ('\u{109d}',
+        '\u{109d}', NSM), ('\u{135d}', '\u{135f}', NSM), ('\u{1390}', '\u{1399}', ON), ('\u{1400}',
+        '\u{1400}', ON), ('\u{1680}', '\u{1680}', WS), ('\u{169b}', '\u{169c}', ON), ('\u{1712}',
+        '\u{1714}', NSM), ('\u{1732}', '\u{1733}', NSM), ('\u{1752}', '\u{1753}', NSM), ('\u{1772}',
+        '\u{1773}', NSM), ('\u{17b4}', '\u{17b5}', NSM), ('\u{17b7}', '\u{17bd}', NSM), ('\u{17c6}',
+        '\u{17c6}', NSM), ('\u{17c9}', '\u{17d3}', NSM), ('\u{17db}', '\u{17db}', ET), ('\u{17dd}',
+        '\u{17dd}', NSM), ('\u{17f0}', '\u{17f9}', ON), ('\u{1800}', '\u{180a}', ON), ('\u{180b}',
+        '\u{180d}', NSM), ('\u{180e}', '\u{180e}', BN), ('\u{1885}', '\u{1886}', NSM), ('\u{18a9}',
+        '\u{18a9}', NSM), ('\u{1920}', '\u{1922}', NSM), ('\u{1927}', '\u{1928}', NSM), ('\u{1932}',
+        '\u{1932}', NSM), ('\u{1939}', '\u{193b}', NSM), ('\u{1940}', '\u{1940}', ON), ('\u{1944}',
+        '\u{1945}', ON), ('\u{19de}', '\u{19ff}', ON), ('\u{1a17}', '\u{1a18}', NSM), ('\u{1a1b}',
+        '\u{1a1b}', NSM), ('\u{1a56}', '\u{1a56}', NSM), ('\u{1a58}', '\u{1a5e}', NSM), ('\u{1a60}',
+        '\u{1a60}', NSM), ('\u{1a62}', '\u{1a62}', NSM), ('\u{1a65}', '\u{1a6c}', NSM), ('\u{1a73}',
+        '\u{1a7c}', NSM), ('\u{1a7f}', '\u{1a7f}', NSM), ('\u{1ab0}', '\u{1abe}', NSM), ('\u{1b00}',
+        '\u{1b03}', NSM), ('\u{1b34}', '\u{1b34}', NSM), ('\u{1b36}', '\u{1b3a}', NSM), ('\u{1b3c}',
+        '\u{1b3c}', NSM), ('\u{1b42}', '\u{1b42}', NSM), ('\u{1b6b}', '\u{1b73}', NSM), ('\u{1b80}',
+        '\u{1b81}', NSM), ('\u{1ba2}', '\u{1ba5}', NSM), ('\u{1ba8}', '\u{1ba9}', NSM), ('\u{1bab}',
+        '\u{1bad}', NSM), ('\u{1be6}', '\u{1be6}', NSM), ('\u{1be8}', '\u{1be9}', NSM), ('\u{1bed}',
+        '\u{1bed}', NSM), ('\u{1bef}', '\u{1bf1}', NSM), ('\u{1c2c}', '\u{1c33}', NSM), ('\u{1c36}',
+        '\u{1c37}', NSM), ('\u{1cd0}', '\u{1cd2}', NSM), ('\u{1cd4}', '\u{1ce0}', NSM), ('\u{1ce2}',
+        '\u{1ce8}', NSM), ('\u{1ced}', '\u{1ced}', NSM), ('\u{1cf4}', '\u{1cf4}', NSM), ('\u{1cf8}',
+        '\u{1cf9}', NSM), ('\u{1dc0}', '\u{1df5}', NSM), ('\u{1dfc}', '\u{1dff}', NSM), ('\u{1fbd}',
+        '\u{1fbd}', ON), ('\u{1fbf}', '\u{1fc1}', ON), ('\u{1fcd}', '\u{1fcf}', ON), ('\u{1fdd}',
+        '\u{1fdf}', ON), ('\u{1fed}', '\u{1fef}', ON), ('\u{1ffd}', '\u{1ffe}', ON), ('\u{2000}',
+        '\u{200a}', WS), ('\u{200b}', '\u{200d}', BN), ('\u{200f}', '\u{200f}', R), ('\u{2010}',
+        '\u{2027}', ON), ('\u{2028}', '\u{2028}', WS), ('\u{2029}', '\u{2029}', B), ('\u{202a}',
+        '\u{202a}', LRE), ('\u{202b}', '\u{202b}', RLE), ('\u{202c}', '\u{202c}', PDF), ('\u{202d}',
+        '\u{202d}', LRO), ('\u{202e}', '\u{202e}', RLO), ('\u{202f}', '\u{202f}', CS), ('\u{2030}',
+        '\u{2034}', ET), ('\u{2035}', '\u{2043}', ON), ('\u{2044}', '\u{2044}', CS), ('\u{2045}',
+        '\u{205e}', ON), ('\u{205f}', '\u{205f}', WS), ('\u{2060}', '\u{2065}', BN), ('\u{2066}',
+        '\u{2066}', LRI), ('\u{2067}', '\u{2067}', RLI), ('\u{2068}', '\u{2068}', FSI), ('\u{2069}',
+        '\u{2069}', PDI), ('\u{206a}', '\u{206f}', BN), ('\u{2070}', '\u{2070}', EN), ('\u{2074}',
+        '\u{2079}', EN), ('\u{207a}', '\u{207b}', ES), ('\u{207c}', '\u{207e}', ON), ('\u{2080}',
+        '\u{2089}', EN), ('\u{208a}', '\u{208b}', ES), ('\u{208c}', '\u{208e}', ON), ('\u{20a0}',
+        '\u{20cf}', ET), ('\u{20d0}', '\u{20f0}', NSM), ('\u{2100}', '\u{2101}', ON), ('\u{2103}',
+        '\u{2106}', ON), ('\u{2108}', '\u{2109}', ON), ('\u{2114}', '\u{2114}', ON), ('\u{2116}',
+        '\u{2118}', ON), ('\u{211e}', '\u{2123}', ON), ('\u{2125}', '\u{2125}', ON), ('\u{2127}',
+        '\u{2127}', ON), ('\u{2129}', '\u{2129}', ON), ('\u{212e}', '\u{212e}', ET), ('\u{213a}',
+        '\u{213b}', ON), ('\u{2140}', '\u{2144}', ON), ('\u{214a}', '\u{214d}', ON), ('\u{2150}',
+        '\u{215f}', ON), ('\u{2189}', '\u{2189}', ON), ('\u{2190}', '\u{2211}', ON), ('\u{2212}',
+        '\u{2212}', ES), ('\u{2213}', '\u{2213}', ET), ('\u{2214}', '\u{2335}', ON), ('\u{237b}',
+        '\u{2394}', ON), ('\u{2396}', '\u{23fa}', ON), ('\u{2400}', '\u{2426}', ON), ('\u{2440}',
+        '\u{244a}', ON), ('\u{2460}', '\u{2487}', ON), ('\u{2488}', '\u{249b}', EN), ('\u{24ea}',
+        '\u{26ab}', ON), ('\u{26ad}', '\u{27ff}', ON), ('\u{2900}', '\u{2b73}', ON), ('\u{2b76}',
+        '\u{2b95}', ON), ('\u{2b98}', '\u{2bb9}', ON), ('\u{2bbd}', '\u{2bc8}', ON), ('\u{2bca}',
+        '\u{2bd1}', ON), ('\u{2ce5}', '\u{2cea}', ON), ('\u{2cef}', '\u{2cf1}', NSM), ('\u{2cf9}',
+        '\u{2cff}', ON), ('\u{2d7f}', '\u{2d7f}', NSM), ('\u{2de0}', '\u{2dff}', NSM), ('\u{2e00}',
+        '\u{2e42}', ON), ('\u{2e80}', '\u{2e99}', ON), ('\u{2e9b}', '\u{2ef3}', ON), ('\u{2f00}',
+        '\u{2fd5}', ON), ('\u{2ff0}', '\u{2ffb}', ON), ('\u{3000}', '\u{3000}', WS), ('\u{3001}',
+        '\u{3004}', ON), ('\u{3008}', '\u{3020}', ON), ('\u{302a}', '\u{302d}', NSM), ('\u{3030}',
+        '\u{3030}', ON), ('\u{3036}', '\u{3037}', ON), ('\u{303d}', '\u{303f}', ON), ('\u{3099}',
+        '\u{309a}', NSM), ('\u{309b}', '\u{309c}', ON), ('\u{30a0}', '\u{30a0}', ON), ('\u{30fb}',
+        '\u{30fb}', ON), ('\u{31c0}', '\u{31e3}', ON), ('\u{321d}', '\u{321e}', ON), ('\u{3250}',
+        '\u{325f}', ON), ('\u{327c}', '\u{327e}', ON), ('\u{32b1}', '\u{32bf}', ON), ('\u{32cc}',
+        '\u{32cf}', ON), ('\u{3377}', '\u{337a}', ON), ('\u{33de}', '\u{33df}', ON), ('\u{33ff}',
+        '\u{33ff}', ON), ('\u{4dc0}', '\u{4dff}', ON), ('\u{a490}', '\u{a4c6}', ON), ('\u{a60d}',
+        '\u{a60f}', ON), ('\u{a66f}', '\u{a672}', NSM), ('\u{a673}', '\u{a673}', ON), ('\u{a674}',
+        '\u{a67d}', NSM), ('\u{a67e}', '\u{a67f}', ON), ('\u{a69f}', '\u{a69f}', NSM), ('\u{a6f0}',
+        '\u{a6f1}', NSM), ('\u{a700}', '\u{a721}', ON), ('\u{a788}', '\u{a788}', ON), ('\u{a802}',
+        '\u{a802}', NSM), ('\u{a806}', '\u{a806}', NSM), ('\u{a80b}', '\u{a80b}', NSM), ('\u{a825}',
+        '\u{a826}', NSM), ('\u{a828}', '\u{a82b}', ON), ('\u{a838}', '\u{a839}', ET), ('\u{a874}',
+        '\u{a877}', ON), ('\u{a8c4}', '\u{a8c4}', NSM), ('\u{a8e0}', '\u{a8f1}', NSM), ('\u{a926}',
+        '\u{a92d}', NSM), ('\u{a947}', '\u{a951}', NSM), ('\u{a980}', '\u{a982}', NSM), ('\u{a9b3}',
+        '\u{a9b3}', NSM), ('\u{a9b6}', '\u{a9b9}', NSM), ('\u{a9bc}', '\u{a9bd}', NSM), ('\u{a9e5}',
+        '\u{a9e5}', NSM), ('\u{aa29}', '\u{aa2e}', NSM), ('\u{aa31}', '\u{aa32}', NSM), ('\u{aa35}',
+        '\u{aa36}', NSM), ('\u{aa43}', '\u{aa43}', NSM), ('\u{aa4c}', '\u{aa4c}', NSM), ('\u{aa7c}',
+        '\u{aa7c}', NSM), ('\u{aab0}', '\u{aab0}', NSM), ('\u{aab2}', '\u{aab4}', NSM), ('\u{aab7}',
+        '\u{aab8}', NSM), ('\u{aabe}', '\u{aabf}', NSM), ('\u{aac1}', '\u{aac1}', NSM), ('\u{aaec}',
+        '\u{aaed}', NSM), ('\u{aaf6}', '\u{aaf6}', NSM), ('\u{abe5}', '\u{abe5}', NSM), ('\u{abe8}',
+        '\u{abe8}', NSM), ('\u{abed}', '\u{abed}', NSM), ('\u{fb1d}', '\u{fb1d}', R), ('\u{fb1e}',
+        '\u{fb1e}', NSM), ('\u{fb1f}', '\u{fb28}', R), ('\u{fb29}', '\u{fb29}', ES), ('\u{fb2a}',
+        '\u{fb4f}', R), ('\u{fb50}', '\u{fd3d}', AL), ('\u{fd3e}', '\u{fd3f}', ON), ('\u{fd40}',
+        '\u{fdcf}', AL), ('\u{fdd0}', '\u{fdef}', BN), ('\u{fdf0}', '\u{fdfc}', AL), ('\u{fdfd}',
+        '\u{fdfd}', ON), ('\u{fe00}', '\u{fe0f}', NSM), ('\u{fe10}', '\u{fe19}', ON), ('\u{fe20}',
+        '\u{fe2d}', NSM), ('\u{fe30}', '\u{fe4f}', ON), ('\u{fe50}', '\u{fe50}', CS), ('\u{fe51}',
+        '\u{fe51}', ON), ('\u{fe52}', '\u{fe52}', CS), ('\u{fe54}', '\u{fe54}', ON), ('\u{fe55}',
+        '\u{fe55}', CS), ('\u{fe56}', '\u{fe5e}', ON), ('\u{fe5f}', '\u{fe5f}', ET), ('\u{fe60}',
+        '\u{fe61}', ON), ('\u{fe62}', '\u{fe63}', ES), ('\u{fe64}', '\u{fe66}', ON), ('\u{fe68}',
+        '\u{fe68}', ON), ('\u{fe69}', '\u{fe6a}', ET), ('\u{fe6b}', '\u{fe6b}', ON), ('\u{fe70}',
+        '\u{fefe}', AL), ('\u{feff}', '\u{feff}', BN), ('\u{ff01}', '\u{ff02}', ON), ('\u{ff03}',
+        '\u{ff05}', ET), ('\u{ff06}', '\u{ff0a}', ON), ('\u{ff0b}', '\u{ff0b}', ES), ('\u{ff0c}',
+        '\u{ff0c}', CS), ('\u{ff0d}', '\u{ff0d}', ES), ('\u{ff0e}', '\u{ff0f}', CS), ('\u{ff10}',
+        '\u{ff19}', EN), ('\u{ff1a}', '\u{ff1a}', CS), ('\u{ff1b}', '\u{ff20}', ON), ('\u{ff3b}',
+        '\u{ff40}', ON), ('\u{ff5b}', '\u{ff65}', ON), ('\u{ffe0}', '\u{ffe1}', ET), ('\u{ffe2}',
+        '\u{ffe4}', ON), ('\u{ffe5}', '\u{ffe6}', ET), ('\u{ffe8}', '\u{ffee}', ON), ('\u{fff0}',
+        '\u{fff8}', BN), ('\u{fff9}', '\u{fffd}', ON), ('\u{fffe}', '\u{ffff}', BN), ('\u{10101}',
+        '\u{10101}', ON), ('\u{10140}', '\u{1018c}', ON), ('\u{10190}', '\u{1019b}', ON),
+        ('\u{101a0}', '\u{101a0}', ON), ('\u{101fd}', '\u{101fd}', NSM), ('\u{102e0}', '\u{102e0}',
+        NSM), ('\u{102e1}', '\u{102fb}', EN), ('\u{10376}', '\u{1037a}', NSM), ('\u{10800}',
+        '\u{1091e}', R), ('\u{1091f}', '\u{1091f}', ON), ('\u{10920}', '\u{10a00}', R),
+        ('\u{10a01}', '\u{10a03}', NSM), ('\u{10a04}', '\u{10a04}', R), ('\u{10a05}', '\u{10a06}',
+        NSM), ('\u{10a07}', '\u{10a0b}', R), ('\u{10a0c}', '\u{10a0f}', NSM), ('\u{10a10}',
+        '\u{10a37}', R), ('\u{10a38}', '\u{10a3a}', NSM), ('\u{10a3b}', '\u{10a3e}', R),
+        ('\u{10a3f}', '\u{10a3f}', NSM), ('\u{10a40}', '\u{10ae4}', R), ('\u{10ae5}', '\u{10ae6}',
+        NSM), ('\u{10ae7}', '\u{10b38}', R), ('\u{10b39}', '\u{10b3f}', ON), ('\u{10b40}',
+        '\u{10d10}', R), ('\u{10d11}', '\u{10d3f}', AL), ('\u{10d40}', '\u{10e5f}', R),
+        ('\u{10e60}', '\u{10e7e}', AN), ('\u{10e7f}', '\u{10f44}', R), ('\u{10f45}', '\u{10f7c}',
+        AL), ('\u{10f7d}', '\u{10fff}', R), ('\u{11001}', '\u{11001}', NSM), ('\u{11038}',
+        '\u{11046}', NSM), ('\u{11052}', '\u{11065}', ON), ('\u{1107f}', '\u{11081}', NSM),
+        ('\u{110b3}', '\u{110b6}', NSM), ('\u{110b9}', '\u{110ba}', NSM), ('\u{11100}', '\u{11102}',
+        NSM), ('\u{11127}', '\u{1112b}', NSM), ('\u{1112d}', '\u{11134}', NSM), ('\u{11173}',
+        '\u{11173}', NSM), ('\u{11180}', '\u{11181}', NSM), ('\u{111b6}', '\u{111be}', NSM),
+        ('\u{1122f}', '\u{11231}', NSM), ('\u{11234}', '\u{11234}', NSM), ('\u{11236}', '\u{11237}',
+        NSM), ('\u{112df}', '\u{112df}', NSM), ('\u{112e3}', '\u{112ea}', NSM), ('\u{11301}',
+        '\u{11301}', NSM), ('\u{1133c}', '\u{1133c}', NSM), ('\u{11340}', '\u{11340}', NSM),
+        ('\u{11366}', '\u{1136c}', NSM), ('\u{11370}', '\u{11374}', NSM), ('\u{114b3}', '\u{114b8}',
+        NSM), ('\u{114ba}', '\u{114ba}', NSM), ('\u{114bf}', '\u{114c0}', NSM), ('\u{114c2}',
+        '\u{114c3}', NSM), ('\u{115b2}', '\u{115b5}', NSM), ('\u{115bc}', '\u{115bd}', NSM),
+        ('\u{115bf}', '\u{115c0}', NSM), ('\u{11633}', '\u{1163a}', NSM), ('\u{1163d}', '\u{1163d}',
+        NSM), ('\u{1163f}', '\u{11640}', NSM), ('\u{116ab}', '\u{116ab}', NSM), ('\u{116ad}',
+        '\u{116ad}', NSM), ('\u{116b0}', '\u{116b5}', NSM), ('\u{116b7}', '\u{116b7}', NSM),
+        ('\u{16af0}', '\u{16af4}', NSM), ('\u{16b30}', '\u{16b36}', NSM), ('\u{16f8f}', '\u{16f92}',
+        NSM), ('\u{1bc9d}', '\u{1bc9e}', NSM), ('\u{1bca0}', '\u{1bca3}', BN), ('\u{1d167}',
+        '\u{1d169}', NSM), ('\u{1d173}', '\u{1d17a}', BN), ('\u{1d17b}', '\u{1d182}', NSM),
+        ('\u{1d185}', '\u{1d18b}', NSM), ('\u{1d1aa}', '\u{1d1ad}', NSM), ('\u{1d200}', '\u{1d241}',
+        ON), ('\u{1d242}', '\u{1d244}', NSM), ('\u{1d245}', '\u{1d245}', ON), ('\u{1d300}',
+        '\u{1d356}', ON), ('\u{1d6db}', '\u{1d6db}', ON), ('\u{1d715}', '\u{1d715}', ON),
+        ('\u{1d74f}', '\u{1d74f}', ON), ('\u{1d789}', '\u{1d789}', ON), ('\u{1d7c3}', '\u{1d7c3}',
+        ON), ('\u{1d7ce}', '\u{1d7ff}', EN), ('\u{1e800}', '\u{1e8cf}', R), ('\u{1e8d0}',
+        '\u{1e8d6}', NSM), ('\u{1e8d7}', '\u{1ec6f}', R), ('\u{1ec70}', '\u{1ecbf}', AL),
+        ('\u{1ecc0}', '\u{1ecff}', R), ('\u{1ed00}', '\u{1ed4f}', AL), ('\u{1ed50}', '\u{1edff}',
+        R), ('\u{1ee00}', '\u{1eeef}', AL), ('\u{1eef0}', '\u{1eef1}', ON), ('\u{1eef2}',
+        '\u{1eeff}', AL), ('\u{1ef00}', '\u{1efff}', R), ('\u{1f000}', '\u{1f02b}', ON),
+        ('\u{1f030}', '\u{1f093}', ON), ('\u{1f0a0}', '\u{1f0ae}', ON), ('\u{1f0b1}', '\u{1f0bf}',
+        ON), ('\u{1f0c1}', '\u{1f0cf}', ON), ('\u{1f0d1}', '\u{1f0f5}', ON), ('\u{1f100}',
+        '\u{1f10a}', EN), ('\u{1f10b}', '\u{1f10c}', ON), ('\u{1f16a}', '\u{1f16b}', ON),
+        ('\u{1f300}', '\u{1f32c}', ON), ('\u{1f330}', '\u{1f37d}', ON), ('\u{1f380}', '\u{1f3ce}',
+        ON), ('\u{1f3d4}', '\u{1f3f7}', ON), ('\u{1f400}', '\u{1f4fe}', ON), ('\u{1f500}',
+        '\u{1f54a}', ON), ('\u{1f550}', '\u{1f579}', ON), ('\u{1f57b}', '\u{1f5a3}', ON),
+        ('\u{1f5a5}', '\u{1f642}', ON), ('\u{1f645}', '\u{1f6cf}', ON), ('\u{1f6e0}', '\u{1f6ec}',
+        ON), ('\u{1f6f0}', '\u{1f6f3}', ON), ('\u{1f700}', '\u{1f773}', ON), ('\u{1f780}',
+        '\u{1f7d4}', ON), ('\u{1f800}', '\u{1f80b}', ON), ('\u{1f810}', '\u{1f847}', ON),
+        ('\u{1f850}', '\u{1f859}', ON), ('\u{1f860}', '\u{1f887}', ON), ('\u{1f890}', '\u{1f8ad}',
+        ON), ('\u{1fffe}', '\u{1ffff}', BN), ('\u{2fffe}', '\u{2ffff}', BN), ('\u{3fffe}',
+        '\u{3ffff}', BN), ('\u{4fffe}', '\u{4ffff}', BN), ('\u{5fffe}', '\u{5ffff}', BN),
+        ('\u{6fffe}', '\u{6ffff}', BN), ('\u{7fffe}', '\u{7ffff}', BN), ('\u{8fffe}', '\u{8ffff}',
+        BN), ('\u{9fffe}', '\u{9ffff}', BN), ('\u{afffe}', '\u{affff}', BN), ('\u{bfffe}',
+        '\u{bffff}', BN), ('\u{cfffe}', '\u{cffff}', BN), ('\u{dfffe}', '\u{e00ff}', BN),
+        ('\u{e0100}', '\u{e01ef}', NSM), ('\u{e01f0}', '\u{e0fff}', BN), ('\u{efffe}', '\u{effff}',
+        BN), ('\u{ffffe}', '\u{fffff}', BN), ('\u{10fffe}', '\u{10ffff}', BN)
+    ];
+
+    static mirror_table: &'static [(char, char)] = &[
+        ('\u{28}', '\u{29}'), ('\u{29}', '\u{28}'), ('\u{3c}', '\u{3e}'), ('\u{3e}', '\u{3c}'),
+        ('\u{5b}', '\u{5d}'), ('\u{5d}', '\u{5b}'), ('\u{7b}', '\u{7d}'), ('\u{7d}', '\u{7b}'),
+        ('\u{ab}', '\u{bb}'), ('\u{bb}', '\u{ab}'), ('\u{f3a}', '\u{f3b}'), ('\u{f3b}', '\u{f3a}'),
+        ('\u{f3c}', '\u{f3d}'), ('\u{f3d}', '\u{f3c}'), ('\u{169b}', '\u{169c}'), ('\u{169c}',
+        '\u{169b}'), ('\u{2039}', '\u{203a}'), ('\u{203a}', '\u{2039}'), ('\u{2045}', '\u{2046}'),
+        ('\u{2046}', '\u{2045}'), ('\u{207d}', '\u{207e}'), ('\u{207e}', '\u{207d}'), ('\u{208d}',
+        '\u{208e}'), ('\u{208e}', '\u{208d}'), ('\u{2208}', '\u{220b}'), ('\u{2209}', '\u{220c}'),
+        ('\u{220a}', '\u{220d}'), ('\u{220b}', '\u{2208}'), ('\u{220c}', '\u{2209}'), ('\u{220d}',
+        '\u{220a}'), ('\u{2215}', '\u{29f5}'), ('\u{2220}', '\u{29a3}'), ('\u{2221}', '\u{299b}'),
+        ('\u{2222}', '\u{29a0}'), ('\u{2224}', '\u{2aee}'), ('\u{223c}', '\u{223d}'), ('\u{223d}',
+        '\u{223c}'), ('\u{2243}', '\u{22cd}'), ('\u{2245}', '\u{224c}'), ('\u{224c}', '\u{2245}'),
+        ('\u{2252}', '\u{2253}'), ('\u{2253}', '\u{2252}'), ('\u{2254}', '\u{2255}'), ('\u{2255}',
+        '\u{2254}'), ('\u{2264}', '\u{2265}'), ('\u{2265}', '\u{2264}'), ('\u{2266}', '\u{2267}'),
+        ('\u{2267}', '\u{2266}'), ('\u{2268}', '\u{2269}'), ('\u{2269}', '\u{2268}'), ('\u{226a}',
+        '\u{226b}'), ('\u{226b}', '\u{226a}'), ('\u{226e}', '\u{226f}'), ('\u{226f}', '\u{226e}'),
+        ('\u{2270}', '\u{2271}'), ('\u{2271}', '\u{2270}'), ('\u{2272}', '\u{2273}'), ('\u{2273}',
+        '\u{2272}'), ('\u{2274}', '\u{2275}'), ('\u{2275}', '\u{2274}'), ('\u{2276}', '\u{2277}'),
+        ('\u{2277}', '\u{2276}'), ('\u{2278}', '\u{2279}'), ('\u{2279}', '\u{2278}'), ('\u{227a}',
+        '\u{227b}'), ('\u{227b}', '\u{227a}'), ('\u{227c}', '\u{227d}'), ('\u{227d}', '\u{227c}'),
+        ('\u{227e}', '\u{227f}'), ('\u{227f}', '\u{227e}'), ('\u{2280}', '\u{2281}'), ('\u{2281}',
+        '\u{2280}'), ('\u{2282}', '\u{2283}'), ('\u{2283}', '\u{2282}'), ('\u{2284}', '\u{2285}'),
+        ('\u{2285}', '\u{2284}'), ('\u{2286}', '\u{2287}'), ('\u{2287}', '\u{2286}'), ('\u{2288}',
+        '\u{2289}'), ('\u{2289}', '\u{2288}'), ('\u{228a}', '\u{228b}'), ('\u{228b}', '\u{228a}'),
+        ('\u{228f}', '\u{2290}'), ('\u{2290}', '\u{228f}'), ('\u{2291}', '\u{2292}'), ('\u{2292}',
+        '\u{2291}'), ('\u{2298}', '\u{29b8}'), ('\u{22a2}', '\u{22a3}'), ('\u{22a3}', '\u{22a2}'),
+        ('\u{22a6}', '\u{2ade}'), ('\u{22a8}', '\u{2ae4}'), ('\u{22a9}', '\u{2ae3}'), ('\u{22ab}',
+        '\u{2ae5}'), ('\u{22b0}', '\u{22b1}'), ('\u{22b1}', '\u{22b0}'), ('\u{22b2}', '\u{22b3}'),
+        ('\u{22b3}', '\u{22b2}'), ('\u{22b4}', '\u{22b5}'), ('\u{22b5}', '\u{22b4}'), ('\u{22b6}',
+        '\u{22b7}'), ('\u{22b7}', '\u{22b6}'), ('\u{22b8}', '\u{27dc}'), ('\u{22c9}', '\u{22ca}'),
+        ('\u{22ca}', '\u{22c9}'), ('\u{22cb}', '\u{22cc}'), ('\u{22cc}', '\u{22cb}'), ('\u{22cd}',
+        '\u{2243}'), ('\u{22d0}', '\u{22d1}'), ('\u{22d1}', '\u{22d0}'), ('\u{22d6}', '\u{22d7}'),
+        ('\u{22d7}', '\u{22d6}'), ('\u{22d8}', '\u{22d9}'), ('\u{22d9}', '\u{22d8}'), ('\u{22da}',
+        '\u{22db}'), ('\u{22db}', '\u{22da}'), ('\u{22dc}', '\u{22dd}'), ('\u{22dd}', '\u{22dc}'),
+        ('\u{22de}', '\u{22df}'), ('\u{22df}', '\u{22de}'), ('\u{22e0}', '\u{22e1}'), ('\u{22e1}',
+        '\u{22e0}'), ('\u{22e2}', '\u{22e3}'), ('\u{22e3}', '\u{22e2}'), ('\u{22e4}', '\u{22e5}'),
+        ('\u{22e5}', '\u{22e4}'), ('\u{22e6}', '\u{22e7}'), ('\u{22e7}', '\u{22e6}'), ('\u{22e8}',
+        '\u{22e9}'), ('\u{22e9}', '\u{22e8}'), ('\u{22ea}', '\u{22eb}'), ('\u{22eb}', '\u{22ea}'),
+        ('\u{22ec}', '\u{22ed}'), ('\u{22ed}', '\u{22ec}'), ('\u{22f0}', '\u{22f1}'), ('\u{22f1}',
+        '\u{22f0}'), ('\u{22f2}', '\u{22fa}'), ('\u{22f3}', '\u{22fb}'), ('\u{22f4}', '\u{22fc}'),
+        ('\u{22f6}', '\u{22fd}'), ('\u{22f7}', '\u{22fe}'), ('\u{22fa}', '\u{22f2}'), ('\u{22fb}',
+        '\u{22f3}'), ('\u{22fc}', '\u{22f4}'), ('\u{22fd}', '\u{22f6}'), ('\u{22fe}', '\u{22f7}'),
+        ('\u{2308}', '\u{2309}'), ('\u{2309}', '\u{2308}'), ('\u{230a}', '\u{230b}'), ('\u{230b}',
+        '\u{230a}'), ('\u{2329}', '\u{232a}'), ('\u{232a}', '\u{2329}'), ('\u{2768}', '\u{2769}'),
+        ('\u{2769}', '\u{2768}'), ('\u{276a}', '\u{276b}'), ('\u{276b}', '\u{276a}'), ('\u{276c}',
+        '\u{276d}'), ('\u{276d}', '\u{276c}'), ('\u{276e}', '\u{276f}'), ('\u{276f}', '\u{276e}'),
+        ('\u{2770}', '\u{2771}'), ('\u{2771}', '\u{2770}'), ('\u{2772}', '\u{2773}'), ('\u{2773}',
+        '\u{2772}'), ('\u{2774}', '\u{2775}'), ('\u{2775}', '\u{2774}'), ('\u{27c3}', '\u{27c4}'),
+        ('\u{27c4}', '\u{27c3}'), ('\u{27c5}', '\u{27c6}'), ('\u{27c6}', '\u{27c5}'), ('\u{27c8}',
+        '\u{27c9}'), ('\u{27c9}', '\u{27c8}'), ('\u{27cb}', '\u{27cd}'), ('\u{27cd}', '\u{27cb}'),
+        ('\u{27d5}', '\u{27d6}'), ('\u{27d6}', '\u{27d5}'), ('\u{27dc}', '\u{22b8}'), ('\u{27dd}',
+        '\u{27de}'), ('\u{27de}', '\u{27dd}'), ('\u{27e2}', '\u{27e3}'), ('\u{27e3}', '\u{27e2}'),
+        ('\u{27e4}', '\u{27e5}'), ('\u{27e5}', '\u{27e4}'), ('\u{27e6}', '\u{27e7}'), ('\u{27e7}',
+        '\u{27e6}'), ('\u{27e8}', '\u{27e9}'), ('\u{27e9}', '\u{27e8}'), ('\u{27ea}', '\u{27eb}'),
+        ('\u{27eb}', '\u{27ea}'), ('\u{27ec}', '\u{27ed}'), ('\u{27ed}', '\u{27ec}'), ('\u{27ee}',
+        '\u{27ef}'), ('\u{27ef}', '\u{27ee}'), ('\u{2983}', '\u{2984}'), ('\u{2984}', '\u{2983}'),
+        ('\u{2985}', '\u{2986}'), ('\u{2986}', '\u{2985}'), ('\u{2987}', '\u{2988}'), ('\u{2988}',
+        '\u{2987}'), ('\u{2989}', '\u{298a}'), ('\u{298a}', '\u{2989}'), ('\u{298b}', '\u{298c}'),
+        ('\u{298c}', '\u{298b}'), ('\u{298d}', '\u{2990}'), ('\u{298e}', '\u{298f}'), ('\u{298f}',
+        '\u{298e}'), ('\u{2990}', '\u{298d}'), ('\u{2991}', '\u{2992}'), ('\u{2992}', '\u{2991}'),
+        ('\u{2993}', '\u{2994}'), ('\u{2994}', '\u{2993}'), ('\u{2995}', '\u{2996}'), ('\u{2996}',
+        '\u{2995}'), ('\u{2997}', '\u{2998}'), ('\u{2998}', '\u{2997}'), ('\u{299b}', '\u{2221}'),
+        ('\u{29a0}', '\u{2222}'), ('\u{29a3}', '\u{2220}'), ('\u{29a4}', '\u{29a5}'), ('\u{29a5}',
+        '\u{29a4}'), ('\u{29a8}', '\u{29a9}'), ('\u{29a9}', '\u{29a8}'), ('\u{29aa}', '\u{29ab}'),
+        ('\u{29ab}', '\u{29aa}'), ('\u{29ac}', '\u{29ad}'), ('\u{29ad}', '\u{29ac}'), ('\u{29ae}',
+        '\u{29af}'), ('\u{29af}', '\u{29ae}'), ('\u{29b8}', '\u{2298}'), ('\u{29c0}', '\u{29c1}'),
+        ('\u{29c1}', '\u{29c0}'), ('\u{29c4}', '\u{29c5}'), ('\u{29c5}', '\u{29c4}'), ('\u{29cf}',
+        '\u{29d0}'), ('\u{29d0}', '\u{29cf}'), ('\u{29d1}', '\u{29d2}'), ('\u{29d2}', '\u{29d1}'),
+        ('\u{29d4}', '\u{29d5}'), ('\u{29d5}', '\u{29d4}'), ('\u{29d8}', '\u{29d9}'), ('\u{29d9}',
+        '\u{29d8}'), ('\u{29da}', '\u{29db}'), ('\u{29db}', '\u{29da}'), ('\u{29e8}', '\u{29e9}'),
+        ('\u{29e9}', '\u{29e8}'), ('\u{29f5}', '\u{2215}'), ('\u{29f8}', '\u{29f9}'), ('\u{29f9}',
+        '\u{29f8}'), ('\u{29fc}', '\u{29fd}'), ('\u{29fd}', '\u{29fc}'), ('\u{2a2b}', '\u{2a2c}'),
+        ('\u{2a2c}', '\u{2a2b}'), ('\u{2a2d}', '\u{2a2e}'), ('\u{2a2e}', '\u{2a2d}'), ('\u{2a34}',
+        '\u{2a35}'), ('\u{2a35}', '\u{2a34}'), ('\u{2a3c}', '\u{2a3d}'), ('\u{2a3d}', '\u{2a3c}'),
+        ('\u{2a64}', '\u{2a65}'), ('\u{2a65}', '\u{2a64}'), ('\u{2a79}', '\u{2a7a}'), ('\u{2a7a}',
+        '\u{2a79}'), ('\u{2a7b}', '\u{2a7c}'), ('\u{2a7c}', '\u{2a7b}'), ('\u{2a7d}', '\u{2a7e}'),
+        ('\u{2a7e}', '\u{2a7d}'), ('\u{2a7f}', '\u{2a80}'), ('\u{2a80}', '\u{2a7f}'), ('\u{2a81}',
+        '\u{2a82}'), ('\u{2a82}', '\u{2a81}'), ('\u{2a83}', '\u{2a84}'), ('\u{2a84}', '\u{2a83}'),
+        ('\u{2a85}', '\u{2a86}'), ('\u{2a86}', '\u{2a85}'), ('\u{2a87}', '\u{2a88}'), ('\u{2a88}',
+        '\u{2a87}'), ('\u{2a89}', '\u{2a8a}'), ('\u{2a8a}', '\u{2a89}'), ('\u{2a8b}', '\u{2a8c}'),
+        ('\u{2a8c}', '\u{2a8b}'), ('\u{2a8d}', '\u{2a8e}'), ('\u{2a8e}', '\u{2a8d}'), ('\u{2a8f}',
+        '\u{2a90}'), ('\u{2a90}', '\u{2a8f}'), ('\u{2a91}', '\u{2a92}'), ('\u{2a92}', '\u{2a91}'),
+        ('\u{2a93}', '\u{2a94}'), ('\u{2a94}', '\u{2a93}'), ('\u{2a95}', '\u{2a96}'), ('\u{2a96}',
+        '\u{2a95}'), ('\u{2a97}', '\u{2a98}'), ('\u{2a98}', '\u{2a97}'), ('\u{2a99}', '\u{2a9a}'),
+        ('\u{2a9a}', '\u{2a99}'), ('\u{2a9b}', '\u{2a9c}'), ('\u{2a9c}', '\u{2a9b}'), ('\u{2a9d}',
+        '\u{2a9e}'), ('\u{2a9e}', '\u{2a9d}'), ('\u{2a9f}', '\u{2aa0}'), ('\u{2aa0}', '\u{2a9f}'),
+        ('\u{2aa1}', '\u{2aa2}'), ('\u{2aa2}', '\u{2aa1}'), ('\u{2aa6}', '\u{2aa7}'), ('\u{2aa7}',
+        '\u{2aa6}'), ('\u{2aa8}', '\u{2aa9}'), ('\u{2aa9}', '\u{2aa8}'), ('\u{2aaa}', '\u{2aab}'),
+        ('\u{2aab}', '\u{2aaa}'), ('\u{2aac}', '\u{2aad}'), ('\u{2aad}', '\u{2aac}'), ('\u{2aaf}',
+        '\u{2ab0}'), ('\u{2ab0}', '\u{2aaf}'), ('\u{2ab1}', '\u{2ab2}'), ('\u{2ab2}', '\u{2ab1}'),
+        ('\u{2ab3}', '\u{2ab4}'), ('\u{2ab4}', '\u{2ab3}'), ('\u{2ab5}', '\u{2ab6}'), ('\u{2ab6}',
+        '\u{2ab5}'), ('\u{2ab7}', '\u{2ab8}'), ('\u{2ab8}', '\u{2ab7}'), ('\u{2ab9}', '\u{2aba}'),
+        ('\u{2aba}', '\u{2ab9}'), ('\u{2abb}', '\u{2abc}'), ('\u{2abc}', '\u{2abb}'), ('\u{2abd}',
+        '\u{2abe}'), ('\u{2abe}', '\u{2abd}'), ('\u{2abf}', '\u{2ac0}'), ('\u{2ac0}', '\u{2abf}'),
+        ('\u{2ac1}', '\u{2ac2}'), ('\u{2ac2}', '\u{2ac1}'), ('\u{2ac3}', '\u{2ac4}'), ('\u{2ac4}',
+        '\u{2ac3}'), ('\u{2ac5}', '\u{2ac6}'), ('\u{2ac6}', '\u{2ac5}'), ('\u{2ac7}', '\u{2ac8}'),
+        ('\u{2ac8}', '\u{2ac7}'), ('\u{2ac9}', '\u{2aca}'), ('\u{2aca}', '\u{2ac9}'), ('\u{2acb}',
+        '\u{2acc}'), ('\u{2acc}', '\u{2acb}'), ('\u{2acd}', '\u{2ace}'), ('\u{2ace}', '\u{2acd}'),
+        ('\u{2acf}', '\u{2ad0}'), ('\u{2ad0}', '\u{2acf}'), ('\u{2ad1}', '\u{2ad2}'), ('\u{2ad2}',
+        '\u{2ad1}'), ('\u{2ad3}', '\u{2ad4}'), ('\u{2ad4}', '\u{2ad3}'), ('\u{2ad5}', '\u{2ad6}'),
+        ('\u{2ad6}', '\u{2ad5}'), ('\u{2ade}', '\u{22a6}'), ('\u{2ae3}', '\u{22a9}'), ('\u{2ae4}',
+        '\u{22a8}'), ('\u{2ae5}', '\u{22ab}'), ('\u{2aec}', '\u{2aed}'), ('\u{2aed}', '\u{2aec}'),
+        ('\u{2aee}', '\u{2224}'), ('\u{2af7}', '\u{2af8}'), ('\u{2af8}', '\u{2af7}'), ('\u{2af9}',
+        '\u{2afa}'), ('\u{2afa}', '\u{2af9}'), ('\u{2e02}', '\u{2e03}'), ('\u{2e03}', '\u{2e02}'),
+        ('\u{2e04}', '\u{2e05}'), ('\u{2e05}', '\u{2e04}'), ('\u{2e09}', '\u{2e0a}'), ('\u{2e0a}',
+        '\u{2e09}'), ('\u{2e0c}', '\u{2e0d}'), ('\u{2e0d}', '\u{2e0c}'), ('\u{2e1c}', '\u{2e1d}'),
+        ('\u{2e1d}', '\u{2e1c}'), ('\u{2e20}', '\u{2e21}'), ('\u{2e21}', '\u{2e20}'), ('\u{2e22}',
+        '\u{2e23}'), ('\u{2e23}', '\u{2e22}'), ('\u{2e24}', '\u{2e25}'), ('\u{2e25}', '\u{2e24}'),
+        ('\u{2e26}', '\u{2e27}'), ('\u{2e27}', '\u{2e26}'), ('\u{2e28}', '\u{2e29}'), ('\u{2e29}',
+        '\u{2e28}'), ('\u{3008}', '\u{3009}'), ('\u{3009}', '\u{3008}'), ('\u{300a}', '\u{300b}'),
+        ('\u{300b}', '\u{300a}'), ('\u{300c}', '\u{300d}'), ('\u{300d}', '\u{300c}'), ('\u{300e}',
+        '\u{300f}'), ('\u{300f}', '\u{300e}'), ('\u{3010}', '\u{3011}'), ('\u{3011}', '\u{3010}'),
+        ('\u{3014}', '\u{3015}'), ('\u{3015}', '\u{3014}'), ('\u{3016}', '\u{3017}'), ('\u{3017}',
+        '\u{3016}'), ('\u{3018}', '\u{3019}'), ('\u{3019}', '\u{3018}'), ('\u{301a}', '\u{301b}'),
+        ('\u{301b}', '\u{301a}'), ('\u{fe59}', '\u{fe5a}'), ('\u{fe5a}', '\u{fe59}'), ('\u{fe5b}',
+        '\u{fe5c}'), ('\u{fe5c}', '\u{fe5b}'), ('\u{fe5d}', '\u{fe5e}'), ('\u{fe5e}', '\u{fe5d}'),
+        ('\u{fe64}', '\u{fe65}'), ('\u{fe65}', '\u{fe64}'), ('\u{ff08}', '\u{ff09}'), ('\u{ff09}',
+        '\u{ff08}'), ('\u{ff1c}', '\u{ff1e}'), ('\u{ff1e}', '\u{ff1c}'), ('\u{ff3b}', '\u{ff3d}'),
+        ('\u{ff3d}', '\u{ff3b}'), ('\u{ff5b}', '\u{ff5d}'), ('\u{ff5d}', '\u{ff5b}'), ('\u{ff5f}',
+        '\u{ff60}'), ('\u{ff60}', '\u{ff5f}'), ('\u{ff62}', '\u{ff63}'), ('\u{ff63}', '\u{ff62}')
+    ];
+
+}
+
+pub mod numeric {
+    use core::cmp::Ordering::{Equal, Less, Greater};
+    use core::slice::SlicePrelude;
+    use core::option::Option;
+    use core::option::Option::{Some, None};
+    use core::slice;
+
+    pub fn numeric_value(c: char) -> Option<(i64, u32)> {
+        match numeric_value_table.binary_search(|&(lo, hi, _, _)| {
+            if lo <= c && c <= hi { Equal }
+            else if hi < c { Less }
+            else { Greater }
+        }) {
+            slice::BinarySearchResult::Found(idx) => {
+                let (_, _, num, den) = numeric_value_table[idx];
+                Some((num, den))
+            }
+            slice::BinarySearchResult::NotFound(_) => None
+        }
+    }
+
+    static numeric_value_table: &'static [(char, char, i64, u32)] = &[
+        ('\u{30}', '\u{30}', 0, 1), ('\u{31}', '\u{31}', 1, 1), ('\u{32}', '\u{32}', 2, 1),
+        ('\u{33}', '\u{33}', 3, 1), ('\u{34}', '\u{34}', 4, 1), ('\u{35}', '\u{35}', 5, 1),
+        ('\u{36}', '\u{36}', 6, 1), ('\u{37}', '\u{37}', 7, 1), ('\u{38}', '\u{38}', 8, 1),
+        ('\u{39}', '\u{39}', 9, 1), ('\u{b2}', '\u{b2}', 2, 1), ('\u{b3}', '\u{b3}', 3, 1),
+        ('\u{b9}', '\u{b9}', 1, 1), ('\u{bc}', '\u{bc}', 1, 4), ('\u{bd}', '\u{bd}', 1, 2),
+        ('\u{be}', '\u{be}', 3, 4), ('\u{660}', '\u{660}', 0, 1), ('\u{661}', '\u{661}', 1, 1),
+        ('\u{662}', '\u{662}', 2, 1), ('\u{663}', '\u{663}', 3, 1), ('\u{664}', '\u{664}', 4, 1),
+        ('\u{665}', '\u{665}', 5, 1), ('\u{666}', '\u{666}', 6, 1), ('\u{667}', '\u{667}', 7, 1),
+        ('\u{668}', '\u{668}', 8, 1), ('\u{669}', '\u{669}', 9, 1), ('\u{6f0}', '\u{6f0}', 0, 1),
+        ('\u{6f1}', '\u{6f1}', 1, 1), ('\u{6f2}', '\u{6f2}', 2, 1), ('\u{6f3}', '\u{6f3}', 3, 1),
+        ('\u{6f4}', '\u{6f4}', 4, 1), ('\u{6f5}', '\u{6f5}', 5, 1), ('\u{6f6}', '\u{6f6}', 6, 1),
+        ('\u{6f7}', '\u{6f7}', 7, 1), ('\u{6f8}', '\u{6f8}', 8, 1), ('\u{6f9}', '\u{6f9}', 9, 1),
+        ('\u{7c0}', '\u{7c0}', 0, 1), ('\u{7c1}', '\u{7c1}', 1, 1), ('\u{7c2}', '\u{7c2}', 2, 1),
+        ('\u{7c3}', '\u{7c3}', 3, 1), ('\u{7c4}', '\u{7c4}', 4, 1), ('\u{7c5}', '\u{7c5}', 5, 1),
+        ('\u{7c6}', '\u{7c6}', 6, 1), ('\u{7c7}', '\u{7c7}', 7, 1), ('\u{7c8}', '\u{7c8}', 8, 1),
+        ('\u{7c9}', '\u{7c9}', 9, 1), ('\u{966}', '\u{966}', 0, 1), ('\u{967}', '\u{967}', 1, 1),
+        ('\u{968}', '\u{968}', 2, 1), ('\u{969}', '\u{969}', 3, 1), ('\u{96a}', '\u{96a}', 4, 1),
+        ('\u{96b}', '\u{96b}', 5, 1), ('\u{96c}', '\u{96c}', 6, 1), ('\u{96d}', '\u{96d}', 7, 1),
+        ('\u{96e}', '\u{96e}', 8, 1), ('\u{96f}', '\u{96f}', 9, 1), ('\u{9e6}', '\u{9e6}', 0, 1),
+        ('\u{9e7}', '\u{9e7}', 1, 1), ('\u{9e8}', '\u{9e8}', 2, 1), ('\u{9e9}', '\u{9e9}', 3, 1),
+        ('\u{9ea}', '\u{9ea}', 4, 1), ('\u{9eb}', '\u{9eb}', 5, 1), ('\u{9ec}', '\u{9ec}', 6, 1),
+        ('\u{9ed}', '\u{9ed}', 7, 1), ('\u{9ee}', '\u{9ee}', 8, 1), ('\u{9ef}', '\u{9ef}', 9, 1),
+        ('\u{9f4}', '\u{9f4}', 1, 16), ('\u{9f5}', '\u{9f5}', 1, 8), ('\u{9f6}', '\u{9f6}', 3, 16),
+        ('\u{9f7}', '\u{9f7}', 1, 4), ('\u{9f8}', '\u{9f8}', 3, 4), ('\u{9f9}', '\u{9f9}', 16, 1),
+        ('\u{a66}', '\u{a66}', 0, 1), ('\u{a67}', '\u{a67}', 1, 1), ('\u{a68}', '\u{a68}', 2, 1),
+        ('\u{a69}', '\u{a69}', 3, 1), ('\u{a6a}', '\u{a6a}', 4, 1), ('\u{a6b}', '\u{a6b}', 5, 1),
+        ('\u{a6c}', '\u{a6c}', 6, 1), ('\u{a6d}', '\u{a6d}', 7, 1), ('\u{a6e}', '\u{a6e}', 8, 1),
+        ('\u{a6f}', '\u{a6f}', 9, 1), ('\u{ae6}', '\u{ae6}', 0, 1), ('\u{ae7}', '\u{ae7}', 1, 1),
+        ('\u{ae8}', '\u{ae8}', 2, 1), ('\u{ae9}', '\u{ae9}', 3, 1), ('\u{aea}', '\u{aea}', 4, 1),
+        ('\u{aeb}', '\u{aeb}', 5, 1), ('\u{aec}', '\u{aec}', 6, 1), ('\u{aed}', '\u{aed}', 7, 1),
+        ('\u{aee}', '\u{aee}', 8, 1), ('\u{aef}', '\u{aef}', 9, 1), ('\u{b66}', '\u{b66}', 0, 1),
+        ('\u{b67}', '\u{b67}', 1, 1), ('\u{b68}', '\u{b68}', 2, 1), ('\u{b69}', '\u{b69}', 3, 1),
+        ('\u{b6a}', '\u{b6a}', 4, 1), ('\u{b6b}', '\u{b6b}', 5, 1), ('\u{b6c}', '\u{b6c}', 6, 1),
+        ('\u{b6d}', '\u{b6d}', 7, 1), ('\u{b6e}', '\u{b6e}', 8, 1), ('\u{b6f}', '\u{b6f}', 9, 1),
+        ('\u{b72}', '\u{b72}', 1, 4), ('\u{b73}', '\u{b73}', 1, 2), ('\u{b74}', '\u{b74}', 3, 4),
+        ('\u{b75}', '\u{b75}', 1, 16), ('\u{b76}', '\u{b76}', 1, 8), ('\u{b77}', '\u{b77}', 3, 16),
+        ('\u{be6}', '\u{be6}', 0, 1), ('\u{be7}', '\u{be7}', 1, 1), ('\u{be8}', '\u{be8}', 2, 1),
+        ('\u{be9}', '\u{be9}', 3, 1), ('\u{bea}', '\u{bea}', 4, 1), ('\u{beb}', '\u{beb}', 5, 1),
+        ('\u{bec}', '\u{bec}', 6, 1), ('\u{bed}', '\u{bed}', 7, 1), ('\u{bee}', '\u{bee}', 8, 1),
+        ('\u{bef}', '\u{bef}', 9, 1), ('\u{bf0}', '\u{bf0}', 10, 1), ('\u{bf1}', '\u{bf1}', 100, 1),
+        ('\u{bf2}', '\u{bf2}', 1000, 1), ('\u{c66}', '\u{c66}', 0, 1), ('\u{c67}', '\u{c67}', 1, 1),
+        ('\u{c68}', '\u{c68}', 2, 1), ('\u{c69}', '\u{c69}', 3, 1), ('\u{c6a}', '\u{c6a}', 4, 1),
+        ('\u{c6b}', '\u{c6b}', 5, 1), ('\u{c6c}', '\u{c6c}', 6, 1), ('\u{c6d}', '\u{c6d}', 7, 1),
+        ('\u{c6e}', '\u{c6e}', 8, 1), ('\u{c6f}', '\u{c6f}', 9, 1), ('\u{c78}', '\u{c78}', 0, 1),
+        ('\u{c79}', '\u{c79}', 1, 1), ('\u{c7a}', '\u{c7a}', 2, 1), ('\u{c7b}', '\u{c7b}', 3, 1),
+        ('\u{c7c}', '\u{c7c}', 1, 1), ('\u{c7d}', '\u{c7d}', 2, 1), ('\u{c7e}', '\u{c7e}', 3, 1),
+        ('\u{ce6}', '\u{ce6}', 0, 1), ('\u{ce7}', '\u{ce7}', 1, 1), ('\u{ce8}', '\u{ce8}', 2, 1),
+        ('\u{ce9}', '\u{ce9}', 3, 1), ('\u{cea}', '\u{cea}', 4, 1), ('\u{ceb}', '\u{ceb}', 5, 1),
+        ('\u{cec}', '\u{cec}', 6, 1), ('\u{ced}', '\u{ced}', 7, 1), ('\u{cee}', '\u{cee}', 8, 1),
+        ('\u{cef}', '\u{cef}', 9, 1), ('\u{d66}', '\u{d66}', 0, 1), ('\u{d67}', '\u{d67}', 1, 1),
+        ('\u{d68}', '\u{d68}', 2, 1), ('\u{d69}', '\u{d69}', 3, 1), ('\u{d6a}', '\u{d6a}', 4, 1),
+        ('\u{d6b}', '\u{d6b}', 5, 1), ('\u{d6c}', '\u{d6c}', 6, 1), ('\u{d6d}', '\u{d6d}', 7, 1),
+        ('\u{d6e}', '\u{d6e}', 8, 1), ('\u{d6f}', '\u{d6f}', 9, 1), ('\u{d70}', '\u{d70}', 10, 1),
+        ('\u{d71}', '\u{d71}', 100, 1), ('\u{d72}', '\u{d72}', 1000, 1), ('\u{d73}', '\u{d73}', 1,
+        4), ('\u{d74}', '\u{d74}', 1, 2), ('\u{d75}', '\u{d75}', 3, 4), ('\u{de6}', '\u{de6}', 0,
+        1), ('\u{de7}', '\u{de7}', 1, 1), ('\u{de8}', '\u{de8}', 2, 1), ('\u{de9}', '\u{de9}', 3,
+        1), ('\u{dea}', '\u{dea}', 4, 1), ('\u{deb}', '\u{deb}', 5, 1), ('\u{dec}', '\u{dec}', 6,
+        1), ('\u{ded}', '\u{ded}', 7, 1), ('\u{dee}', '\u{dee}', 8, 1), ('\u{def}', '\u{def}', 9,
+        1), ('\u{e50}', '\u{e50}', 0, 1), ('\u{e51}', '\u{e51}', 1, 1), ('\u{e52}', '\u{e52}', 2,
+        1), ('\u{e53}', '\u{e53}', 3, 1), ('\u{e54}', '\u{e54}', 4, 1), ('\u{e55}', '\u{e55}', 5,
+        1), ('\u{e56}', '\u{e56}', 6, 1), ('\u{e57}', '\u{e57}', 7, 1), ('\u{e58}', '\u{e58}', 8,
+        1), ('\u{e59}', '\u{e59}', 9, 1), ('\u{ed0}', '\u{ed0}', 0, 1), ('\u{ed1}', '\u{ed1}', 1,
+        1), ('\u{ed2}', '\u{ed2}', 2, 1), ('\u{ed3}', '\u{ed3}', 3, 1), ('\u{ed4}', '\u{ed4}', 4,
+        1), ('\u{ed5}', '\u{ed5}', 5, 1), ('\u{ed6}', '\u{ed6}', 6, 1), ('\u{ed7}', '\u{ed7}', 7,
+        1), ('\u{ed8}', '\u{ed8}', 8, 1), ('\u{ed9}', '\u{ed9}', 9, 1), ('\u{f20}', '\u{f20}', 0,
+        1), ('\u{f21}', '\u{f21}', 1, 1), ('\u{f22}', '\u{f22}', 2, 1), ('\u{f23}', '\u{f23}', 3,
+        1), ('\u{f24}', '\u{f24}', 4, 1), ('\u{f25}', '\u{f25}', 5, 1), ('\u{f26}', '\u{f26}', 6,
+        1), ('\u{f27}', '\u{f27}', 7, 1), ('\u{f28}', '\u{f28}', 8, 1), ('\u{f29}', '\u{f29}', 9,
+        1), ('\u{f2a}', '\u{f2a}', 1, 2), ('\u{f2b}', '\u{f2b}', 3, 2), ('\u{f2c}', '\u{f2c}', 5,
+        2), ('\u{f2d}', '\u{f2d}', 7, 2), ('\u{f2e}', '\u{f2e}', 9, 2), ('\u{f2f}', '\u{f2f}', 11,
+        2), ('\u{f30}', '\u{f30}', 13, 2), ('\u{f31}', '\u{f31}', 15, 2), ('\u{f32}', '\u{f32}', 17,
+        2), ('\u{f33}', '\u{f33}', -1, 2), ('\u{1040}', '\u{1040}', 0, 1), ('\u{1041}', '\u{1041}',
+        1, 1), ('\u{1042}', '\u{1042}', 2, 1), ('\u{1043}', '\u{1043}', 3, 1), ('\u{1044}',
+        '\u{1044}', 4, 1), ('\u{1045}', '\u{1045}', 5, 1), ('\u{1046}', '\u{1046}', 6, 1),
+        ('\u{1047}', '\u{1047}', 7, 1), ('\u{1048}', '\u{1048}', 8, 1), ('\u{1049}', '\u{1049}', 9,
+        1), ('\u{1090}', '\u{1090}', 0, 1), ('\u{1091}', '\u{1091}', 1, 1), ('\u{1092}', '\u{1092}',
+        2, 1), ('\u{1093}', '\u{1093}', 3, 1), ('\u{1094}', '\u{1094}', 4, 1), ('\u{1095}',
+        '\u{1095}', 5, 1), ('\u{1096}', '\u{1096}', 6, 1), ('\u{1097}', '\u{1097}', 7, 1),
+        ('\u{1098}', '\u{1098}', 8, 1), ('\u{1099}', '\u{1099}', 9, 1), ('\u{1369}', '\u{1369}', 1,
+        1), ('\u{136a}', '\u{136a}', 2, 1), ('\u{136b}', '\u{136b}', 3, 1), ('\u{136c}', '\u{136c}',
+        4, 1), ('\u{136d}', '\u{136d}', 5, 1), ('\u{136e}', '\u{136e}', 6, 1), ('\u{136f}',
+        '\u{136f}', 7, 1), ('\u{1370}', '\u{1370}', 8, 1), ('\u{1371}', '\u{1371}', 9, 1),
+        ('\u{1372}', '\u{1372}', 10, 1), ('\u{1373}', '\u{1373}', 20, 1), ('\u{1374}', '\u{1374}',
+        30, 1), ('\u{1375}', '\u{1375}', 40, 1), ('\u{1376}', '\u{1376}', 50, 1), ('\u{1377}',
+        '\u{1377}', 60, 1), ('\u{1378}', '\u{1378}', 70, 1), ('\u{1379}', '\u{1379}', 80, 1),
+        ('\u{137a}', '\u{137a}', 90, 1), ('\u{137b}', '\u{137b}', 100, 1), ('\u{137c}', '\u{137c}',
+        10000, 1), ('\u{16ee}', '\u{16ee}', 17, 1), ('\u{16ef}', '\u{16ef}', 18, 1), ('\u{16f0}',
+        '\u{16f0}', 19, 1), ('\u{17e0}', '\u{17e0}', 0, 1), ('\u{17e1}', '\u{17e1}', 1, 1),
+        ('\u{17e2}', '\u{17e2}', 2, 1), ('\u{17e3}', '\u{17e3}', 3, 1), ('\u{17e4}', '\u{17e4}', 4,
+        1), ('\u{17e5}', '\u{17e5}', 5, 1), ('\u{17e6}', '\u{17e6}', 6, 1), ('\u{17e7}', '\u{17e7}',
+        7, 1), ('\u{17e8}', '\u{17e8}', 8, 1), ('\u{17e9}', '\u{17e9}', 9, 1), ('\u{17f0}',
+        '\u{17f0}', 0, 1), ('\u{17f1}', '\u{17f1}', 1, 1), ('\u{17f2}', '\u{17f2}', 2, 1),
+        ('\u{17f3}', '\u{17f3}', 3, 1), ('\u{17f4}', '\u{17f4}', 4, 1), ('\u{17f5}', '\u{17f5}', 5,
+        1), ('\u{17f6}', '\u{17f6}', 6, 1), ('\u{17f7}', '\u{17f7}', 7, 1), ('\u{17f8}', '\u{17f8}',
+        8, 1), ('\u{17f9}', '\u{17f9}', 9, 1), ('\u{1810}', '\u{1810}', 0, 1), ('\u{1811}',
+        '\u{1811}', 1, 1), ('\u{1812}', '\u{1812}', 2, 1), ('\u{1813}', '\u{1813}', 3, 1),
+        ('\u{1814}', '\u{1814}', 4, 1), ('\u{1815}', '\u{1815}', 5, 1), ('\u{1816}', '\u{1816}', 6,
+        1), ('\u{1817}', '\u{1817}', 7, 1), ('\u{1818}', '\u{1818}', 8, 1), ('\u{1819}', '\u{1819}',
+        9, 1), ('\u{1946}', '\u{1946}', 0, 1), ('\u{1947}', '\u{1947}', 1, 1), ('\u{1948}',
+        '\u{1948}', 2, 1), ('\u{1949}', '\u{1949}', 3, 1), ('\u{194a}', '\u{194a}', 4, 1),
+        ('\u{194b}', '\u{194b}', 5, 1), ('\u{194c}', '\u{194c}', 6, 1), ('\u{194d}', '\u{194d}', 7,
+        1), ('\u{194e}', '\u{194e}', 8, 1), ('\u{194f}', '\u{194f}', 9, 1), ('\u{19d0}', '\u{19d0}',
+        0, 1), ('\u{19d1}', '\u{19d1}', 1, 1), ('\u{19d2}', '\u{19d2}', 2, 1), ('\u{19d3}',
+        '\u{19d3}', 3, 1), ('\u{19d4}', '\u{19d4}', 4, 1), ('\u{19d5}', '\u{19d5}', 5, 1),
+        ('\u{19d6}', '\u{19d6}', 6, 1), ('\u{19d7}', '\u{19d7}', 7, 1), ('\u{19d8}', '\u{19d8}', 8,
+        1), ('\u{19d9}', '\u{19d9}', 9, 1), ('\u{19da}', '\u{19da}', 1, 1), ('\u{1a80}', '\u{1a80}',
+        0, 1), ('\u{1a81}', '\u{1a81}', 1, 1), ('\u{1a82}', '\u{1a82}', 2, 1), ('\u{1a83}',
+        '\u{1a83}', 3, 1), ('\u{1a84}', '\u{1a84}', 4, 1), ('\u{1a85}', '\u{1a85}', 5, 1),
+        ('\u{1a86}', '\u{1a86}', 6, 1), ('\u{1a87}', '\u{1a87}', 7, 1), ('\u{1a88}', '\u{1a88}', 8,
+        1), ('\u{1a89}', '\u{1a89}', 9, 1), ('\u{1a90}', '\u{1a90}', 0, 1), ('\u{1a91}', '\u{1a91}',
+        1, 1), ('\u{1a92}', '\u{1a92}', 2, 1), ('\u{1a93}', '\u{1a93}', 3, 1), ('\u{1a94}',
+        '\u{1a94}', 4, 1), ('\u{1a95}', '\u{1a95}', 5, 1), ('\u{1a96}', '\u{1a96}', 6, 1),
+        ('\u{1a97}', '\u{1a97}', 7, 1), ('\u{1a98}', '\u{1a98}', 8, 1), ('\u{1a99}', '\u{1a99}', 9,
+        1), ('\u{1b50}', '\u{1b50}', 0, 1), ('\u{1b51}', '\u{1b51}', 1, 1), ('\u{1b52}', '\u{1b52}',
+        2, 1), ('\u{1b53}', '\u{1b53}', 3, 1), ('\u{1b54}', '\u{1b54}', 4, 1), ('\u{1b55}',
+        '\u{1b55}', 5, 1), ('\u{1b56}', '\u{1b56}', 6, 1), ('\u{1b57}', '\u{1b57}', 7, 1),
+        ('\u{1b58}', '\u{1b58}', 8, 1), ('\u{1b59}', '\u{1b59}', 9, 1), ('\u{1bb0}', '\u{1bb0}', 0,
+        1), ('\u{1bb1}', '\u{1bb1}', 1, 1), ('\u{1bb2}', '\u{1bb2}', 2, 1), ('\u{1bb3}', '\u{1bb3}',
+        3, 1), ('\u{1bb4}', '\u{1bb4}', 4, 1), ('\u{1bb5}', '\u{1bb5}', 5, 1), ('\u{1bb6}',
+        '\u{1bb6}', 6, 1), ('\u{1bb7}', '\u{1bb7}', 7, 1), ('\u{1bb8}', '\u{1bb8}', 8, 1),
+        ('\u{1bb9}', '\u{1bb9}', 9, 1), ('\u{1c40}', '\u{1c40}', 0, 1), ('\u{1c41}', '\u{1c41}', 1,
+        1), ('\u{1c42}', '\u{1c42}', 2, 1), ('\u{1c43}', '\u{1c43}', 3, 1), ('\u{1c44}', '\u{1c44}',
+        4, 1), ('\u{1c45}', '\u{1c45}', 5, 1), ('\u{1c46}', '\u{1c46}', 6, 1), ('\u{1c47}',
+        '\u{1c47}', 7, 1), ('\u{1c48}', '\u{1c48}', 8, 1), ('\u{1c49}', '\u{1c49}', 9, 1),
+        ('\u{1c50}', '\u{1c50}', 0, 1), ('\u{1c51}', '\u{1c51}', 1, 1), ('\u{1c52}', '\u{1c52}', 2,
+        1), ('\u{1c53}', '\u{1c53}', 3, 1), ('\u{1c54}', '\u{1c54}', 4, 1), ('\u{1c55}', '\u{1c55}',
+        5, 1), ('\u{1c56}', '\u{1c56}', 6, 1), ('\u{1c57}', '\u{1c57}', 7, 1), ('\u{1c58}',
+        '\u{1c58}', 8, 1), ('\u{1c59}', '\u{1c59}', 9, 1), ('\u{2070}', '\u{2070}', 0, 1),
+        ('\u{2074}', '\u{2074}', 4, 1), ('\u{2075}', '\u{2075}', 5, 1), ('\u{2076}', '\u{2076}', 6,
+        1), ('\u{2077}', '\u{2077}', 7, 1), ('\u{2078}', '\u{2078}', 8, 1), ('\u{2079}', '\u{2079}',
+        9, 1), ('\u{2080}', '\u{2080}', 0, 1), ('\u{2081}', '\u{2081}', 1, 1), ('\u{2082}',
+        '\u{2082}', 2, 1), ('\u{2083}', '\u{2083}', 3, 1), ('\u{2084}', '\u{2084}', 4, 1),
+        ('\u{2085}', '\u{2085}', 5, 1), ('\u{2086}', '\u{2086}', 6, 1), ('\u{2087}', '\u{2087}', 7,
+        1), ('\u{2088}', '\u{2088}', 8, 1), ('\u{2089}', '\u{2089}', 9, 1), ('\u{2150}', '\u{2150}',
+        1, 7), ('\u{2151}', '\u{2151}', 1, 9), ('\u{2152}', '\u{2152}', 1, 10), ('\u{2153}',
+        '\u{2153}', 1, 3), ('\u{2154}', '\u{2154}', 2, 3), ('\u{2155}', '\u{2155}', 1, 5),
+        ('\u{2156}', '\u{2156}', 2, 5), ('\u{2157}', '\u{2157}', 3, 5), ('\u{2158}', '\u{2158}', 4,
+        5), ('\u{2159}', '\u{2159}', 1, 6), ('\u{215a}', '\u{215a}', 5, 6), ('\u{215b}', '\u{215b}',
+        1, 8), ('\u{215c}', '\u{215c}', 3, 8), ('\u{215d}', '\u{215d}', 5, 8), ('\u{215e}',
+        '\u{215e}', 7, 8), ('\u{215f}', '\u{2160}', 1, 1), ('\u{2161}', '\u{2161}', 2, 1),
+        ('\u{2162}', '\u{2162}', 3, 1), ('\u{2163}', '\u{2163}', 4, 1), ('\u{2164}', '\u{2164}', 5,
+        1), ('\u{2165}', '\u{2165}', 6, 1), ('\u{2166}', '\u{2166}', 7, 1), ('\u{2167}', '\u{2167}',
+        8, 1), ('\u{2168}', '\u{2168}', 9, 1), ('\u{2169}', '\u{2169}', 10, 1), ('\u{216a}',
+        '\u{216a}', 11, 1), ('\u{216b}', '\u{216b}', 12, 1), ('\u{216c}', '\u{216c}', 50, 1),
+        ('\u{216d}', '\u{216d}', 100, 1), ('\u{216e}', '\u{216e}', 500, 1), ('\u{216f}', '\u{216f}',
+        1000, 1), ('\u{2170}', '\u{2170}', 1, 1), ('\u{2171}', '\u{2171}', 2, 1), ('\u{2172}',
+        '\u{2172}', 3, 1), ('\u{2173}', '\u{2173}', 4, 1), ('\u{2174}', '\u{2174}', 5, 1),
+        ('\u{2175}', '\u{2175}', 6, 1), ('\u{2176}', '\u{2176}', 7, 1), ('\u{2177}', '\u{2177}', 8,
+        1), ('\u{2178}', '\u{2178}', 9, 1), ('\u{2179}', '\u{2179}', 10, 1), ('\u{217a}',
+        '\u{217a}', 11, 1), ('\u{217b}', '\u{217b}', 12, 1), ('\u{217c}', '\u{217c}', 50, 1),
+        ('\u{217d}', '\u{217d}', 100, 1), ('\u{217e}', '\u{217e}', 500, 1), ('\u{217f}', '\u{2180}',
+        1000, 1), ('\u{2181}', '\u{2181}', 5000, 1), ('\u{2182}', '\u{2182}', 10000, 1),
+        ('\u{2185}', '\u{2185}', 6, 1), ('\u{2186}', '\u{2186}', 50, 1), ('\u{2187}', '\u{2187}',
+        50000, 1), ('\u{2188}', '\u{2188}', 100000, 1), ('\u{2189}', '\u{2189}', 0, 1), ('\u{2460}',
+        '\u{2460}', 1, 1), ('\u{2461}', '\u{2461}', 2, 1), ('\u{2462}', '\u{2462}', 3, 1),
+        ('\u{2463}', '\u{2463}', 4, 1), ('\u{2464}', '\u{2464}', 5, 1), ('\u{2465}', '\u{2465}', 6,
+        1), ('\u{2466}', '\u{2466}', 7, 1), ('\u{2467}', '\u{2467}', 8, 1), ('\u{2468}', '\u{2468}',
+        9, 1), ('\u{2469}', '\u{2469}', 10, 1), ('\u{246a}', '\u{246a}', 11, 1), ('\u{246b}',
+        '\u{246b}', 12, 1), ('\u{246c}', '\u{246c}', 13, 1), ('\u{246d}', '\u{246d}', 14, 1),
+        ('\u{246e}', '\u{246e}', 15, 1), ('\u{246f}', '\u{246f}', 16, 1), ('\u{2470}', '\u{2470}',
+        17, 1), ('\u{2471}', '\u{2471}', 18, 1), ('\u{2472}', '\u{2472}', 19, 1), ('\u{2473}',
+        '\u{2473}', 20, 1), ('\u{2474}', '\u{2474}', 1, 1), ('\u{2475}', '\u{2475}', 2, 1),
+        ('\u{2476}', '\u{2476}', 3, 1), ('\u{2477}', '\u{2477}', 4, 1), ('\u{2478}', '\u{2478}', 5,
+        1), ('\u{2479}', '\u{2479}', 6, 1), ('\u{247a}', '\u{247a}', 7, 1), ('\u{247b}', '\u{247b}',
+        8, 1), ('\u{247c}', '\u{247c}', 9, 1), ('\u{247d}', '\u{247d}', 10, 1), ('\u{247e}',
+        '\u{247e}', 11, 1), ('\u{247f}', '\u{247f}', 12, 1), ('\u{2480}', '\u{2480}', 13, 1),
+        ('\u{2481}', '\u{2481}', 14, 1), ('\u{2482}', '\u{2482}', 15, 1), ('\u{2483}', '\u{2483}',
+        16, 1), ('\u{2484}', '\u{2484}', 17, 1), ('\u{2485}', '\u{2485}', 18, 1), ('\u{2486}',
+        '\u{2486}', 19, 1), ('\u{2487}', '\u{2487}', 20, 1), ('\u{2488}', '\u{2488}', 1, 1),
+        ('\u{2489}', '\u{2489}', 2, 1), ('\u{248a}', '\u{248a}', 3, 1), ('\u{248b}', '\u{248b}', 4,
+        1), ('\u{248c}', '\u{248c}', 5, 1), ('\u{248d}', '\u{248d}', 6, 1), ('\u{248e}', '\u{248e}',
+        7, 1), ('\u{248f}', '\u{248f}', 8, 1), ('\u{2490}', '\u{2490}', 9, 1), ('\u{2491}',
+        '\u{2491}', 10, 1), ('\u{2492}', '\u{2492}', 11, 1), ('\u{2493}', '\u{2493}', 12, 1),
+        ('\u{2494}', '\u{2494}', 13, 1), ('\u{2495}', '\u{2495}', 14, 1), ('\u{2496}', '\u{2496}',
+        15, 1), ('\u{2497}', '\u{2497}', 16, 1), ('\u{2498}', '\u{2498}', 17, 1), ('\u{2499}',
+        '\u{2499}', 18, 1), ('\u{249a}', '\u{249a}', 19, 1), ('\u{249b}', '\u{249b}', 20, 1),
+        ('\u{24ea}', '\u{24ea}', 0, 1), ('\u{24eb}', '\u{24eb}', 11, 1), ('\u{24ec}', '\u{24ec}',
+        12, 1), ('\u{24ed}', '\u{24ed}', 13, 1), ('\u{24ee}', '\u{24ee}', 14, 1), ('\u{24ef}',
+        '\u{24ef}', 15, 1), ('\u{24f0}', '\u{24f0}', 16, 1), ('\u{24f1}', '\u{24f1}', 17, 1),
+        ('\u{24f2}', '\u{24f2}', 18, 1), ('\u{24f3}', '\u{24f3}', 19, 1), ('\u{24f4}', '\u{24f4}',
+        20, 1), ('\u{24f5}', '\u{24f5}', 1, 1), ('\u{24f6}', '\u{24f6}', 2, 1), ('\u{24f7}',
+        '\u{24f7}', 3, 1), ('\u{24f8}', '\u{24f8}', 4, 1), ('\u{24f9}', '\u{24f9}', 5, 1),
+        ('\u{24fa}', '\u{24fa}', 6, 1), ('\u{24fb}', '\u{24fb}', 7, 1), ('\u{24fc}', '\u{24fc}', 8,
+        1), ('\u{24fd}', '\u{24fd}', 9, 1), ('\u{24fe}', '\u{24fe}', 10, 1), ('\u{24ff}',
+        '\u{24ff}', 0, 1), ('\u{2776}', '\u{2776}', 1, 1), ('\u{2777}', '\u{2777}', 2, 1),
+        ('\u{2778}', '\u{2778}', 3, 1), ('\u{2779}', '\u{2779}', 4, 1), ('\u{277a}', '\u{277a}', 5,
+        1), ('\u{277b}', '\u{277b}', 6, 1), ('\u{277c}', '\u{277c}', 7, 1), ('\u{277d}', '\u{277d}',
+        8, 1), ('\u{277e}', '\u{277e}', 9, 1), ('\u{277f}', '\u{277f}', 10, 1), ('\u{2780}',
+        '\u{2780}', 1, 1), ('\u{2781}', '\u{2781}', 2, 1), ('\u{2782}', '\u{2782}', 3, 1),
+        ('\u{2783}', '\u{2783}', 4, 1), ('\u{2784}', '\u{2784}', 5, 1), ('\u{2785}', '\u{2785}', 6,
+        1), ('\u{2786}', '\u{2786}', 7, 1), ('\u{2787}', '\u{2787}', 8, 1), ('\u{2788}', '\u{2788}',
+        9, 1), ('\u{2789}', '\u{2789}', 10, 1), ('\u{278a}', '\u{278a}', 1, 1), ('\u{278b}',
+        '\u{278b}', 2, 1), ('\u{278c}', '\u{278c}', 3, 1), ('\u{278d}', '\u{278d}', 4, 1),
+        ('\u{278e}', '\u{278e}', 5, 1), ('\u{278f}', '\u{278f}', 6, 1), ('\u{2790}', '\u{2790}', 7,
+        1), ('\u{2791}', '\u{2791}', 8, 1), ('\u{2792}', '\u{2792}', 9, 1), ('\u{2793}', '\u{2793}',
+        10, 1), ('\u{2cfd}', '\u{2cfd}', 1, 2), ('\u{3007}', '\u{3007}', 0, 1), ('\u{3021}',
+        '\u{3021}', 1, 1), ('\u{3022}', '\u{3022}', 2, 1), ('\u{3023}', '\u{3023}', 3, 1),
+        ('\u{3024}', '\u{3024}', 4, 1), ('\u{3025}', '\u{3025}', 5, 1), ('\u{3026}', '\u{3026}', 6,
+        1), ('\u{3027}', '\u{3027}', 7, 1), ('\u{3028}', '\u{3028}', 8, 1), ('\u{3029}', '\u{3029}',
+        9, 1), ('\u{3038}', '\u{3038}', 10, 1), ('\u{3039}', '\u{3039}', 20, 1), ('\u{303a}',
+        '\u{303a}', 30, 1), ('\u{3192}', '\u{3192}', 1, 1), ('\u{3193}', '\u{3193}', 2, 1),
+        ('\u{3194}', '\u{3194}', 3, 1), ('\u{3195}', '\u{3195}', 4, 1), ('\u{3220}', '\u{3220}', 1,
+        1), ('\u{3221}', '\u{3221}', 2, 1), ('\u{3222}', '\u{3222}', 3, 1), ('\u{3223}', '\u{3223}',
+        4, 1), ('\u{3224}', '\u{3224}', 5, 1), ('\u{3225}', '\u{3225}', 6, 1), ('\u{3226}',
+        '\u{3226}', 7, 1), ('\u{3227}', '\u{3227}', 8, 1), ('\u{3228}', '\u{3228}', 9, 1),
+        ('\u{3229}', '\u{3229}', 10, 1), ('\u{3248}', '\u{3248}', 10, 1), ('\u{3249}', '\u{3249}',
+        20, 1), ('\u{324a}', '\u{324a}', 30, 1), ('\u{324b}', '\u{324b}', 40, 1), ('\u{324c}',
+        '\u{324c}', 50, 1), ('\u{324d}', '\u{324d}', 60, 1), ('\u{324e}', '\u{324e}', 70, 1),
+        ('\u{324f}', '\u{324f}', 80, 1), ('\u{3251}', '\u{3251}', 21, 1), ('\u{3252}', '\u{3252}',
+        22, 1), ('\u{3253}', '\u{3253}', 23, 1), ('\u{3254}', '\u{3254}', 24, 1), ('\u{3255}',
+        '\u{3255}', 25, 1), ('\u{3256}', '\u{3256}', 26, 1), ('\u{3257}', '\u{3257}', 27, 1),
+        ('\u{3258}', '\u{3258}', 28, 1), ('\u{3259}', '\u{3259}', 29, 1), ('\u{325a}', '\u{325a}',
+        30, 1), ('\u{325b}', '\u{325b}', 31, 1), ('\u{325c}', '\u{325c}', 32, 1), ('\u{325d}',
+        '\u{325d}', 33, 1), ('\u{325e}', '\u{325e}', 34, 1), ('\u{325f}', '\u{325f}', 35, 1),
+        ('\u{3280}', '\u{3280}', 1, 1), ('\u{3281}', '\u{3281}', 2, 1), ('\u{3282}', '\u{3282}', 3,
+        1), ('\u{3283}', '\u{3283}', 4, 1), ('\u{3284}', '\u{3284}', 5, 1), ('\u{3285}', '\u{3285}',
+        6, 1), ('\u{3286}', '\u{3286}', 7, 1), ('\u{3287}', '\u{3287}', 8, 1), ('\u{3288}',
+        '\u{3288}', 9, 1), ('\u{3289}', '\u{3289}', 10, 1), ('\u{32b1}', '\u{32b1}', 36, 1),
+        ('\u{32b2}', '\u{32b2}', 37, 1), ('\u{32b3}', '\u{32b3}', 38, 1), ('\u{32b4}', '\u{32b4}',
+        39, 1), ('\u{32b5}', '\u{32b5}', 40, 1), ('\u{32b6}', '\u{32b6}', 41, 1), ('\u{32b7}',
+        '\u{32b7}', 42, 1), ('\u{32b8}', '\u{32b8}', 43, 1), ('\u{32b9}', '\u{32b9}', 44, 1),
+        ('\u{32ba}', '\u{32ba}', 45, 1), ('\u{32bb}', '\u{32bb}', 46, 1), ('\u{32bc}', '\u{32bc}',
+        47, 1), ('\u{32bd}', '\u{32bd}', 48, 1), ('\u{32be}', '\u{32be}', 49, 1), ('\u{32bf}',
+        '\u{32bf}', 50, 1), ('\u{3405}', '\u{3405}', 5, 1), ('\u{3483}', '\u{3483}', 2, 1),
+        ('\u{382a}', '\u{382a}', 5, 1), ('\u{3b4d}', '\u{3b4d}', 7, 1), ('\u{4e00}', '\u{4e00}', 1,
+        1), ('\u{4e03}', '\u{4e03}', 7, 1), ('\u{4e07}', '\u{4e07}', 10000, 1), ('\u{4e09}',
+        '\u{4e09}', 3, 1), ('\u{4e5d}', '\u{4e5d}', 9, 1), ('\u{4e8c}', '\u{4e8c}', 2, 1),
+        ('\u{4e94}', '\u{4e94}', 5, 1), ('\u{4e96}', '\u{4e96}', 4, 1), ('\u{4ebf}', '\u{4ebf}',
+        100000000, 1), ('\u{4ec0}', '\u{4ec0}', 10, 1), ('\u{4edf}', '\u{4edf}', 1000, 1),
+        ('\u{4ee8}', '\u{4ee8}', 3, 1), ('\u{4f0d}', '\u{4f0d}', 5, 1), ('\u{4f70}', '\u{4f70}',
+        100, 1), ('\u{5104}', '\u{5104}', 100000000, 1), ('\u{5146}', '\u{5146}', 1000000000000, 1),
+        ('\u{5169}', '\u{5169}', 2, 1), ('\u{516b}', '\u{516b}', 8, 1), ('\u{516d}', '\u{516d}', 6,
+        1), ('\u{5341}', '\u{5341}', 10, 1), ('\u{5343}', '\u{5343}', 1000, 1), ('\u{5344}',
+        '\u{5344}', 20, 1), ('\u{5345}', '\u{5345}', 30, 1), ('\u{534c}', '\u{534c}', 40, 1),
+        ('\u{53c1}', '\u{53c4}', 3, 1), ('\u{56db}', '\u{56db}', 4, 1), ('\u{58f1}', '\u{58f1}', 1,
+        1), ('\u{58f9}', '\u{58f9}', 1, 1), ('\u{5e7a}', '\u{5e7a}', 1, 1), ('\u{5efe}', '\u{5efe}',
+        9, 1), ('\u{5eff}', '\u{5eff}', 20, 1), ('\u{5f0c}', '\u{5f0c}', 1, 1), ('\u{5f0d}',
+        '\u{5f0d}', 2, 1), ('\u{5f0e}', '\u{5f0e}', 3, 1), ('\u{5f10}', '\u{5f10}', 2, 1),
+        ('\u{62fe}', '\u{62fe}', 10, 1), ('\u{634c}', '\u{634c}', 8, 1), ('\u{67d2}', '\u{67d2}', 7,
+        1), ('\u{6f06}', '\u{6f06}', 7, 1), ('\u{7396}', '\u{7396}', 9, 1), ('\u{767e}', '\u{767e}',
+        100, 1), ('\u{8086}', '\u{8086}', 4, 1), ('\u{842c}', '\u{842c}', 10000, 1), ('\u{8cae}',
+        '\u{8cae}', 2, 1), ('\u{8cb3}', '\u{8cb3}', 2, 1), ('\u{8d30}', '\u{8d30}', 2, 1),
+        ('\u{9621}', '\u{9621}', 1000, 1), ('\u{9646}', '\u{9646}', 6, 1), ('\u{964c}', '\u{964c}',
+        100, 1), ('\u{9678}', '\u{9678}', 6, 1), ('\u{96f6}', '\u{96f6}', 0, 1), ('\u{a620}',
+        '\u{a620}', 0, 1), ('\u{a621}', '\u{a621}', 1, 1), ('\u{a622}', '\u{a622}', 2, 1),
+        ('\u{a623}', '\u{a623}', 3, 1), ('\u{a624}', '\u{a624}', 4, 1), ('\u{a625}', '\u{a625}', 5,
+        1), ('\u{a626}', '\u{a626}', 6, 1), ('\u{a627}', '\u{a627}', 7, 1), ('\u{a628}', '\u{a628}',
+        8, 1), ('\u{a629}', '\u{a629}', 9, 1), ('\u{a6e6}', '\u{a6e6}', 1, 1), ('\u{a6e7}',
+        '\u{a6e7}', 2, 1), ('\u{a6e8}', '\u{a6e8}', 3, 1), ('\u{a6e9}', '\u{a6e9}', 4, 1),
+        ('\u{a6ea}', '\u{a6ea}', 5, 1), ('\u{a6eb}', '\u{a6eb}', 6, 1), ('\u{a6ec}', '\u{a6ec}', 7,
+        1), ('\u{a6ed}', '\u{a6ed}', 8, 1), ('\u{a6ee}', '\u{a6ee}', 9, 1), ('\u{a6ef}', '\u{a6ef}',
+        0, 1), ('\u{a830}', '\u{a830}', 1, 4), ('\u{a831}', '\u{a831}', 1, 2), ('\u{a832}',
+        '\u{a832}', 3, 4), ('\u{a833}', '\u{a833}', 1, 16), ('\u{a834}', '\u{a834}', 1, 8),
+        ('\u{a835}', '\u{a835}', 3, 16), ('\u{a8d0}', '\u{a8d0}', 0, 1), ('\u{a8d1}', '\u{a8d1}', 1,
+        1), ('\u{a8d2}', '\u{a8d2}', 2, 1), ('\u{a8d3}', '\u{a8d3}', 3, 1), ('\u{a8d4}', '\u{a8d4}',
+        4, 1), ('\u{a8d5}', '\u{a8d5}', 5, 1), ('\u{a8d6}', '\u{a8d6}', 6, 1), ('\u{a8d7}',
+        '\u{a8d7}', 7, 1), ('\u{a8d8}', '\u{a8d8}', 8, 1), ('\u{a8d9}', '\u{a8d9}', 9, 1),
+        ('\u{a900}', '\u{a900}', 0, 1), ('\u{a901}', '\u{a901}', 1, 1), ('\u{a902}', '\u{a902}', 2,
+        1), ('\u{a903}', '\u{a903}', 3, 1), ('\u{a904}', '\u{a904}', 4, 1), ('\u{a905}', '\u{a905}',
+        5, 1), ('\u{a906}', '\u{a906}', 6, 1), ('\u{a907}', '\u{a907}', 7, 1), ('\u{a908}',
+        '\u{a908}', 8, 1), ('\u{a909}', '\u{a909}', 9, 1), ('\u{a9d0}', '\u{a9d0}', 0, 1),
+        ('\u{a9d1}', '\u{a9d1}', 1, 1), ('\u{a9d2}', '\u{a9d2}', 2, 1), ('\u{a9d3}', '\u{a9d3}', 3,
+        1), ('\u{a9d4}', '\u{a9d4}', 4, 1), ('\u{a9d5}', '\u{a9d5}', 5, 1), ('\u{a9d6}', '\u{a9d6}',
+        6, 1), ('\u{a9d7}', '\u{a9d7}', 7, 1), ('\u{a9d8}', '\u{a9d8}', 8, 1), ('\u{a9d9}',
+        '\u{a9d9}', 9, 1), ('\u{a9f0}', '\u{a9f0}', 0, 1), ('\u{a9f1}', '\u{a9f1}', 1, 1),
+        ('\u{a9f2}', '\u{a9f2}', 2, 1), ('\u{a9f3}', '\u{a9f3}', 3, 1), ('\u{a9f4}', '\u{a9f4}', 4,
+        1), ('\u{a9f5}', '\u{a9f5}', 5, 1), ('\u{a9f6}', '\u{a9f6}', 6, 1), ('\u{a9f7}', '\u{a9f7}',
+        7, 1), ('\u{a9f8}', '\u{a9f8}', 8, 1), ('\u{a9f9}', '\u{a9f9}', 9, 1), ('\u{aa50}',
+        '\u{aa50}', 0, 1), ('\u{aa51}', '\u{aa51}', 1, 1), ('\u{aa52}', '\u{aa52}', 2, 1),
+        ('\u{aa53}', '\u{aa53}', 3, 1), ('\u{aa54}', '\u{aa54}', 4, 1), ('\u{aa55}', '\u{aa55}', 5,
+        1), ('\u{aa56}', '\u{aa56}', 6, 1), ('\u{aa57}', '\u{aa57}', 7, 1), ('\u{aa58}', '\u{aa58}',
+        8, 1), ('\u{aa59}', '\u{aa59}', 9, 1), ('\u{abf0}', '\u{abf0}', 0, 1), ('\u{abf1}',
+        '\u{abf1}', 1, 1), ('\u{abf2}', '\u{abf2}', 2, 1), ('\u{abf3}', '\u{abf3}', 3, 1),
+        ('\u{abf4}', '\u{abf4}', 4, 1), ('\u{abf5}', '\u{abf5}', 5, 1), ('\u{abf6}', '\u{abf6}', 6,
+        1), ('\u{abf7}', '\u{abf7}', 7, 1), ('\u{abf8}', '\u{abf8}', 8, 1), ('\u{abf9}', '\u{abf9}',
+        9, 1), ('\u{f96b}', '\u{f96b}', 3, 1), ('\u{f973}', '\u{f973}', 10, 1), ('\u{f978}',
+        '\u{f978}', 2, 1), ('\u{f9b2}', '\u{f9b2}', 0, 1), ('\u{f9d1}', '\u{f9d1}', 6, 1),
+        ('\u{f9d3}', '\u{f9d3}', 6, 1), ('\u{f9fd}', '\u{f9fd}', 10, 1), ('\u{ff10}', '\u{ff10}', 0,
+        1), ('\u{ff11}', '\u{ff11}', 1, 1), ('\u{ff12}', '\u{ff12}', 2, 1), ('\u{ff13}', '\u{ff13}',
+        3, 1), ('\u{ff14}', '\u{ff14}', 4, 1), ('\u{ff15}', '\u{ff15}', 5, 1), ('\u{ff16}',
+        '\u{ff16}', 6, 1), ('\u{ff17}', '\u{ff17}', 7, 1), ('\u{ff18}', '\u{ff18}', 8, 1),
+        ('\u{ff19}', '\u{ff19}', 9, 1), ('\u{10107}', '\u{10107}', 1, 1), ('\u{10108}', '\u{10108}',
+        2, 1), ('\u{10109}', '\u{10109}', 3, 1), ('\u{1010a}', '\u{1010a}', 4, 1), ('\u{1010b}',
+        '\u{1010b}', 5, 1), ('\u{1010c}', '\u{1010c}', 6, 1), ('\u{1010d}', '\u{1010d}', 7, 1),
+        ('\u{1010e}', '\u{1010e}', 8, 1), ('\u{1010f}', '\u{1010f}', 9, 1), ('\u{10110}',
+        '\u{10110}', 10, 1), ('\u{10111}', '\u{10111}', 20, 1), ('\u{10112}', '\u{10112}', 30, 1),
+        ('\u{10113}', '\u{10113}', 40, 1), ('\u{10114}', '\u{10114}', 50, 1), ('\u{10115}',
+        '\u{10115}', 60, 1), ('\u{10116}', '\u{10116}', 70, 1), ('\u{10117}', '\u{10117}', 80, 1),
+        ('\u{10118}', '\u{10118}', 90, 1), ('\u{10119}', '\u{10119}', 100, 1), ('\u{1011a}',
+        '\u{1011a}', 200, 1), ('\u{1011b}', '\u{1011b}', 300, 1), ('\u{1011c}', '\u{1011c}', 400,
+        1), ('\u{1011d}', '\u{1011d}', 500, 1), ('\u{1011e}', '\u{1011e}', 600, 1), ('\u{1011f}',
+        '\u{1011f}', 700, 1), ('\u{10120}', '\u{10120}', 800, 1), ('\u{10121}', '\u{10121}', 900,
+        1), ('\u{10122}', '\u{10122}', 1000, 1), ('\u{10123}', '\u{10123}', 2000, 1), ('\u{10124}',
+        '\u{10124}', 3000, 1), ('\u{10125}', '\u{10125}', 4000, 1), ('\u{10126}', '\u{10126}', 5000,
+        1), ('\u{10127}', '\u{10127}', 6000, 1), ('\u{10128}', '\u{10128}', 7000, 1), ('\u{10129}',
+        '\u{10129}', 8000, 1), ('\u{1012a}', '\u{1012a}', 9000, 1), ('\u{1012b}', '\u{1012b}',
+        10000, 1), ('\u{1012c}', '\u{1012c}', 20000, 1), ('\u{1012d}', '\u{1012d}', 30000, 1),
+        ('\u{1012e}', '\u{1012e}', 40000, 1), ('\u{1012f}', '\u{1012f}', 50000, 1), ('\u{10130}',
+        '\u{10130}', 60000, 1), ('\u{10131}', '\u{10131}', 70000, 1), ('\u{10132}', '\u{10132}',
+        80000, 1), ('\u{10133}', '\u{10133}', 90000, 1), ('\u{10140}', '\u{10140}', 1, 4),
+        ('\u{10141}', '\u{10141}', 1, 2), ('\u{10142}', '\u{10142}', 1, 1), ('\u{10143}',
+        '\u{10143}', 5, 1), ('\u{10144}', '\u{10144}', 50, 1), ('\u{10145}', '\u{10145}', 500, 1),
+        ('\u{10146}', '\u{10146}', 5000, 1), ('\u{10147}', '\u{10147}', 50000, 1), ('\u{10148}',
+        '\u{10148}', 5, 1), ('\u{10149}', '\u{10149}', 10, 1), ('\u{1014a}', '\u{1014a}', 50, 1),
+        ('\u{1014b}', '\u{1014b}', 100, 1), ('\u{1014c}', '\u{1014c}', 500, 1), ('\u{1014d}',
+        '\u{1014d}', 1000, 1), ('\u{1014e}', '\u{1014e}', 5000, 1), ('\u{1014f}', '\u{1014f}', 5,
+        1), ('\u{10150}', '\u{10150}', 10, 1), ('\u{10151}', '\u{10151}', 50, 1), ('\u{10152}',
+        '\u{10152}', 100, 1), ('\u{10153}', '\u{10153}', 500, 1), ('\u{10154}', '\u{10154}', 1000,
+        1), ('\u{10155}', '\u{10155}', 10000, 1), ('\u{10156}', '\u{10156}', 50000, 1),
+        ('\u{10157}', '\u{10157}', 10, 1), ('\u{10158}', '\u{1015a}', 1, 1), ('\u{1015b}',
+        '\u{1015e}', 2, 1), ('\u{1015f}', '\u{1015f}', 5, 1), ('\u{10160}', '\u{10164}', 10, 1),
+        ('\u{10165}', '\u{10165}', 30, 1), ('\u{10166}', '\u{10169}', 50, 1), ('\u{1016a}',
+        '\u{1016a}', 100, 1), ('\u{1016b}', '\u{1016b}', 300, 1), ('\u{1016c}', '\u{10170}', 500,
+        1), ('\u{10171}', '\u{10171}', 1000, 1), ('\u{10172}', '\u{10172}', 5000, 1), ('\u{10173}',
+        '\u{10173}', 5, 1), ('\u{10174}', '\u{10174}', 50, 1), ('\u{10175}', '\u{10176}', 1, 2),
+        ('\u{10177}', '\u{10177}', 2, 3), ('\u{10178}', '\u{10178}', 3, 4), ('\u{1018a}',
+        '\u{1018a}', 0, 1), ('\u{1018b}', '\u{1018b}', 1, 4), ('\u{102e1}', '\u{102e1}', 1, 1),
+        ('\u{102e2}', '\u{102e2}', 2, 1), ('\u{102e3}', '\u{102e3}', 3, 1), ('\u{102e4}',
+        '\u{102e4}', 4, 1), ('\u{102e5}', '\u{102e5}', 5, 1), ('\u{102e6}', '\u{102e6}', 6, 1),
+        ('\u{102e7}', '\u{102e7}', 7, 1), ('\u{102e8}', '\u{102e8}', 8, 1), ('\u{102e9}',
+        '\u{102e9}', 9, 1), ('\u{102ea}', '\u{102ea}', 10, 1), ('\u{102eb}', '\u{102eb}', 20, 1),
+        ('\u{102ec}', '\u{102ec}', 30, 1), ('\u{102ed}', '\u{102ed}', 40, 1), ('\u{102ee}',
+        '\u{102ee}', 50, 1), ('\u{102ef}', '\u{102ef}', 60, 1), ('\u{102f0}', '\u{102f0}', 70, 1),
+        ('\u{102f1}', '\u{102f1}', 80, 1), ('\u{102f2}', '\u{102f2}', 90, 1), ('\u{102f3}',
+        '\u{102f3}', 100, 1), ('\u{102f4}', '\u{102f4}', 200, 1), ('\u{102f5}', '\u{102f5}', 300,
+        1), ('\u{102f6}', '\u{102f6}', 400, 1), ('\u{102f7}', '\u{102f7}', 500, 1), ('\u{102f8}',
+        '\u{102f8}', 600, 1), ('\u{102f9}', '\u{102f9}', 700, 1), ('\u{102fa}', '\u{102fa}', 800,
+        1), ('\u{102fb}', '\u{102fb}', 900, 1), ('\u{10320}', '\u{10320}', 1, 1), ('\u{10321}',
+        '\u{10321}', 5, 1), ('\u{10322}', '\u{10322}', 10, 1), ('\u{10323}', '\u{10323}', 50, 1),
+        ('\u{10341}', '\u{10341}', 90, 1), ('\u{1034a}', '\u{1034a}', 900, 1), ('\u{103d1}',
+        '\u{103d1}', 1, 1), ('\u{103d2}', '\u{103d2}', 2, 1), ('\u{103d3}', '\u{103d3}', 10, 1),
+        ('\u{103d4}', '\u{103d4}', 20, 1), ('\u{103d5}', '\u{103d5}', 100, 1), ('\u{104a0}',
+        '\u{104a0}', 0, 1), ('\u{104a1}', '\u{104a1}', 1, 1), ('\u{104a2}', '\u{104a2}', 2, 1),
+        ('\u{104a3}', '\u{104a3}', 3, 1), ('\u{104a4}', '\u{104a4}', 4, 1), ('\u{104a5}',
+        '\u{104a5}', 5, 1), ('\u{104a6}', '\u{104a6}', 6, 1), ('\u{104a7}', '\u{104a7}', 7, 1),
+        ('\u{104a8}', '\u{104a8}', 8, 1), ('\u{104a9}', '\u{104a9}', 9, 1), ('\u{10858}',
+        '\u{10858}', 1, 1), ('\u{10859}', '\u{10859}', 2, 1), ('\u{1085a}', '\u{1085a}', 3, 1),
+        ('\u{1085b}', '\u{1085b}', 10, 1), ('\u{1085c}', '\u{1085c}', 20, 1), ('\u{1085d}',
+        '\u{1085d}', 100, 1), ('\u{1085e}', '\u{1085e}', 1000, 1), ('\u{1085f}', '\u{1085f}', 10000,
+        1), ('\u{10879}', '\u{10879}', 1, 1), ('\u{1087a}', '\u{1087a}', 2, 1), ('\u{1087b}',
+        '\u{1087b}', 3, 1), ('\u{1087c}', '\u{1087c}', 4, 1), ('\u{1087d}', '\u{1087d}', 5, 1),
+        ('\u{1087e}', '\u{1087e}', 10, 1), ('\u{1087f}', '\u{1087f}', 20, 1), ('\u{108a7}',
+        '\u{108a7}', 1, 1), ('\u{108a8}', '\u{108a8}', 2, 1), ('\u{108a9}', '\u{108a9}', 3, 1),
+        ('\u{108aa}', '\u{108ab}', 4, 1), ('\u{108ac}', '\u{108ac}', 5, 1), ('\u{108ad}',
+        '\u{108ad}', 10, 1), ('\u{108ae}', '\u{108ae}', 20, 1), ('\u{108af}', '\u{108af}', 100, 1),
+        ('\u{10916}', '\u{10916}', 1, 1), ('\u{10917}', '\u{10917}', 10, 1), ('\u{10918}',
+        '\u{10918}', 20, 1), ('\u{10919}', '\u{10919}', 100, 1), ('\u{1091a}', '\u{1091a}', 2, 1),
+        ('\u{1091b}', '\u{1091b}', 3, 1), ('\u{10a40}', '\u{10a40}', 1, 1), ('\u{10a41}',
+        '\u{10a41}', 2, 1), ('\u{10a42}', '\u{10a42}', 3, 1), ('\u{10a43}', '\u{10a43}', 4, 1),
+        ('\u{10a44}', '\u{10a44}', 10, 1), ('\u{10a45}', '\u{10a45}', 20, 1), ('\u{10a46}',
+        '\u{10a46}', 100, 1), ('\u{10a47}', '\u{10a47}', 1000, 1), ('\u{10a7d}', '\u{10a7d}', 1, 1),
+        ('\u{10a7e}', '\u{10a7e}', 50, 1), ('\u{10a9d}', '\u{10a9d}', 1, 1), ('\u{10a9e}',
+        '\u{10a9e}', 10, 1), ('\u{10a9f}', '\u{10a9f}', 20, 1), ('\u{10aeb}', '\u{10aeb}', 1, 1),
+        ('\u{10aec}', '\u{10aec}', 5, 1), ('\u{10aed}', '\u{10aed}', 10, 1), ('\u{10aee}',
+        '\u{10aee}', 20, 1), ('\u{10aef}', '\u{10aef}', 100, 1), ('\u{10b58}', '\u{10b58}', 1, 1),
+        ('\u{10b59}', '\u{10b59}', 2, 1), ('\u{10b5a}', '\u{10b5a}', 3, 1), ('\u{10b5b}',
+        '\u{10b5b}', 4, 1), ('\u{10b5c}', '\u{10b5c}', 10, 1), ('\u{10b5d}', '\u{10b5d}', 20, 1),
+        ('\u{10b5e}', '\u{10b5e}', 100, 1), ('\u{10b5f}', '\u{10b5f}', 1000, 1), ('\u{10b78}',
+        '\u{10b78}', 1, 1), ('\u{10b79}', '\u{10b79}', 2, 1), ('\u{10b7a}', '\u{10b7a}', 3, 1),
+        ('\u{10b7b}', '\u{10b7b}', 4, 1), ('\u{10b7c}', '\u{10b7c}', 10, 1), ('\u{10b7d}',
+        '\u{10b7d}', 20, 1), ('\u{10b7e}', '\u{10b7e}', 100, 1), ('\u{10b7f}', '\u{10b7f}', 1000,
+        1), ('\u{10ba9}', '\u{10ba9}', 1, 1), ('\u{10baa}', '\u{10baa}', 2, 1), ('\u{10bab}',
+        '\u{10bab}', 3, 1), ('\u{10bac}', '\u{10bac}', 4, 1), ('\u{10bad}', '\u{10bad}', 10, 1),
+        ('\u{10bae}', '\u{10bae}', 20, 1), ('\u{10baf}', '\u{10baf}', 100, 1), ('\u{10e60}',
+        '\u{10e60}', 1, 1), ('\u{10e61}', '\u{10e61}', 2, 1), ('\u{10e62}', '\u{10e62}', 3, 1),
+        ('\u{10e63}', '\u{10e63}', 4, 1), ('\u{10e64}', '\u{10e64}', 5, 1), ('\u{10e65}',
+        '\u{10e65}', 6, 1), ('\u{10e66}', '\u{10e66}', 7, 1), ('\u{10e67}', '\u{10e67}', 8, 1),
+        ('\u{10e68}', '\u{10e68}', 9, 1), ('\u{10e69}', '\u{10e69}', 10, 1), ('\u{10e6a}',
+        '\u{10e6a}', 20, 1), ('\u{10e6b}', '\u{10e6b}', 30, 1), ('\u{10e6c}', '\u{10e6c}', 40, 1),
+        ('\u{10e6d}', '\u{10e6d}', 50, 1), ('\u{10e6e}', '\u{10e6e}', 60, 1), ('\u{10e6f}',
+        '\u{10e6f}', 70, 1), ('\u{10e70}', '\u{10e70}', 80, 1), ('\u{10e71}', '\u{10e71}', 90, 1),
+        ('\u{10e72}', '\u{10e72}', 100, 1), ('\u{10e73}', '\u{10e73}', 200, 1), ('\u{10e74}',
+        '\u{10e74}', 300, 1), ('\u{10e75}', '\u{10e75}', 400, 1), ('\u{10e76}', '\u{10e76}', 500,
+        1), ('\u{10e77}', '\u{10e77}', 600, 1), ('\u{10e78}', '\u{10e78}', 700, 1), ('\u{10e79}',
+        '\u{10e79}', 800, 1), ('\u{10e7a}', '\u{10e7a}', 900, 1), ('\u{10e7b}', '\u{10e7b}', 1, 2),
+        ('\u{10e7c}', '\u{10e7c}', 1, 4), ('\u{10e7d}', '\u{10e7d}', 1, 3), ('\u{10e7e}',
+        '\u{10e7e}', 2, 3), ('\u{11052}', '\u{11052}', 1, 1), ('\u{11053}', '\u{11053}', 2, 1),
+        ('\u{11054}', '\u{11054}', 3, 1), ('\u{11055}', '\u{11055}', 4, 1), ('\u{11056}',
+        '\u{11056}', 5, 1), ('\u{11057}', '\u{11057}', 6, 1), ('\u{11058}', '\u{11058}', 7, 1),
+        ('\u{11059}', '\u{11059}', 8, 1), ('\u{1105a}', '\u{1105a}', 9, 1), ('\u{1105b}',
+        '\u{1105b}', 10, 1), ('\u{1105c}', '\u{1105c}', 20, 1), ('\u{1105d}', '\u{1105d}', 30, 1),
+        ('\u{1105e}', '\u{1105e}', 40, 1), ('\u{1105f}', '\u{1105f}', 50, 1), ('\u{11060}',
+        '\u{11060}', 60, 1), ('\u{11061}', '\u{11061}', 70, 1), ('\u{11062}', '\u{11062}', 80, 1),
+        ('\u{11063}', '\u{11063}', 90, 1), ('\u{11064}', '\u{11064}', 100, 1), ('\u{11065}',
+        '\u{11065}', 1000, 1), ('\u{11066}', '\u{11066}', 0, 1), ('\u{11067}', '\u{11067}', 1, 1),
+        ('\u{11068}', '\u{11068}', 2, 1), ('\u{11069}', '\u{11069}', 3, 1), ('\u{1106a}',
+        '\u{1106a}', 4, 1), ('\u{1106b}', '\u{1106b}', 5, 1), ('\u{1106c}', '\u{1106c}', 6, 1),
+        ('\u{1106d}', '\u{1106d}', 7, 1), ('\u{1106e}', '\u{1106e}', 8, 1), ('\u{1106f}',
+        '\u{1106f}', 9, 1), ('\u{110f0}', '\u{110f0}', 0, 1), ('\u{110f1}', '\u{110f1}', 1, 1),
+        ('\u{110f2}', '\u{110f2}', 2, 1), ('\u{110f3}', '\u{110f3}', 3, 1), ('\u{110f4}',
+        '\u{110f4}', 4, 1), ('\u{110f5}', '\u{110f5}', 5, 1), ('\u{110f6}', '\u{110f6}', 6, 1),
+        ('\u{110f7}', '\u{110f7}', 7, 1), ('\u{110f8}', '\u{110f8}', 8, 1), ('\u{110f9}',
+        '\u{110f9}', 9, 1), ('\u{11136}', '\u{11136}', 0, 1), ('\u{11137}', '\u{11137}', 1, 1),
+        ('\u{11138}', '\u{11138}', 2, 1), ('\u{11139}', '\u{11139}', 3, 1), ('\u{1113a}',
+        '\u{1113a}', 4, 1), ('\u{1113b}', '\u{1113b}', 5, 1), ('\u{1113c}', '\u{1113c}', 6, 1),
+        ('\u{1113d}', '\u{1113d}', 7, 1), ('\u{1113e}', '\u{1113e}', 8, 1), ('\u{1113f}',
+        '\u{1113f}', 9, 1), ('\u{111d0}', '\u{111d0}', 0, 1), ('\u{111d1}', '\u{111d1}', 1, 1),
+        ('\u{111d2}', '\u{111d2}', 2, 1), ('\u{111d3}', '\u{111d3}', 3, 1), ('\u{111d4}',
+        '\u{111d4}', 4, 1), ('\u{111d5}', '\u{111d5}', 5, 1), ('\u{111d6}', '\u{111d6}', 6, 1),
+        ('\u{111d7}', '\u{111d7}', 7, 1), ('\u{111d8}', '\u{111d8}', 8, 1), ('\u{111d9}',
+        '\u{111d9}', 9, 1), ('\u{111e1}', '\u{111e1}', 1, 1), ('\u{111e2}', '\u{111e2}', 2, 1),
+        ('\u{111e3}', '\u{111e3}', 3, 1), ('\u{111e4}', '\u{111e4}', 4, 1), ('\u{111e5}',
+        '\u{111e5}', 5, 1), ('\u{111e6}', '\u{111e6}', 6, 1), ('\u{111e7}', '\u{111e7}', 7, 1),
+        ('\u{111e8}', '\u{111e8}', 8, 1), ('\u{111e9}', '\u{111e9}', 9, 1), ('\u{111ea}',
+        '\u{111ea}', 10, 1), ('\u{111eb}', '\u{111eb}', 20, 1), ('\u{111ec}', '\u{111ec}', 30, 1),
+        ('\u{111ed}', '\u{111ed}', 40, 1), ('\u{111ee}', '\u{111ee}', 50, 1), ('\u{111ef}',
+        '\u{111ef}', 60, 1), ('\u{111f0}', '\u{111f0}', 70, 1), ('\u{111f1}', '\u{111f1}', 80, 1),
+        ('\u{111f2}', '\u{111f2}', 90, 1), ('\u{111f3}', '\u{111f3}', 100, 1), ('\u{111f4}',
+        '\u{111f4}', 1000, 1), ('\u{112f0}', '\u{112f0}', 0, 1), ('\u{112f1}', '\u{112f1}', 1, 1),
+        ('\u{112f2}', '\u{112f2}', 2, 1), ('\u{112f3}', '\u{112f3}', 3, 1), ('\u{112f4}',
+        '\u{112f4}', 4, 1), ('\u{112f5}', '\u{112f5}', 5, 1), ('\u{112f6}', '\u{112f6}', 6, 1),
+        ('\u{112f7}', '\u{112f7}', 7, 1), ('\u{112f8}', '\u{112f8}', 8, 1), ('\u{112f9}',
+        '\u{112f9}', 9, 1), ('\u{114d0}', '\u{114d0}', 0, 1), ('\u{114d1}', '\u{114d1}', 1, 1),
+        ('\u{114d2}', '\u{114d2}', 2, 1), ('\u{114d3}', '\u{114d3}', 3, 1), ('\u{114d4}',
+        '\u{114d4}', 4, 1), ('\u{114d5}', '\u{114d5}', 5, 1), ('\u{114d6}', '\u{114d6}', 6, 1),
+        ('\u{114d7}', '\u{114d7}', 7, 1), ('\u{114d8}', '\u{114d8}', 8, 1), ('\u{114d9}',
+        '\u{114d9}', 9, 1), ('\u{11650}', '\u{11650}', 0, 1), ('\u{11651}', '\u{11651}', 1, 1),
+        ('\u{11652}', '\u{11652}', 2, 1), ('\u{11653}', '\u{11653}', 3, 1), ('\u{11654}',
+        '\u{11654}', 4, 1), ('\u{11655}', '\u{11655}', 5, 1), ('\u{11656}', '\u{11656}', 6, 1),
+        ('\u{11657}', '\u{11657}', 7, 1), ('\u{11658}', '\u{11658}', 8, 1), ('\u{11659}',
+        '\u{11659}', 9, 1), ('\u{116c0}', '\u{116c0}', 0, 1), ('\u{116c1}', '\u{116c1}', 1, 1),
+        ('\u{116c2}', '\u{116c2}', 2, 1), ('\u{116c3}', '\u{116c3}', 3, 1), ('\u{116c4}',
+        '\u{116c4}', 4, 1), ('\u{116c5}', '\u{116c5}', 5, 1), ('\u{116c6}', '\u{116c6}', 6, 1),
+        ('\u{116c7}', '\u{116c7}', 7, 1), ('\u{116c8}', '\u{116c8}', 8, 1), ('\u{116c9}',
+        '\u{116c9}', 9, 1), ('\u{118e0}', '\u{118e0}', 0, 1), ('\u{118e1}', '\u{118e1}', 1, 1),
+        ('\u{118e2}', '\u{118e2}', 2, 1), ('\u{118e3}', '\u{118e3}', 3, 1), ('\u{118e4}',
+        '\u{118e4}', 4, 1), ('\u{118e5}', '\u{118e5}', 5, 1), ('\u{118e6}', '\u{118e6}', 6, 1),
+        ('\u{118e7}', '\u{118e7}', 7, 1), ('\u{118e8}', '\u{118e8}', 8, 1), ('\u{118e9}',
+        '\u{118e9}', 9, 1), ('\u{118ea}', '\u{118ea}', 10, 1), ('\u{118eb}', '\u{118eb}', 20, 1),
+        ('\u{118ec}', '\u{118ec}', 30, 1), ('\u{118ed}', '\u{118ed}', 40, 1), ('\u{118ee}',
+        '\u{118ee}', 50, 1), ('\u{118ef}', '\u{118ef}', 60, 1), ('\u{118f0}', '\u{118f0}', 70, 1),
+        ('\u{118f1}', '\u{118f1}', 80, 1), ('\u{118f2}', '\u{118f2}', 90, 1), ('\u{12400}',
+        '\u{12400}', 2, 1), ('\u{12401}', '\u{12401}', 3, 1), ('\u{12402}', '\u{12402}', 4, 1),
+        ('\u{12403}', '\u{12403}', 5, 1), ('\u{12404}', '\u{12404}', 6, 1), ('\u{12405}',
+        '\u{12405}', 7, 1), ('\u{12406}', '\u{12406}', 8, 1), ('\u{12407}', '\u{12407}', 9, 1),
+        ('\u{12408}', '\u{12408}', 3, 1), ('\u{12409}', '\u{12409}', 4, 1), ('\u{1240a}',
+        '\u{1240a}', 5, 1), ('\u{1240b}', '\u{1240b}', 6, 1), ('\u{1240c}', '\u{1240c}', 7, 1),
+        ('\u{1240d}', '\u{1240d}', 8, 1), ('\u{1240e}', '\u{1240e}', 9, 1), ('\u{1240f}',
+        '\u{1240f}', 4, 1), ('\u{12410}', '\u{12410}', 5, 1), ('\u{12411}', '\u{12411}', 6, 1),
+        ('\u{12412}', '\u{12412}', 7, 1), ('\u{12413}', '\u{12413}', 8, 1), ('\u{12414}',
+        '\u{12414}', 9, 1), ('\u{12415}', '\u{12415}', 1, 1), ('\u{12416}', '\u{12416}', 2, 1),
+        ('\u{12417}', '\u{12417}', 3, 1), ('\u{12418}', '\u{12418}', 4, 1), ('\u{12419}',
+        '\u{12419}', 5, 1), ('\u{1241a}', '\u{1241a}', 6, 1), ('\u{1241b}', '\u{1241b}', 7, 1),
+        ('\u{1241c}', '\u{1241c}', 8, 1), ('\u{1241d}', '\u{1241d}', 9, 1), ('\u{1241e}',
+        '\u{1241e}', 1, 1), ('\u{1241f}', '\u{1241f}', 2, 1), ('\u{12420}', '\u{12420}', 3, 1),
+        ('\u{12421}', '\u{12421}', 4, 1), ('\u{12422}', '\u{12422}', 5, 1), ('\u{12423}',
+        '\u{12423}', 2, 1), ('\u{12424}', '\u{12425}', 3, 1), ('\u{12426}', '\u{12426}', 4, 1),
+        ('\u{12427}', '\u{12427}', 5, 1), ('\u{12428}', '\u{12428}', 6, 1), ('\u{12429}',
+        '\u{12429}', 7, 1), ('\u{1242a}', '\u{1242a}', 8, 1), ('\u{1242b}', '\u{1242b}', 9, 1),
+        ('\u{1242c}', '\u{1242c}', 1, 1), ('\u{1242d}', '\u{1242d}', 2, 1), ('\u{1242e}',
+        '\u{1242f}', 3, 1), ('\u{12430}', '\u{12430}', 4, 1), ('\u{12431}', '\u{12431}', 5, 1),
+        ('\u{12432}', '\u{12432}', 216000, 1), ('\u{12433}', '\u{12433}', 432000, 1), ('\u{12434}',
+        '\u{12434}', 1, 1), ('\u{12435}', '\u{12435}', 2, 1), ('\u{12436}', '\u{12437}', 3, 1),
+        ('\u{12438}', '\u{12438}', 4, 1), ('\u{12439}', '\u{12439}', 5, 1), ('\u{1243a}',
+        '\u{1243b}', 3, 1), ('\u{1243c}', '\u{1243f}', 4, 1), ('\u{12440}', '\u{12440}', 6, 1),
+        ('\u{12441}', '\u{12443}', 7, 1), ('\u{12444}', '\u{12445}', 8, 1), ('\u{12446}',
+        '\u{12449}', 9, 1), ('\u{1244a}', '\u{1244a}', 2, 1), ('\u{1244b}', '\u{1244b}', 3, 1),
+        ('\u{1244c}', '\u{1244c}', 4, 1), ('\u{1244d}', '\u{1244d}', 5, 1), ('\u{1244e}',
+        '\u{1244e}', 6, 1), ('\u{1244f}', '\u{1244f}', 1, 1), ('\u{12450}', '\u{12450}', 2, 1),
+        ('\u{12451}', '\u{12451}', 3, 1), ('\u{12452}', '\u{12453}', 4, 1), ('\u{12454}',
+        '\u{12455}', 5, 1), ('\u{12456}', '\u{12456}', 2, 1), ('\u{12457}', '\u{12457}', 3, 1),
+        ('\u{12458}', '\u{12458}', 1, 1), ('\u{12459}', '\u{12459}', 2, 1), ('\u{1245a}',
+        '\u{1245a}', 1, 3), ('\u{1245b}', '\u{1245b}', 2, 3), ('\u{1245c}', '\u{1245c}', 5, 6),
+        ('\u{1245d}', '\u{1245d}', 1, 3), ('\u{1245e}', '\u{1245e}', 2, 3), ('\u{1245f}',
+        '\u{1245f}', 1, 8), ('\u{12460}', '\u{12460}', 1, 4), ('\u{12461}', '\u{12461}', 1, 6),
+        ('\u{12462}', '\u{12463}', 1, 4), ('\u{12464}', '\u{12464}', 1, 2), ('\u{12465}',
+        '\u{12465}', 1, 3), ('\u{12466}', '\u{12466}', 2, 3), ('\u{12467}', '\u{12467}', 40, 1),
+        ('\u{12468}', '\u{12468}', 50, 1), ('\u{12469}', '\u{12469}', 4, 1), ('\u{1246a}',
+        '\u{1246a}', 5, 1), ('\u{1246b}', '\u{1246b}', 6, 1), ('\u{1246c}', '\u{1246c}', 7, 1),
+        ('\u{1246d}', '\u{1246d}', 8, 1), ('\u{1246e}', '\u{1246e}', 9, 1), ('\u{16a60}',
+        '\u{16a60}', 0, 1), ('\u{16a61}', '\u{16a61}', 1, 1), ('\u{16a62}', '\u{16a62}', 2, 1),
+        ('\u{16a63}', '\u{16a63}', 3, 1), ('\u{16a64}', '\u{16a64}', 4, 1), ('\u{16a65}',
+        '\u{16a65}', 5, 1), ('\u{16a66}', '\u{16a66}', 6, 1), ('\u{16a67}', '\u{16a67}', 7, 1),
+        ('\u{16a68}', '\u{16a68}', 8, 1), ('\u{16a69}', '\u{16a69}', 9, 1), ('\u{16b50}',
+        '\u{16b50}', 0, 1), ('\u{16b51}', '\u{16b51}', 1, 1), ('\u{16b52}', '\u{16b52}', 2, 1),
+        ('\u{16b53}', '\u{16b53}', 3, 1), ('\u{16b54}', '\u{16b54}', 4, 1), ('\u{16b55}',
+        '\u{16b55}', 5, 1), ('\u{16b56}', '\u{16b56}', 6, 1), ('\u{16b57}', '\u{16b57}', 7, 1),
+        ('\u{16b58}', '\u{16b58}', 8, 1), ('\u{16b59}', '\u{16b59}', 9, 1), ('\u{16b5b}',
+        '\u{16b5b}', 10, 1), ('\u{16b5c}', '\u{16b5c}', 100, 1), ('\u{16b5d}', '\u{16b5d}', 10000,
+        1), ('\u{16b5e}', '\u{16b5e}', 1000000, 1), ('\u{16b5f}', '\u{16b5f}', 100000000, 1),
+        ('\u{16b60}', '\u{16b60}', 10000000000, 1), ('\u{16b61}', '\u{16b61}', 1000000000000, 1),
+        ('\u{1d360}', '\u{1d360}', 1, 1), ('\u{1d361}', '\u{1d361}', 2, 1), ('\u{1d362}',
+        '\u{1d362}', 3, 1), ('\u{1d363}', '\u{1d363}', 4, 1), ('\u{1d364}', '\u{1d364}', 5, 1),
+        ('\u{1d365}', '\u{1d365}', 6, 1), ('\u{1d366}', '\u{1d366}', 7, 1), ('\u{1d367}',
+        '\u{1d367}', 8, 1), ('\u{1d368}', '\u{1d368}', 9, 1), ('\u{1d369}', '\u{1d369}', 10, 1),
+        ('\u{1d36a}', '\u{1d36a}', 20, 1), ('\u{1d36b}', '\u{1d36b}', 30, 1), ('\u{1d36c}',
+        '\u{1d36c}', 40, 1), ('\u{1d36d}', '\u{1d36d}', 50, 1), ('\u{1d36e}', '\u{1d36e}', 60, 1),
+        ('\u{1d36f}', '\u{1d36f}', 70, 1), ('\u{1d370}', '\u{1d370}', 80, 1), ('\u{1d371}',
+        '\u{1d371}', 90, 1), ('\u{1d7ce}', '\u{1d7ce}', 0, 1), ('\u{1d7cf}', '\u{1d7cf}', 1, 1),
+        ('\u{1d7d0}', '\u{1d7d0}', 2, 1), ('\u{1d7d1}', '\u{1d7d1}', 3, 1), ('\u{1d7d2}',
+        '\u{1d7d2}', 4, 1), ('\u{1d7d3}', '\u{1d7d3}', 5, 1), ('\u{1d7d4}', '\u{1d7d4}', 6, 1),
+        ('\u{1d7d5}', '\u{1d7d5}', 7, 1), ('\u{1d7d6}', '\u{1d7d6}', 8, 1), ('\u{1d7d7}',
+        '\u{1d7d7}', 9, 1), ('\u{1d7d8}', '\u{1d7d8}', 0, 1), ('\u{1d7d9}', '\u{1d7d9}', 1, 1),
+        ('\u{1d7da}', '\u{1d7da}', 2, 1), ('\u{1d7db}', '\u{1d7db}', 3, 1), ('\u{1d7dc}',
+        '\u{1d7dc}', 4, 1), ('\u{1d7dd}', '\u{1d7dd}', 5, 1), ('\u{1d7de}', '\u{1d7de}', 6, 1),
+        ('\u{1d7df}', '\u{1d7df}', 7, 1), ('\u{1d7e0}', '\u{1d7e0}', 8, 1), ('\u{1d7e1}',
+        '\u{1d7e1}', 9, 1), ('\u{1d7e2}', '\u{1d7e2}', 0, 1), ('\u{1d7e3}', '\u{1d7e3}', 1, 1),
+        ('\u{1d7e4}', '\u{1d7e4}', 2, 1), ('\u{1d7e5}', '\u{1d7e5}', 3, 1), ('\u{1d7e6}',
+        '\u{1d7e6}', 4, 1), ('\u{1d7e7}', '\u{1d7e7}', 5, 1), ('\u{1d7e8}', '\u{1d7e8}', 6, 1),
+        ('\u{1d7e9}', '\u{1d7e9}', 7, 1), ('\u{1d7ea}', '\u{1d7ea}', 8, 1), ('\u{1d7eb}',
+        '\u{1d7eb}', 9, 1), ('\u{1d7ec}', '\u{1d7ec}', 0, 1), ('\u{1d7ed}', '\u{1d7ed}', 1, 1),
+        ('\u{1d7ee}', '\u{1d7ee}', 2, 1), ('\u{1d7ef}', '\u{1d7ef}', 3, 1), ('\u{1d7f0}',
+        '\u{1d7f0}', 4, 1), ('\u{1d7f1}', '\u{1d7f1}', 5, 1), ('\u{1d7f2}', '\u{1d7f2}', 6, 1),
+        ('\u{1d7f3}', '\u{1d7f3}', 7, 1), ('\u{1d7f4}', '\u{1d7f4}', 8, 1), ('\u{1d7f5}',
+        '\u{1d7f5}', 9, 1), ('\u{1d7f6}', '\u{1d7f6}', 0, 1), ('\u{1d7f7}', '\u{1d7f7}', 1, 1),
+        ('\u{1d7f8}', '\u{1d7f8}', 2, 1), ('\u{1d7f9}', '\u{1d7f9}', 3, 1), ('\u{1d7fa}',
+        '\u{1d7fa}', 4, 1), ('\u{1d7fb}', '\u{1d7fb}', 5, 1), ('\u{1d7fc}', '\u{1d7fc}', 6, 1),
+        ('\u{1d7fd}', '\u{1d7fd}', 7, 1), ('\u{1d7fe}', '\u{1d7fe}', 8, 1), ('\u{1d7ff}',
+        '\u{1d7ff}', 9, 1), ('\u{1e8c7}', '\u{1e8c7}', 1, 1), ('\u{1e8c8}', '\u{1e8c8}', 2, 1),
+        ('\u{1e8c9}', '\u{1e8c9}', 3, 1), ('\u{1e8ca}', '\u{1e8ca}', 4, 1), ('\u{1e8cb}',
+        '\u{1e8cb}', 5, 1), ('\u{1e8cc}', '\u{1e8cc}', 6, 1), ('\u{1e8cd}', '\u{1e8cd}', 7, 1),
+        ('\u{1e8ce}', '\u{1e8ce}', 8, 1), ('\u{1e8cf}', '\u{1e8cf}', 9, 1), ('\u{1f100}',
+        '\u{1f101}', 0, 1), ('\u{1f102}', '\u{1f102}', 1, 1), ('\u{1f103}', '\u{1f103}', 2, 1),
+        ('\u{1f104}', '\u{1f104}', 3, 1), ('\u{1f105}', '\u{1f105}', 4, 1), ('\u{1f106}',
+        '\u{1f106}', 5, 1), ('\u{1f107}', '\u{1f107}', 6, 1), ('\u{1f108}', '\u{1f108}', 7, 1),
+        ('\u{1f109}', '\u{1f109}', 8, 1), ('\u{1f10a}', '\u{1f10a}', 9, 1), ('\u{1f10b}',
+        '\u{1f10c}', 0, 1), ('\u{20001}', '\u{20001}', 7, 1), ('\u{20064}', '\u{20064}', 4, 1),
+        ('\u{200e2}', '\u{200e2}', 4, 1), ('\u{20121}', '\u{20121}', 5, 1), ('\u{2092a}',
+        '\u{2092a}', 1, 1), ('\u{20983}', '\u{20983}', 30, 1), ('\u{2098c}', '\u{2098c}', 40, 1),
+        ('\u{2099c}', '\u{2099c}', 40, 1), ('\u{20aea}', '\u{20aea}', 6, 1), ('\u{20afd}',
+        '\u{20afd}', 3, 1), ('\u{20b19}', '\u{20b19}', 3, 1), ('\u{22390}', '\u{22390}', 2, 1),
+        ('\u{22998}', '\u{22998}', 3, 1), ('\u{23b1b}', '\u{23b1b}', 3, 1), ('\u{2626d}',
+        '\u{2626d}', 4, 1), ('\u{2f890}', '\u{2f890}', 9, 1)
+    ];
+
 }
 
 pub mod charwidth {
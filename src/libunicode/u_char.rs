@@ -13,8 +13,19 @@
 //! These methods implement functionality for `char` that requires knowledge of
 //! Unicode definitions, including normalization, categorization, and display information.
 
+use core::char::Char;
+use core::iter::Iterator;
 use core::option::Option;
+use core::option::Option::{Some, None};
+use core::slice::SlicePrelude;
 use tables::{derived_property, property, general_category, conversions, charwidth};
+use tables::emoji;
+use tables::numeric;
+use tables::normalization;
+use tables::bidi;
+use tables::bidi::BidiClass;
+use tables::category;
+use tables::category::GeneralCategory;
 
 /// Returns whether the specified `char` is considered a Unicode alphabetic
 /// code point
@@ -223,9 +234,98 @@ pub trait UnicodeChar {
     /// Category `Cc`.
     fn is_control(self) -> bool;
 
+    /// Indicates whether a character is a combining mark.
+    ///
+    /// Combining marks are those in the Unicode General Categories `Mn`,
+    /// `Mc` and `Me`, which combine with a preceding base character.
+    fn is_combining_mark(self) -> bool;
+
     /// Indicates whether the character is numeric (Nd, Nl, or No).
     fn is_numeric(self) -> bool;
 
+    /// Converts a character to the corresponding digit, recognizing the
+    /// decimal digits of every script as well as ASCII ones.
+    ///
+    /// The characters `0-9`, `a-z` and `A-Z` are converted as by
+    /// `Char::to_digit()`. Other characters are only accepted if they are in
+    /// the Unicode General Category `Nd`, every member of which has a
+    /// decimal value from 0 to 9, such as the fullwidth digits `０-９`.
+    ///
+    /// # Return value
+    ///
+    /// If `c` is between '0' and '9', the corresponding value
+    /// between 0 and 9. If `c` is 'a' or 'A', 10. If `c` is
+    /// 'b' or 'B', 11, etc. If `c` is another `Nd` character, its decimal
+    /// value. Returns none if the value is not less than `radix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given a `radix` > 36.
+    fn to_digit_unicode(self, radix: uint) -> Option<uint>;
+
+    /// Returns the Unicode Numeric_Value of the character.
+    ///
+    /// Besides the decimal digits, this covers characters such as the
+    /// vulgar fraction `'½'` (0.5), the Roman numeral `'Ⅻ'` (12) and the
+    /// CJK numeral `'万'` (10000).
+    ///
+    /// # Return value
+    ///
+    /// Returns `None` if the character has no numeric value.
+    fn numeric_value(self) -> Option<f64>;
+
+    /// Returns the Unicode Numeric_Value of the character if it is an
+    /// integer.
+    ///
+    /// # Return value
+    ///
+    /// Returns `None` if the character has no numeric value, or if its
+    /// value is a fraction such as that of `'½'`.
+    fn numeric_value_integer(self) -> Option<i64>;
+
+    /// Returns the Unicode General_Category of the character.
+    ///
+    /// Unassigned code points are in the `Cn` category. No `char` is ever in
+    /// the `Cs` (surrogate) category.
+    fn general_category(self) -> GeneralCategory;
+
+    /// Returns the Unicode Canonical_Combining_Class of the character.
+    ///
+    /// This is 0 for starters, which includes most characters, and nonzero
+    /// for combining marks, such as 230 for marks placed above the base
+    /// character. It is used to put marks into canonical order during
+    /// normalization.
+    fn canonical_combining_class(self) -> u8;
+
+    /// Returns the character that mirrors this one in right-to-left text.
+    ///
+    /// This is the Unicode Bidi_Mirroring_Glyph property, which maps paired
+    /// characters such as `(` and `)` onto each other. Returns `None` for
+    /// characters without a mirroring glyph, including mirrored characters
+    /// like `∛` that have no counterpart.
+    fn bidi_mirror(self) -> Option<char>;
+
+    /// Returns the Unicode Bidi_Class of the character.
+    ///
+    /// This is the directionality used by the Unicode Bidirectional
+    /// Algorithm (UAX #9), such as `L` for Latin letters, `AL` for Arabic
+    /// letters and `EN` for European digits.
+    fn bidi_class(self) -> BidiClass;
+
+    /// Returns whether the specified character satisfies the 'Emoji'
+    /// property from [UTS #51](http://unicode.org/reports/tr51/).
+    ///
+    /// Besides pictographs, this includes characters like `#`, `*` and the
+    /// ASCII digits, which form emoji keycap sequences. Only characters
+    /// assigned in the Unicode version given by `UNICODE_VERSION` are
+    /// included.
+    fn is_emoji(self) -> bool;
+
+    /// Returns whether the specified character satisfies the
+    /// 'Emoji_Presentation' property, i.e. is displayed as an emoji rather
+    /// than as text by default.
+    fn is_emoji_presentation(self) -> bool;
+
     /// Converts a character to its lowercase equivalent.
     ///
     /// The case-folding performed is the common or simple mapping. See
@@ -259,6 +359,57 @@ pub trait UnicodeChar {
     /// [2]: http://www.unicode.org/versions/Unicode4.0.0/ch03.pdf#G33992
     fn to_uppercase(self) -> char;
 
+    /// Returns an iterator over the full lowercase equivalent of the
+    /// character.
+    ///
+    /// Unlike `to_lowercase()`, this also considers the unconditional mappings
+    /// in [`SpecialCasing.txt`], which may expand one character to several:
+    /// `'İ'` becomes `"i\u{307}"`. Mappings that depend on context or
+    /// language are not applied.
+    ///
+    /// [`SpecialCasing.txt`]: ftp://ftp.unicode.org/Public/UNIDATA/SpecialCasing.txt
+    fn to_lowercase_full(self) -> ToLowercase;
+
+    /// Returns an iterator over the full uppercase equivalent of the
+    /// character.
+    ///
+    /// Unlike `to_uppercase()`, this also considers the unconditional mappings
+    /// in [`SpecialCasing.txt`], which may expand one character to several:
+    /// `'ß'` becomes `"SS"` and `'ﬁ'` becomes `"FI"`. Mappings that depend
+    /// on context or language are not applied.
+    ///
+    /// [`SpecialCasing.txt`]: ftp://ftp.unicode.org/Public/UNIDATA/SpecialCasing.txt
+    fn to_uppercase_full(self) -> ToUppercase;
+
+    /// Converts a character to its titlecase equivalent.
+    ///
+    /// This is the simple mapping from the Unicode database. It only differs
+    /// from `to_uppercase()` for a handful of characters, most notably the
+    /// Latin digraphs: `'ǆ'` becomes `'ǅ'` rather than `'Ǆ'`.
+    ///
+    /// # Return value
+    ///
+    /// Returns the titlecase equivalent of the character, or its uppercase
+    /// equivalent if the character has no distinct titlecase mapping.
+    fn to_titlecase(self) -> char;
+
+    /// Returns the case-folded form of a character.
+    ///
+    /// This is the simple case folding from the Unicode database, made up of
+    /// the common (C) and simple (S) mappings in `CaseFolding.txt`. Unlike
+    /// `to_lowercase()` it is meant for caseless matching rather than for
+    /// display: for instance `'ς'` folds to `'σ'`, and `'ẞ'` to `'ß'`.
+    ///
+    /// # Return value
+    ///
+    /// Returns the folded character, or the character itself if it has no
+    /// folding.
+    fn fold_case(self) -> char;
+
+    /// Checks whether two characters are equal under simple case folding,
+    /// i.e. whether `fold_case()` gives the same character for both.
+    fn eq_ignore_case(self, other: char) -> bool;
+
     /// Returns this character's displayed width in columns, or `None` if it is a
     /// control character other than `'\x00'`.
     ///
@@ -317,10 +468,27 @@ impl UnicodeChar for char {
     }
 
     fn is_alphanumeric(self) -> bool {
-        self.is_alphabetic() || self.is_numeric()
+        match self {
+            'a' ... 'z' | 'A' ... 'Z' | '0' ... '9' => true,
+            c if c > '\x7f' => derived_property::Alphabetic(c) || general_category::N(c),
+            _ => false
+        }
+    }
+
+    #[inline]
+    fn is_control(self) -> bool {
+        // Cc is exactly the C0 and C1 control blocks, plus DEL
+        match self {
+            '\x00' ... '\x1f' | '\x7f' ... '\u{9f}' => true,
+            _ => false
+        }
     }
 
-    fn is_control(self) -> bool { general_category::Cc(self) }
+    #[inline]
+    fn is_combining_mark(self) -> bool {
+        // there are no marks in ASCII
+        self > '\x7f' && general_category::M(self)
+    }
 
     fn is_numeric(self) -> bool {
         match self {
@@ -330,10 +498,185 @@ impl UnicodeChar for char {
         }
     }
 
-    fn to_lowercase(self) -> char { conversions::to_lower(self) }
+    fn to_digit_unicode(self, radix: uint) -> Option<uint> {
+        // `to_digit` checks the radix for every character
+        match self.to_digit(radix) {
+            None if self > '\x7f' => match decimal_value(self) {
+                // as for `to_digit`, radices 0 and 1 have no digits
+                Some(val) if radix >= 2 && val < radix => Some(val),
+                _ => None
+            },
+            val => val
+        }
+    }
 
-    fn to_uppercase(self) -> char { conversions::to_upper(self) }
+    fn numeric_value(self) -> Option<f64> {
+        match self {
+            '0' ... '9' => Some((self as u8 - b'0') as f64),
+            c if c > '\x7f' => numeric::numeric_value(c).map(|(num, den)| {
+                num as f64 / den as f64
+            }),
+            _ => None
+        }
+    }
+
+    fn numeric_value_integer(self) -> Option<i64> {
+        match self {
+            '0' ... '9' => Some((self as u8 - b'0') as i64),
+            c if c > '\x7f' => match numeric::numeric_value(c) {
+                Some((num, 1)) => Some(num),
+                _ => None
+            },
+            _ => None
+        }
+    }
+
+    fn general_category(self) -> GeneralCategory { category::general_category(self) }
+
+    fn canonical_combining_class(self) -> u8 {
+        normalization::canonical_combining_class(self)
+    }
+
+    fn bidi_mirror(self) -> Option<char> { bidi::mirror(self) }
+
+    fn bidi_class(self) -> BidiClass { bidi::bidi_class(self) }
+
+    fn is_emoji(self) -> bool { emoji::Emoji(self) }
+
+    fn is_emoji_presentation(self) -> bool { emoji::Emoji_Presentation(self) }
+
+    fn to_lowercase(self) -> char {
+        match self {
+            'A' ... 'Z' => (self as u8 + b'a' - b'A') as char,
+            c if c > '\x7f' => conversions::to_lower(c),
+            _ => self
+        }
+    }
+
+    fn to_uppercase(self) -> char {
+        match self {
+            'a' ... 'z' => (self as u8 - b'a' + b'A') as char,
+            c if c > '\x7f' => conversions::to_upper(c),
+            _ => self
+        }
+    }
+
+    fn to_titlecase(self) -> char {
+        match self {
+            c if c > '\x7f' => conversions::to_title(c),
+            _ => self.to_uppercase()
+        }
+    }
+
+    fn fold_case(self) -> char {
+        match self {
+            'A' ... 'Z' => (self as u8 + b'a' - b'A') as char,
+            c if c > '\x7f' => conversions::fold_case(c),
+            _ => self
+        }
+    }
+
+    fn eq_ignore_case(self, other: char) -> bool {
+        self == other || self.fold_case() == other.fold_case()
+    }
+
+    fn to_lowercase_full(self) -> ToLowercase {
+        let inner = match conversions::to_lower_full(self) {
+            Some(chars) => CaseMappingIter::Many(chars),
+            None => CaseMappingIter::One(self.to_lowercase())
+        };
+        ToLowercase { inner: inner }
+    }
+
+    fn to_uppercase_full(self) -> ToUppercase {
+        let inner = match conversions::to_upper_full(self) {
+            Some(chars) => CaseMappingIter::Many(chars),
+            None => CaseMappingIter::One(self.to_uppercase())
+        };
+        ToUppercase { inner: inner }
+    }
 
     #[experimental = "needs expert opinion. is_cjk flag stands out as ugly"]
     fn width(self, is_cjk: bool) -> Option<uint> { charwidth::width(self, is_cjk) }
 }
+
+// Returns the decimal value of a character in the Nd category. Nd is made
+// up of contiguous runs of ten digits, from zero to nine, so the value is
+// the offset into the run.
+fn decimal_value(c: char) -> Option<uint> {
+    use core::cmp::Ordering::{Equal, Less, Greater};
+    let table = general_category::Nd_table;
+    table.binary_search(|&(lo, hi)| {
+        if lo <= c && c <= hi { Equal }
+        else if hi < c { Less }
+        else { Greater }
+    }).found().map(|idx| {
+        let (lo, _) = table[idx];
+        (c as uint - lo as uint) % 10
+    })
+}
+
+/// An iterator over the full lowercase mapping of a `char`, as returned by
+/// `UnicodeChar::to_lowercase_full`.
+#[deriving(Clone)]
+pub struct ToLowercase {
+    inner: CaseMappingIter,
+}
+
+impl Iterator<char> for ToLowercase {
+    #[inline]
+    fn next(&mut self) -> Option<char> { self.inner.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.inner.size_hint() }
+}
+
+/// An iterator over the full uppercase mapping of a `char`, as returned by
+/// `UnicodeChar::to_uppercase_full`.
+#[deriving(Clone)]
+pub struct ToUppercase {
+    inner: CaseMappingIter,
+}
+
+impl Iterator<char> for ToUppercase {
+    #[inline]
+    fn next(&mut self) -> Option<char> { self.inner.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.inner.size_hint() }
+}
+
+#[deriving(Clone)]
+enum CaseMappingIter {
+    One(char),
+    Many(&'static [char]),
+    Done,
+}
+
+impl Iterator<char> for CaseMappingIter {
+    fn next(&mut self) -> Option<char> {
+        match *self {
+            CaseMappingIter::One(c) => {
+                *self = CaseMappingIter::Done;
+                Some(c)
+            }
+            CaseMappingIter::Many(chars) => match chars.head() {
+                Some(&c) => {
+                    *self = CaseMappingIter::Many(chars.tail());
+                    Some(c)
+                }
+                None => None
+            },
+            CaseMappingIter::Done => None
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let n = match *self {
+            CaseMappingIter::One(_) => 1,
+            CaseMappingIter::Many(chars) => chars.len(),
+            CaseMappingIter::Done => 0
+        };
+        (n, Some(n))
+    }
+}